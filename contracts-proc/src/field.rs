@@ -0,0 +1,23 @@
+//! Parsing of the `field = "name"` argument shared by modifier-like
+//! attributes, which name the component field the injected check is called
+//! on.
+
+use proc_macro2::Ident;
+use syn::{parse::ParseStream, LitStr, Result, Token};
+
+/// Parses an optional `field = "name"` argument, falling back to `default`
+/// when the input is empty.
+pub(crate) fn parse_field(input: ParseStream, default: &str) -> Result<Ident> {
+    if input.is_empty() {
+        return Ok(Ident::new(default, input.span()));
+    }
+
+    let ident: Ident = input.parse()?;
+    if ident == "field" {
+        let _: Token![=] = input.parse()?;
+        let lit: LitStr = input.parse()?;
+        lit.parse()
+    } else {
+        error!(@ident, "expected identifier 'field'")
+    }
+}
@@ -83,8 +83,8 @@ pub(crate) fn interface_id(
 }
 
 /// Contains arguments of the `#[selector(..)]` attribute.
-struct SelectorArgs {
-    name: String,
+pub(crate) struct SelectorArgs {
+    pub(crate) name: String,
 }
 
 impl Parse for SelectorArgs {
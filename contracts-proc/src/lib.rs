@@ -17,7 +17,15 @@ macro_rules! error {
     }};
 }
 
+mod field;
 mod interface_id;
+mod namespace;
+mod non_reentrant;
+mod only_owner;
+mod only_role;
+mod pausable;
+mod selectors;
+mod solidity_error;
 
 /// Computes the interface id as an associated constant `INTERFACE_ID` for the
 /// trait that describes contract's abi.
@@ -62,3 +70,271 @@ mod interface_id;
 pub fn interface_id(attr: TokenStream, input: TokenStream) -> TokenStream {
     interface_id::interface_id(&attr, input)
 }
+
+/// Binds a storage struct to an [ERC-7201] namespace, by implementing the
+/// `Namespace` trait with the provided namespace id.
+///
+/// Wrapping the struct in `Namespaced` then places it at the root slot of its
+/// namespace, instead of following the layout of its parent.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[namespace(id = "openzeppelin.storage.ERC20")]
+/// #[storage]
+/// pub struct Erc20Storage {
+///     balances: StorageMap<Address, StorageU256>,
+///     total_supply: StorageU256,
+/// }
+///
+/// sol_storage! {
+///     #[entrypoint]
+///     struct Token {
+///         Namespaced<Erc20Storage> erc20;
+///     }
+/// }
+/// ```
+///
+/// [ERC-7201]: https://eips.ethereum.org/EIPS/eip-7201
+#[proc_macro_attribute]
+pub fn namespace(attr: TokenStream, input: TokenStream) -> TokenStream {
+    namespace::namespace(attr, input)
+}
+
+/// Restricts a `#[public]` method to the owner of the contract, by injecting
+/// the `self.ownable.only_owner()?;` check at the top of its body.
+///
+/// The method should return a `Result` whose error type can be converted
+/// from the `Ownable` error, e.g. `Vec<u8>`.
+///
+/// The `Ownable` field is expected to be named `ownable`. Another name can be
+/// provided with `#[only_owner(field = "name")]`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[public]
+/// #[inherit(Erc20, Ownable)]
+/// impl OwnableExample {
+///     #[only_owner]
+///     pub fn transfer(
+///         &mut self,
+///         to: Address,
+///         value: U256,
+///     ) -> Result<(), Vec<u8>> {
+///         self.erc20.transfer(to, value)?;
+///         Ok(())
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn only_owner(attr: TokenStream, input: TokenStream) -> TokenStream {
+    only_owner::only_owner(attr, input)
+}
+
+/// Restricts a `#[public]` method to the accounts that have been granted a
+/// role, by injecting the `self.access.only_role(ROLE.into())?;` check at the
+/// top of its body.
+///
+/// The role is a constant convertible into a `B256`, e.g. a `[u8; 32]` role
+/// identifier. It can be passed either as a path or as a string naming the
+/// constant. The method should return a `Result` whose error type can be
+/// converted from the `AccessControl` error, e.g. `Vec<u8>`.
+///
+/// The `AccessControl` field is expected to be named `access`. Another name
+/// can be provided with `#[only_role(ROLE, field = "name")]`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[public]
+/// #[inherit(Erc20, AccessControl)]
+/// impl AccessControlExample {
+///     #[only_role(TRANSFER_ROLE)]
+///     pub fn transfer_from(
+///         &mut self,
+///         from: Address,
+///         to: Address,
+///         value: U256,
+///     ) -> Result<bool, Vec<u8>> {
+///         let transfer_result = self.erc20.transfer_from(from, to, value)?;
+///         Ok(transfer_result)
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn only_role(attr: TokenStream, input: TokenStream) -> TokenStream {
+    only_role::only_role(attr, input)
+}
+
+/// Prevents a `#[public]` method from being called while any method guarded
+/// by the same `ReentrancyGuard` is being executed, e.g. through a reentrant
+/// call.
+///
+/// The body of the method is wrapped with the guard's enter and exit, and the
+/// guard is released on every path out of it, including early returns and
+/// errors. The method should take `&mut self` and return a `Result` whose
+/// error type can be converted from the `ReentrancyGuard` error, e.g.
+/// `Vec<u8>`.
+///
+/// The `ReentrancyGuard` field is expected to be named `reentrancy_guard`.
+/// Another name can be provided with `#[non_reentrant(field = "name")]`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[public]
+/// impl Vault {
+///     #[non_reentrant]
+///     pub fn withdraw(&mut self, amount: U256) -> Result<(), Vec<u8>> {
+///         self._debit(msg::sender(), amount)?;
+///         call::transfer_eth(self, msg::sender(), amount)?;
+///         Ok(())
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn non_reentrant(attr: TokenStream, input: TokenStream) -> TokenStream {
+    non_reentrant::non_reentrant(attr, input)
+}
+
+/// Lists the selectors exported by a `#[public]` implementation in the
+/// `Selectors` trait, and fails to compile if two of its methods export the
+/// same selector, or if two contracts of its `#[inherit(..)]` attribute do
+/// and it doesn't override that selector itself.
+///
+/// The attribute should be placed above `#[public]`, and every inherited
+/// contract should implement `Selectors` as well. Selectors are computed like
+/// `#[public]` does, i.e. from the camel-cased name of the method, or the one
+/// of its `#[selector(name = "..")]` attribute, and its argument types.
+///
+/// The attribute also implements the `Interface` trait, listing the Solidity
+/// functions of the methods, and the events and errors passed as
+/// `#[selectors(events(..), errors(..))]`, which should be declared in a
+/// `sol!` block with `#![sol(abi)]`. The functions, events and errors of the
+/// inherited contracts follow, so that `export` prints the interface of the
+/// whole inheritance chain.
+///
+/// Generic implementations are supported as long as they don't inherit other
+/// contracts.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[selectors(events(Transfer, Approval), errors(ERC20InvalidSender))]
+/// #[public]
+/// impl IErc20 for Erc20 {
+///     // ...
+/// }
+///
+/// #[selectors]
+/// #[public]
+/// #[inherit(Erc20, Ownable)]
+/// impl OwnableExample {
+///     // Overrides `Erc20::transfer`, which is then not checked for
+///     // collisions.
+///     #[only_owner]
+///     pub fn transfer(
+///         &mut self,
+///         to: Address,
+///         value: U256,
+///     ) -> Result<(), Vec<u8>> {
+///         self.erc20.transfer(to, value)?;
+///         Ok(())
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn selectors(attr: TokenStream, input: TokenStream) -> TokenStream {
+    selectors::selectors(attr, input)
+}
+
+/// Implements the conversions of an error enum whose variants each wrap a
+/// Solidity error, or the error enum of another contract, like the
+/// `SolidityError` derive of `stylus_sdk`.
+///
+/// The revert data of each error is encoded with `RevertData`, i.e. as the
+/// selector of the error followed by its ABI-encoded fields, or as its
+/// selector only when the `compact-errors` feature of `openzeppelin-stylus`
+/// is enabled.
+///
+/// The enum also implements `Display`, printing the wrapped error, and
+/// `core::error::Error`, so it should implement `Debug`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// sol! {
+///     #[derive(Debug)]
+///     error ERC20InsufficientBalance(address sender, uint256 balance, uint256 needed);
+/// }
+///
+/// #[derive(SolidityError, Debug)]
+/// pub enum Error {
+///     InsufficientBalance(ERC20InsufficientBalance),
+///     Ownable(ownable::Error),
+/// }
+/// ```
+#[proc_macro_derive(SolidityError)]
+pub fn solidity_error(input: TokenStream) -> TokenStream {
+    solidity_error::solidity_error(input)
+}
+
+/// Restricts a `#[public]` method to the `Unpaused` state of the contract, by
+/// injecting the `self.pausable.when_not_paused()?;` check at the top of its
+/// body.
+///
+/// The method should return a `Result` whose error type can be converted
+/// from the `Pausable` error, e.g. `Vec<u8>`.
+///
+/// The `Pausable` field is expected to be named `pausable`. Another name can
+/// be provided with `#[when_not_paused(field = "name")]`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[public]
+/// #[inherit(Erc20, Pausable)]
+/// impl Erc20Example {
+///     #[when_not_paused]
+///     pub fn transfer(
+///         &mut self,
+///         to: Address,
+///         value: U256,
+///     ) -> Result<bool, Vec<u8>> {
+///         self.erc20.transfer(to, value).map_err(|e| e.into())
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn when_not_paused(attr: TokenStream, input: TokenStream) -> TokenStream {
+    pausable::when_not_paused(attr, input)
+}
+
+/// Restricts a `#[public]` method to the `Paused` state of the contract, by
+/// injecting the `self.pausable.when_paused()?;` check at the top of its
+/// body.
+///
+/// The method should return a `Result` whose error type can be converted
+/// from the `Pausable` error, e.g. `Vec<u8>`.
+///
+/// The `Pausable` field is expected to be named `pausable`. Another name can
+/// be provided with `#[when_paused(field = "name")]`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[public]
+/// #[inherit(Erc20, Pausable)]
+/// impl Erc20Example {
+///     #[when_paused]
+///     pub fn emergency_withdraw(&mut self) -> Result<(), Vec<u8>> {
+///         // ...
+///         Ok(())
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn when_paused(attr: TokenStream, input: TokenStream) -> TokenStream {
+    pausable::when_paused(attr, input)
+}
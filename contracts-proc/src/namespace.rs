@@ -0,0 +1,54 @@
+//! Defines the `#[namespace]` procedural macro.
+
+use proc_macro::TokenStream;
+use proc_macro2::Ident;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input, ItemStruct, LitStr, Result, Token,
+};
+
+/// Binds a storage struct to an ERC-7201 namespace.
+pub(crate) fn namespace(attr: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attr as NamespaceArgs);
+    let input = parse_macro_input!(input as ItemStruct);
+
+    let name = &input.ident;
+    let id = args.id;
+    let (impl_generics, ty_generics, where_clause) =
+        input.generics.split_for_impl();
+
+    // Keep the same struct and attach the namespace id to it.
+    quote! {
+        #input
+
+        impl #impl_generics openzeppelin_stylus::utils::namespaced::Namespace
+            for #name #ty_generics #where_clause
+        {
+            const NAMESPACE_ID: &'static str = #id;
+        }
+    }
+    .into()
+}
+
+/// Contains arguments of the `#[namespace(..)]` attribute.
+struct NamespaceArgs {
+    id: LitStr,
+}
+
+impl Parse for NamespaceArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ident: Ident = input.parse()?;
+
+        if ident == "id" {
+            let _: Token![=] = input.parse()?;
+            let id: LitStr = input.parse()?;
+            if id.value().is_empty() {
+                error!(@id, "namespace id should not be empty");
+            }
+            Ok(NamespaceArgs { id })
+        } else {
+            error!(@ident, "expected identifier 'id'")
+        }
+    }
+}
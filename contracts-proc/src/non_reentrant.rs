@@ -0,0 +1,72 @@
+//! Defines the `#[non_reentrant]` procedural macro.
+
+use proc_macro::TokenStream;
+use proc_macro2::Ident;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input, FnArg, ImplItemFn, Result, ReturnType,
+};
+
+use crate::field::parse_field;
+
+/// Name of the `ReentrancyGuard` field used when none is provided.
+const DEFAULT_FIELD: &str = "reentrancy_guard";
+
+/// Prevents a contract method from being entered while a guarded method is
+/// being executed.
+pub(crate) fn non_reentrant(
+    attr: TokenStream,
+    input: TokenStream,
+) -> TokenStream {
+    let args = parse_macro_input!(attr as NonReentrantArgs);
+    let mut func = parse_macro_input!(input as ImplItemFn);
+
+    if !matches!(
+        func.sig.inputs.first(),
+        Some(FnArg::Receiver(receiver)) if receiver.mutability.is_some()
+    ) {
+        error!(
+            func.sig,
+            "#[non_reentrant] requires a method taking `&mut self`"
+        );
+    }
+    let ReturnType::Type(_, output) = &func.sig.output else {
+        error!(
+            func.sig,
+            "#[non_reentrant] requires a method returning a `Result`"
+        );
+    };
+
+    let field = args.field;
+    let stmts = &func.block.stmts;
+    // The body runs inside a closure, so that the guard is released on every
+    // path out of it, including early returns and `?`.
+    let block = quote! {
+        {
+            self.#field._non_reentrant_before()?;
+            #[allow(clippy::redundant_closure_call)]
+            let result = (|| -> #output { #(#stmts)* })();
+            self.#field._non_reentrant_after();
+            result
+        }
+    };
+    func.block = match syn::parse2(block) {
+        Ok(block) => block,
+        Err(error) => return error.to_compile_error().into(),
+    };
+
+    quote! { #func }.into()
+}
+
+/// Contains arguments of the `#[non_reentrant(..)]` attribute.
+struct NonReentrantArgs {
+    field: Ident,
+}
+
+impl Parse for NonReentrantArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let field = parse_field(input, DEFAULT_FIELD)?;
+        Ok(NonReentrantArgs { field })
+    }
+}
@@ -0,0 +1,41 @@
+//! Defines the `#[only_owner]` procedural macro.
+
+use proc_macro::TokenStream;
+use proc_macro2::Ident;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input, parse_quote, FnArg, ImplItemFn, Result,
+};
+
+use crate::field::parse_field;
+
+/// Name of the `Ownable` field used when none is provided.
+const DEFAULT_FIELD: &str = "ownable";
+
+/// Restricts a contract method to the owner of the contract.
+pub(crate) fn only_owner(attr: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attr as OnlyOwnerArgs);
+    let mut func = parse_macro_input!(input as ImplItemFn);
+
+    if !matches!(func.sig.inputs.first(), Some(FnArg::Receiver(_))) {
+        error!(func.sig, "#[only_owner] requires a method taking `self`");
+    }
+
+    let field = args.field;
+    func.block.stmts.insert(0, parse_quote! { self.#field.only_owner()?; });
+
+    quote! { #func }.into()
+}
+
+/// Contains arguments of the `#[only_owner(..)]` attribute.
+struct OnlyOwnerArgs {
+    field: Ident,
+}
+
+impl Parse for OnlyOwnerArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let field = parse_field(input, DEFAULT_FIELD)?;
+        Ok(OnlyOwnerArgs { field })
+    }
+}
@@ -0,0 +1,59 @@
+//! Defines the `#[only_role]` procedural macro.
+
+use proc_macro::TokenStream;
+use proc_macro2::Ident;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input, parse_quote, Expr, ExprLit, FnArg, ImplItemFn, Lit,
+    Result, Token,
+};
+
+use crate::field::parse_field;
+
+/// Name of the `AccessControl` field used when none is provided.
+const DEFAULT_FIELD: &str = "access";
+
+/// Restricts a contract method to the accounts that have a specific role.
+pub(crate) fn only_role(attr: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attr as OnlyRoleArgs);
+    let mut func = parse_macro_input!(input as ImplItemFn);
+
+    if !matches!(func.sig.inputs.first(), Some(FnArg::Receiver(_))) {
+        error!(func.sig, "#[only_role] requires a method taking `self`");
+    }
+
+    let OnlyRoleArgs { role, field } = args;
+    func.block
+        .stmts
+        .insert(0, parse_quote! { self.#field.only_role((#role).into())?; });
+
+    quote! { #func }.into()
+}
+
+/// Contains arguments of the `#[only_role(..)]` attribute.
+struct OnlyRoleArgs {
+    role: Expr,
+    field: Ident,
+}
+
+impl Parse for OnlyRoleArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.is_empty() {
+            return Err(input.error("expected a role constant"));
+        }
+
+        let role = match input.parse()? {
+            // A string names the role constant, e.g. `"TRANSFER_ROLE"`.
+            Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) => lit.parse()?,
+            role => role,
+        };
+
+        if !input.is_empty() {
+            let _: Token![,] = input.parse()?;
+        }
+        let field = parse_field(input, DEFAULT_FIELD)?;
+
+        Ok(OnlyRoleArgs { role, field })
+    }
+}
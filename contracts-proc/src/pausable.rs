@@ -0,0 +1,64 @@
+//! Defines the `#[when_not_paused]` and `#[when_paused]` procedural macros.
+
+use proc_macro::TokenStream;
+use proc_macro2::Ident;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input, parse_quote, FnArg, ImplItemFn, Result,
+};
+
+use crate::field::parse_field;
+
+/// Name of the `Pausable` field used when none is provided.
+const DEFAULT_FIELD: &str = "pausable";
+
+/// Restricts a contract method to the `Unpaused` state of the contract.
+pub(crate) fn when_not_paused(
+    attr: TokenStream,
+    input: TokenStream,
+) -> TokenStream {
+    inject_check(attr, input, "when_not_paused")
+}
+
+/// Restricts a contract method to the `Paused` state of the contract.
+pub(crate) fn when_paused(
+    attr: TokenStream,
+    input: TokenStream,
+) -> TokenStream {
+    inject_check(attr, input, "when_paused")
+}
+
+/// Injects a call to the `Pausable` `check` function at the top of the
+/// method body.
+fn inject_check(
+    attr: TokenStream,
+    input: TokenStream,
+    check: &str,
+) -> TokenStream {
+    let args = parse_macro_input!(attr as PausableArgs);
+    let mut func = parse_macro_input!(input as ImplItemFn);
+
+    if !matches!(func.sig.inputs.first(), Some(FnArg::Receiver(_))) {
+        error!(func.sig, "#[{}] requires a method taking `self`", check);
+    }
+
+    let field = args.field;
+    let check = Ident::new(check, field.span());
+    func.block.stmts.insert(0, parse_quote! { self.#field.#check()?; });
+
+    quote! { #func }.into()
+}
+
+/// Contains arguments of the `#[when_not_paused(..)]` and `#[when_paused(..)]`
+/// attributes.
+struct PausableArgs {
+    field: Ident,
+}
+
+impl Parse for PausableArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let field = parse_field(input, DEFAULT_FIELD)?;
+        Ok(PausableArgs { field })
+    }
+}
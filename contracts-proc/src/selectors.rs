@@ -0,0 +1,355 @@
+//! Defines the `#[selectors]` procedural macro.
+
+use convert_case::{Case, Casing};
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::{quote, ToTokens};
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    FnArg, GenericArgument, ImplItem, ImplItemFn, ItemImpl, LitStr, Pat,
+    PathArguments, Result, ReturnType, Token, Type,
+};
+
+use crate::interface_id::SelectorArgs;
+
+/// Lists the selectors and the Solidity interface of a `#[public]`
+/// implementation, and checks that its selectors don't collide with each
+/// other nor across the inherited contracts.
+pub(crate) fn selectors(attr: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attr as SelectorsArgs);
+    let input = parse_macro_input!(input as ItemImpl);
+
+    let inherits = match inherits(&input) {
+        Ok(inherits) => inherits,
+        Err(error) => return error.to_compile_error().into(),
+    };
+    if !input.generics.params.is_empty() && !inherits.is_empty() {
+        error!(
+            input.generics,
+            "#[selectors] doesn't support generic implementations with \
+             `#[inherit]`"
+        );
+    }
+
+    let mut methods = Vec::new();
+    for item in &input.items {
+        let ImplItem::Fn(func) = item else {
+            continue;
+        };
+        match Method::parse(func) {
+            Ok(method) => methods.push(method),
+            Err(error) => return error.to_compile_error().into(),
+        }
+    }
+
+    let self_ty = &input.self_ty;
+    let (impl_generics, _, where_clause) = input.generics.split_for_impl();
+    let name = type_name(self_ty);
+
+    let selectors = methods.iter().map(Method::selector);
+    let all = if inherits.is_empty() {
+        quote! { OWN }
+    } else {
+        quote! {{
+            const LEN: usize = OWN.len()
+                #(+ <#inherits as Selectors>::SELECTORS.len())*;
+            const ALL: [u32; LEN] = selectors::concat(&[
+                OWN,
+                #(<#inherits as Selectors>::SELECTORS,)*
+            ]);
+            &ALL
+        }}
+    };
+
+    let duplicates = LitStr::new(
+        &format!("`{name}` exports the same selector more than once"),
+        Span::call_site(),
+    );
+    let mut collisions = Vec::new();
+    for (i, a) in inherits.iter().enumerate() {
+        for b in &inherits[i + 1..] {
+            let message = LitStr::new(
+                &format!(
+                    "`{}` and `{}` export the same selector, override it in \
+                     `{name}`",
+                    type_name(a),
+                    type_name(b)
+                ),
+                Span::call_site(),
+            );
+            collisions.push(quote! {
+                assert!(
+                    !selectors::collide(
+                        OWN,
+                        <#a as Selectors>::SELECTORS,
+                        <#b as Selectors>::SELECTORS,
+                    ),
+                    #message
+                );
+            });
+        }
+    }
+
+    let interface = interface(&input, &methods, &inherits, &args);
+
+    quote! {
+        #input
+
+        const _: () = {
+            use openzeppelin_stylus::utils::{
+                interface::{self, Interface},
+                selectors::{self, Selectors},
+            };
+
+            const OWN: &[u32] = &[#(#selectors),*];
+
+            impl #impl_generics Selectors for #self_ty #where_clause {
+                const SELECTORS: &'static [u32] = #all;
+            }
+
+            #interface
+
+            assert!(!selectors::has_duplicates(OWN), #duplicates);
+            #(#collisions)*
+        };
+    }
+    .into()
+}
+
+/// Returns the implementation of the `Interface` trait for `input`.
+fn interface(
+    input: &ItemImpl,
+    methods: &[Method],
+    inherits: &[Type],
+    args: &SelectorsArgs,
+) -> TokenStream2 {
+    let self_ty = &input.self_ty;
+    let (impl_generics, _, where_clause) = input.generics.split_for_impl();
+    let functions = methods.iter().map(Method::function);
+    let events = &args.events;
+    let errors = &args.errors;
+
+    quote! {
+        impl #impl_generics Interface for #self_ty #where_clause {
+            fn functions() -> interface::Vec<interface::Function> {
+                let mut functions = interface::Vec::from([#(#functions),*]);
+                #(functions.extend(<#inherits as Interface>::functions());)*
+                functions
+            }
+
+            fn events() -> interface::Vec<interface::Event> {
+                let mut events = interface::Vec::from([
+                    #(<#events as interface::JsonAbiExt>::abi()),*
+                ]);
+                #(events.extend(<#inherits as Interface>::events());)*
+                events
+            }
+
+            fn errors() -> interface::Vec<interface::Error> {
+                let mut errors = interface::Vec::from([
+                    #(<#errors as interface::JsonAbiExt>::abi()),*
+                ]);
+                #(errors.extend(<#inherits as Interface>::errors());)*
+                errors
+            }
+        }
+    }
+}
+
+/// Contains arguments of the `#[selectors(..)]` attribute.
+#[derive(Default)]
+struct SelectorsArgs {
+    events: Vec<Type>,
+    errors: Vec<Type>,
+}
+
+impl Parse for SelectorsArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut args = SelectorsArgs::default();
+        while !input.is_empty() {
+            let ident: Ident = input.parse()?;
+            let content;
+            parenthesized!(content in input);
+            let types =
+                Punctuated::<Type, Token![,]>::parse_terminated(&content)?;
+            if ident == "events" {
+                args.events.extend(types);
+            } else if ident == "errors" {
+                args.errors.extend(types);
+            } else {
+                error!(@ident, "expected identifier 'events' or 'errors'");
+            }
+
+            if !input.is_empty() {
+                let _: Token![,] = input.parse()?;
+            }
+        }
+        Ok(args)
+    }
+}
+
+/// Returns the contracts of the `#[inherit(..)]` attribute of `input`, if
+/// any.
+fn inherits(input: &ItemImpl) -> Result<Vec<Type>> {
+    let mut inherits = Vec::new();
+    for attr in &input.attrs {
+        if attr.path().is_ident("inherit") {
+            let types = attr.parse_args_with(
+                Punctuated::<Type, Token![,]>::parse_terminated,
+            )?;
+            inherits.extend(types);
+        }
+    }
+    Ok(inherits)
+}
+
+/// Solidity state mutability of a method.
+enum Mutability {
+    Pure,
+    View,
+    NonPayable,
+    Payable,
+}
+
+/// A method of a `#[public]` implementation, as exported by the `#[public]`
+/// macro of `stylus_sdk`.
+struct Method {
+    /// Solidity name of the method.
+    name: String,
+    /// Names and types of the arguments of the method, without its storage.
+    args: Vec<(String, Type)>,
+    /// Type returned by the method, without its `Result`.
+    output: Option<Type>,
+    mutability: Mutability,
+}
+
+impl Method {
+    fn parse(func: &ImplItemFn) -> Result<Self> {
+        let mut override_fn_name = None;
+        let mut payable = false;
+        for attr in &func.attrs {
+            if attr.path().is_ident("selector") {
+                if override_fn_name.is_some() {
+                    error!(@attr.path(), "more than one selector attribute");
+                }
+                let args: SelectorArgs = attr.parse_args()?;
+                override_fn_name = Some(args.name);
+            } else if attr.path().is_ident("payable") {
+                payable = true;
+            }
+        }
+
+        let name = override_fn_name.unwrap_or_else(|| {
+            let rust_fn_name = func.sig.ident.to_string();
+            rust_fn_name.to_case(Case::Camel)
+        });
+
+        // The storage is passed either as `self`, or as a reference in the
+        // first argument.
+        let mut inputs = func.sig.inputs.iter().peekable();
+        let storage = match inputs.peek() {
+            Some(FnArg::Receiver(receiver)) => {
+                Some(receiver.mutability.is_some())
+            }
+            Some(FnArg::Typed(arg)) => match &*arg.ty {
+                Type::Reference(ty) => Some(ty.mutability.is_some()),
+                _ => None,
+            },
+            None => None,
+        };
+        if storage.is_some() {
+            inputs.next();
+        }
+        let mutability = match storage {
+            _ if payable => Mutability::Payable,
+            Some(true) => Mutability::NonPayable,
+            Some(false) => Mutability::View,
+            None => Mutability::Pure,
+        };
+
+        let args = inputs
+            .filter_map(|arg| match arg {
+                FnArg::Typed(arg) => {
+                    let name = match &*arg.pat {
+                        Pat::Ident(pat) => pat.ident.to_string(),
+                        _ => String::new(),
+                    };
+                    Some((name, (*arg.ty).clone()))
+                }
+                FnArg::Receiver(_) => None,
+            })
+            .collect();
+
+        let output = match &func.sig.output {
+            ReturnType::Default => None,
+            ReturnType::Type(_, ty) => Some(ok_type(ty)),
+        };
+
+        Ok(Self { name, args, output, mutability })
+    }
+
+    /// Returns the selector expression of the method, computed the same way
+    /// as the `#[public]` macro of `stylus_sdk` does.
+    fn selector(&self) -> TokenStream2 {
+        let name = &self.name;
+        let arg_types = self.args.iter().map(|(_, ty)| ty);
+        quote! {
+            u32::from_be_bytes(stylus_sdk::function_selector!(
+                #name #(, #arg_types )*
+            ))
+        }
+    }
+
+    /// Returns the expression building the Solidity function of the method.
+    fn function(&self) -> TokenStream2 {
+        let name = &self.name;
+        let args = self.args.iter().map(|(name, ty)| {
+            quote! {
+                (#name, <#ty as stylus_sdk::abi::AbiType>::ABI.as_str())
+            }
+        });
+        let output = self.output.as_ref().map_or(quote! { "()" }, |ty| {
+            quote! { <#ty as stylus_sdk::abi::AbiType>::ABI.as_str() }
+        });
+        let mutability = match self.mutability {
+            Mutability::Pure => quote! { Pure },
+            Mutability::View => quote! { View },
+            Mutability::NonPayable => quote! { NonPayable },
+            Mutability::Payable => quote! { Payable },
+        };
+        quote! {
+            interface::function(
+                #name,
+                &[#(#args),*],
+                #output,
+                interface::StateMutability::#mutability,
+            )
+        }
+    }
+}
+
+/// Returns the type returned on success by a method returning `ty`, i.e. `T`
+/// for a `Result<T, E>`, and `ty` otherwise.
+fn ok_type(ty: &Type) -> Type {
+    if let Type::Path(path) = ty {
+        if let Some(segment) = path.path.segments.last() {
+            if segment.ident == "Result" {
+                if let PathArguments::AngleBracketed(args) = &segment.arguments
+                {
+                    if let Some(GenericArgument::Type(ok)) = args.args.first() {
+                        return ok.clone();
+                    }
+                }
+            }
+        }
+    }
+    ty.clone()
+}
+
+/// Returns the name of `ty`, as printed in the error messages.
+fn type_name(ty: &Type) -> String {
+    ty.to_token_stream().to_string().replace(' ', "")
+}
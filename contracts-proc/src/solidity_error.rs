@@ -0,0 +1,117 @@
+//! Defines the `#[derive(SolidityError)]` procedural macro.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Fields, ItemEnum};
+
+/// Implements the conversions of an error enum, whose variants each wrap a
+/// Solidity error or another error enum, like `stylus_sdk` does, but encodes
+/// its revert data with `RevertData`.
+///
+/// Also implements `Display`, with the `ErrorMessage` of the wrapped error,
+/// and `core::error::Error`.
+pub(crate) fn solidity_error(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ItemEnum);
+    let name = &input.ident;
+
+    let mut match_arms = Vec::new();
+    let mut message_arms = Vec::new();
+    let mut conversions = Vec::new();
+    let mut errors = Vec::new();
+    for variant in &input.variants {
+        let variant_name = &variant.ident;
+        let ty = match &variant.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                &fields.unnamed[0].ty
+            }
+            _ => error!(variant.fields, "variant should be a 1-tuple"),
+        };
+        match_arms.push(quote! {
+            #name::#variant_name(e) => revert::RevertData::revert_data(e),
+        });
+        message_arms.push(quote! {
+            #name::#variant_name(e) => revert::ErrorMessage::fmt_message(e, f),
+        });
+        conversions.push(quote! {
+            impl From<#ty> for #name {
+                fn from(value: #ty) -> Self {
+                    #name::#variant_name(value)
+                }
+            }
+        });
+        errors.push(ty);
+    }
+
+    // Like `stylus_sdk`, lists the errors in the exported ABI. Each variant
+    // lists the Solidity error it wraps, or the errors of the enum it wraps.
+    let export_abi = if cfg!(feature = "export-abi") {
+        quote! {
+            impl revert::InnerErrors<revert::Wrapped> for #name {
+                fn inner_errors() -> revert::Vec<revert::InnerType> {
+                    let mut errors = revert::Vec::new();
+                    #(
+                        errors.extend(
+                            <#errors as revert::InnerErrors<_>>::inner_errors()
+                        );
+                    )*
+                    errors
+                }
+            }
+
+            impl revert::InnerTypes for #name {
+                fn inner_types() -> revert::Vec<revert::InnerType> {
+                    <Self as revert::InnerErrors<revert::Wrapped>>::inner_errors()
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        #(#conversions)*
+
+        const _: () = {
+            use openzeppelin_stylus::utils::revert;
+
+            impl revert::RevertData<revert::Wrapped> for #name {
+                fn revert_data(self) -> revert::Vec<u8> {
+                    match self {
+                        #(#match_arms)*
+                    }
+                }
+            }
+
+            impl From<#name> for revert::Vec<u8> {
+                fn from(err: #name) -> revert::Vec<u8> {
+                    revert::RevertData::revert_data(err)
+                }
+            }
+
+            impl core::fmt::Display for #name {
+                fn fmt(
+                    &self,
+                    f: &mut core::fmt::Formatter<'_>,
+                ) -> core::fmt::Result {
+                    match self {
+                        #(#message_arms)*
+                    }
+                }
+            }
+
+            impl core::error::Error for #name {}
+
+            impl revert::ErrorMessage<revert::Wrapped> for #name {
+                fn fmt_message(
+                    &self,
+                    f: &mut core::fmt::Formatter<'_>,
+                ) -> core::fmt::Result {
+                    core::fmt::Display::fmt(self, f)
+                }
+            }
+
+            #export_abi
+        };
+    }
+    .into()
+}
@@ -0,0 +1,69 @@
+//! Measures the pure-Rust cost of the hot paths of the contracts, without the
+//! WASM runtime nor the cost of the storage of the chain, so that changes to
+//! their code can be compared with each other.
+//!
+//! The gas used by the deployed contracts is measured by the `benches` crate
+//! instead, with `scripts/bench.sh`.
+//!
+//! Run with `cargo bench -p openzeppelin-stylus --features std`, optionally
+//! followed by `-- <filter>` to only run the benchmarks whose name matches
+//! `<filter>`.
+// `criterion_group!` defines an undocumented public function.
+#![allow(missing_docs)]
+use std::hint::black_box;
+
+use alloy_primitives::{address, keccak256, uint, Address, U256};
+use criterion::{criterion_group, criterion_main, Criterion};
+use motsu::prelude::with_context;
+use openzeppelin_crypto::merkle::{tree::StandardMerkleTree, Verifier};
+use openzeppelin_stylus::token::erc20::{Erc20, IErc20};
+use stylus_sdk::msg;
+
+const ALICE: Address = address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
+const BOB: Address = address!("B0B0cB49ec2e96DF5F5fFB081acaE66A2cBBc2e2");
+
+fn erc20(c: &mut Criterion) {
+    with_context::<Erc20>(|contract| {
+        // Leave room for the total supply to grow with every minting
+        // iteration, without ever overflowing.
+        let initial_balance = U256::MAX / uint!(4_U256);
+        contract._mint(ALICE, initial_balance).expect("should mint");
+        contract._mint(msg::sender(), initial_balance).expect("should mint");
+
+        let value = uint!(1_U256);
+        contract.transfer(BOB, value).expect("should transfer");
+        c.bench_function("erc20/_mint", |b| {
+            b.iter(|| contract._mint(BOB, black_box(value)));
+        });
+        c.bench_function("erc20/_update", |b| {
+            b.iter(|| contract._update(ALICE, BOB, black_box(value)));
+        });
+        c.bench_function("erc20/transfer", |b| {
+            b.iter(|| contract.transfer(BOB, black_box(value)));
+        });
+        c.bench_function("erc20/balance_of", |b| {
+            b.iter(|| contract.balance_of(black_box(ALICE)));
+        });
+    });
+}
+
+fn hashing(c: &mut Criterion) {
+    let data = [0xab; 64];
+    c.bench_function("keccak256/64 bytes", |b| {
+        b.iter(|| keccak256(black_box(data)));
+    });
+
+    let values: Vec<[u8; 32]> =
+        (0..1024_u32).map(|i| U256::from(i).to_be_bytes()).collect();
+    let tree = StandardMerkleTree::of(&values).expect("should build tree");
+    let root = tree.root();
+    let leaf = tree.leaf_hash(0).expect("should have leaf");
+    let proof = tree.proof(0).expect("should have proof");
+    assert!(Verifier::verify(&proof, root, leaf));
+    c.bench_function("merkle/verify 1024 leaves", |b| {
+        b.iter(|| Verifier::verify(black_box(&proof), root, leaf));
+    });
+}
+
+criterion_group!(benches, erc20, hashing);
+criterion_main!(benches);
@@ -42,18 +42,22 @@
 //! this role.
 use alloy_primitives::{Address, B256};
 use alloy_sol_types::sol;
+use openzeppelin_stylus_proc::{selectors, SolidityError};
 use stylus_sdk::{
     evm, msg,
-    stylus_proc::{public, sol_storage, SolidityError},
+    stylus_proc::{public, sol_storage},
 };
 
 sol! {
+    #![sol(abi)]
+
     /// Emitted when `new_admin_role` is set as `role`'s admin role, replacing
     /// `previous_admin_role`.
     ///
     /// `DEFAULT_ADMIN_ROLE` is the starting admin for all roles, despite
     /// `RoleAdminChanged` not being emitted signaling this.
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     event RoleAdminChanged(bytes32 indexed role, bytes32 indexed previous_admin_role, bytes32 indexed new_admin_role);
     /// Emitted when `account` is granted `role`.
     ///
@@ -62,6 +66,7 @@ sol! {
     /// Expected in cases where the role was granted using the internal
     /// [`AccessControl::grant_role`].
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     event RoleGranted(bytes32 indexed role, address indexed account, address indexed sender);
     /// Emitted when `account` is revoked `role`.
     ///
@@ -69,22 +74,27 @@ sol! {
     ///   - if using `revoke_role`, it is the admin role bearer.
     ///   - if using `renounce_role`, it is the role bearer (i.e. `account`).
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     event RoleRevoked(bytes32 indexed role, address indexed account, address indexed sender);
 }
 
 sol! {
+    #![sol(abi)]
+
     /// The `account` is missing a role.
     ///
     /// * `account` - Account that was found to not be authorized.
     /// * `needed_role` - The missing role.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error AccessControlUnauthorizedAccount(address account, bytes32 needed_role);
     /// The caller of a function is not the expected one.
     ///
     /// NOTE: Don't confuse with [`AccessControlUnauthorizedAccount`].
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error AccessControlBadConfirmation();
 }
 
@@ -114,6 +124,10 @@ sol_storage! {
     }
 }
 
+#[selectors(
+    events(RoleAdminChanged, RoleGranted, RoleRevoked),
+    errors(AccessControlUnauthorizedAccount, AccessControlBadConfirmation)
+)]
 #[public]
 impl AccessControl {
     /// The default admin role. `[0; 32]` by default.
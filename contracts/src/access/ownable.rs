@@ -10,29 +10,40 @@
 //! to the owner.
 use alloy_primitives::Address;
 use alloy_sol_types::sol;
+use openzeppelin_stylus_proc::{selectors, SolidityError};
 use stylus_sdk::{
+    call::MethodError,
     evm, msg,
-    stylus_proc::{public, sol_storage, SolidityError},
+    stylus_proc::{public, sol_storage},
 };
 
+use crate::arbitrum::messaging;
+
 sol! {
+    #![sol(abi)]
+
     /// Emitted when ownership gets transferred between accounts.
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     event OwnershipTransferred(address indexed previous_owner, address indexed new_owner);
 }
 
 sol! {
+    #![sol(abi)]
+
     /// The caller account is not authorized to perform an operation.
     ///
     /// * `account` - Account that was found to not be authorized.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error OwnableUnauthorizedAccount(address account);
     /// The owner is not a valid owner account. (eg. `Address::ZERO`)
     ///
     /// * `owner` - Account that's not allowed to become the owner.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error OwnableInvalidOwner(address owner);
 }
 
@@ -45,6 +56,12 @@ pub enum Error {
     InvalidOwner(OwnableInvalidOwner),
 }
 
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
 sol_storage! {
     /// State of an `Ownable` contract.
     pub struct Ownable {
@@ -53,6 +70,10 @@ sol_storage! {
     }
 }
 
+#[selectors(
+    events(OwnershipTransferred),
+    errors(OwnableUnauthorizedAccount, OwnableInvalidOwner)
+)]
 #[public]
 impl Ownable {
     /// Returns the address of the current owner.
@@ -124,6 +145,32 @@ impl Ownable {
 }
 
 impl Ownable {
+    /// Checks if the [`msg::sender`] is the alias of the owner, i.e. if the
+    /// call is a message sent by the owner from the parent chain of an
+    /// Arbitrum chain.
+    ///
+    /// Contracts governed from the parent chain set an admin contract of the
+    /// parent chain as their owner, and restrict operations with this check.
+    /// See [`crate::arbitrum::messaging`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    ///
+    /// # Errors
+    ///
+    /// If called by any account other than the alias of the owner, then the
+    /// error [`Error::UnauthorizedAccount`] is returned.
+    pub fn only_l1_owner(&self) -> Result<(), Error> {
+        if !messaging::is_l1_sender(self.owner()) {
+            return Err(Error::UnauthorizedAccount(
+                OwnableUnauthorizedAccount { account: msg::sender() },
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Transfers ownership of the contract to a new account (`new_owner`).
     /// Internal function without access restriction.
     ///
@@ -144,6 +191,9 @@ mod tests {
     use stylus_sdk::msg;
 
     use super::{Error, Ownable};
+    use crate::arbitrum::messaging::{
+        apply_l1_to_l2_alias, undo_l1_to_l2_alias,
+    };
 
     const ALICE: Address = address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
 
@@ -209,4 +259,18 @@ mod tests {
         let owner = contract._owner.get();
         assert_eq!(owner, ALICE);
     }
+
+    #[motsu::test]
+    fn checks_l1_owner(contract: Ownable) {
+        contract._owner.set(undo_l1_to_l2_alias(msg::sender()));
+        contract.only_l1_owner().expect("should accept the owner's alias");
+
+        contract._owner.set(msg::sender());
+        let err = contract.only_l1_owner().unwrap_err();
+        assert!(matches!(err, Error::UnauthorizedAccount(_)));
+
+        contract._owner.set(apply_l1_to_l2_alias(msg::sender()));
+        let err = contract.only_l1_owner().unwrap_err();
+        assert!(matches!(err, Error::UnauthorizedAccount(_)));
+    }
 }
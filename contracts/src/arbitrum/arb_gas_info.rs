@@ -0,0 +1,179 @@
+//! Bindings for the [ArbGasInfo] precompile, which exposes the gas prices of
+//! Arbitrum chains.
+//!
+//! Besides the gas used on the Arbitrum chain itself, transactions pay for
+//! posting their calldata to the parent chain. Contracts quoting fees, e.g.
+//! paymasters, estimate this surcharge with [`l1_calldata_cost`].
+//!
+//! [ArbGasInfo]: https://docs.arbitrum.io/build-decentralized-apps/precompiles/reference#arbgasinfo
+use alloc::vec::Vec;
+
+use alloy_primitives::{address, Address, U256};
+use openzeppelin_stylus_proc::SolidityError;
+use stylus_sdk::{
+    call::{self, Call, MethodError},
+    prelude::sol_interface,
+};
+
+/// Address of the `ArbGasInfo` precompile.
+pub const ARB_GAS_INFO_ADDRESS: Address =
+    address!("000000000000000000000000000000000000006C");
+
+sol_interface! {
+    /// Interface of the `ArbGasInfo` precompile.
+    interface IArbGasInfo {
+        /// Returns the estimated base fee of the parent chain, in wei.
+        #[allow(missing_docs)]
+        function getL1BaseFeeEstimate() external view returns (uint256);
+
+        /// Returns the prices in wei of a transaction, a byte of calldata
+        /// posted to the parent chain, a storage allocation, and a unit of
+        /// gas: base, congestion and total.
+        #[allow(missing_docs)]
+        function getPricesInWei() external view returns (uint256, uint256, uint256, uint256, uint256, uint256);
+
+        /// Returns the minimum gas price of the chain, in wei.
+        #[allow(missing_docs)]
+        function getMinimumGasPrice() external view returns (uint256);
+    }
+}
+
+/// An `ArbGasInfo` error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// The call to `ArbGasInfo` failed, with the reason specified by it.
+    FailedCall(call::Error),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> Vec<u8> {
+        self.into()
+    }
+}
+
+/// Prices in wei returned by [`prices_in_wei`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PricesInWei {
+    /// Price of a transaction.
+    pub per_l2_tx: U256,
+    /// Price of a byte of calldata posted to the parent chain.
+    pub per_l1_calldata_byte: U256,
+    /// Price of a storage allocation.
+    pub per_storage_allocation: U256,
+    /// Base price of a unit of gas.
+    pub per_arb_gas_base: U256,
+    /// Congestion price of a unit of gas.
+    pub per_arb_gas_congestion: U256,
+    /// Total price of a unit of gas.
+    pub per_arb_gas_total: U256,
+}
+
+impl PricesInWei {
+    /// Returns the estimated surcharge in wei for posting `calldata_len`
+    /// bytes of calldata to the parent chain.
+    ///
+    /// The estimate doesn't account for the compression of the calldata by
+    /// the sequencer, so it is an upper bound of the actual cost.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Prices of the chain.
+    /// * `calldata_len` - Length of the calldata, in bytes.
+    #[must_use]
+    pub fn l1_calldata_cost(&self, calldata_len: usize) -> U256 {
+        self.per_l1_calldata_byte.saturating_mul(U256::from(calldata_len))
+    }
+}
+
+/// Returns the estimated base fee of the parent chain, in wei.
+///
+/// # Errors
+///
+/// If the call to `ArbGasInfo` fails, then the error [`Error::FailedCall`] is
+/// returned.
+pub fn l1_base_fee_estimate() -> Result<U256, Error> {
+    Ok(IArbGasInfo::new(ARB_GAS_INFO_ADDRESS)
+        .get_l_1_base_fee_estimate(Call::new())?)
+}
+
+/// Returns the minimum gas price of the chain, in wei.
+///
+/// # Errors
+///
+/// If the call to `ArbGasInfo` fails, then the error [`Error::FailedCall`] is
+/// returned.
+pub fn minimum_gas_price() -> Result<U256, Error> {
+    Ok(IArbGasInfo::new(ARB_GAS_INFO_ADDRESS)
+        .get_minimum_gas_price(Call::new())?)
+}
+
+/// Returns the current prices of the chain, in wei.
+///
+/// # Errors
+///
+/// If the call to `ArbGasInfo` fails, then the error [`Error::FailedCall`] is
+/// returned.
+pub fn prices_in_wei() -> Result<PricesInWei, Error> {
+    let (
+        per_l2_tx,
+        per_l1_calldata_byte,
+        per_storage_allocation,
+        per_arb_gas_base,
+        per_arb_gas_congestion,
+        per_arb_gas_total,
+    ) = IArbGasInfo::new(ARB_GAS_INFO_ADDRESS).get_prices_in_wei(Call::new())?;
+    Ok(PricesInWei {
+        per_l2_tx,
+        per_l1_calldata_byte,
+        per_storage_allocation,
+        per_arb_gas_base,
+        per_arb_gas_congestion,
+        per_arb_gas_total,
+    })
+}
+
+/// Returns the estimated surcharge in wei for posting `calldata_len` bytes of
+/// calldata to the parent chain, at the current prices.
+///
+/// See [`PricesInWei::l1_calldata_cost`].
+///
+/// # Arguments
+///
+/// * `calldata_len` - Length of the calldata, in bytes.
+///
+/// # Errors
+///
+/// If the call to `ArbGasInfo` fails, then the error [`Error::FailedCall`] is
+/// returned.
+pub fn l1_calldata_cost(calldata_len: usize) -> Result<U256, Error> {
+    Ok(prices_in_wei()?.l1_calldata_cost(calldata_len))
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::U256;
+
+    use super::*;
+
+    #[test]
+    fn computes_l1_calldata_cost() {
+        let prices = PricesInWei {
+            per_l1_calldata_byte: U256::from(16),
+            ..PricesInWei::default()
+        };
+        assert_eq!(prices.l1_calldata_cost(0), U256::ZERO);
+        assert_eq!(prices.l1_calldata_cost(100), U256::from(1600));
+
+        let prices = PricesInWei { per_l1_calldata_byte: U256::MAX, ..prices };
+        assert_eq!(prices.l1_calldata_cost(2), U256::MAX);
+    }
+
+    // `ArbGasInfo` doesn't exist in tests, so calls succeed without returning
+    // the expected data.
+    #[motsu::test]
+    fn fails_to_decode_missing_precompile() {
+        assert!(matches!(l1_base_fee_estimate(), Err(Error::FailedCall(_))));
+        assert!(matches!(minimum_gas_price(), Err(Error::FailedCall(_))));
+        assert!(matches!(l1_calldata_cost(1), Err(Error::FailedCall(_))));
+    }
+}
@@ -0,0 +1,177 @@
+//! Bindings for the [ArbSys] precompile, which exposes system functionality
+//! of Arbitrum chains.
+//!
+//! NOTE: Block numbers and hashes exposed by Stylus' `block` module are the
+//! ones of the parent chain, e.g. Ethereum. Use [`arb_block_number`] and
+//! [`arb_block_hash`] to read the ones of the Arbitrum chain itself.
+//!
+//! [ArbSys]: https://docs.arbitrum.io/build-decentralized-apps/precompiles/reference#arbsys
+use alloc::vec::Vec;
+
+use alloy_primitives::{address, Address, B256, U256};
+use openzeppelin_stylus_proc::SolidityError;
+use stylus_sdk::{
+    call::{self, Call, MethodError},
+    prelude::sol_interface,
+    storage::TopLevelStorage,
+};
+
+/// Address of the `ArbSys` precompile.
+pub const ARB_SYS_ADDRESS: Address =
+    address!("0000000000000000000000000000000000000064");
+
+sol_interface! {
+    /// Interface of the `ArbSys` precompile.
+    interface IArbSys {
+        /// Returns the current block number of the Arbitrum chain.
+        #[allow(missing_docs)]
+        function arbBlockNumber() external view returns (uint256);
+
+        /// Returns the hash of the Arbitrum block `arb_block_num`, which must
+        /// be one of the 256 most recent blocks.
+        #[allow(missing_docs)]
+        function arbBlockHash(uint256 arb_block_num) external view returns (bytes32);
+
+        /// Sends the value of the call to `destination` on the parent chain,
+        /// and returns the unique id of the message.
+        #[allow(missing_docs)]
+        function withdrawEth(address destination) external payable returns (uint256);
+
+        /// Sends a transaction with `data` and the value of the call to
+        /// `destination` on the parent chain, and returns the unique id of
+        /// the message.
+        #[allow(missing_docs)]
+        function sendTxToL1(address destination, bytes calldata data) external payable returns (uint256);
+    }
+}
+
+/// An `ArbSys` error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// The call to `ArbSys` failed, with the reason specified by it.
+    FailedCall(call::Error),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> Vec<u8> {
+        self.into()
+    }
+}
+
+/// Returns the current block number of the Arbitrum chain.
+///
+/// # Errors
+///
+/// If the call to `ArbSys` fails, then the error [`Error::FailedCall`] is
+/// returned.
+pub fn arb_block_number() -> Result<U256, Error> {
+    Ok(IArbSys::new(ARB_SYS_ADDRESS).arb_block_number(Call::new())?)
+}
+
+/// Returns the hash of the Arbitrum block `block_number`.
+///
+/// # Arguments
+///
+/// * `block_number` - Number of one of the 256 most recent blocks, excluding
+///   the current one.
+///
+/// # Errors
+///
+/// If `block_number` is out of range, or the call to `ArbSys` fails, then the
+/// error [`Error::FailedCall`] is returned.
+pub fn arb_block_hash(block_number: U256) -> Result<B256, Error> {
+    Ok(IArbSys::new(ARB_SYS_ADDRESS)
+        .arb_block_hash(Call::new(), block_number)?)
+}
+
+/// Withdraws `value` of Ether from the calling contract to `destination` on
+/// the parent chain, and returns the unique id of the message.
+///
+/// The Ether can be claimed on the parent chain once the message is
+/// confirmed, through its Outbox contract.
+///
+/// # Arguments
+///
+/// * `storage` - Write access to the contract's state.
+/// * `destination` - Recipient of the Ether on the parent chain.
+/// * `value` - Amount of Ether to withdraw, in wei.
+///
+/// # Errors
+///
+/// If the call to `ArbSys` fails, then the error [`Error::FailedCall`] is
+/// returned.
+pub fn withdraw_eth(
+    storage: &mut impl TopLevelStorage,
+    destination: Address,
+    value: U256,
+) -> Result<U256, Error> {
+    let call = Call::new_in(storage).value(value);
+    Ok(IArbSys::new(ARB_SYS_ADDRESS).withdraw_eth(call, destination)?)
+}
+
+/// Sends a transaction with `data` and `value` of Ether from the calling
+/// contract to `destination` on the parent chain, and returns the unique id
+/// of the message.
+///
+/// The transaction can be executed on the parent chain once the message is
+/// confirmed, through its Outbox contract.
+///
+/// # Arguments
+///
+/// * `storage` - Write access to the contract's state.
+/// * `destination` - Address called on the parent chain.
+/// * `data` - Calldata of the transaction.
+/// * `value` - Amount of Ether sent along, in wei.
+///
+/// # Errors
+///
+/// If the call to `ArbSys` fails, then the error [`Error::FailedCall`] is
+/// returned.
+pub fn send_tx_to_l1(
+    storage: &mut impl TopLevelStorage,
+    destination: Address,
+    data: &[u8],
+    value: U256,
+) -> Result<U256, Error> {
+    let call = Call::new_in(storage).value(value);
+    Ok(IArbSys::new(ARB_SYS_ADDRESS).send_tx_to_l_1(
+        call,
+        destination,
+        data.to_vec().into(),
+    )?)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{address, Address, U256};
+    use stylus_sdk::{prelude::sol_storage, storage::TopLevelStorage};
+
+    use super::*;
+
+    const ALICE: Address = address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
+
+    sol_storage! {
+        struct Sender {}
+    }
+
+    unsafe impl TopLevelStorage for Sender {}
+
+    // ArbSys doesn't exist in tests, so calls succeed without returning the
+    // expected data.
+    #[motsu::test]
+    fn fails_to_decode_missing_precompile(contract: Sender) {
+        assert!(matches!(arb_block_number(), Err(Error::FailedCall(_))));
+        assert!(matches!(
+            arb_block_hash(U256::ZERO),
+            Err(Error::FailedCall(_))
+        ));
+        assert!(matches!(
+            withdraw_eth(contract, ALICE, U256::ZERO),
+            Err(Error::FailedCall(_))
+        ));
+        assert!(matches!(
+            send_tx_to_l1(contract, ALICE, &[], U256::ZERO),
+            Err(Error::FailedCall(_))
+        ));
+    }
+}
@@ -0,0 +1,290 @@
+//! Helpers for messaging between an Arbitrum chain and its parent chain.
+//!
+//! From the parent chain, contracts send messages to an Arbitrum chain
+//! through _retryable tickets_, created by the `Inbox` contract of the chain.
+//! [`RetryableTicket`] composes the calldata of `createRetryableTicket`, e.g.
+//! for a message sent to the parent chain with [`send_to_l1`] that in turn
+//! calls back the Arbitrum chain.
+//!
+//! When a retryable ticket is executed, the caller is the sender on the
+//! parent chain, offset by [`L1_TO_L2_ALIAS_OFFSET`]. A contract governed from
+//! the parent chain checks its caller against the alias of its governor:
+//!
+//! ```rust,ignore
+//! #[public]
+//! impl MyContract {
+//!     fn set_value(&mut self, value: U256) -> Result<(), Vec<u8>> {
+//!         if !messaging::is_l1_sender(self.governor.get()) {
+//!             return Err(/* unauthorized */);
+//!         }
+//!         self.value.set(value);
+//!         Ok(())
+//!     }
+//! }
+//! ```
+//!
+//! Contracts owned by a contract of the parent chain use
+//! [`crate::access::ownable::Ownable::only_l1_owner`] instead.
+//!
+//! See the [messaging documentation] of Arbitrum.
+//!
+//! [messaging documentation]: https://docs.arbitrum.io/how-arbitrum-works/l1-to-l2-messaging
+use alloc::vec::Vec;
+
+use alloy_primitives::{address, aliases::U160, Address, U256};
+use alloy_sol_types::{sol, SolCall};
+use stylus_sdk::{msg, storage::TopLevelStorage};
+
+use crate::arbitrum::arb_sys;
+
+/// Offset added to the address of a contract of the parent chain to get its
+/// alias, i.e. the caller of the messages it sends to the Arbitrum chain.
+pub const L1_TO_L2_ALIAS_OFFSET: Address =
+    address!("1111000000000000000000000000000000001111");
+
+/// Base size in bytes of a retryable ticket, used to compute its submission
+/// fee.
+const RETRYABLE_BASE_SIZE: u64 = 1400;
+
+/// Cost in bytes of each byte of the calldata of a retryable ticket, used to
+/// compute its submission fee.
+const RETRYABLE_BYTE_COST: u64 = 6;
+
+sol! {
+    /// Functions of the `Inbox` contract of an Arbitrum chain, deployed on
+    /// its parent chain.
+    #[allow(missing_docs)]
+    interface IInbox {
+        function createRetryableTicket(
+            address to,
+            uint256 l2CallValue,
+            uint256 maxSubmissionCost,
+            address excessFeeRefundAddress,
+            address callValueRefundAddress,
+            uint256 gasLimit,
+            uint256 maxFeePerGas,
+            bytes data
+        ) external payable returns (uint256);
+    }
+}
+
+/// Returns the alias of the parent chain's `l1_address`, i.e. the caller of
+/// the messages it sends to the Arbitrum chain.
+///
+/// # Arguments
+///
+/// * `l1_address` - Address of a contract of the parent chain.
+#[must_use]
+pub fn apply_l1_to_l2_alias(l1_address: Address) -> Address {
+    let aliased =
+        to_u160(l1_address).wrapping_add(to_u160(L1_TO_L2_ALIAS_OFFSET));
+    Address::from(aliased.to_be_bytes())
+}
+
+/// Returns the address on the parent chain whose alias is `l2_address`, see
+/// [`apply_l1_to_l2_alias`].
+///
+/// # Arguments
+///
+/// * `l2_address` - Alias of a contract of the parent chain.
+#[must_use]
+pub fn undo_l1_to_l2_alias(l2_address: Address) -> Address {
+    let l1_address =
+        to_u160(l2_address).wrapping_sub(to_u160(L1_TO_L2_ALIAS_OFFSET));
+    Address::from(l1_address.to_be_bytes())
+}
+
+/// Returns true if the caller is the alias of the parent chain's
+/// `l1_sender`, i.e. if the call is a message sent by `l1_sender`, and false
+/// otherwise.
+///
+/// # Arguments
+///
+/// * `l1_sender` - Address of a contract of the parent chain.
+#[must_use]
+pub fn is_l1_sender(l1_sender: Address) -> bool {
+    msg::sender() == apply_l1_to_l2_alias(l1_sender)
+}
+
+/// Returns the fee to submit a retryable ticket with `data_len` bytes of
+/// calldata, when the base fee of the parent chain is `l1_base_fee`.
+///
+/// Matches `Inbox.calculateRetryableSubmissionFee`, and is the minimum
+/// `max_submission_cost` of a [`RetryableTicket`].
+///
+/// # Arguments
+///
+/// * `data_len` - Length of the calldata of the ticket, in bytes.
+/// * `l1_base_fee` - Base fee of the parent chain, in wei.
+#[must_use]
+pub fn retryable_submission_fee(data_len: usize, l1_base_fee: U256) -> U256 {
+    let size = U256::from(RETRYABLE_BASE_SIZE).saturating_add(
+        U256::from(RETRYABLE_BYTE_COST).saturating_mul(U256::from(data_len)),
+    );
+    size.saturating_mul(l1_base_fee)
+}
+
+/// A retryable ticket, sent from the parent chain to call `to` on the
+/// Arbitrum chain with `data`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryableTicket {
+    /// Address called on the Arbitrum chain.
+    pub to: Address,
+    /// Value of the call on the Arbitrum chain, in wei.
+    pub l2_call_value: U256,
+    /// Maximum fee paid to submit the ticket, see
+    /// [`retryable_submission_fee`].
+    pub max_submission_cost: U256,
+    /// Recipient on the Arbitrum chain of the unused fees.
+    pub excess_fee_refund_address: Address,
+    /// Recipient on the Arbitrum chain of `l2_call_value` if the ticket is
+    /// canceled or expires.
+    pub call_value_refund_address: Address,
+    /// Gas limit of the call on the Arbitrum chain.
+    pub gas_limit: U256,
+    /// Maximum gas price of the call on the Arbitrum chain, in wei.
+    pub max_fee_per_gas: U256,
+    /// Calldata of the call on the Arbitrum chain.
+    pub data: Vec<u8>,
+}
+
+impl RetryableTicket {
+    /// Returns the calldata of the `Inbox.createRetryableTicket` call
+    /// creating the ticket.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - The retryable ticket.
+    #[must_use]
+    pub fn calldata(&self) -> Vec<u8> {
+        IInbox::createRetryableTicketCall {
+            to: self.to,
+            l2CallValue: self.l2_call_value,
+            maxSubmissionCost: self.max_submission_cost,
+            excessFeeRefundAddress: self.excess_fee_refund_address,
+            callValueRefundAddress: self.call_value_refund_address,
+            gasLimit: self.gas_limit,
+            maxFeePerGas: self.max_fee_per_gas,
+            data: self.data.clone().into(),
+        }
+        .abi_encode()
+    }
+
+    /// Returns the value sent along with `Inbox.createRetryableTicket` to
+    /// create the ticket, i.e. the call value and the maximum fees.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - The retryable ticket.
+    #[must_use]
+    pub fn deposit(&self) -> U256 {
+        self.l2_call_value
+            .saturating_add(self.max_submission_cost)
+            .saturating_add(self.gas_limit.saturating_mul(self.max_fee_per_gas))
+    }
+}
+
+/// Sends a message calling `destination` with `call` on the parent chain,
+/// along with `value` of Ether, and returns the unique id of the message.
+///
+/// # Arguments
+///
+/// * `storage` - Write access to the contract's state.
+/// * `destination` - Address called on the parent chain.
+/// * `call` - Call performed on the parent chain.
+/// * `value` - Amount of Ether sent along, in wei.
+///
+/// # Errors
+///
+/// If the call to `ArbSys` fails, then the error
+/// [`arb_sys::Error::FailedCall`] is returned.
+pub fn send_to_l1(
+    storage: &mut impl TopLevelStorage,
+    destination: Address,
+    call: &impl SolCall,
+    value: U256,
+) -> Result<U256, arb_sys::Error> {
+    arb_sys::send_tx_to_l1(storage, destination, &call.abi_encode(), value)
+}
+
+/// Returns `address` as a 160-bit integer.
+fn to_u160(address: Address) -> U160 {
+    U160::from_be_bytes(address.into_array())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::vec;
+
+    use alloy_primitives::{address, Address, U256};
+    use alloy_sol_types::SolCall;
+    use stylus_sdk::msg;
+
+    use super::*;
+
+    const L1_GOVERNOR: Address =
+        address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
+
+    #[test]
+    fn aliases_l1_addresses() {
+        let alias = address!("B22DEacF9aa32246d767FCCD72e02d6bCbcC486e");
+        assert_eq!(apply_l1_to_l2_alias(L1_GOVERNOR), alias);
+        assert_eq!(undo_l1_to_l2_alias(alias), L1_GOVERNOR);
+
+        // Aliasing wraps around the address space.
+        assert_eq!(
+            apply_l1_to_l2_alias(address!(
+                "ffffffffffffffffffffffffffffffffffffffff"
+            )),
+            address!("1111000000000000000000000000000000001110")
+        );
+        assert_eq!(
+            undo_l1_to_l2_alias(Address::ZERO),
+            address!("eeeeffffffffffffffffffffffffffffffffeeef")
+        );
+    }
+
+    #[motsu::test]
+    fn checks_l1_sender() {
+        assert!(is_l1_sender(undo_l1_to_l2_alias(msg::sender())));
+        assert!(!is_l1_sender(msg::sender()));
+        assert!(!is_l1_sender(L1_GOVERNOR));
+    }
+
+    #[test]
+    fn computes_retryable_submission_fee() {
+        assert_eq!(
+            retryable_submission_fee(0, U256::from(10)),
+            U256::from(14_000)
+        );
+        assert_eq!(
+            retryable_submission_fee(100, U256::from(10)),
+            U256::from(20_000)
+        );
+        assert_eq!(retryable_submission_fee(100, U256::ZERO), U256::ZERO);
+    }
+
+    #[test]
+    fn composes_retryable_tickets() {
+        let ticket = RetryableTicket {
+            to: L1_GOVERNOR,
+            l2_call_value: U256::from(1),
+            max_submission_cost: U256::from(2),
+            excess_fee_refund_address: L1_GOVERNOR,
+            call_value_refund_address: L1_GOVERNOR,
+            gas_limit: U256::from(3),
+            max_fee_per_gas: U256::from(4),
+            data: vec![0xde, 0xad],
+        };
+        assert_eq!(ticket.deposit(), U256::from(15));
+
+        let call = IInbox::createRetryableTicketCall::abi_decode(
+            &ticket.calldata(),
+            true,
+        )
+        .expect("should decode calldata");
+        assert_eq!(call.to, L1_GOVERNOR);
+        assert_eq!(call.maxSubmissionCost, U256::from(2));
+        assert_eq!(call.data.as_ref(), &[0xde, 0xad]);
+    }
+}
@@ -0,0 +1,4 @@
+//! Bindings for the precompiles of Arbitrum chains.
+pub mod arb_gas_info;
+pub mod arb_sys;
+pub mod messaging;
@@ -0,0 +1,64 @@
+//! Interfaces of the ERC-1155 standard, as defined in the [ERC].
+//!
+//! [ERC]: https://eips.ethereum.org/EIPS/eip-1155
+use stylus_sdk::prelude::sol_interface;
+
+sol_interface! {
+    /// Interface of an ERC-1155 multi token.
+    interface IERC1155 {
+        /// Returns the number of `id` tokens owned by `account`.
+        #[allow(missing_docs)]
+        function balanceOf(address account, uint256 id) external view returns (uint256);
+
+        /// Batched version of `balanceOf`.
+        #[allow(missing_docs)]
+        function balanceOfBatch(address[] calldata accounts, uint256[] calldata ids) external view returns (uint256[] memory);
+
+        /// Approves or removes `operator` as an operator for the caller.
+        #[allow(missing_docs)]
+        function setApprovalForAll(address operator, bool approved) external;
+
+        /// Returns true if `operator` is allowed to manage all the tokens of
+        /// `account`.
+        #[allow(missing_docs)]
+        function isApprovedForAll(address account, address operator) external view returns (bool);
+
+        /// Transfers `value` tokens of type `id` from `from` to `to`, calling
+        /// [`IERC1155Receiver::on_erc_1155_received`] on contract recipients.
+        #[allow(missing_docs)]
+        function safeTransferFrom(address from, address to, uint256 id, uint256 value, bytes calldata data) external;
+
+        /// Batched version of `safeTransferFrom`, calling
+        /// [`IERC1155Receiver::on_erc_1155_batch_received`] on contract
+        /// recipients.
+        #[allow(missing_docs)]
+        function safeBatchTransferFrom(address from, address to, uint256[] calldata ids, uint256[] calldata values, bytes calldata data) external;
+    }
+
+    /// ERC-1155 token receiver interface.
+    interface IERC1155Receiver {
+        /// Handles the receipt of a single ERC-1155 token type.
+        ///
+        /// It must return its function selector to accept the transfer.
+        #[allow(missing_docs)]
+        function onERC1155Received(
+            address operator,
+            address from,
+            uint256 id,
+            uint256 value,
+            bytes calldata data
+        ) external returns (bytes4);
+
+        /// Handles the receipt of multiple ERC-1155 token types.
+        ///
+        /// It must return its function selector to accept the transfer.
+        #[allow(missing_docs)]
+        function onERC1155BatchReceived(
+            address operator,
+            address from,
+            uint256[] calldata ids,
+            uint256[] calldata values,
+            bytes calldata data
+        ) external returns (bytes4);
+    }
+}
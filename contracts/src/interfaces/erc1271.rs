@@ -0,0 +1,16 @@
+//! Interface of the ERC-1271 standard, as defined in the [ERC].
+//!
+//! To implement it, see [`crate::utils::cryptography::erc1271`].
+//!
+//! [ERC]: https://eips.ethereum.org/EIPS/eip-1271
+use stylus_sdk::prelude::sol_interface;
+
+sol_interface! {
+    /// Interface of the contracts validating signatures.
+    interface IERC1271 {
+        /// Returns [`crate::utils::cryptography::erc1271::MAGIC_VALUE`] if
+        /// `signature` is valid for `hash`.
+        #[allow(missing_docs)]
+        function isValidSignature(bytes32 hash, bytes calldata signature) external view returns (bytes4 magic_value);
+    }
+}
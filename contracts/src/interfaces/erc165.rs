@@ -0,0 +1,16 @@
+//! Interface of the ERC-165 standard, as defined in the [ERC].
+//!
+//! To implement it, see [`crate::utils::introspection::erc165`].
+//!
+//! [ERC]: https://eips.ethereum.org/EIPS/eip-165
+use stylus_sdk::prelude::sol_interface;
+
+sol_interface! {
+    /// Interface of the contracts declaring the interfaces they support.
+    interface IERC165 {
+        /// Returns true if the contract implements the interface defined by
+        /// `interface_id`.
+        #[allow(missing_docs)]
+        function supportsInterface(bytes4 interface_id) external view returns (bool);
+    }
+}
@@ -0,0 +1,52 @@
+//! Interfaces of the ERC-20 standard, as defined in the [ERC].
+//!
+//! [ERC]: https://eips.ethereum.org/EIPS/eip-20
+use stylus_sdk::prelude::sol_interface;
+
+sol_interface! {
+    /// Interface of an ERC-20 token.
+    interface IERC20 {
+        /// Returns the number of tokens in existence.
+        #[allow(missing_docs)]
+        function totalSupply() external view returns (uint256);
+
+        /// Returns the number of tokens owned by `account`.
+        #[allow(missing_docs)]
+        function balanceOf(address account) external view returns (uint256);
+
+        /// Moves `value` tokens from the caller's account to `to`.
+        #[allow(missing_docs)]
+        function transfer(address to, uint256 value) external returns (bool);
+
+        /// Returns the remaining number of tokens that `spender` is allowed
+        /// to spend on behalf of `owner`.
+        #[allow(missing_docs)]
+        function allowance(address owner, address spender) external view returns (uint256);
+
+        /// Sets `value` as the allowance of `spender` over the caller's
+        /// tokens.
+        #[allow(missing_docs)]
+        function approve(address spender, uint256 value) external returns (bool);
+
+        /// Moves `value` tokens from `from` to `to` using the allowance
+        /// mechanism.
+        #[allow(missing_docs)]
+        function transferFrom(address from, address to, uint256 value) external returns (bool);
+    }
+
+    /// Interface of the optional metadata functions of an ERC-20 token.
+    interface IERC20Metadata {
+        /// Returns the name of the token.
+        #[allow(missing_docs)]
+        function name() external view returns (string);
+
+        /// Returns the symbol of the token.
+        #[allow(missing_docs)]
+        function symbol() external view returns (string);
+
+        /// Returns the number of decimals used to represent amounts of the
+        /// token.
+        #[allow(missing_docs)]
+        function decimals() external view returns (uint8);
+    }
+}
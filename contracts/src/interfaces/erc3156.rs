@@ -0,0 +1,37 @@
+//! Interfaces of the ERC-3156 flash loans standard, as defined in the [ERC].
+//!
+//! [ERC]: https://eips.ethereum.org/EIPS/eip-3156
+use stylus_sdk::prelude::sol_interface;
+
+sol_interface! {
+    /// Interface of a flash lender.
+    interface IERC3156FlashLender {
+        /// Returns the amount of `token` available to be lent.
+        #[allow(missing_docs)]
+        function maxFlashLoan(address token) external view returns (uint256);
+
+        /// Returns the fee charged for a loan of `amount` of `token`.
+        #[allow(missing_docs)]
+        function flashFee(address token, uint256 amount) external view returns (uint256);
+
+        /// Lends `amount` of `token` to `receiver`, and calls its
+        /// [`IERC3156FlashBorrower::on_flash_loan`] with `data`.
+        #[allow(missing_docs)]
+        function flashLoan(address receiver, address token, uint256 amount, bytes calldata data) external returns (bool);
+    }
+
+    /// Interface of a flash borrower.
+    interface IERC3156FlashBorrower {
+        /// Receives a flash loan of `amount` of `token` from `initiator`.
+        ///
+        /// It must return `keccak256("ERC3156FlashBorrower.onFlashLoan")`.
+        #[allow(missing_docs)]
+        function onFlashLoan(
+            address initiator,
+            address token,
+            uint256 amount,
+            uint256 fee,
+            bytes calldata data
+        ) external returns (bytes32);
+    }
+}
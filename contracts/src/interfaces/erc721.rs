@@ -0,0 +1,64 @@
+//! Interfaces of the ERC-721 standard, as defined in the [ERC].
+//!
+//! [ERC]: https://eips.ethereum.org/EIPS/eip-721
+use stylus_sdk::prelude::sol_interface;
+
+sol_interface! {
+    /// Interface of an ERC-721 token.
+    interface IERC721 {
+        /// Returns the number of tokens owned by `owner`.
+        #[allow(missing_docs)]
+        function balanceOf(address owner) external view returns (uint256);
+
+        /// Returns the owner of the `token_id` token.
+        #[allow(missing_docs)]
+        function ownerOf(uint256 token_id) external view returns (address);
+
+        /// Safely transfers the `token_id` token from `from` to `to`, calling
+        /// [`IERC721Receiver::on_erc_721_received`] with `data` on contract
+        /// recipients.
+        #[allow(missing_docs)]
+        function safeTransferFrom(address from, address to, uint256 token_id, bytes calldata data) external;
+
+        /// Transfers the `token_id` token from `from` to `to`.
+        #[allow(missing_docs)]
+        function transferFrom(address from, address to, uint256 token_id) external;
+
+        /// Gives permission to `to` to transfer the `token_id` token.
+        #[allow(missing_docs)]
+        function approve(address to, uint256 token_id) external;
+
+        /// Approves or removes `operator` as an operator for the caller.
+        #[allow(missing_docs)]
+        function setApprovalForAll(address operator, bool approved) external;
+
+        /// Returns the account approved for the `token_id` token.
+        #[allow(missing_docs)]
+        function getApproved(uint256 token_id) external view returns (address);
+
+        /// Returns true if `operator` is allowed to manage all the tokens of
+        /// `owner`.
+        #[allow(missing_docs)]
+        function isApprovedForAll(address owner, address operator) external view returns (bool);
+    }
+
+    /// ERC-721 token receiver interface.
+    ///
+    /// Interface for any contract that wants to support `safe_transfers`
+    /// from ERC-721 asset contracts.
+    interface IERC721Receiver {
+        /// Whenever an ERC-721 `token_id` token is transferred to this
+        /// contract via `safeTransferFrom`.
+        ///
+        /// It must return its function selector to confirm the token transfer.
+        /// If any other value is returned or the interface is not implemented
+        /// by the recipient, the transfer will be reverted.
+        #[allow(missing_docs)]
+        function onERC721Received(
+            address operator,
+            address from,
+            uint256 token_id,
+            bytes calldata data
+        ) external returns (bytes4);
+    }
+}
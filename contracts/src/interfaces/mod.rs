@@ -0,0 +1,21 @@
+//! Solidity interfaces of the standards implemented by the library, to call
+//! other contracts implementing them.
+//!
+//! Modules calling other contracts use these bindings rather than declaring
+//! their own subsets of the same interfaces:
+//!
+//! ```rust,ignore
+//! use openzeppelin_stylus::interfaces::erc20::IERC20;
+//!
+//! let balance = IERC20::new(token).balance_of(Call::new(), account)?;
+//! ```
+//!
+//! NOTE: Rust has no function overloading, so only one variant of overloaded
+//! Solidity functions is declared, e.g. ERC-721 `safeTransferFrom` with its
+//! `data` argument.
+pub mod erc1155;
+pub mod erc1271;
+pub mod erc165;
+pub mod erc20;
+pub mod erc3156;
+pub mod erc721;
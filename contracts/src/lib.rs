@@ -40,17 +40,39 @@ sol_storage! {
 #[inherit(Erc20)]
 impl MyContract { }
 ```
+
+## Features
+
+Each family of contracts is behind a feature, all enabled by default: `access`,
+`erc20`, `erc721`, `safe-erc20` and `crypto`. Deployments which only use some
+of them can disable the default features to compile, and ship, fewer modules:
+
+```toml
+[dependencies]
+openzeppelin-stylus = { version = "x.x.x", default-features = false, features = ["erc20", "access"] }
+```
+
+The `compact-errors` feature shrinks binaries further, by reverting with the
+selectors of errors only, see [`utils::revert`]. The `serde` feature derives
+`Serialize` and `Deserialize` for the events and errors, for off-chain code
+consuming them, e.g. indexers.
 */
 
 #![allow(clippy::pub_underscore_fields, clippy::module_name_repetitions)]
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 #![deny(rustdoc::broken_intra_doc_links)]
 extern crate alloc;
+// Allows procedural macros to refer to this crate from within itself.
+extern crate self as openzeppelin_stylus;
 
 #[global_allocator]
 static ALLOC: mini_alloc::MiniAlloc = mini_alloc::MiniAlloc::INIT;
 
+#[cfg(feature = "access")]
 pub mod access;
+pub mod arbitrum;
+pub mod interfaces;
+pub mod proxy;
 pub mod token;
 pub mod utils;
 
@@ -0,0 +1,383 @@
+//! Getters, setters and upgrade functions for the storage slots defined in
+//! [ERC-1967].
+//!
+//! Proxies delegate their calls to an implementation, whose address, like the
+//! addresses of the proxy admin and of the beacon, is stored at a slot
+//! derived from a hash, so that it can't collide with the storage layout of
+//! the implementation.
+//!
+//! These functions read and write the slots of the currently executing
+//! contract, i.e. of the proxy when called from an implementation through a
+//! delegate call. They are the foundation of upgradeable contracts, like
+//! `UUPSUpgradeable`, and don't perform any access control themselves.
+//!
+//! [ERC-1967]: https://eips.ethereum.org/EIPS/eip-1967
+use alloc::vec::Vec;
+
+use alloy_primitives::{b256, Address, B256, U256};
+use alloy_sol_types::sol;
+use openzeppelin_stylus_proc::SolidityError;
+use stylus_sdk::{
+    call::{self, Call, MethodError},
+    evm, msg,
+    prelude::*,
+    storage::{StorageAddress, StorageType, TopLevelStorage},
+};
+
+/// Storage slot with the address of the current implementation, i.e.
+/// `keccak256("eip1967.proxy.implementation") - 1`.
+pub const IMPLEMENTATION_SLOT: B256 =
+    b256!("360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc");
+
+/// Storage slot with the admin of the proxy, i.e.
+/// `keccak256("eip1967.proxy.admin") - 1`.
+pub const ADMIN_SLOT: B256 =
+    b256!("b53127684a568b3173ae13b9f8a6016e243e63b6e8ee1178d6a717850b5d6103");
+
+/// Storage slot with the beacon of the proxy, i.e.
+/// `keccak256("eip1967.proxy.beacon") - 1`.
+pub const BEACON_SLOT: B256 =
+    b256!("a3f0ad74e5423aebfd80d3ef4346578335a9a72aeaee59ff6cb3582b35133d50");
+
+sol! {
+    /// Emitted when the implementation is upgraded.
+    ///
+    /// * `implementation` - Address of the new implementation.
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    event Upgraded(address indexed implementation);
+
+    /// Emitted when the admin account has changed.
+    ///
+    /// * `previous_admin` - Address of the previous admin.
+    /// * `new_admin` - Address of the new admin.
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    event AdminChanged(address previous_admin, address new_admin);
+
+    /// Emitted when the beacon is changed.
+    ///
+    /// * `beacon` - Address of the new beacon.
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    event BeaconUpgraded(address indexed beacon);
+}
+
+sol! {
+    /// The `implementation` of the proxy is invalid.
+    ///
+    /// * `implementation` - Address of the invalid implementation.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error ERC1967InvalidImplementation(address implementation);
+
+    /// The `admin` of the proxy is invalid.
+    ///
+    /// * `admin` - Address of the invalid admin.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error ERC1967InvalidAdmin(address admin);
+
+    /// The `beacon` of the proxy is invalid.
+    ///
+    /// * `beacon` - Address of the invalid beacon.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error ERC1967InvalidBeacon(address beacon);
+
+    /// An upgrade function sees `msg.value > 0` that may be lost.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error ERC1967NonPayable();
+}
+
+/// An ERC-1967 error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// The implementation of the proxy is invalid.
+    InvalidImplementation(ERC1967InvalidImplementation),
+    /// The admin of the proxy is invalid.
+    InvalidAdmin(ERC1967InvalidAdmin),
+    /// The beacon of the proxy is invalid.
+    InvalidBeacon(ERC1967InvalidBeacon),
+    /// An upgrade function sees `msg.value > 0` that may be lost.
+    NonPayable(ERC1967NonPayable),
+    /// The call to the new implementation failed, with the reason specified
+    /// by it.
+    FailedCall(call::Error),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+sol_interface! {
+    /// Interface of the beacons read by the proxies, see [`BEACON_SLOT`].
+    interface IBeacon {
+        /// Returns the address of the implementation of the proxies using
+        /// this beacon.
+        #[allow(missing_docs)]
+        function implementation() external view returns (address);
+    }
+}
+
+/// Returns an accessor to the address stored at `slot`.
+fn address_at(slot: B256) -> StorageAddress {
+    // SAFETY: ERC-1967 slots are derived from hashes, so they can't overlap
+    // with the layout of the contract's storage.
+    unsafe { StorageAddress::new(U256::from_be_bytes(slot.0), 0) }
+}
+
+/// Returns the current implementation address.
+#[must_use]
+pub fn get_implementation() -> Address {
+    address_at(IMPLEMENTATION_SLOT).get()
+}
+
+/// Performs an implementation upgrade, with an additional call to the new
+/// implementation if `data` is not empty.
+///
+/// # Arguments
+///
+/// * `storage` - Write access to storage.
+/// * `new_implementation` - Address of the new implementation.
+/// * `data` - Calldata delegated to the new implementation, e.g. to initialize
+///   it.
+///
+/// # Errors
+///
+/// * If `new_implementation` has no code, then the error
+///   [`Error::InvalidImplementation`] is returned.
+/// * If the delegate call to `new_implementation` fails, then the error
+///   [`Error::FailedCall`] is returned.
+/// * If `data` is empty and the call has a value, then the error
+///   [`Error::NonPayable`] is returned.
+///
+/// # Events
+///
+/// Emits an [`Upgraded`] event.
+pub fn upgrade_to_and_call(
+    storage: &mut impl TopLevelStorage,
+    new_implementation: Address,
+    data: &[u8],
+) -> Result<(), Error> {
+    set_implementation(new_implementation)?;
+    evm::log(Upgraded { implementation: new_implementation });
+
+    if data.is_empty() {
+        check_non_payable()
+    } else {
+        delegate(storage, new_implementation, data)
+    }
+}
+
+/// Stores a new address in the implementation slot.
+///
+/// # Errors
+///
+/// * If `new_implementation` has no code, then the error
+///   [`Error::InvalidImplementation`] is returned.
+fn set_implementation(new_implementation: Address) -> Result<(), Error> {
+    if !new_implementation.has_code() {
+        return Err(ERC1967InvalidImplementation {
+            implementation: new_implementation,
+        }
+        .into());
+    }
+    address_at(IMPLEMENTATION_SLOT).set(new_implementation);
+    Ok(())
+}
+
+/// Returns the current admin.
+#[must_use]
+pub fn get_admin() -> Address {
+    address_at(ADMIN_SLOT).get()
+}
+
+/// Changes the admin of the proxy.
+///
+/// # Arguments
+///
+/// * `new_admin` - Address of the new admin.
+///
+/// # Errors
+///
+/// * If `new_admin` is `Address::ZERO`, then the error [`Error::InvalidAdmin`]
+///   is returned.
+///
+/// # Events
+///
+/// Emits an [`AdminChanged`] event.
+pub fn change_admin(new_admin: Address) -> Result<(), Error> {
+    if new_admin.is_zero() {
+        return Err(ERC1967InvalidAdmin { admin: new_admin }.into());
+    }
+    let previous_admin = get_admin();
+    address_at(ADMIN_SLOT).set(new_admin);
+    evm::log(AdminChanged { previous_admin, new_admin });
+    Ok(())
+}
+
+/// Returns the current beacon.
+#[must_use]
+pub fn get_beacon() -> Address {
+    address_at(BEACON_SLOT).get()
+}
+
+/// Changes the beacon and triggers a setup call to the implementation of the
+/// new beacon if `data` is not empty.
+///
+/// # Arguments
+///
+/// * `storage` - Write access to storage.
+/// * `new_beacon` - Address of the new beacon.
+/// * `data` - Calldata delegated to the implementation of the new beacon.
+///
+/// # Errors
+///
+/// * If `new_beacon` has no code, then the error [`Error::InvalidBeacon`] is
+///   returned.
+/// * If the implementation of `new_beacon` can't be read, or has no code, then
+///   the error [`Error::InvalidImplementation`] is returned.
+/// * If the delegate call to the implementation fails, then the error
+///   [`Error::FailedCall`] is returned.
+/// * If `data` is empty and the call has a value, then the error
+///   [`Error::NonPayable`] is returned.
+///
+/// # Events
+///
+/// Emits a [`BeaconUpgraded`] event.
+pub fn upgrade_beacon_to_and_call(
+    storage: &mut impl TopLevelStorage,
+    new_beacon: Address,
+    data: &[u8],
+) -> Result<(), Error> {
+    if !new_beacon.has_code() {
+        return Err(ERC1967InvalidBeacon { beacon: new_beacon }.into());
+    }
+    let implementation = beacon_implementation(storage, new_beacon)?;
+    address_at(BEACON_SLOT).set(new_beacon);
+    evm::log(BeaconUpgraded { beacon: new_beacon });
+
+    if data.is_empty() {
+        check_non_payable()
+    } else {
+        delegate(storage, implementation, data)
+    }
+}
+
+/// Returns the implementation of `beacon`, checking that it has code.
+///
+/// # Errors
+///
+/// * If the implementation can't be read, or has no code, then the error
+///   [`Error::InvalidImplementation`] is returned.
+fn beacon_implementation(
+    storage: &mut impl TopLevelStorage,
+    beacon: Address,
+) -> Result<Address, Error> {
+    let implementation = IBeacon::new(beacon)
+        .implementation(Call::new_in(storage))
+        .unwrap_or_default();
+    if !implementation.has_code() {
+        return Err(ERC1967InvalidImplementation { implementation }.into());
+    }
+    Ok(implementation)
+}
+
+/// Delegates a call with `data` to `implementation`, bubbling up its revert
+/// reason.
+///
+/// # Errors
+///
+/// * If the call fails, then the error [`Error::FailedCall`] is returned.
+fn delegate(
+    storage: &mut impl TopLevelStorage,
+    implementation: Address,
+    data: &[u8],
+) -> Result<(), Error> {
+    // SAFETY: the implementation is trusted by the caller, who authorized
+    // the upgrade, with the storage of this contract.
+    let _: Vec<u8> =
+        unsafe { call::delegate_call(storage, implementation, data) }
+            .map_err(Error::FailedCall)?;
+    Ok(())
+}
+
+/// Reverts if `msg.value` is not zero, which would otherwise be stuck in the
+/// contract, since upgrades without data don't call the implementation.
+///
+/// # Errors
+///
+/// * If the call has a value, then the error [`Error::NonPayable`] is returned.
+fn check_non_payable() -> Result<(), Error> {
+    if msg::value().is_zero() {
+        Ok(())
+    } else {
+        Err(ERC1967NonPayable {}.into())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{address, keccak256, Address, B256, U256};
+    use stylus_sdk::{prelude::sol_storage, storage::TopLevelStorage};
+
+    use super::*;
+
+    const ALICE: Address = address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
+
+    sol_storage! {
+        struct Proxy {}
+    }
+
+    unsafe impl TopLevelStorage for Proxy {}
+
+    fn erc1967_slot(id: &str) -> B256 {
+        let hash = U256::from_be_bytes(keccak256(id).0);
+        B256::from(hash - U256::from(1))
+    }
+
+    #[test]
+    fn derives_slots() {
+        assert_eq!(
+            IMPLEMENTATION_SLOT,
+            erc1967_slot("eip1967.proxy.implementation")
+        );
+        assert_eq!(ADMIN_SLOT, erc1967_slot("eip1967.proxy.admin"));
+        assert_eq!(BEACON_SLOT, erc1967_slot("eip1967.proxy.beacon"));
+    }
+
+    #[motsu::test]
+    fn changes_admin(_contract: Proxy) {
+        assert_eq!(get_admin(), Address::ZERO);
+        change_admin(ALICE).expect("should change admin");
+        assert_eq!(get_admin(), ALICE);
+
+        let err = change_admin(Address::ZERO).expect_err("should reject zero");
+        assert!(matches!(err, Error::InvalidAdmin(_)));
+        assert_eq!(get_admin(), ALICE);
+    }
+
+    #[motsu::test]
+    fn rejects_implementations_without_code(contract: Proxy) {
+        let err = upgrade_to_and_call(contract, ALICE, &[])
+            .expect_err("should reject EOA");
+        assert!(matches!(err, Error::InvalidImplementation(_)));
+        assert_eq!(get_implementation(), Address::ZERO);
+    }
+
+    #[motsu::test]
+    fn rejects_beacons_without_code(contract: Proxy) {
+        let err = upgrade_beacon_to_and_call(contract, ALICE, &[])
+            .expect_err("should reject EOA");
+        assert!(matches!(err, Error::InvalidBeacon(_)));
+        assert_eq!(get_beacon(), Address::ZERO);
+    }
+}
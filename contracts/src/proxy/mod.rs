@@ -0,0 +1,6 @@
+//! Smart Contracts for proxies and upgradeability.
+pub mod erc1967;
+#[cfg(feature = "access")]
+pub mod proxy_admin;
+pub mod transparent_upgradeable_proxy;
+pub mod uups_upgradeable;
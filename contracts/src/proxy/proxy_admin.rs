@@ -0,0 +1,174 @@
+//! The admin of a [`TransparentUpgradeableProxy`].
+//!
+//! The admin of a transparent proxy can't call its implementation, so a
+//! dedicated [`ProxyAdmin`] contract is deployed as the admin, and upgrades
+//! are performed by its owner through [`ProxyAdmin::upgrade_and_call`]. A
+//! single [`ProxyAdmin`] can administer several proxies.
+//!
+//! Contracts expose the functions of [`Ownable`] along with those of the
+//! [`ProxyAdmin`], by borrowing its owner:
+//!
+//! ```rust,ignore
+//! sol_storage! {
+//!     #[entrypoint]
+//!     struct MyProxyAdmin {
+//!         #[borrow]
+//!         ProxyAdmin admin;
+//!     }
+//! }
+//!
+//! impl Borrow<Ownable> for MyProxyAdmin {
+//!     fn borrow(&self) -> &Ownable {
+//!         &self.admin.ownable
+//!     }
+//! }
+//!
+//! impl BorrowMut<Ownable> for MyProxyAdmin {
+//!     fn borrow_mut(&mut self) -> &mut Ownable {
+//!         &mut self.admin.ownable
+//!     }
+//! }
+//!
+//! #[public]
+//! #[inherit(ProxyAdmin, Ownable)]
+//! impl MyProxyAdmin {}
+//! ```
+//!
+//! [`TransparentUpgradeableProxy`]: crate::proxy::transparent_upgradeable_proxy::TransparentUpgradeableProxy
+use alloc::{string::String, vec::Vec};
+
+use alloy_primitives::Address;
+use openzeppelin_stylus_proc::{selectors, SolidityError};
+use stylus_sdk::{
+    abi::Bytes,
+    call::{self, Call, MethodError},
+    msg,
+    storage::TopLevelStorage,
+    stylus_proc::{public, sol_storage},
+};
+
+use crate::{
+    access::ownable::{self, Ownable},
+    proxy::transparent_upgradeable_proxy::ITransparentUpgradeableProxy,
+};
+
+/// The version of the upgrade interface of the contract.
+///
+/// `upgradeAndCall(address,address,bytes)` is the only interface, and an
+/// empty `data` only upgrades the implementation, without calling it.
+pub const UPGRADE_INTERFACE_VERSION: &str = "5.0.0";
+
+/// A Proxy Admin error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Error type from [`Ownable`] contract [`ownable::Error`].
+    Ownable(ownable::Error),
+    /// The upgrade of the proxy failed, with the reason specified by it.
+    FailedCall(call::Error),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> Vec<u8> {
+        self.into()
+    }
+}
+
+sol_storage! {
+    /// State of a Proxy Admin Contract.
+    pub struct ProxyAdmin {
+        /// Owner allowed to upgrade the proxies.
+        Ownable ownable;
+    }
+}
+
+/// NOTE: Implementation of [`TopLevelStorage`] to be able use `&mut self` when
+/// calling other contracts and not `&mut (impl TopLevelStorage +
+/// BorrowMut<Self>)`. Should be fixed in the future by the Stylus team.
+unsafe impl TopLevelStorage for ProxyAdmin {}
+
+#[selectors]
+#[public]
+impl ProxyAdmin {
+    /// Returns the version of the upgrade interface of the contract, see
+    /// [`UPGRADE_INTERFACE_VERSION`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[selector(name = "UPGRADE_INTERFACE_VERSION")]
+    #[must_use]
+    pub fn upgrade_interface_version(&self) -> String {
+        UPGRADE_INTERFACE_VERSION.into()
+    }
+
+    /// Upgrades `proxy` to `implementation`, and calls the new implementation
+    /// with `data` and the value sent along, if `data` is not empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `proxy` - Address of the proxy to upgrade.
+    /// * `implementation` - Address of the new implementation.
+    /// * `data` - Calldata delegated to the new implementation, e.g. to
+    ///   reinitialize it.
+    ///
+    /// # Errors
+    ///
+    /// * If called by any account other than the owner, then the error
+    ///   [`ownable::Error::UnauthorizedAccount`] is returned.
+    /// * If the upgrade fails, then the error [`Error::FailedCall`] is
+    ///   returned.
+    #[payable]
+    pub fn upgrade_and_call(
+        &mut self,
+        proxy: Address,
+        implementation: Address,
+        data: Bytes,
+    ) -> Result<(), Error> {
+        self.ownable.only_owner()?;
+        let call = Call::new_in(self).value(msg::value());
+        ITransparentUpgradeableProxy::new(proxy).upgrade_to_and_call(
+            call,
+            implementation,
+            data.0.into(),
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{address, Address};
+    use stylus_sdk::msg;
+
+    use super::{Error, ProxyAdmin, UPGRADE_INTERFACE_VERSION};
+    use crate::access::ownable;
+
+    const ALICE: Address = address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
+    const PROXY: Address = address!("B0B0cB49ec2e96DF5F5fFB081acaE66A2cBBc2e2");
+
+    #[motsu::test]
+    fn reads_upgrade_interface_version(contract: ProxyAdmin) {
+        assert_eq!(
+            contract.upgrade_interface_version(),
+            UPGRADE_INTERFACE_VERSION
+        );
+    }
+
+    #[motsu::test]
+    fn upgrades_only_from_owner(contract: ProxyAdmin) {
+        contract.ownable._transfer_ownership(ALICE);
+        let err = contract
+            .upgrade_and_call(PROXY, ALICE, vec![].into())
+            .expect_err("should reject non-owner");
+        assert!(matches!(
+            err,
+            Error::Ownable(ownable::Error::UnauthorizedAccount(_))
+        ));
+
+        contract.ownable._transfer_ownership(msg::sender());
+        contract
+            .upgrade_and_call(PROXY, ALICE, vec![].into())
+            .expect("should upgrade from owner");
+    }
+}
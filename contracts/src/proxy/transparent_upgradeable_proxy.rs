@@ -0,0 +1,217 @@
+//! A proxy that is upgradeable through an immutable admin.
+//!
+//! To avoid [proxy selector clashing], which can potentially be used in an
+//! attack, the proxy routes calls depending on their caller:
+//!
+//! - If any account other than the admin calls the proxy, the call is delegated
+//!   to the implementation, even if the call matches the `upgradeToAndCall`
+//!   function exposed by the proxy itself.
+//! - If the admin calls the proxy, it can call the `upgradeToAndCall` function
+//!   but any other call is rejected with a [`ProxyDeniedAdminAccess`] error.
+//!
+//! So the admin can't interact with the implementation, and is meant to be a
+//! dedicated [`crate::proxy::proxy_admin::ProxyAdmin`] contract, owned by the
+//! account allowed to upgrade the proxy.
+//!
+//! Since Stylus contracts have no fallback function, a proxy routes all its
+//! calls itself with [`TransparentUpgradeableProxy::route`]:
+//!
+//! ```rust,ignore
+//! #[entrypoint]
+//! fn proxy(input: Vec<u8>) -> ArbResult {
+//!     // SAFETY: The proxy has no storage of its own outside of the ERC-1967
+//!     // slots, so there is no other instance to alias.
+//!     let mut proxy =
+//!         unsafe { TransparentUpgradeableProxy::new(U256::ZERO, 0) };
+//!     proxy.route(&input)
+//! }
+//! ```
+//!
+//! NOTE: Stylus contracts have no immutable variables, so the admin is read
+//! from the [`crate::proxy::erc1967::ADMIN_SLOT`], which must be set when the
+//! proxy is deployed, e.g. by its Solidity constructor. The proxy exposes no
+//! function to change its admin, but implementations could still overwrite the
+//! slot, and must not.
+//!
+//! [proxy selector clashing]: https://medium.com/nomic-foundation-blog/malicious-backdoors-in-ethereum-proxies-62629adf3357
+use alloc::vec::Vec;
+
+use alloy_sol_types::{sol, SolCall};
+use openzeppelin_stylus_proc::SolidityError;
+use stylus_sdk::{
+    call::{MethodError, RawCall},
+    msg,
+    prelude::sol_interface,
+    storage::TopLevelStorage,
+    stylus_proc::sol_storage,
+    ArbResult,
+};
+
+use crate::proxy::erc1967;
+
+sol! {
+    /// The proxy caller is the current admin, and can't fallback to the
+    /// proxy target.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error ProxyDeniedAdminAccess();
+
+    /// Upgrades the implementation of the proxy, see
+    /// [`erc1967::upgrade_to_and_call`].
+    #[allow(missing_docs)]
+    function upgradeToAndCall(address newImplementation, bytes data) external payable;
+}
+
+/// A Transparent Upgradeable Proxy error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// The admin called a function other than `upgradeToAndCall`.
+    DeniedAdminAccess(ProxyDeniedAdminAccess),
+    /// Error type from [`erc1967`] utilities [`erc1967::Error`].
+    Erc1967(erc1967::Error),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> Vec<u8> {
+        self.into()
+    }
+}
+
+sol_interface! {
+    /// Interface of a [`TransparentUpgradeableProxy`], only callable by its
+    /// admin.
+    interface ITransparentUpgradeableProxy {
+        /// See [`erc1967::upgrade_to_and_call`].
+        #[allow(missing_docs)]
+        function upgradeToAndCall(address new_implementation, bytes calldata data) external payable;
+    }
+}
+
+sol_storage! {
+    /// State of a Transparent Upgradeable Proxy, which only lives in the
+    /// ERC-1967 slots.
+    pub struct TransparentUpgradeableProxy {}
+}
+
+/// NOTE: Implementation of [`TopLevelStorage`] to be able use `&mut self` when
+/// calling other contracts and not `&mut (impl TopLevelStorage +
+/// BorrowMut<Self>)`. Should be fixed in the future by the Stylus team.
+unsafe impl TopLevelStorage for TransparentUpgradeableProxy {}
+
+impl TransparentUpgradeableProxy {
+    /// Handles a call to the proxy with calldata `input`, following the
+    /// routing rules of the [module documentation](self).
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `input` - Calldata of the call.
+    ///
+    /// # Errors
+    ///
+    /// * If the admin calls any function but `upgradeToAndCall`, then the error
+    ///   [`Error::DeniedAdminAccess`] is returned.
+    /// * If the admin's upgrade fails, then the error [`Error::Erc1967`] is
+    ///   returned.
+    /// * If the call delegated to the implementation reverts, then its revert
+    ///   data is returned.
+    ///
+    /// # Events
+    ///
+    /// Emits an [`erc1967::Upgraded`] event when the admin upgrades the
+    /// proxy.
+    pub fn route(&mut self, input: &[u8]) -> ArbResult {
+        if msg::sender() == erc1967::get_admin() {
+            self.dispatch_upgrade_to_and_call(input)?;
+            Ok(Vec::new())
+        } else {
+            fallback(input)
+        }
+    }
+
+    /// Upgrades the implementation of the proxy, if `input` is a call to
+    /// `upgradeToAndCall`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `input` - Calldata of the call.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::route`].
+    fn dispatch_upgrade_to_and_call(
+        &mut self,
+        input: &[u8],
+    ) -> Result<(), Error> {
+        let call = upgradeToAndCallCall::abi_decode(input, true)
+            .map_err(|_| ProxyDeniedAdminAccess {})?;
+        erc1967::upgrade_to_and_call(self, call.newImplementation, &call.data)
+            .map_err(Error::Erc1967)
+    }
+}
+
+/// Delegates the call with calldata `input` to the current implementation.
+///
+/// # Errors
+///
+/// If the call reverts, then its revert data is returned.
+fn fallback(input: &[u8]) -> ArbResult {
+    RawCall::new_delegate().call(erc1967::get_implementation(), input)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::vec::Vec;
+
+    use alloy_primitives::{address, Address, U256};
+    use alloy_sol_types::SolCall;
+    use stylus_sdk::{
+        msg,
+        storage::{StorageAddress, StorageType},
+    };
+
+    use super::*;
+    use crate::proxy::erc1967::{ERC1967InvalidImplementation, ADMIN_SLOT};
+
+    const ALICE: Address = address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
+
+    fn set_admin(admin: Address) {
+        let slot = U256::from_be_bytes(ADMIN_SLOT.0);
+        unsafe { StorageAddress::new(slot, 0) }.set(admin);
+    }
+
+    fn encoded(err: impl Into<Error>) -> Vec<u8> {
+        err.into().into()
+    }
+
+    #[motsu::test]
+    fn denies_admin_access(contract: TransparentUpgradeableProxy) {
+        set_admin(msg::sender());
+
+        let err = contract
+            .route(&[0xde, 0xad, 0xbe, 0xef])
+            .expect_err("should deny admin");
+        assert_eq!(err, encoded(ProxyDeniedAdminAccess {}));
+    }
+
+    #[motsu::test]
+    fn upgrades_from_admin(contract: TransparentUpgradeableProxy) {
+        set_admin(msg::sender());
+
+        let input = upgradeToAndCallCall {
+            newImplementation: ALICE,
+            data: Vec::new().into(),
+        }
+        .abi_encode();
+        let err = contract.route(&input).expect_err("should reject EOA");
+        assert_eq!(
+            err,
+            encoded(Error::Erc1967(
+                ERC1967InvalidImplementation { implementation: ALICE }.into()
+            ))
+        );
+        assert_eq!(erc1967::get_implementation(), Address::ZERO);
+    }
+}
@@ -0,0 +1,319 @@
+//! An upgradeability mechanism designed for UUPS proxies.
+//!
+//! Implementations of [`UUPSUpgradeable`] are meant to be called through an
+//! [ERC-1967] proxy, which delegates all its calls to them. The logic to
+//! upgrade the implementation of the proxy is part of the implementation
+//! itself, so every new implementation must implement [`UUPSUpgradeable`] as
+//! well to remain upgradeable, which [`UUPSUpgradeable::upgrade_to_and_call`]
+//! checks through its ERC-1822 `proxiableUUID`.
+//!
+//! Contracts authorize upgrades by implementing
+//! [`UUPSUpgradeable::_authorize_upgrade`], e.g. with an
+//! [`crate::access::ownable::Ownable::only_owner`] check, and expose the
+//! functions of the mixin under their Solidity names:
+//!
+//! ```rust,ignore
+//! impl UUPSUpgradeable for MyContract {
+//!     fn _authorize_upgrade(&mut self, _: Address) -> Result<(), Vec<u8>> {
+//!         self.ownable.only_owner()?;
+//!         Ok(())
+//!     }
+//! }
+//!
+//! #[public]
+//! impl MyContract {
+//!     #[selector(name = "proxiableUUID")]
+//!     fn proxiable_uuid(&self) -> Result<B256, Vec<u8>> {
+//!         Ok(UUPSUpgradeable::proxiable_uuid(self)?)
+//!     }
+//!
+//!     #[selector(name = "upgradeToAndCall")]
+//!     fn upgrade_to_and_call(
+//!         &mut self,
+//!         new_implementation: Address,
+//!         data: Bytes,
+//!     ) -> Result<(), Vec<u8>> {
+//!         UUPSUpgradeable::upgrade_to_and_call(self, new_implementation, &data)
+//!     }
+//! }
+//! ```
+//!
+//! NOTE: Stylus contracts have no immutable variables, so unlike its Solidity
+//! counterpart, an implementation can't record its own address. A call is
+//! considered to go through a proxy whenever the ERC-1967 implementation slot
+//! of the executing contract is set to another address.
+//!
+//! [ERC-1967]: https://eips.ethereum.org/EIPS/eip-1967
+use alloc::vec::Vec;
+
+use alloy_primitives::{Address, B256};
+use alloy_sol_types::sol;
+use openzeppelin_stylus_proc::SolidityError;
+use stylus_sdk::{
+    call::{Call, MethodError},
+    contract,
+    prelude::sol_interface,
+    storage::TopLevelStorage,
+};
+
+use crate::proxy::erc1967::{
+    self, ERC1967InvalidImplementation, IMPLEMENTATION_SLOT,
+};
+
+sol! {
+    /// The call is from an unauthorized context, i.e. not through a proxy,
+    /// or through one when it must not be.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error UUPSUnauthorizedCallContext();
+
+    /// The storage `slot` is unsupported as a UUID.
+    ///
+    /// * `slot` - UUID returned by the new implementation.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error UUPSUnsupportedProxiableUUID(bytes32 slot);
+}
+
+/// A UUPS Upgradeable error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// The call is from an unauthorized context.
+    UnauthorizedCallContext(UUPSUnauthorizedCallContext),
+    /// The UUID of the new implementation is unsupported.
+    UnsupportedProxiableUUID(UUPSUnsupportedProxiableUUID),
+    /// Error type from [`erc1967`] utilities [`erc1967::Error`].
+    Erc1967(erc1967::Error),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> Vec<u8> {
+        self.into()
+    }
+}
+
+sol_interface! {
+    /// ERC-1822 interface of the implementations of UUPS proxies.
+    interface IERC1822Proxiable {
+        /// Returns the storage slot that the proxiable contract assumes is
+        /// being used to store the implementation address.
+        #[allow(missing_docs)]
+        function proxiableUUID() external view returns (bytes32);
+    }
+}
+
+/// Upgradeability mixin, implemented by the implementations of UUPS proxies.
+pub trait UUPSUpgradeable: TopLevelStorage + Sized {
+    /// Checks that the caller is allowed to upgrade the contract to
+    /// `new_implementation`, returning revert data otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `new_implementation` - Address of the new implementation.
+    ///
+    /// # Errors
+    ///
+    /// Any error encoded as revert data, e.g. an
+    /// [`crate::access::ownable::Error`].
+    fn _authorize_upgrade(
+        &mut self,
+        new_implementation: Address,
+    ) -> Result<(), Vec<u8>>;
+
+    /// Returns the storage slot of the implementation address, as defined by
+    /// ERC-1822.
+    ///
+    /// It must not be callable through a proxy, so that a proxy can't be
+    /// upgraded to another proxy, which would brick it.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    ///
+    /// # Errors
+    ///
+    /// If called through a proxy, then the error
+    /// [`Error::UnauthorizedCallContext`] is returned.
+    fn proxiable_uuid(&self) -> Result<B256, Error> {
+        not_delegated()?;
+        Ok(IMPLEMENTATION_SLOT)
+    }
+
+    /// Upgrades the implementation of the proxy to `new_implementation`, and
+    /// delegates a call with `data` to it if `data` is not empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `new_implementation` - Address of the new implementation.
+    /// * `data` - Calldata delegated to the new implementation, e.g. to
+    ///   reinitialize it.
+    ///
+    /// # Errors
+    ///
+    /// * If not called through a proxy, then the error
+    ///   [`Error::UnauthorizedCallContext`] is returned.
+    /// * If [`Self::_authorize_upgrade`] fails, then its revert data is
+    ///   returned.
+    /// * If `new_implementation` doesn't return its `proxiableUUID`, then the
+    ///   error [`erc1967::Error::InvalidImplementation`] is returned.
+    /// * If the `proxiableUUID` of `new_implementation` is not
+    ///   [`IMPLEMENTATION_SLOT`], then the error
+    ///   [`Error::UnsupportedProxiableUUID`] is returned.
+    /// * If the upgrade itself fails, then the error [`Error::Erc1967`] is
+    ///   returned.
+    ///
+    /// # Events
+    ///
+    /// Emits an [`erc1967::Upgraded`] event.
+    fn upgrade_to_and_call(
+        &mut self,
+        new_implementation: Address,
+        data: &[u8],
+    ) -> Result<(), Vec<u8>> {
+        only_proxy()?;
+        self._authorize_upgrade(new_implementation)?;
+        upgrade_to_and_call_uups(self, new_implementation, data)?;
+        Ok(())
+    }
+}
+
+/// Checks that the execution is performed through a proxy.
+///
+/// # Errors
+///
+/// If the implementation slot is unset, or set to the executing contract,
+/// then the error [`Error::UnauthorizedCallContext`] is returned.
+fn only_proxy() -> Result<(), Error> {
+    let implementation = erc1967::get_implementation();
+    if implementation.is_zero() || implementation == contract::address() {
+        return Err(UUPSUnauthorizedCallContext {}.into());
+    }
+    Ok(())
+}
+
+/// Checks that the execution is not performed through a proxy.
+///
+/// # Errors
+///
+/// If the implementation slot is set, then the error
+/// [`Error::UnauthorizedCallContext`] is returned.
+fn not_delegated() -> Result<(), Error> {
+    if !erc1967::get_implementation().is_zero() {
+        return Err(UUPSUnauthorizedCallContext {}.into());
+    }
+    Ok(())
+}
+
+/// Upgrades to `new_implementation` after checking its `proxiableUUID`.
+///
+/// # Errors
+///
+/// See [`UUPSUpgradeable::upgrade_to_and_call`].
+fn upgrade_to_and_call_uups(
+    storage: &mut impl TopLevelStorage,
+    new_implementation: Address,
+    data: &[u8],
+) -> Result<(), Error> {
+    let slot = IERC1822Proxiable::new(new_implementation)
+        .proxiable_uuid(Call::new_in(storage))
+        .map_err(|_| {
+            Error::Erc1967(
+                ERC1967InvalidImplementation {
+                    implementation: new_implementation,
+                }
+                .into(),
+            )
+        })?;
+    if slot != IMPLEMENTATION_SLOT {
+        return Err(UUPSUnsupportedProxiableUUID { slot }.into());
+    }
+    erc1967::upgrade_to_and_call(storage, new_implementation, data)
+        .map_err(Error::Erc1967)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use alloy_primitives::{address, Address, U256};
+    use stylus_sdk::{
+        prelude::sol_storage,
+        storage::{StorageAddress, StorageType, TopLevelStorage},
+    };
+
+    use super::*;
+
+    const ALICE: Address = address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
+    const BOB: Address = address!("B0B0cB49ec2e96DF5F5fFB081acaE66A2cBBc2e2");
+
+    sol_storage! {
+        struct Upgradeable {
+            bool authorized;
+        }
+    }
+
+    unsafe impl TopLevelStorage for Upgradeable {}
+
+    impl UUPSUpgradeable for Upgradeable {
+        fn _authorize_upgrade(&mut self, _: Address) -> Result<(), Vec<u8>> {
+            if self.authorized.get() {
+                Ok(())
+            } else {
+                Err(vec![0xff])
+            }
+        }
+    }
+
+    /// Simulates a call through a proxy delegating to `implementation`.
+    fn delegate_to(implementation: Address) {
+        let slot = U256::from_be_bytes(IMPLEMENTATION_SLOT.0);
+        unsafe { StorageAddress::new(slot, 0) }.set(implementation);
+    }
+
+    fn encoded(err: impl Into<Error>) -> Vec<u8> {
+        err.into().into()
+    }
+
+    #[motsu::test]
+    fn returns_proxiable_uuid(contract: Upgradeable) {
+        assert_eq!(contract.proxiable_uuid().ok(), Some(IMPLEMENTATION_SLOT));
+
+        delegate_to(ALICE);
+        let err = contract.proxiable_uuid().expect_err("should be delegated");
+        assert!(matches!(err, Error::UnauthorizedCallContext(_)));
+    }
+
+    #[motsu::test]
+    fn upgrades_only_through_proxy(contract: Upgradeable) {
+        contract.authorized.set(true);
+        let err = contract
+            .upgrade_to_and_call(BOB, &[])
+            .expect_err("should not be delegated");
+        assert_eq!(err, encoded(UUPSUnauthorizedCallContext {}));
+    }
+
+    #[motsu::test]
+    fn authorizes_upgrades(contract: Upgradeable) {
+        delegate_to(ALICE);
+        let err = contract
+            .upgrade_to_and_call(BOB, &[])
+            .expect_err("should not be authorized");
+        assert_eq!(err, vec![0xff]);
+
+        contract.authorized.set(true);
+        let err = contract
+            .upgrade_to_and_call(BOB, &[])
+            .expect_err("should not be proxiable");
+        assert_eq!(
+            err,
+            encoded(Error::Erc1967(
+                ERC1967InvalidImplementation { implementation: BOB }.into()
+            ))
+        );
+        assert_eq!(erc1967::get_implementation(), ALICE);
+    }
+}
@@ -0,0 +1,246 @@
+//! Bridged Contract.
+//!
+//! Extension of the ERC-20 standard implementing the `IArbToken` interface of
+//! the Arbitrum token bridge, so that the token can be registered as the
+//! custom counterpart on an Arbitrum chain of the `l1_address` token of the
+//! parent chain.
+//!
+//! The bridge's gateway mints tokens when they are deposited on the parent
+//! chain, and burns them when they are withdrawn. Contracts expose the
+//! functions of [`Erc20`] along with those of [`Erc20Bridged`], by borrowing
+//! its `erc20`:
+//!
+//! ```rust,ignore
+//! sol_storage! {
+//!     #[entrypoint]
+//!     struct MyToken {
+//!         #[borrow]
+//!         Erc20Bridged bridged;
+//!     }
+//! }
+//!
+//! impl Borrow<Erc20> for MyToken {
+//!     fn borrow(&self) -> &Erc20 {
+//!         &self.bridged.erc20
+//!     }
+//! }
+//!
+//! impl BorrowMut<Erc20> for MyToken {
+//!     fn borrow_mut(&mut self) -> &mut Erc20 {
+//!         &mut self.bridged.erc20
+//!     }
+//! }
+//!
+//! #[public]
+//! #[inherit(Erc20Bridged, Erc20)]
+//! impl MyToken {}
+//! ```
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::sol;
+use openzeppelin_stylus_proc::{selectors, SolidityError};
+use stylus_sdk::{
+    msg,
+    stylus_proc::{public, sol_storage},
+};
+
+use crate::token::erc20::{self, Erc20};
+
+sol! {
+    #![sol(abi)]
+
+    /// The caller account is not the gateway of the token bridge.
+    ///
+    /// * `account` - Account that was found to not be authorized.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error ERC20BridgedUnauthorizedGateway(address account);
+}
+
+/// A Bridged error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// The caller account is not the gateway of the token bridge.
+    UnauthorizedGateway(ERC20BridgedUnauthorizedGateway),
+    /// Error type from [`Erc20`] contract [`erc20::Error`].
+    Erc20(erc20::Error),
+}
+
+sol_storage! {
+    /// State of a Bridged Contract.
+    #[allow(clippy::pub_underscore_fields)]
+    pub struct Erc20Bridged {
+        /// ERC-20 contract.
+        Erc20 erc20;
+        /// Gateway of the token bridge on the Arbitrum chain.
+        address _l2_gateway;
+        /// Counterpart of the token on the parent chain.
+        address _l1_address;
+    }
+}
+
+#[selectors(errors(ERC20BridgedUnauthorizedGateway))]
+#[public]
+impl Erc20Bridged {
+    /// Returns the address of the counterpart of the token on the parent
+    /// chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[must_use]
+    pub fn l1_address(&self) -> Address {
+        self._l1_address.get()
+    }
+
+    /// Returns the address of the gateway of the token bridge, allowed to mint
+    /// and burn tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[must_use]
+    pub fn l2_gateway(&self) -> Address {
+        self._l2_gateway.get()
+    }
+
+    /// Mints `amount` tokens to `account`, when they are deposited on the
+    /// parent chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `account` - Recipient of the tokens.
+    /// * `amount` - Amount of tokens to mint.
+    ///
+    /// # Errors
+    ///
+    /// * If called by any account other than the gateway, then the error
+    ///   [`Error::UnauthorizedGateway`] is returned.
+    /// * If `account` is `Address::ZERO`, then the error
+    ///   [`erc20::Error::InvalidReceiver`] is returned.
+    ///
+    /// # Panics
+    ///
+    /// If the total supply exceeds `U256::MAX`.
+    ///
+    /// # Events
+    ///
+    /// Emits a [`erc20::Transfer`] event.
+    pub fn bridge_mint(
+        &mut self,
+        account: Address,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self.only_l2_gateway()?;
+        self.erc20._mint(account, amount)?;
+        Ok(())
+    }
+
+    /// Burns `amount` tokens of `account`, when they are withdrawn to the
+    /// parent chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `account` - Owner of the tokens.
+    /// * `amount` - Amount of tokens to burn.
+    ///
+    /// # Errors
+    ///
+    /// * If called by any account other than the gateway, then the error
+    ///   [`Error::UnauthorizedGateway`] is returned.
+    /// * If `account` doesn't have enough tokens, then the error
+    ///   [`erc20::Error::InsufficientBalance`] is returned.
+    ///
+    /// # Events
+    ///
+    /// Emits a [`erc20::Transfer`] event.
+    pub fn bridge_burn(
+        &mut self,
+        account: Address,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self.only_l2_gateway()?;
+        self.erc20._burn(account, amount)?;
+        Ok(())
+    }
+}
+
+impl Erc20Bridged {
+    /// Checks that the caller is the gateway of the token bridge.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    ///
+    /// # Errors
+    ///
+    /// If called by any account other than the gateway, then the error
+    /// [`Error::UnauthorizedGateway`] is returned.
+    pub fn only_l2_gateway(&self) -> Result<(), Error> {
+        let account = msg::sender();
+        if account != self._l2_gateway.get() {
+            return Err(ERC20BridgedUnauthorizedGateway { account }.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{address, uint, Address};
+    use stylus_sdk::msg;
+
+    use super::{Erc20Bridged, Error};
+    use crate::token::erc20::{self, IErc20};
+
+    const ALICE: Address = address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
+    const L1_TOKEN: Address =
+        address!("B0B0cB49ec2e96DF5F5fFB081acaE66A2cBBc2e2");
+
+    #[motsu::test]
+    fn reads_bridge_addresses(contract: Erc20Bridged) {
+        contract._l2_gateway.set(ALICE);
+        contract._l1_address.set(L1_TOKEN);
+        assert_eq!(contract.l2_gateway(), ALICE);
+        assert_eq!(contract.l1_address(), L1_TOKEN);
+    }
+
+    #[motsu::test]
+    fn mints_and_burns_from_gateway(contract: Erc20Bridged) {
+        contract._l2_gateway.set(msg::sender());
+        let amount = uint!(10_U256);
+
+        contract.bridge_mint(ALICE, amount).expect("should mint");
+        assert_eq!(contract.erc20.balance_of(ALICE), amount);
+
+        contract.bridge_burn(ALICE, uint!(4_U256)).expect("should burn");
+        assert_eq!(contract.erc20.balance_of(ALICE), uint!(6_U256));
+        assert_eq!(contract.erc20.total_supply(), uint!(6_U256));
+
+        let err = contract
+            .bridge_burn(ALICE, amount)
+            .expect_err("should not burn more than the balance");
+        assert!(matches!(
+            err,
+            Error::Erc20(erc20::Error::InsufficientBalance(_))
+        ));
+    }
+
+    #[motsu::test]
+    fn rejects_other_accounts(contract: Erc20Bridged) {
+        contract._l2_gateway.set(ALICE);
+        let err = contract
+            .bridge_mint(ALICE, uint!(1_U256))
+            .expect_err("should reject non-gateway");
+        assert!(
+            matches!(err, Error::UnauthorizedGateway(e) if e.account == msg::sender())
+        );
+
+        let err = contract
+            .bridge_burn(ALICE, uint!(1_U256))
+            .expect_err("should reject non-gateway");
+        assert!(matches!(err, Error::UnauthorizedGateway(_)));
+    }
+}
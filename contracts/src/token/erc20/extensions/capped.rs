@@ -7,19 +7,24 @@
 
 use alloy_primitives::U256;
 use alloy_sol_types::sol;
-use stylus_sdk::stylus_proc::{public, sol_storage, SolidityError};
+use openzeppelin_stylus_proc::{selectors, SolidityError};
+use stylus_sdk::stylus_proc::{public, sol_storage};
 
 sol! {
+    #![sol(abi)]
+
     /// Indicates an error related to the operation that failed
     /// because `total_supply` exceeded the `_cap`.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error ERC20ExceededCap(uint256 increased_supply, uint256 cap);
 
     /// Indicates an error related to the operation that failed
     /// because the supplied `cap` is not a valid cap value.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error ERC20InvalidCap(uint256 cap);
 }
 
@@ -43,6 +48,7 @@ sol_storage! {
     }
 }
 
+#[selectors(errors(ERC20ExceededCap, ERC20InvalidCap))]
 #[public]
 impl Capped {
     /// Returns the cap on the token's total supply.
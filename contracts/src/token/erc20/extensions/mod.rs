@@ -1,10 +1,20 @@
 //! Common extensions to the ERC-20 standard.
+pub mod bridged;
 pub mod burnable;
 pub mod capped;
 pub mod metadata;
+pub mod permissioned;
+#[cfg(feature = "crypto")]
 pub mod permit;
+pub mod soulbound;
+pub mod transfer_limit;
 
+pub use bridged::Erc20Bridged;
 pub use burnable::IErc20Burnable;
 pub use capped::Capped;
 pub use metadata::{Erc20Metadata, IErc20Metadata};
+pub use permissioned::Erc20Permissioned;
+#[cfg(feature = "crypto")]
 pub use permit::Erc20Permit;
+pub use soulbound::Erc20Soulbound;
+pub use transfer_limit::Erc20TransferLimit;
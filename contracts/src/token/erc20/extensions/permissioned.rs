@@ -0,0 +1,892 @@
+//! Permissioned Contract.
+//!
+//! Extension of the ERC-20 standard in the style of [ERC-3643], for tokens of
+//! real-world assets whose holders must be verified, and whose transfers must
+//! comply with the rules of their issuer.
+//!
+//! Every transfer, mint and burn goes through [`Erc20Permissioned::_update`],
+//! which requires the recipient to be verified by the identity registry, and
+//! the transfer to be allowed by the compliance contract. The compliance
+//! contract is then notified of the transfer, mint or burn, e.g. to track the
+//! number of holders. When no identity registry is set, every account is
+//! verified, and when no compliance contract is set, every transfer is
+//! allowed.
+//!
+//! Agents of the issuer can force transfers that the compliance contract
+//! doesn't allow, e.g. on a court order, and recover the tokens of a wallet
+//! lost by its holder, with [`Erc20Permissioned::forced_transfer`] and
+//! [`Erc20Permissioned::recovery_address`].
+//!
+//! [`Erc20Permissioned`] overrides the functions of [`Erc20`] moving tokens,
+//! which bypass the checks, so contracts inherit it before [`Erc20`] for its
+//! functions to take precedence, and expose who can add agents, e.g. with
+//! [`crate::access::ownable::Ownable`]:
+//!
+//! ```rust,ignore
+//! sol_storage! {
+//!     #[entrypoint]
+//!     struct MyToken {
+//!         #[borrow]
+//!         Erc20Permissioned permissioned;
+//!         #[borrow]
+//!         Ownable ownable;
+//!     }
+//! }
+//!
+//! #[public]
+//! #[inherit(Erc20Permissioned, Erc20, Ownable)]
+//! impl MyToken {
+//!     fn add_agent(&mut self, agent: Address) -> Result<(), Vec<u8>> {
+//!         self.ownable.only_owner()?;
+//!         self.permissioned._add_agent(agent);
+//!         Ok(())
+//!     }
+//! }
+//! ```
+//!
+//! Along with the `Borrow<Erc20>` and `BorrowMut<Erc20>` implementations
+//! returning `self.permissioned.erc20`, like for
+//! [`super::bridged::Erc20Bridged`].
+//!
+//! [ERC-3643]: https://eips.ethereum.org/EIPS/eip-3643
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::sol;
+use openzeppelin_stylus_proc::{selectors, SolidityError};
+use stylus_sdk::{
+    call::{self, Call},
+    evm, msg,
+    prelude::sol_interface,
+    storage::TopLevelStorage,
+    stylus_proc::{public, sol_storage},
+};
+
+use crate::token::erc20::{
+    self, ERC20InvalidReceiver, ERC20InvalidSender, Erc20, IErc20,
+};
+
+sol_interface! {
+    /// Interface of the registry of the verified identities of the holders.
+    interface IIdentityRegistry {
+        /// Returns true if `user_address` is verified, and false otherwise.
+        #[allow(missing_docs)]
+        function isVerified(address user_address) external view returns (bool);
+    }
+
+    /// Interface of the contract enforcing the transfer rules of the issuer.
+    interface ICompliance {
+        /// Returns true if `amount` tokens can be transferred from `from` to
+        /// `to`, and false otherwise.
+        #[allow(missing_docs)]
+        function canTransfer(address from, address to, uint256 amount) external view returns (bool);
+
+        /// Notifies the compliance contract of a transfer.
+        #[allow(missing_docs)]
+        function transferred(address from, address to, uint256 amount) external;
+
+        /// Notifies the compliance contract of a mint.
+        #[allow(missing_docs)]
+        function created(address to, uint256 amount) external;
+
+        /// Notifies the compliance contract of a burn.
+        #[allow(missing_docs)]
+        function destroyed(address from, uint256 amount) external;
+    }
+}
+
+sol! {
+    #![sol(abi)]
+
+    /// Emitted when the identity registry is set to `identity_registry`.
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    event IdentityRegistryAdded(address indexed identity_registry);
+
+    /// Emitted when the compliance contract is set to `compliance`.
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    event ComplianceAdded(address indexed compliance);
+
+    /// Emitted when `agent` is added to the agents of the token.
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    event AgentAdded(address indexed agent);
+
+    /// Emitted when `agent` is removed from the agents of the token.
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    event AgentRemoved(address indexed agent);
+
+    /// Emitted when the tokens of `lost_wallet` are recovered to
+    /// `new_wallet`.
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    event RecoverySuccess(address indexed lost_wallet, address indexed new_wallet);
+}
+
+sol! {
+    #![sol(abi)]
+
+    /// Indicates an error related to an `account` that isn't verified by the
+    /// identity registry.
+    ///
+    /// * `account` - Account that isn't verified.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error ERC3643UnverifiedAccount(address account);
+
+    /// Indicates an error related to a transfer of `amount` tokens from
+    /// `from` to `to` that the compliance contract doesn't allow.
+    ///
+    /// * `from` - Account the tokens are transferred from.
+    /// * `to` - Account the tokens are transferred to.
+    /// * `amount` - Amount of tokens transferred.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error ERC3643NonCompliantTransfer(address from, address to, uint256 amount);
+
+    /// The caller account is not an agent of the token.
+    ///
+    /// * `account` - Account that was found to not be authorized.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error ERC3643UnauthorizedAgent(address account);
+}
+
+/// A Permissioned error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// The recipient isn't verified by the identity registry.
+    UnverifiedAccount(ERC3643UnverifiedAccount),
+    /// The compliance contract doesn't allow the transfer.
+    NonCompliantTransfer(ERC3643NonCompliantTransfer),
+    /// The caller account is not an agent of the token.
+    UnauthorizedAgent(ERC3643UnauthorizedAgent),
+    /// The notification of the compliance contract reverted, with the reason
+    /// specified by it.
+    FailedCall(call::Error),
+    /// Error type from [`Erc20`] contract [`erc20::Error`].
+    Erc20(erc20::Error),
+}
+
+sol_storage! {
+    /// State of a Permissioned Contract.
+    #[allow(clippy::pub_underscore_fields)]
+    pub struct Erc20Permissioned {
+        /// ERC-20 contract.
+        Erc20 erc20;
+        /// Registry of the verified identities. Zero verifies every account.
+        address _identity_registry;
+        /// Contract enforcing the transfer rules. Zero allows every transfer.
+        address _compliance;
+        /// Agents of the token, allowed to force transfers.
+        mapping(address => bool) _agents;
+    }
+}
+
+/// NOTE: Implementation of [`TopLevelStorage`] to be able use `&mut self` when
+/// calling other contracts and not `&mut (impl TopLevelStorage +
+/// BorrowMut<Self>)`. Should be fixed in the future by the Stylus team.
+unsafe impl TopLevelStorage for Erc20Permissioned {}
+
+#[selectors(
+    events(
+        IdentityRegistryAdded,
+        ComplianceAdded,
+        AgentAdded,
+        AgentRemoved,
+        RecoverySuccess
+    ),
+    errors(
+        ERC3643UnverifiedAccount,
+        ERC3643NonCompliantTransfer,
+        ERC3643UnauthorizedAgent
+    )
+)]
+#[public]
+impl Erc20Permissioned {
+    /// Returns the address of the identity registry.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[must_use]
+    pub fn identity_registry(&self) -> Address {
+        self._identity_registry.get()
+    }
+
+    /// Returns the address of the compliance contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[must_use]
+    pub fn compliance(&self) -> Address {
+        self._compliance.get()
+    }
+
+    /// Returns true if `account` is an agent of the token, and false
+    /// otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `account` - Account to check.
+    #[must_use]
+    pub fn is_agent(&self, account: Address) -> bool {
+        self._agents.get(account)
+    }
+
+    /// Transfers `amount` tokens from `from` to `to`, without checking that
+    /// the compliance contract allows it.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account to transfer tokens from.
+    /// * `to` - Account to transfer tokens to.
+    /// * `amount` - Amount of tokens to transfer.
+    ///
+    /// # Errors
+    ///
+    /// * If called by any account other than an agent, then the error
+    ///   [`Error::UnauthorizedAgent`] is returned.
+    /// * If `to` isn't verified, then the error [`Error::UnverifiedAccount`] is
+    ///   returned.
+    /// * If `from` doesn't have enough tokens, then the error
+    ///   [`erc20::Error::InsufficientBalance`] is returned.
+    /// * If the notification of the compliance contract reverts, then the error
+    ///   [`Error::FailedCall`] is returned.
+    ///
+    /// # Events
+    ///
+    /// Emits a [`erc20::Transfer`] event.
+    pub fn forced_transfer(
+        &mut self,
+        from: Address,
+        to: Address,
+        amount: U256,
+    ) -> Result<bool, Error> {
+        self.only_agent()?;
+        self._forced_transfer(from, to, amount)?;
+        Ok(true)
+    }
+
+    /// Transfers all the tokens of `lost_wallet` to `new_wallet`, when its
+    /// holder lost access to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `lost_wallet` - Wallet the holder lost access to.
+    /// * `new_wallet` - New wallet of the holder.
+    ///
+    /// # Errors
+    ///
+    /// * If called by any account other than an agent, then the error
+    ///   [`Error::UnauthorizedAgent`] is returned.
+    /// * If `new_wallet` isn't verified, then the error
+    ///   [`Error::UnverifiedAccount`] is returned.
+    /// * If the notification of the compliance contract reverts, then the error
+    ///   [`Error::FailedCall`] is returned.
+    ///
+    /// # Events
+    ///
+    /// Emits a [`erc20::Transfer`] event, followed by a [`RecoverySuccess`]
+    /// event.
+    pub fn recovery_address(
+        &mut self,
+        lost_wallet: Address,
+        new_wallet: Address,
+    ) -> Result<bool, Error> {
+        self.only_agent()?;
+        let balance = self.erc20.balance_of(lost_wallet);
+        self._forced_transfer(lost_wallet, new_wallet, balance)?;
+        evm::log(RecoverySuccess { lost_wallet, new_wallet });
+        Ok(true)
+    }
+
+    /// Moves `value` tokens from the caller's account to `to`, like
+    /// [`Erc20::transfer`], if `to` is verified and the transfer is
+    /// compliant.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `to` - Account to transfer tokens to.
+    /// * `value` - Number of tokens to transfer.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::_update`], along with the errors of [`Erc20::transfer`].
+    ///
+    /// # Events
+    ///
+    /// Emits a [`erc20::Transfer`] event.
+    pub fn transfer(
+        &mut self,
+        to: Address,
+        value: U256,
+    ) -> Result<bool, Error> {
+        let from = msg::sender();
+        self._transfer(from, to, value)?;
+        Ok(true)
+    }
+
+    /// Moves `value` tokens from `from` to `to` using the allowance mechanism,
+    /// like [`Erc20::transfer_from`], if `to` is verified and the transfer is
+    /// compliant.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account to transfer tokens from.
+    /// * `to` - Account to transfer tokens to.
+    /// * `value` - Number of tokens to transfer.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::_update`], along with the errors of
+    /// [`Erc20::transfer_from`].
+    ///
+    /// # Events
+    ///
+    /// Emits a [`erc20::Transfer`] event.
+    pub fn transfer_from(
+        &mut self,
+        from: Address,
+        to: Address,
+        value: U256,
+    ) -> Result<bool, Error> {
+        let spender = msg::sender();
+        self.erc20._spend_allowance(from, spender, value)?;
+        self._transfer(from, to, value)?;
+        Ok(true)
+    }
+}
+
+impl Erc20Permissioned {
+    /// Creates a `value` amount of tokens and assigns them to `account`, like
+    /// [`Erc20::_mint`], if `account` is verified.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `account` - Account to mint tokens to.
+    /// * `value` - Number of tokens to mint.
+    ///
+    /// # Errors
+    ///
+    /// * If `account` is `Address::ZERO`, then the error
+    ///   [`erc20::Error::InvalidReceiver`] is returned.
+    /// * See [`Self::_update`].
+    ///
+    /// # Panics
+    ///
+    /// If the total supply exceeds `U256::MAX`.
+    ///
+    /// # Events
+    ///
+    /// Emits a [`erc20::Transfer`] event.
+    pub fn _mint(
+        &mut self,
+        account: Address,
+        value: U256,
+    ) -> Result<(), Error> {
+        if account.is_zero() {
+            return Err(erc20::Error::InvalidReceiver(ERC20InvalidReceiver {
+                receiver: Address::ZERO,
+            })
+            .into());
+        }
+        self._update(Address::ZERO, account, value)
+    }
+
+    /// Destroys a `value` amount of tokens from `account`, like
+    /// [`Erc20::_burn`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `account` - Account to burn tokens from.
+    /// * `value` - Number of tokens to burn.
+    ///
+    /// # Errors
+    ///
+    /// * If `account` is `Address::ZERO`, then the error
+    ///   [`erc20::Error::InvalidSender`] is returned.
+    /// * See [`Self::_update`].
+    ///
+    /// # Events
+    ///
+    /// Emits a [`erc20::Transfer`] event.
+    pub fn _burn(
+        &mut self,
+        account: Address,
+        value: U256,
+    ) -> Result<(), Error> {
+        if account.is_zero() {
+            return Err(erc20::Error::InvalidSender(ERC20InvalidSender {
+                sender: Address::ZERO,
+            })
+            .into());
+        }
+        self._update(account, Address::ZERO, value)
+    }
+
+    /// Transfers `value` tokens from `from` to `to`, like [`Erc20::_update`],
+    /// requiring `to` to be verified, unless tokens are burnt, and the
+    /// compliance contract to allow the transfer, unless tokens are minted or
+    /// burnt. Then notifies the compliance contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account to transfer tokens from.
+    /// * `to` - Account to transfer tokens to.
+    /// * `value` - Number of tokens to transfer.
+    ///
+    /// # Errors
+    ///
+    /// * If `to` isn't verified, or the identity registry can't be queried,
+    ///   then the error [`Error::UnverifiedAccount`] is returned.
+    /// * If the compliance contract doesn't allow the transfer, or can't be
+    ///   queried, then the error [`Error::NonCompliantTransfer`] is returned.
+    /// * If `from` doesn't have enough tokens, then the error
+    ///   [`erc20::Error::InsufficientBalance`] is returned.
+    /// * If the notification of the compliance contract reverts, then the error
+    ///   [`Error::FailedCall`] is returned.
+    ///
+    /// # Panics
+    ///
+    /// If the total supply exceeds `U256::MAX`.
+    ///
+    /// # Events
+    ///
+    /// Emits a [`erc20::Transfer`] event.
+    pub fn _update(
+        &mut self,
+        from: Address,
+        to: Address,
+        value: U256,
+    ) -> Result<(), Error> {
+        if !to.is_zero() {
+            self.when_verified(to)?;
+        }
+        if !from.is_zero() && !to.is_zero() {
+            self.when_compliant(from, to, value)?;
+        }
+        self.erc20._update(from, to, value)?;
+        self._notify_compliance(from, to, value)
+    }
+
+    /// Checks that the caller is an agent of the token.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    ///
+    /// # Errors
+    ///
+    /// If called by any account other than an agent, then the error
+    /// [`Error::UnauthorizedAgent`] is returned.
+    pub fn only_agent(&self) -> Result<(), Error> {
+        let account = msg::sender();
+        if !self._agents.get(account) {
+            return Err(ERC3643UnauthorizedAgent { account }.into());
+        }
+        Ok(())
+    }
+
+    /// Checks that `account` is verified by the identity registry.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `account` - Account to check.
+    ///
+    /// # Errors
+    ///
+    /// If `account` isn't verified, or the identity registry can't be
+    /// queried, then the error [`Error::UnverifiedAccount`] is returned.
+    pub fn when_verified(&self, account: Address) -> Result<(), Error> {
+        let registry = self._identity_registry.get();
+        if registry.is_zero() {
+            return Ok(());
+        }
+
+        match IIdentityRegistry::new(registry).is_verified(self, account) {
+            Ok(true) => Ok(()),
+            Ok(false) | Err(_) => {
+                Err(ERC3643UnverifiedAccount { account }.into())
+            }
+        }
+    }
+
+    /// Checks that the compliance contract allows the transfer of `amount`
+    /// tokens from `from` to `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `from` - Account the tokens are transferred from.
+    /// * `to` - Account the tokens are transferred to.
+    /// * `amount` - Amount of tokens transferred.
+    ///
+    /// # Errors
+    ///
+    /// If the compliance contract doesn't allow the transfer, or can't be
+    /// queried, then the error [`Error::NonCompliantTransfer`] is returned.
+    pub fn when_compliant(
+        &self,
+        from: Address,
+        to: Address,
+        amount: U256,
+    ) -> Result<(), Error> {
+        let compliance = self._compliance.get();
+        if compliance.is_zero() {
+            return Ok(());
+        }
+
+        match ICompliance::new(compliance).can_transfer(self, from, to, amount)
+        {
+            Ok(true) => Ok(()),
+            Ok(false) | Err(_) => {
+                Err(ERC3643NonCompliantTransfer { from, to, amount }.into())
+            }
+        }
+    }
+
+    /// Sets the identity registry to `identity_registry`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `identity_registry` - Address of the identity registry. Zero verifies
+    ///   every account.
+    ///
+    /// # Events
+    ///
+    /// Emits an [`IdentityRegistryAdded`] event.
+    pub fn _set_identity_registry(&mut self, identity_registry: Address) {
+        self._identity_registry.set(identity_registry);
+        evm::log(IdentityRegistryAdded { identity_registry });
+    }
+
+    /// Sets the compliance contract to `compliance`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `compliance` - Address of the compliance contract. Zero allows every
+    ///   transfer.
+    ///
+    /// # Events
+    ///
+    /// Emits a [`ComplianceAdded`] event.
+    pub fn _set_compliance(&mut self, compliance: Address) {
+        self._compliance.set(compliance);
+        evm::log(ComplianceAdded { compliance });
+    }
+
+    /// Adds `agent` to the agents of the token.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `agent` - Account to add.
+    ///
+    /// # Events
+    ///
+    /// Emits an [`AgentAdded`] event, if `agent` wasn't an agent.
+    pub fn _add_agent(&mut self, agent: Address) {
+        if !self._agents.get(agent) {
+            self._agents.setter(agent).set(true);
+            evm::log(AgentAdded { agent });
+        }
+    }
+
+    /// Removes `agent` from the agents of the token.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `agent` - Account to remove.
+    ///
+    /// # Events
+    ///
+    /// Emits an [`AgentRemoved`] event, if `agent` was an agent.
+    pub fn _remove_agent(&mut self, agent: Address) {
+        if self._agents.get(agent) {
+            self._agents.setter(agent).set(false);
+            evm::log(AgentRemoved { agent });
+        }
+    }
+
+    /// Moves `value` tokens from `from` to `to`, checking the zero addresses
+    /// like [`Erc20`] does.
+    fn _transfer(
+        &mut self,
+        from: Address,
+        to: Address,
+        value: U256,
+    ) -> Result<(), Error> {
+        check_accounts(from, to)?;
+        self._update(from, to, value)
+    }
+
+    /// Moves `value` tokens from `from` to `to`, requiring `to` to be
+    /// verified, but not the compliance contract to allow the transfer.
+    fn _forced_transfer(
+        &mut self,
+        from: Address,
+        to: Address,
+        value: U256,
+    ) -> Result<(), Error> {
+        check_accounts(from, to)?;
+        self.when_verified(to)?;
+        self.erc20._update(from, to, value)?;
+        self._notify_compliance(from, to, value)
+    }
+
+    /// Notifies the compliance contract of the transfer, mint or burn of
+    /// `value` tokens, if it is set.
+    fn _notify_compliance(
+        &mut self,
+        from: Address,
+        to: Address,
+        value: U256,
+    ) -> Result<(), Error> {
+        let compliance = self._compliance.get();
+        if compliance.is_zero() {
+            return Ok(());
+        }
+
+        let compliance = ICompliance::new(compliance);
+        let result = if from.is_zero() {
+            compliance.created(Call::new_in(self), to, value)
+        } else if to.is_zero() {
+            compliance.destroyed(Call::new_in(self), from, value)
+        } else {
+            compliance.transferred(Call::new_in(self), from, to, value)
+        };
+        result.map_err(Error::FailedCall)
+    }
+}
+
+/// Checks that neither `from` nor `to` is `Address::ZERO`.
+fn check_accounts(from: Address, to: Address) -> Result<(), Error> {
+    if from.is_zero() {
+        return Err(erc20::Error::InvalidSender(ERC20InvalidSender {
+            sender: Address::ZERO,
+        })
+        .into());
+    }
+    if to.is_zero() {
+        return Err(erc20::Error::InvalidReceiver(ERC20InvalidReceiver {
+            receiver: Address::ZERO,
+        })
+        .into());
+    }
+    Ok(())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{address, uint, Address, U256};
+    use alloy_sol_types::SolValue;
+    use motsu::prelude::{mock_call, mock_revert};
+    use stylus_sdk::{
+        function_selector, msg,
+        prelude::{public, sol_storage},
+        storage::TopLevelStorage,
+    };
+
+    use super::{ERC3643NonCompliantTransfer, Erc20Permissioned, Error};
+    use crate::{
+        token::erc20::{self, Erc20, IErc20},
+        utils::hooks::{route_with_hooks, ExecutionHooks},
+    };
+
+    const ALICE: Address = address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
+    const BOB: Address = address!("B0B0cB49ec2e96DF5F5fFB081acaE66A2cBBc2e2");
+    const REGISTRY: Address =
+        address!("40C57923924B5c5c5455c48D93317139ADDaC8fb");
+    const COMPLIANCE: Address =
+        address!("F4EB5Af2Fa8d7fD1C4B23707E8D3f21Ec77F4b83");
+
+    #[motsu::test]
+    fn allows_everyone_without_registry_and_compliance(
+        contract: Erc20Permissioned,
+    ) {
+        let amount = uint!(10_U256);
+        contract._mint(msg::sender(), amount).expect("should mint");
+        contract.transfer(ALICE, amount).expect("should transfer");
+        assert_eq!(contract.erc20.balance_of(ALICE), amount);
+
+        contract._burn(ALICE, amount).expect("should burn");
+        assert_eq!(contract.erc20.total_supply(), uint!(0_U256));
+    }
+
+    #[motsu::test]
+    fn rejects_unverified_receivers(contract: Erc20Permissioned) {
+        contract._set_identity_registry(REGISTRY);
+        assert_eq!(contract.identity_registry(), REGISTRY);
+        let selector = function_selector!("isVerified", Address);
+        mock_call(REGISTRY, selector, false.abi_encode());
+
+        let err = contract
+            ._mint(ALICE, uint!(1_U256))
+            .expect_err("should reject unverified receiver");
+        assert!(
+            matches!(err, Error::UnverifiedAccount(e) if e.account == ALICE)
+        );
+
+        mock_call(REGISTRY, selector, true.abi_encode());
+        contract._mint(ALICE, uint!(1_U256)).expect("should mint");
+        assert_eq!(contract.erc20.balance_of(ALICE), uint!(1_U256));
+    }
+
+    #[motsu::test]
+    fn rejects_non_compliant_transfers(contract: Erc20Permissioned) {
+        let amount = uint!(10_U256);
+        contract._mint(msg::sender(), amount).expect("should mint");
+        contract._set_compliance(COMPLIANCE);
+        let selector =
+            function_selector!("canTransfer", Address, Address, U256);
+        mock_call(COMPLIANCE, selector, false.abi_encode());
+
+        let err = contract
+            .transfer(ALICE, amount)
+            .expect_err("should reject non-compliant transfer");
+        assert!(matches!(
+            err,
+            Error::NonCompliantTransfer(e) if e.to == ALICE && e.amount == amount
+        ));
+
+        mock_call(COMPLIANCE, selector, true.abi_encode());
+        let selector =
+            function_selector!("transferred", Address, Address, U256);
+        mock_call(COMPLIANCE, selector, vec![]);
+        contract.transfer(ALICE, uint!(4_U256)).expect("should transfer");
+        assert_eq!(contract.erc20.balance_of(ALICE), uint!(4_U256));
+
+        mock_revert(COMPLIANCE, selector, vec![]);
+        let err = contract
+            .transfer(ALICE, uint!(4_U256))
+            .expect_err("should revert with the compliance contract");
+        assert!(matches!(err, Error::FailedCall(_)));
+    }
+
+    #[motsu::test]
+    fn agents_force_transfers_and_recover_wallets(contract: Erc20Permissioned) {
+        let amount = uint!(10_U256);
+        contract._mint(ALICE, amount).expect("should mint");
+        contract._set_compliance(COMPLIANCE);
+        let selector =
+            function_selector!("canTransfer", Address, Address, U256);
+        mock_call(COMPLIANCE, selector, false.abi_encode());
+        let selector =
+            function_selector!("transferred", Address, Address, U256);
+        mock_call(COMPLIANCE, selector, vec![]);
+
+        let err = contract
+            .forced_transfer(ALICE, BOB, uint!(4_U256))
+            .expect_err("should reject non-agent");
+        assert!(
+            matches!(err, Error::UnauthorizedAgent(e) if e.account == msg::sender())
+        );
+
+        contract._add_agent(msg::sender());
+        assert!(contract.is_agent(msg::sender()));
+        contract
+            .forced_transfer(ALICE, BOB, uint!(4_U256))
+            .expect("should force transfer");
+        assert_eq!(contract.erc20.balance_of(BOB), uint!(4_U256));
+
+        contract.recovery_address(ALICE, BOB).expect("should recover");
+        assert_eq!(contract.erc20.balance_of(ALICE), uint!(0_U256));
+        assert_eq!(contract.erc20.balance_of(BOB), amount);
+
+        contract._remove_agent(msg::sender());
+        let err = contract
+            .recovery_address(BOB, ALICE)
+            .expect_err("should reject removed agent");
+        assert!(matches!(err, Error::UnauthorizedAgent(_)));
+
+        let err = contract
+            .transfer_from(BOB, ALICE, amount)
+            .expect_err("should spend the allowance first");
+        assert!(matches!(
+            err,
+            Error::Erc20(erc20::Error::InsufficientAllowance(_))
+        ));
+    }
+
+    sol_storage! {
+        struct Token {
+            #[borrow]
+            Erc20Permissioned permissioned;
+        }
+    }
+
+    unsafe impl TopLevelStorage for Token {}
+
+    impl core::borrow::Borrow<Erc20> for Token {
+        fn borrow(&self) -> &Erc20 {
+            &self.permissioned.erc20
+        }
+    }
+
+    impl core::borrow::BorrowMut<Erc20> for Token {
+        fn borrow_mut(&mut self) -> &mut Erc20 {
+            &mut self.permissioned.erc20
+        }
+    }
+
+    #[public]
+    #[inherit(Erc20Permissioned, Erc20)]
+    impl Token {}
+
+    const _: () = Token::__stylus_assert_overrides();
+
+    impl ExecutionHooks for Token {}
+
+    #[motsu::test]
+    fn checks_transfers_of_composed_token(contract: Token) {
+        let amount = uint!(10_U256);
+        let permissioned = &mut contract.permissioned;
+        permissioned._mint(msg::sender(), amount).expect("should mint");
+        permissioned._set_compliance(COMPLIANCE);
+        let selector =
+            function_selector!("canTransfer", Address, Address, U256);
+        mock_call(COMPLIANCE, selector, false.abi_encode());
+
+        let calldata = [
+            function_selector!("transfer", Address, U256).as_slice(),
+            &(ALICE, amount).abi_encode_params(),
+        ]
+        .concat();
+        let err = route_with_hooks::<Token>(&calldata)
+            .expect_err("should reject non-compliant `transfer`");
+        let expected: Vec<u8> =
+            Error::NonCompliantTransfer(ERC3643NonCompliantTransfer {
+                from: msg::sender(),
+                to: ALICE,
+                amount,
+            })
+            .into();
+        assert_eq!(err, expected);
+
+        let calldata = [
+            function_selector!("balanceOf", Address).as_slice(),
+            &ALICE.abi_encode(),
+        ]
+        .concat();
+        let balance = route_with_hooks::<Token>(&calldata)
+            .expect("should route inherited `Erc20::balance_of`");
+        assert_eq!(balance, U256::ZERO.abi_encode());
+    }
+}
@@ -11,17 +11,18 @@
 //! and thus is not required to hold Ether at all.
 use alloy_primitives::{b256, keccak256, Address, B256, U256};
 use alloy_sol_types::{sol, SolType};
+use openzeppelin_stylus_proc::{selectors, SolidityError};
 use stylus_sdk::{
-    block,
     prelude::StorageType,
     storage::TopLevelStorage,
-    stylus_proc::{public, sol_storage, SolidityError},
+    stylus_proc::{public, sol_storage},
 };
 
 use crate::{
     token::erc20::{self, Erc20, IErc20},
     utils::{
         cryptography::{ecdsa, eip712::IEip712},
+        deadline,
         nonces::Nonces,
     },
 };
@@ -36,15 +37,19 @@ type StructHashTuple = sol! {
 };
 
 sol! {
+    #![sol(abi)]
+
     /// Indicates an error related to the fact that
     /// permit deadline has expired.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error ERC2612ExpiredSignature(uint256 deadline);
 
     /// Indicates an error related to the issue about mismatched signature.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error ERC2612InvalidSigner(address signer, address owner);
 }
 
@@ -81,6 +86,13 @@ sol_storage! {
 /// BorrowMut<Self>)`. Should be fixed in the future by the Stylus team.
 unsafe impl<T: IEip712 + StorageType> TopLevelStorage for Erc20Permit<T> {}
 
+#[selectors(errors(
+    ERC2612ExpiredSignature,
+    ERC2612InvalidSigner,
+    ecdsa::ECDSAInvalidSignature,
+    ecdsa::ECDSAInvalidSignatureLength,
+    ecdsa::ECDSAInvalidSignatureS
+))]
 #[public]
 impl<T: IEip712 + StorageType> Erc20Permit<T> {
     /// Returns the current nonce for `owner`.
@@ -156,9 +168,9 @@ impl<T: IEip712 + StorageType> Erc20Permit<T> {
         r: B256,
         s: B256,
     ) -> Result<(), Error> {
-        if U256::from(block::timestamp()) > deadline {
-            return Err(ERC2612ExpiredSignature { deadline }.into());
-        }
+        // ERC-2612 mandates its own error for an expired permit.
+        deadline::ensure_deadline(deadline)
+            .map_err(|_| ERC2612ExpiredSignature { deadline })?;
 
         let struct_hash = keccak256(StructHashTuple::abi_encode(&(
             *PERMIT_TYPEHASH,
@@ -0,0 +1,284 @@
+//! Soulbound Contract.
+//!
+//! Extension of the ERC-20 standard making tokens non-transferable, e.g. for
+//! reputation or points tokens, which can only be minted to and burnt from
+//! their holders.
+//!
+//! Transfers are only allowed when either the sender or the recipient is
+//! whitelisted, e.g. a contract redeeming points for rewards.
+//!
+//! Like [`super::transfer_limit::Erc20TransferLimit`], tokens check every
+//! transfer performed by the functions of [`Erc20`] with
+//! [`Erc20Soulbound::before_transfer`] from their execution hooks, so that it
+//! composes with the checks of other extensions, and applies to inherited
+//! functions:
+//!
+//! ```rust,ignore
+//! impl ExecutionHooks for MyPoints {
+//!     fn before_call(
+//!         &mut self,
+//!         selector: u32,
+//!         input: &[u8],
+//!     ) -> Result<(), Vec<u8>> {
+//!         if let Some((from, to, _)) = transfer_of_call(selector, input) {
+//!             self.soulbound.before_transfer(from, to)?;
+//!         }
+//!         Ok(())
+//!     }
+//! }
+//!
+//! const _: () = MyPoints::__stylus_assert_overrides();
+//!
+//! #[entrypoint]
+//! fn entrypoint(input: Vec<u8>) -> ArbResult {
+//!     route_with_hooks::<MyPoints>(&input)
+//! }
+//! ```
+//!
+//! The execution hooks only see the calls of `transfer` and `transferFrom`.
+//! Functions of the token moving tokens between two accounts themselves, e.g.
+//! custom functions calling [`Erc20::_update`], or
+//! [`Erc20Permissioned::forced_transfer`] and
+//! [`Erc20Permissioned::recovery_address`], bypass the hooks, and must call
+//! [`Erc20Soulbound::before_transfer`] before moving the tokens.
+//!
+//! [`Erc20`]: crate::token::erc20::Erc20
+//! [`Erc20::_update`]: crate::token::erc20::Erc20::_update
+//! [`Erc20Permissioned::forced_transfer`]: crate::token::erc20::extensions::Erc20Permissioned::forced_transfer
+//! [`Erc20Permissioned::recovery_address`]: crate::token::erc20::extensions::Erc20Permissioned::recovery_address
+use alloy_primitives::Address;
+use alloy_sol_types::sol;
+use openzeppelin_stylus_proc::{selectors, SolidityError};
+use stylus_sdk::{
+    evm,
+    stylus_proc::{public, sol_storage},
+};
+
+sol! {
+    #![sol(abi)]
+
+    /// Emitted when `account` is added to, or removed from, the whitelist of
+    /// accounts allowed to send and receive tokens.
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    event SoulboundWhitelistSet(address indexed account, bool whitelisted);
+}
+
+sol! {
+    #![sol(abi)]
+
+    /// Indicates an error related to a transfer from `from` to `to`, neither
+    /// of which is whitelisted.
+    ///
+    /// * `from` - Account the tokens are transferred from.
+    /// * `to` - Account the tokens are transferred to.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error ERC20NonTransferable(address from, address to);
+}
+
+/// A Soulbound error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Neither the sender nor the recipient of a transfer is whitelisted.
+    NonTransferable(ERC20NonTransferable),
+}
+
+sol_storage! {
+    /// State of a Soulbound Contract.
+    #[allow(clippy::pub_underscore_fields)]
+    pub struct Erc20Soulbound {
+        /// Mapping from account to whether it can send and receive tokens.
+        mapping(address => bool) _whitelisted;
+    }
+}
+
+#[selectors(events(SoulboundWhitelistSet), errors(ERC20NonTransferable))]
+#[public]
+impl Erc20Soulbound {
+    /// Returns true if `account` can send and receive tokens, and false
+    /// otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `account` - Account to query.
+    #[must_use]
+    pub fn is_whitelisted(&self, account: Address) -> bool {
+        self._whitelisted.get(account)
+    }
+}
+
+impl Erc20Soulbound {
+    /// Checks that tokens can be transferred from `from` to `to`, i.e. that
+    /// tokens are minted or burnt, or that either account is whitelisted.
+    ///
+    /// Should be called before every transfer of tokens from `from` to `to`,
+    /// e.g. from the execution hooks of the token, see
+    /// [`crate::token::erc20::hooks`], and from the functions of the token
+    /// which move tokens themselves.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `from` - Account sending the tokens.
+    /// * `to` - Account receiving the tokens.
+    ///
+    /// # Errors
+    ///
+    /// If neither `from` nor `to` is whitelisted, then the error
+    /// [`Error::NonTransferable`] is returned.
+    pub fn before_transfer(
+        &self,
+        from: Address,
+        to: Address,
+    ) -> Result<(), Error> {
+        if from.is_zero()
+            || to.is_zero()
+            || self._whitelisted.get(from)
+            || self._whitelisted.get(to)
+        {
+            return Ok(());
+        }
+        Err(ERC20NonTransferable { from, to }.into())
+    }
+
+    /// Adds `account` to the whitelist, or removes it.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `account` - Account to add or remove.
+    /// * `whitelisted` - Whether `account` can send and receive tokens.
+    ///
+    /// # Events
+    ///
+    /// Emits a [`SoulboundWhitelistSet`] event.
+    pub fn _set_whitelisted(&mut self, account: Address, whitelisted: bool) {
+        self._whitelisted.setter(account).set(whitelisted);
+        evm::log(SoulboundWhitelistSet { account, whitelisted });
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{address, uint, Address, U256};
+    use alloy_sol_types::SolValue;
+    use stylus_sdk::{
+        function_selector, msg,
+        prelude::{public, sol_storage},
+        storage::TopLevelStorage,
+    };
+
+    use super::{ERC20NonTransferable, Erc20Soulbound, Error};
+    use crate::{
+        token::erc20::{hooks::transfer_of_call, Erc20, IErc20},
+        utils::hooks::{route_with_hooks, ExecutionHooks},
+    };
+
+    const ALICE: Address = address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
+    const BOB: Address = address!("B0B0cB49ec2e96DF5F5fFB081acaE66A2cBBc2e2");
+
+    #[motsu::test]
+    fn allows_mints_and_burns(contract: Erc20Soulbound) {
+        contract
+            .before_transfer(Address::ZERO, ALICE)
+            .expect("should allow mints");
+        contract
+            .before_transfer(ALICE, Address::ZERO)
+            .expect("should allow burns");
+    }
+
+    #[motsu::test]
+    fn rejects_transfers_between_holders(contract: Erc20Soulbound) {
+        let err = contract
+            .before_transfer(ALICE, BOB)
+            .expect_err("should reject transfer");
+        assert!(matches!(
+            err,
+            Error::NonTransferable(e) if e.from == ALICE && e.to == BOB
+        ));
+    }
+
+    #[motsu::test]
+    fn allows_transfers_with_whitelisted_accounts(contract: Erc20Soulbound) {
+        contract._set_whitelisted(BOB, true);
+        assert!(contract.is_whitelisted(BOB));
+        contract
+            .before_transfer(ALICE, BOB)
+            .expect("should allow transfer to whitelisted account");
+        contract
+            .before_transfer(BOB, ALICE)
+            .expect("should allow transfer from whitelisted account");
+
+        contract._set_whitelisted(BOB, false);
+        assert!(contract.before_transfer(ALICE, BOB).is_err());
+    }
+
+    sol_storage! {
+        struct Points {
+            #[borrow]
+            Erc20 erc20;
+            Erc20Soulbound soulbound;
+        }
+    }
+
+    unsafe impl TopLevelStorage for Points {}
+
+    #[public]
+    #[inherit(Erc20)]
+    impl Points {}
+
+    const _: () = Points::__stylus_assert_overrides();
+
+    impl ExecutionHooks for Points {
+        fn before_call(
+            &mut self,
+            selector: u32,
+            input: &[u8],
+        ) -> Result<(), Vec<u8>> {
+            if let Some((from, to, _)) = transfer_of_call(selector, input) {
+                self.soulbound.before_transfer(from, to)?;
+            }
+            Ok(())
+        }
+    }
+
+    #[motsu::test]
+    fn rejects_transfers_of_erc20(contract: Points) {
+        let value = uint!(1_U256);
+        contract.erc20._mint(msg::sender(), value).expect("should mint");
+        contract.erc20.approve(msg::sender(), value).expect("should approve");
+        let expected: Vec<u8> = Error::NonTransferable(ERC20NonTransferable {
+            from: msg::sender(),
+            to: BOB,
+        })
+        .into();
+
+        let calldata = [
+            function_selector!("transfer", Address, U256).as_slice(),
+            &(BOB, value).abi_encode_params(),
+        ]
+        .concat();
+        let err = route_with_hooks::<Points>(&calldata)
+            .expect_err("should reject `Erc20::transfer`");
+        assert_eq!(err, expected);
+
+        let calldata = [
+            function_selector!("transferFrom", Address, Address, U256)
+                .as_slice(),
+            &(msg::sender(), BOB, value).abi_encode_params(),
+        ]
+        .concat();
+        let err = route_with_hooks::<Points>(&calldata)
+            .expect_err("should reject `Erc20::transfer_from`");
+        assert_eq!(err, expected);
+        assert_eq!(contract.erc20.balance_of(BOB), U256::ZERO);
+
+        contract.soulbound._set_whitelisted(BOB, true);
+        route_with_hooks::<Points>(&calldata)
+            .expect("should transfer to whitelisted account");
+        assert_eq!(contract.erc20.balance_of(BOB), value);
+    }
+}
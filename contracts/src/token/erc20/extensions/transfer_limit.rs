@@ -0,0 +1,365 @@
+//! Transfer Limit Contract.
+//!
+//! Extension of the ERC-20 standard which caps the amount of tokens each
+//! account can send within a period of time, e.g. to protect a token from
+//! whales during its launch.
+//!
+//! The amounts are tracked per sender with a [`RateLimiter`], over a rolling
+//! window: the amount sent by an account is released linearly over the
+//! following `period` seconds, so that an account which sent the whole cap
+//! has to wait `period` seconds to send it again. Exempt senders, e.g. the
+//! treasury or a liquidity pool seeded by the issuer, aren't limited, and
+//! neither are mints and burns. No limit applies until one is set with
+//! [`Erc20TransferLimit::_set_transfer_limit`].
+//!
+//! The limit applies to every transfer checked with
+//! [`Erc20TransferLimit::before_transfer`]. Tokens check the transfers
+//! performed by the `transfer` and `transferFrom` functions of [`Erc20`] from
+//! their execution hooks, with [`transfer_of_call`]:
+//!
+//! ```rust,ignore
+//! impl ExecutionHooks for MyToken {
+//!     fn before_call(
+//!         &mut self,
+//!         selector: u32,
+//!         input: &[u8],
+//!     ) -> Result<(), Vec<u8>> {
+//!         if let Some((from, to, value)) = transfer_of_call(selector, input) {
+//!             self.transfer_limit.before_transfer(from, to, value)?;
+//!         }
+//!         Ok(())
+//!     }
+//! }
+//!
+//! const _: () = MyToken::__stylus_assert_overrides();
+//!
+//! #[entrypoint]
+//! fn entrypoint(input: Vec<u8>) -> ArbResult {
+//!     route_with_hooks::<MyToken>(&input)
+//! }
+//! ```
+//!
+//! Functions of the token moving tokens between two accounts themselves,
+//! e.g. custom functions calling [`Erc20::_update`], or the forced transfers
+//! of [`Erc20Permissioned`], bypass the execution hooks, and must call
+//! [`Erc20TransferLimit::before_transfer`] before moving the tokens.
+//!
+//! [`Erc20`]: crate::token::erc20::Erc20
+//! [`Erc20::_update`]: crate::token::erc20::Erc20::_update
+//! [`Erc20Permissioned`]: crate::token::erc20::extensions::Erc20Permissioned
+//! [`transfer_of_call`]: crate::token::erc20::hooks::transfer_of_call
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::sol;
+use openzeppelin_stylus_proc::{selectors, SolidityError};
+use stylus_sdk::{
+    evm,
+    stylus_proc::{public, sol_storage},
+};
+
+use crate::utils::rate_limiter::{
+    self, RateLimitExceeded, RateLimitSet, RateLimiter,
+    RateLimiterInvalidPeriod,
+};
+
+sol! {
+    #![sol(abi)]
+
+    /// Emitted when `account` is exempted from the transfer limit, or when
+    /// its exemption is revoked.
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    event TransferLimitExemptionSet(address indexed account, bool exempt);
+}
+
+/// A Transfer Limit error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Error type from [`RateLimiter`] contract [`rate_limiter::Error`].
+    RateLimiter(rate_limiter::Error),
+}
+
+sol_storage! {
+    /// State of a Transfer Limit Contract.
+    #[allow(clippy::pub_underscore_fields)]
+    pub struct Erc20TransferLimit {
+        /// Amounts sent by each account, not released yet.
+        RateLimiter limiter;
+        /// Mapping from account to whether it is exempt from the limit.
+        mapping(address => bool) _exempt;
+    }
+}
+
+#[selectors(
+    events(RateLimitSet, TransferLimitExemptionSet),
+    errors(RateLimitExceeded, RateLimiterInvalidPeriod)
+)]
+#[public]
+impl Erc20TransferLimit {
+    /// Returns the maximum amount of tokens an account can send at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[must_use]
+    pub fn transfer_cap(&self) -> U256 {
+        self.limiter.cap()
+    }
+
+    /// Returns the duration over which the amount sent by an account is
+    /// released, in seconds. Zero when no limit is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[must_use]
+    pub fn transfer_period(&self) -> u64 {
+        self.limiter.period()
+    }
+
+    /// Returns the amount of tokens `account` can currently send.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `account` - Account to query.
+    #[must_use]
+    pub fn available_transfer(&self, account: Address) -> U256 {
+        if self.limiter.period() == 0 || self._exempt.get(account) {
+            return U256::MAX;
+        }
+        self.limiter.available(account)
+    }
+
+    /// Returns true if `account` is exempt from the transfer limit, and false
+    /// otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `account` - Account to query.
+    #[must_use]
+    pub fn is_exempt_from_limit(&self, account: Address) -> bool {
+        self._exempt.get(account)
+    }
+}
+
+impl Erc20TransferLimit {
+    /// Consumes `value` from the amount `from` can currently send, unless
+    /// tokens are minted or burnt, `from` is exempt, or no limit is set.
+    ///
+    /// Should be called before every transfer of `value` tokens from `from`
+    /// to `to`, e.g. from the execution hooks of the token, see
+    /// [`crate::token::erc20::hooks`], and from the functions of the token
+    /// which move tokens themselves.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `from` - Account sending the tokens.
+    /// * `to` - Account receiving the tokens.
+    /// * `value` - Amount of tokens transferred.
+    ///
+    /// # Errors
+    ///
+    /// If `value` is greater than the amount `from` can currently send, then
+    /// the error [`rate_limiter::Error::RateLimitExceeded`] is returned.
+    pub fn before_transfer(
+        &mut self,
+        from: Address,
+        to: Address,
+        value: U256,
+    ) -> Result<(), Error> {
+        if from.is_zero()
+            || to.is_zero()
+            || self.limiter.period() == 0
+            || self._exempt.get(from)
+        {
+            return Ok(());
+        }
+        self.limiter.consume(from, value)?;
+        Ok(())
+    }
+
+    /// Sets the transfer limit to `cap` tokens per `period` seconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `cap` - Maximum amount of tokens an account can send at once.
+    /// * `period` - Duration over which the amount sent by an account is
+    ///   released, in seconds.
+    ///
+    /// # Errors
+    ///
+    /// If `period` is zero, then the error
+    /// [`rate_limiter::Error::InvalidPeriod`] is returned.
+    ///
+    /// # Events
+    ///
+    /// Emits a [`RateLimitSet`] event.
+    pub fn _set_transfer_limit(
+        &mut self,
+        cap: U256,
+        period: u64,
+    ) -> Result<(), Error> {
+        self.limiter._set_rate_limit(cap, period)?;
+        Ok(())
+    }
+
+    /// Exempts `account` from the transfer limit, or revokes its exemption.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `account` - Account to exempt.
+    /// * `exempt` - Whether `account` is exempt from the limit.
+    ///
+    /// # Events
+    ///
+    /// Emits a [`TransferLimitExemptionSet`] event.
+    pub fn _set_exempt(&mut self, account: Address, exempt: bool) {
+        self._exempt.setter(account).set(exempt);
+        evm::log(TransferLimitExemptionSet { account, exempt });
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{address, uint, Address, U256};
+    use alloy_sol_types::SolValue;
+    use stylus_sdk::{
+        function_selector, msg,
+        prelude::{public, sol_storage},
+        storage::TopLevelStorage,
+    };
+
+    use super::{Erc20TransferLimit, Error};
+    use crate::{
+        token::erc20::{hooks::transfer_of_call, Erc20, IErc20},
+        utils::{
+            hooks::{route_with_hooks, ExecutionHooks},
+            rate_limiter::{self, RateLimitExceeded},
+        },
+    };
+
+    const ALICE: Address = address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
+    const BOB: Address = address!("B0B0cB49ec2e96DF5F5fFB081acaE66A2cBBc2e2");
+    const CAP: U256 = uint!(1000_U256);
+    const DAY: u64 = 86_400;
+
+    #[motsu::test]
+    fn allows_transfers_without_limit(contract: Erc20TransferLimit) {
+        assert_eq!(contract.transfer_period(), 0);
+        assert_eq!(contract.available_transfer(ALICE), U256::MAX);
+        contract
+            .before_transfer(ALICE, BOB, U256::MAX)
+            .expect("should allow any transfer");
+    }
+
+    #[motsu::test]
+    fn limits_transfers_per_period(contract: Erc20TransferLimit) {
+        contract._set_transfer_limit(CAP, DAY).expect("should set limit");
+        assert_eq!(contract.transfer_cap(), CAP);
+        assert_eq!(contract.transfer_period(), DAY);
+
+        contract
+            .before_transfer(ALICE, BOB, uint!(600_U256))
+            .expect("should transfer within cap");
+        assert_eq!(contract.available_transfer(ALICE), uint!(400_U256));
+        assert_eq!(contract.available_transfer(BOB), CAP);
+
+        let err = contract
+            .before_transfer(ALICE, BOB, uint!(401_U256))
+            .expect_err("should exceed cap");
+        assert!(matches!(
+            err,
+            Error::RateLimiter(rate_limiter::Error::RateLimitExceeded(e))
+                if e.account == ALICE && e.available == uint!(400_U256)
+        ));
+    }
+
+    #[motsu::test]
+    fn skips_exempt_senders_mints_and_burns(contract: Erc20TransferLimit) {
+        contract._set_transfer_limit(CAP, DAY).expect("should set limit");
+        let value = uint!(1001_U256);
+
+        contract
+            .before_transfer(Address::ZERO, ALICE, value)
+            .expect("should not limit mints");
+        contract
+            .before_transfer(ALICE, Address::ZERO, value)
+            .expect("should not limit burns");
+
+        contract._set_exempt(ALICE, true);
+        assert!(contract.is_exempt_from_limit(ALICE));
+        contract
+            .before_transfer(ALICE, BOB, value)
+            .expect("should not limit exempt sender");
+
+        contract._set_exempt(ALICE, false);
+        let result = contract.before_transfer(ALICE, BOB, value);
+        assert!(matches!(result, Err(Error::RateLimiter(_))));
+    }
+
+    sol_storage! {
+        struct Token {
+            #[borrow]
+            Erc20 erc20;
+            Erc20TransferLimit transfer_limit;
+        }
+    }
+
+    unsafe impl TopLevelStorage for Token {}
+
+    #[public]
+    #[inherit(Erc20)]
+    impl Token {}
+
+    const _: () = Token::__stylus_assert_overrides();
+
+    impl ExecutionHooks for Token {
+        fn before_call(
+            &mut self,
+            selector: u32,
+            input: &[u8],
+        ) -> Result<(), Vec<u8>> {
+            if let Some((from, to, value)) = transfer_of_call(selector, input) {
+                self.transfer_limit.before_transfer(from, to, value)?;
+            }
+            Ok(())
+        }
+    }
+
+    #[motsu::test]
+    fn limits_transfers_of_erc20(contract: Token) {
+        contract.erc20._mint(msg::sender(), CAP).expect("should mint");
+        contract
+            .transfer_limit
+            ._set_transfer_limit(CAP, DAY)
+            .expect("should set limit");
+        let transfer = |value: U256| {
+            let calldata = [
+                function_selector!("transfer", Address, U256).as_slice(),
+                &(BOB, value).abi_encode_params(),
+            ]
+            .concat();
+            route_with_hooks::<Token>(&calldata)
+        };
+
+        transfer(uint!(600_U256)).expect("should transfer within cap");
+        assert_eq!(contract.erc20.balance_of(BOB), uint!(600_U256));
+
+        let err = transfer(uint!(401_U256))
+            .expect_err("should reject `Erc20::transfer` above cap");
+        let expected: Vec<u8> = Error::RateLimiter(
+            rate_limiter::Error::RateLimitExceeded(RateLimitExceeded {
+                account: msg::sender(),
+                requested: uint!(401_U256),
+                available: uint!(400_U256),
+            }),
+        )
+        .into();
+        assert_eq!(err, expected);
+        assert_eq!(contract.erc20.balance_of(BOB), uint!(600_U256));
+    }
+}
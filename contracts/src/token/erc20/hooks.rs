@@ -0,0 +1,140 @@
+//! Checks of the transfers of an ERC-20 token, run by its execution hooks.
+//!
+//! Extensions restricting transfers, e.g. [`Erc20TransferLimit`] or
+//! [`Erc20Soulbound`], must check every transfer between two accounts,
+//! including the ones performed by the functions of [`Erc20`] inherited by
+//! the token. Instead of overriding each of these functions, the token checks
+//! the transfers from its [`ExecutionHooks::before_call`], which runs before
+//! every call routed to the token, with the transfer returned by
+//! [`transfer_of_call`]:
+//!
+//! ```rust,ignore
+//! impl ExecutionHooks for MyToken {
+//!     fn before_call(
+//!         &mut self,
+//!         selector: u32,
+//!         input: &[u8],
+//!     ) -> Result<(), Vec<u8>> {
+//!         if let Some((from, to, value)) = transfer_of_call(selector, input) {
+//!             self.soulbound.before_transfer(from, to)?;
+//!             self.transfer_limit.before_transfer(from, to, value)?;
+//!         }
+//!         Ok(())
+//!     }
+//! }
+//!
+//! const _: () = MyToken::__stylus_assert_overrides();
+//!
+//! #[entrypoint]
+//! fn entrypoint(input: Vec<u8>) -> ArbResult {
+//!     route_with_hooks::<MyToken>(&input)
+//! }
+//! ```
+//!
+//! Only the calls of `transfer` and `transferFrom` are checked this way.
+//! Mints, burns, and any other function of the token moving tokens itself,
+//! e.g. with [`Erc20::_update`] or with the forced transfers of
+//! [`Erc20Permissioned`], bypass the execution hooks, and must call the checks
+//! of the extensions restricting them before moving the tokens.
+//!
+//! [`Erc20`]: super::Erc20
+//! [`Erc20::_update`]: super::Erc20::_update
+//! [`Erc20Permissioned`]: super::extensions::Erc20Permissioned
+//! [`Erc20TransferLimit`]: super::extensions::Erc20TransferLimit
+//! [`Erc20Soulbound`]: super::extensions::Erc20Soulbound
+//! [`ExecutionHooks::before_call`]: crate::utils::hooks::ExecutionHooks::before_call
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::{sol, SolCall};
+use stylus_sdk::msg;
+
+sol! {
+    /// Functions of [`super::IErc20`] moving tokens between two accounts.
+    #[allow(missing_docs)]
+    interface IErc20Transfers {
+        function transfer(address to, uint256 value) external returns (bool);
+        function transferFrom(address from, address to, uint256 value) external returns (bool);
+    }
+}
+
+/// Selector of [`super::IErc20::transfer`].
+const TRANSFER: u32 =
+    u32::from_be_bytes(IErc20Transfers::transferCall::SELECTOR);
+
+/// Selector of [`super::IErc20::transfer_from`].
+const TRANSFER_FROM: u32 =
+    u32::from_be_bytes(IErc20Transfers::transferFromCall::SELECTOR);
+
+/// Returns the sender, recipient and amount of the transfer requested by a
+/// call to a function of [`super::Erc20`] moving tokens between two accounts,
+/// and `None` for any other call, or for arguments that can't be decoded.
+///
+/// # Arguments
+///
+/// * `selector` - Selector of the called function.
+/// * `input` - ABI-encoded arguments of the call, without the selector.
+#[must_use]
+pub fn transfer_of_call(
+    selector: u32,
+    input: &[u8],
+) -> Option<(Address, Address, U256)> {
+    match selector {
+        TRANSFER => {
+            let call =
+                IErc20Transfers::transferCall::abi_decode_raw(input, true)
+                    .ok()?;
+            Some((msg::sender(), call.to, call.value))
+        }
+        TRANSFER_FROM => {
+            let call =
+                IErc20Transfers::transferFromCall::abi_decode_raw(input, true)
+                    .ok()?;
+            Some((call.from, call.to, call.value))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{address, uint, Address, U256};
+    use alloy_sol_types::SolValue;
+    use stylus_sdk::{function_selector, msg};
+
+    use super::transfer_of_call;
+
+    const ALICE: Address = address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
+    const BOB: Address = address!("B0B0cB49ec2e96DF5F5fFB081acaE66A2cBBc2e2");
+
+    #[motsu::test]
+    fn decodes_transfers() {
+        let value = uint!(10_U256);
+        let transfer =
+            u32::from_be_bytes(function_selector!("transfer", Address, U256));
+        let transfer_from = u32::from_be_bytes(function_selector!(
+            "transferFrom",
+            Address,
+            Address,
+            U256
+        ));
+
+        assert_eq!(
+            transfer_of_call(transfer, &(BOB, value).abi_encode_params()),
+            Some((msg::sender(), BOB, value))
+        );
+        assert_eq!(
+            transfer_of_call(
+                transfer_from,
+                &(ALICE, BOB, value).abi_encode_params()
+            ),
+            Some((ALICE, BOB, value))
+        );
+        assert_eq!(transfer_of_call(transfer, &[0x01]), None);
+
+        let approve =
+            u32::from_be_bytes(function_selector!("approve", Address, U256));
+        assert_eq!(
+            transfer_of_call(approve, &(BOB, value).abi_encode_params()),
+            None
+        );
+    }
+}
@@ -6,31 +6,42 @@
 //! [`Erc20`] applications.
 use alloy_primitives::{Address, FixedBytes, U256};
 use alloy_sol_types::sol;
-use openzeppelin_stylus_proc::interface_id;
+use openzeppelin_stylus_proc::{interface_id, selectors, SolidityError};
 use stylus_sdk::{
     call::MethodError,
     evm, msg,
-    stylus_proc::{public, sol_storage, SolidityError},
+    stylus_proc::{public, sol_storage},
 };
 
-use crate::utils::introspection::erc165::{Erc165, IErc165};
+use crate::utils::{
+    introspection::erc165::{Erc165, IErc165},
+    math::storage::{AddAssignUnchecked, SubAssignUnchecked},
+};
 
 pub mod extensions;
+pub mod hooks;
+pub mod utils;
 
 sol! {
+    #![sol(abi)]
+
     /// Emitted when `value` tokens are moved from one account (`from`) to
     /// another (`to`).
     ///
     /// Note that `value` may be zero.
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     event Transfer(address indexed from, address indexed to, uint256 value);
     /// Emitted when the allowance of a `spender` for an `owner` is set by a
     /// call to `approve`. `value` is the new allowance.
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     event Approval(address indexed owner, address indexed spender, uint256 value);
 }
 
 sol! {
+    #![sol(abi)]
+
     /// Indicates an error related to the current `balance` of `sender`. Used
     /// in transfers.
     ///
@@ -39,18 +50,21 @@ sol! {
     /// * `needed` - Minimum amount required to perform a transfer.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error ERC20InsufficientBalance(address sender, uint256 balance, uint256 needed);
     /// Indicates a failure with the token `sender`. Used in transfers.
     ///
     /// * `sender` - Address whose tokens are being transferred.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error ERC20InvalidSender(address sender);
     /// Indicates a failure with the token `receiver`. Used in transfers.
     ///
     /// * `receiver` - Address to which the tokens are being transferred.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error ERC20InvalidReceiver(address receiver);
     /// Indicates a failure with the `spender`’s `allowance`. Used in
     /// transfers.
@@ -62,6 +76,7 @@ sol! {
     /// * `needed` - Minimum amount required to perform a transfer.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error ERC20InsufficientAllowance(address spender, uint256 allowance, uint256 needed);
     /// Indicates a failure with the `spender` to be approved. Used in
     /// approvals.
@@ -70,6 +85,7 @@ sol! {
     /// being their owner.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error ERC20InvalidSpender(address spender);
 
 }
@@ -241,6 +257,16 @@ pub trait IErc20 {
     ) -> Result<bool, Self::Error>;
 }
 
+#[selectors(
+    events(Transfer, Approval),
+    errors(
+        ERC20InsufficientBalance,
+        ERC20InvalidSender,
+        ERC20InvalidReceiver,
+        ERC20InsufficientAllowance,
+        ERC20InvalidSpender
+    )
+)]
 #[public]
 impl IErc20 for Erc20 {
     type Error = Error;
@@ -253,11 +279,7 @@ impl IErc20 for Erc20 {
         self._balances.get(account)
     }
 
-    fn transfer(
-        &mut self,
-        to: Address,
-        value: U256,
-    ) -> Result<bool, Self::Error> {
+    fn transfer(&mut self, to: Address, value: U256) -> Result<bool, Error> {
         let from = msg::sender();
         self._transfer(from, to, value)?;
         Ok(true)
@@ -271,7 +293,7 @@ impl IErc20 for Erc20 {
         &mut self,
         spender: Address,
         value: U256,
-    ) -> Result<bool, Self::Error> {
+    ) -> Result<bool, Error> {
         let owner = msg::sender();
         self._approve(owner, spender, value)
     }
@@ -281,7 +303,7 @@ impl IErc20 for Erc20 {
         from: Address,
         to: Address,
         value: U256,
-    ) -> Result<bool, Self::Error> {
+    ) -> Result<bool, Error> {
         let spender = msg::sender();
         self._spend_allowance(from, spender, value)?;
         self._transfer(from, to, value)?;
@@ -461,17 +483,15 @@ impl Erc20 {
         }
 
         if to.is_zero() {
-            let total_supply = self.total_supply();
             // Overflow not possible:
             // `value` <= `_total_supply` or
             // `value` <= `from_balance` <= `_total_supply`.
-            self._total_supply.set(total_supply - value);
+            self._total_supply.sub_assign_unchecked(value);
         } else {
-            let balance_to = self._balances.get(to);
             // Overflow not possible:
             // `balance_to` + `value` is at most `total_supply`,
             // which fits into a `U256`.
-            self._balances.setter(to).set(balance_to + value);
+            self._balances.setter(to).add_assign_unchecked(value);
         }
 
         evm::log(Transfer { from, to, value });
@@ -557,9 +577,10 @@ impl Erc20 {
 #[cfg(all(test, feature = "std"))]
 mod tests {
     use alloy_primitives::{address, uint, Address, U256};
+    use motsu::prelude::{assert_storage_layout, emitted_events};
     use stylus_sdk::msg;
 
-    use super::{Erc20, Error, IErc20};
+    use super::{Approval, Erc20, Error, IErc20, Transfer};
     use crate::{
         token::erc721::{Erc721, IErc721},
         utils::introspection::erc165::IErc165,
@@ -792,6 +813,27 @@ mod tests {
         assert_eq!(one, contract.balance_of(bob));
     }
 
+    #[motsu::test]
+    fn emits_transfer_and_approval_events(contract: Erc20) {
+        let alice = address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
+        let one = uint!(1_U256);
+
+        contract._mint(msg::sender(), one).unwrap();
+        contract.approve(alice, one).unwrap();
+        contract.transfer(alice, one).unwrap();
+
+        let transfers = emitted_events::<Transfer>();
+        assert_eq!(transfers.len(), 2);
+        assert_eq!(transfers[0].from, Address::ZERO);
+        assert_eq!(transfers[1].from, msg::sender());
+        assert_eq!(transfers[1].to, alice);
+        assert_eq!(transfers[1].value, one);
+
+        let approvals = emitted_events::<Approval>();
+        assert_eq!(approvals.len(), 1);
+        assert_eq!(approvals[0].spender, alice);
+    }
+
     #[motsu::test]
     fn transfers_from(contract: Erc20) {
         let alice = address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
@@ -897,6 +939,14 @@ mod tests {
         assert!(matches!(result, Err(Error::InvalidSpender(_))));
     }
 
+    #[motsu::test]
+    fn keeps_storage_layout(contract: Erc20) {
+        let alice = address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
+        contract._mint(alice, uint!(1_U256)).unwrap();
+        contract.approve(alice, uint!(2_U256)).unwrap();
+        assert_storage_layout("erc20");
+    }
+
     #[motsu::test]
     fn interface_id() {
         let actual = <Erc20 as IErc20>::INTERFACE_ID;
@@ -907,4 +957,27 @@ mod tests {
         let expected = 0x01ffc9a7;
         assert_eq!(actual, expected);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serializes_events_and_errors() {
+        use super::ERC20InvalidSpender;
+
+        let alice = address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
+        let transfer =
+            Transfer { from: Address::ZERO, to: alice, value: uint!(10_U256) };
+        let json = serde_json::to_string(&transfer).unwrap();
+        assert_eq!(
+            json,
+            r#"{"from":"0x0000000000000000000000000000000000000000","to":"0xa11ceacf9aa32246d767fccd72e02d6bcbcc375d","value":"0xa"}"#
+        );
+        let decoded: Transfer = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.to, alice);
+        assert_eq!(decoded.value, uint!(10_U256));
+
+        let json =
+            r#"{"spender":"0x0000000000000000000000000000000000000000"}"#;
+        let error: ERC20InvalidSpender = serde_json::from_str(json).unwrap();
+        assert_eq!(error.spender, Address::ZERO);
+    }
 }
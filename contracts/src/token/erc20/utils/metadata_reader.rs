@@ -0,0 +1,147 @@
+//! Best-effort reader of the metadata of ERC-20 tokens.
+//!
+//! `name`, `symbol` and `decimals` are optional in the ERC-20 standard, and
+//! some tokens, e.g. MKR, return their name and symbol as a `bytes32` instead
+//! of a `string`. Contracts mirroring the metadata of an underlying token,
+//! like vaults or wrappers, read it with these functions, which return `None`
+//! instead of failing when the metadata is missing or malformed.
+use alloc::{string::String, vec::Vec};
+
+use alloy_primitives::{Address, B256};
+use alloy_sol_types::{sol, sol_data, SolCall, SolType};
+use stylus_sdk::call::RawCall;
+
+sol! {
+    /// ERC-20 metadata functions read by this module.
+    #[allow(missing_docs)]
+    interface IErc20Metadata {
+        function name() external view returns (string);
+        function symbol() external view returns (string);
+        function decimals() external view returns (uint8);
+    }
+}
+
+/// Metadata of an ERC-20 token, see [`read`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TokenMetadata {
+    /// Name of the token, if any.
+    pub name: Option<String>,
+    /// Symbol of the token, if any.
+    pub symbol: Option<String>,
+    /// Decimals of the token, if any.
+    pub decimals: Option<u8>,
+}
+
+/// Returns the name, symbol and decimals of `token`.
+///
+/// # Arguments
+///
+/// * `token` - Address of the ERC-20 token.
+#[must_use]
+pub fn read(token: Address) -> TokenMetadata {
+    TokenMetadata {
+        name: try_name(token),
+        symbol: try_symbol(token),
+        decimals: try_decimals(token),
+    }
+}
+
+/// Returns the name of `token`, returned either as a `string` or as a
+/// `bytes32`, or `None` if it can't be read.
+///
+/// # Arguments
+///
+/// * `token` - Address of the ERC-20 token.
+#[must_use]
+pub fn try_name(token: Address) -> Option<String> {
+    static_call(token, &IErc20Metadata::nameCall {})
+        .and_then(|data| decode_string(&data))
+}
+
+/// Returns the symbol of `token`, returned either as a `string` or as a
+/// `bytes32`, or `None` if it can't be read.
+///
+/// # Arguments
+///
+/// * `token` - Address of the ERC-20 token.
+#[must_use]
+pub fn try_symbol(token: Address) -> Option<String> {
+    static_call(token, &IErc20Metadata::symbolCall {})
+        .and_then(|data| decode_string(&data))
+}
+
+/// Returns the decimals of `token`, or `None` if they can't be read or don't
+/// fit in a `u8`.
+///
+/// # Arguments
+///
+/// * `token` - Address of the ERC-20 token.
+#[must_use]
+pub fn try_decimals(token: Address) -> Option<u8> {
+    static_call(token, &IErc20Metadata::decimalsCall {})
+        .and_then(|data| decode_decimals(&data))
+}
+
+/// Calls `token` with `call` without modifying its state, and returns the
+/// returned data, or `None` if the call reverts.
+fn static_call(token: Address, call: &impl SolCall) -> Option<Vec<u8>> {
+    RawCall::new_static().call(token, &call.abi_encode()).ok()
+}
+
+/// Decodes `data` as an ABI-encoded `string`, or as a `bytes32` holding a
+/// right-padded UTF-8 string.
+fn decode_string(data: &[u8]) -> Option<String> {
+    if data.len() == 32 {
+        let bytes = B256::from_slice(data);
+        let len = bytes.iter().position(|b| *b == 0).unwrap_or(32);
+        return String::from_utf8(bytes[..len].to_vec()).ok();
+    }
+    sol_data::String::abi_decode(data, true).ok()
+}
+
+/// Decodes `data` as an ABI-encoded `uint8`.
+fn decode_decimals(data: &[u8]) -> Option<u8> {
+    let decimals = sol_data::Uint::<256>::abi_decode(data, true).ok()?;
+    u8::try_from(decimals).ok()
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{address, b256, Address, U256};
+    use alloy_sol_types::{sol_data, SolType};
+
+    use super::*;
+
+    const TOKEN: Address = address!("B0B0cB49ec2e96DF5F5fFB081acaE66A2cBBc2e2");
+
+    #[test]
+    fn decodes_string_metadata() {
+        let data = sol_data::String::abi_encode("Token");
+        assert_eq!(decode_string(&data).as_deref(), Some("Token"));
+    }
+
+    #[test]
+    fn decodes_bytes32_metadata() {
+        let data = b256!(
+            "4d616b6572000000000000000000000000000000000000000000000000000000"
+        );
+        assert_eq!(decode_string(data.as_slice()).as_deref(), Some("Maker"));
+        assert_eq!(decode_string(&[]), None);
+        assert_eq!(decode_string(&[0xff; 32]), None);
+    }
+
+    #[test]
+    fn decodes_decimals() {
+        let data = sol_data::Uint::<256>::abi_encode(&U256::from(18));
+        assert_eq!(decode_decimals(&data), Some(18));
+
+        let data = sol_data::Uint::<256>::abi_encode(&U256::from(256));
+        assert_eq!(decode_decimals(&data), None);
+        assert_eq!(decode_decimals(&[]), None);
+    }
+
+    #[motsu::test]
+    fn reads_missing_metadata() {
+        assert_eq!(read(TOKEN), TokenMetadata::default());
+    }
+}
@@ -0,0 +1,5 @@
+//! Utilities for ERC-20 tokens.
+pub mod metadata_reader;
+pub mod permit2;
+#[cfg(feature = "safe-erc20")]
+pub mod safe_erc20;
@@ -0,0 +1,393 @@
+//! Helpers to pull ERC-20 tokens through Uniswap's [Permit2].
+//!
+//! [Permit2] lets token holders approve it once, and then authorize transfers
+//! to any contract with off-chain signatures. Contracts consuming tokens can
+//! pull them in a single transaction, instead of requiring the holder to
+//! `approve` them first:
+//!
+//! ```rust,ignore
+//! #[public]
+//! impl MyContract {
+//!     fn deposit(
+//!         &mut self,
+//!         token: Address,
+//!         amount: U256,
+//!         nonce: U256,
+//!         deadline: U256,
+//!         signature: Bytes,
+//!     ) -> Result<(), Vec<u8>> {
+//!         let permit = PermitTransferFrom {
+//!             permitted: TokenPermissions { token, amount },
+//!             nonce,
+//!             deadline,
+//!         };
+//!         let details =
+//!             SignatureTransferDetails { to: contract::address(), requested_amount: amount };
+//!         permit2::permit_transfer_from(
+//!             self,
+//!             PERMIT2_ADDRESS,
+//!             &permit,
+//!             &details,
+//!             msg::sender(),
+//!             &signature,
+//!         )?;
+//!         // ...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+//!
+//! Holders sign permits with the EIP-712 domain of the [Permit2] contract,
+//! and the consuming contract as `spender`.
+//!
+//! [Permit2]: https://github.com/Uniswap/permit2
+use alloc::vec::Vec;
+
+use alloy_primitives::{address, aliases::U160, Address, U256};
+use openzeppelin_stylus_proc::SolidityError;
+use stylus_sdk::{
+    call::{self, Call, MethodError},
+    prelude::sol_interface,
+    storage::TopLevelStorage,
+};
+
+/// Address of the canonical [Permit2] deployment, which is the same on every
+/// chain it is deployed to.
+///
+/// [Permit2]: https://github.com/Uniswap/permit2
+pub const PERMIT2_ADDRESS: Address =
+    address!("000000000022D473030F116dDEE9F6B43aC78BA3");
+
+sol_interface! {
+    /// Functions of [Permit2] used to pull tokens, with its structs flattened
+    /// into tuples.
+    ///
+    /// [Permit2]: https://github.com/Uniswap/permit2
+    interface IPermit2 {
+        /// Transfers tokens from `owner` with a signed permit, see
+        /// [`PermitTransferFrom`] and [`SignatureTransferDetails`].
+        #[allow(missing_docs)]
+        function permitTransferFrom(((address, uint256), uint256, uint256) permit, (address, uint256) transfer_details, address owner, bytes calldata signature) external;
+
+        /// Sets the allowance of a spender with a signed permit, see
+        /// [`PermitSingle`].
+        #[allow(missing_docs)]
+        function permit(address owner, ((address, uint160, uint48, uint48), address, uint256) permit_single, bytes calldata signature) external;
+
+        /// Transfers tokens from `from` using the allowance of the caller.
+        #[allow(missing_docs)]
+        function transferFrom(address from, address to, uint160 amount, address token) external;
+
+        /// Returns the amount, expiration and nonce of the allowance of
+        /// `spender` over the `token` of `user`.
+        #[allow(missing_docs)]
+        function allowance(address user, address token, address spender) external view returns (uint160, uint48, uint48);
+    }
+}
+
+/// A Permit2 error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// The call to [Permit2] failed, with the reason specified by it.
+    ///
+    /// [Permit2]: https://github.com/Uniswap/permit2
+    FailedCall(call::Error),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> Vec<u8> {
+        self.into()
+    }
+}
+
+/// Token and amount of a permit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TokenPermissions {
+    /// Address of the ERC-20 token.
+    pub token: Address,
+    /// Maximum amount that can be transferred.
+    pub amount: U256,
+}
+
+/// Signed permit of a single transfer, consumed by
+/// [`permit_transfer_from`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PermitTransferFrom {
+    /// Token and maximum amount of the transfer.
+    pub permitted: TokenPermissions,
+    /// Unique nonce of the permit, in Permit2's unordered nonce space.
+    pub nonce: U256,
+    /// Timestamp after which the permit is no longer valid.
+    pub deadline: U256,
+}
+
+/// Recipient and amount of a transfer of [`permit_transfer_from`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SignatureTransferDetails {
+    /// Recipient of the tokens.
+    pub to: Address,
+    /// Amount to transfer, at most [`TokenPermissions::amount`].
+    pub requested_amount: U256,
+}
+
+/// Allowance granted by a [`PermitSingle`], or returned by [`allowance`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PermitDetails {
+    /// Address of the ERC-20 token.
+    pub token: Address,
+    /// Maximum amount the spender can transfer.
+    pub amount: U160,
+    /// Timestamp at which the allowance expires.
+    pub expiration: u64,
+    /// Nonce of the owner's allowance for the token and spender.
+    pub nonce: u64,
+}
+
+/// Signed permit setting the allowance of `spender`, consumed by
+/// [`permit`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PermitSingle {
+    /// Allowance granted to `spender`.
+    pub details: PermitDetails,
+    /// Account allowed to spend the tokens.
+    pub spender: Address,
+    /// Timestamp after which the signature is no longer valid.
+    pub sig_deadline: U256,
+}
+
+impl From<&PermitTransferFrom> for ((Address, U256), U256, U256) {
+    fn from(permit: &PermitTransferFrom) -> Self {
+        let TokenPermissions { token, amount } = permit.permitted;
+        ((token, amount), permit.nonce, permit.deadline)
+    }
+}
+
+impl From<&SignatureTransferDetails> for (Address, U256) {
+    fn from(details: &SignatureTransferDetails) -> Self {
+        (details.to, details.requested_amount)
+    }
+}
+
+impl From<&PermitSingle> for ((Address, U256, u64, u64), Address, U256) {
+    fn from(permit: &PermitSingle) -> Self {
+        let PermitDetails { token, amount, expiration, nonce } = permit.details;
+        (
+            (token, U256::from(amount), expiration, nonce),
+            permit.spender,
+            permit.sig_deadline,
+        )
+    }
+}
+
+/// Transfers tokens from `owner` as described by `details`, with the
+/// `permit` signed by `owner` for the calling contract.
+///
+/// # Arguments
+///
+/// * `storage` - Write access to the contract's state.
+/// * `permit2` - Address of the Permit2 contract, e.g. [`PERMIT2_ADDRESS`].
+/// * `permit` - Permit signed by `owner`.
+/// * `details` - Recipient and amount of the transfer.
+/// * `owner` - Account whose tokens are transferred.
+/// * `signature` - Signature of `permit` by `owner`.
+///
+/// # Errors
+///
+/// If Permit2 rejects the permit or the transfer, then the error
+/// [`Error::FailedCall`] is returned.
+pub fn permit_transfer_from(
+    storage: &mut impl TopLevelStorage,
+    permit2: Address,
+    permit: &PermitTransferFrom,
+    details: &SignatureTransferDetails,
+    owner: Address,
+    signature: &[u8],
+) -> Result<(), Error> {
+    IPermit2::new(permit2).permit_transfer_from(
+        Call::new_in(storage),
+        permit.into(),
+        details.into(),
+        owner,
+        signature.to_vec().into(),
+    )?;
+    Ok(())
+}
+
+/// Sets the Permit2 allowance of [`PermitSingle::spender`] over the tokens of
+/// `owner`, with the `permit` signed by `owner`.
+///
+/// # Arguments
+///
+/// * `storage` - Write access to the contract's state.
+/// * `permit2` - Address of the Permit2 contract, e.g. [`PERMIT2_ADDRESS`].
+/// * `owner` - Account granting the allowance.
+/// * `permit` - Permit signed by `owner`.
+/// * `signature` - Signature of `permit` by `owner`.
+///
+/// # Errors
+///
+/// If Permit2 rejects the permit, then the error [`Error::FailedCall`] is
+/// returned.
+pub fn permit(
+    storage: &mut impl TopLevelStorage,
+    permit2: Address,
+    owner: Address,
+    permit: &PermitSingle,
+    signature: &[u8],
+) -> Result<(), Error> {
+    IPermit2::new(permit2).permit(
+        Call::new_in(storage),
+        owner,
+        permit.into(),
+        signature.to_vec().into(),
+    )?;
+    Ok(())
+}
+
+/// Transfers `amount` of `token` from `from` to `to`, using the Permit2
+/// allowance of the calling contract.
+///
+/// # Arguments
+///
+/// * `storage` - Write access to the contract's state.
+/// * `permit2` - Address of the Permit2 contract, e.g. [`PERMIT2_ADDRESS`].
+/// * `from` - Account whose tokens are transferred.
+/// * `to` - Recipient of the tokens.
+/// * `amount` - Amount of tokens to transfer.
+/// * `token` - Address of the ERC-20 token.
+///
+/// # Errors
+///
+/// If the allowance is insufficient or expired, or the transfer fails, then
+/// the error [`Error::FailedCall`] is returned.
+pub fn transfer_from(
+    storage: &mut impl TopLevelStorage,
+    permit2: Address,
+    from: Address,
+    to: Address,
+    amount: U160,
+    token: Address,
+) -> Result<(), Error> {
+    IPermit2::new(permit2).transfer_from(
+        Call::new_in(storage),
+        from,
+        to,
+        U256::from(amount),
+        token,
+    )?;
+    Ok(())
+}
+
+/// Returns the Permit2 allowance of `spender` over the `token` of `user`.
+///
+/// # Arguments
+///
+/// * `storage` - Read access to the contract's state.
+/// * `permit2` - Address of the Permit2 contract, e.g. [`PERMIT2_ADDRESS`].
+/// * `user` - Account granting the allowance.
+/// * `token` - Address of the ERC-20 token.
+/// * `spender` - Account allowed to spend the tokens.
+///
+/// # Errors
+///
+/// If the call to Permit2 fails, then the error [`Error::FailedCall`] is
+/// returned.
+pub fn allowance(
+    storage: &impl TopLevelStorage,
+    permit2: Address,
+    user: Address,
+    token: Address,
+    spender: Address,
+) -> Result<PermitDetails, Error> {
+    let (amount, expiration, nonce) =
+        IPermit2::new(permit2).allowance(storage, user, token, spender)?;
+    Ok(PermitDetails {
+        token,
+        amount: U160::saturating_from(amount),
+        expiration,
+        nonce,
+    })
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{address, aliases::U160, Address, U256};
+    use stylus_sdk::{prelude::sol_storage, storage::TopLevelStorage};
+
+    use super::*;
+
+    const ALICE: Address = address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
+    const TOKEN: Address = address!("B0B0cB49ec2e96DF5F5fFB081acaE66A2cBBc2e2");
+
+    sol_storage! {
+        struct Consumer {}
+    }
+
+    unsafe impl TopLevelStorage for Consumer {}
+
+    #[test]
+    fn flattens_permits() {
+        let permit = PermitTransferFrom {
+            permitted: TokenPermissions { token: TOKEN, amount: U256::from(1) },
+            nonce: U256::from(2),
+            deadline: U256::from(3),
+        };
+        let flat: ((Address, U256), U256, U256) = (&permit).into();
+        assert_eq!(
+            flat,
+            ((TOKEN, U256::from(1)), U256::from(2), U256::from(3))
+        );
+
+        let permit = PermitSingle {
+            details: PermitDetails {
+                token: TOKEN,
+                amount: U160::from(1),
+                expiration: 2,
+                nonce: 3,
+            },
+            spender: ALICE,
+            sig_deadline: U256::from(4),
+        };
+        let flat: ((Address, U256, u64, u64), Address, U256) = (&permit).into();
+        assert_eq!(flat, ((TOKEN, U256::from(1), 2, 3), ALICE, U256::from(4)));
+    }
+
+    #[motsu::test]
+    fn pulls_tokens(contract: Consumer) {
+        let permit = PermitTransferFrom {
+            permitted: TokenPermissions { token: TOKEN, amount: U256::from(1) },
+            nonce: U256::ZERO,
+            deadline: U256::MAX,
+        };
+        let details = SignatureTransferDetails {
+            to: ALICE,
+            requested_amount: U256::from(1),
+        };
+        permit_transfer_from(
+            contract,
+            PERMIT2_ADDRESS,
+            &permit,
+            &details,
+            ALICE,
+            &[],
+        )
+        .expect("should transfer with permit");
+
+        transfer_from(
+            contract,
+            PERMIT2_ADDRESS,
+            ALICE,
+            ALICE,
+            U160::from(1),
+            TOKEN,
+        )
+        .expect("should transfer with allowance");
+    }
+
+    #[motsu::test]
+    fn rejects_undecodable_allowances(contract: Consumer) {
+        let err = allowance(contract, PERMIT2_ADDRESS, ALICE, TOKEN, ALICE)
+            .expect_err("should fail to decode empty return data");
+        assert!(matches!(err, Error::FailedCall(_)));
+    }
+}
@@ -0,0 +1,713 @@
+//! Wrappers around ERC-20 operations that revert on failure.
+//!
+//! Some tokens return no value from `transfer`, `transferFrom` or `approve`,
+//! instead of the `bool` required by the standard, and some others return
+//! `false` instead of reverting. [`SafeErc20`] handles both: a call succeeds if
+//! the token returns nothing, or returns `true`, and fails otherwise.
+//!
+//! The `*_and_call_relaxed` functions call the [ERC-1363] hooks of the
+//! recipient, unless it has no code, in which case they fall back to the
+//! plain operations.
+//!
+//! Contracts use it as a field, and call its functions from their own:
+//!
+//! ```rust,ignore
+//! sol_storage! {
+//!     #[entrypoint]
+//!     struct MyVault {
+//!         Ownable ownable;
+//!         SafeErc20 safe_erc20;
+//!         address token;
+//!     }
+//! }
+//!
+//! #[public]
+//! impl MyVault {
+//!     fn withdraw(&mut self, value: U256) -> Result<(), Vec<u8>> {
+//!         self.ownable.only_owner()?;
+//!         let token = self.token.get();
+//!         self.safe_erc20.safe_transfer(token, msg::sender(), value)?;
+//!         Ok(())
+//!     }
+//! }
+//! ```
+//!
+//! WARNING: The functions of [`SafeErc20`] move the tokens and change the
+//! allowances of the calling contract. They must never be exposed as public
+//! functions of a contract, e.g. with `#[inherit(SafeErc20)]`, since anyone
+//! could then transfer its tokens, or approve themselves to spend them.
+//!
+//! [ERC-1363]: https://eips.ethereum.org/EIPS/eip-1363
+use alloc::vec::Vec;
+
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::{sol, sol_data::Bool, SolCall, SolType};
+use openzeppelin_stylus_proc::SolidityError;
+use stylus_sdk::{
+    abi::Bytes,
+    call::{self, MethodError, RawCall},
+    contract,
+    storage::TopLevelStorage,
+    stylus_proc::sol_storage,
+    types::AddressVM,
+};
+
+sol! {
+    #![sol(abi)]
+
+    /// An operation with an ERC-20 token failed.
+    ///
+    /// * `token` - Address of the ERC-20 token.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error SafeErc20FailedOperation(address token);
+
+    /// The decrease of the allowance of `spender` requested by
+    /// `requestedDecrease` is greater than its `currentAllowance`.
+    ///
+    /// * `spender` - Account allowed to spend the tokens.
+    /// * `currentAllowance` - Current allowance of `spender`.
+    /// * `requestedDecrease` - Requested decrease of the allowance.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error SafeErc20FailedDecreaseAllowance(
+        address spender,
+        uint256 currentAllowance,
+        uint256 requestedDecrease
+    );
+}
+
+/// A [`SafeErc20`] error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// The token has no code, or returned `false`.
+    FailedOperation(SafeErc20FailedOperation),
+    /// The requested decrease of an allowance is greater than the allowance.
+    FailedDecreaseAllowance(SafeErc20FailedDecreaseAllowance),
+    /// The call to the token reverted, with the reason specified by it.
+    FailedCall(call::Error),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> Vec<u8> {
+        self.into()
+    }
+}
+
+sol! {
+    /// ERC-20 functions called by [`SafeErc20`].
+    #[allow(missing_docs)]
+    interface IErc20 {
+        function transfer(address to, uint256 value) external returns (bool);
+        function transferFrom(address from, address to, uint256 value) external returns (bool);
+        function approve(address spender, uint256 value) external returns (bool);
+        function allowance(address owner, address spender) external view returns (uint256);
+    }
+
+    /// ERC-1363 functions called by [`SafeErc20`].
+    #[allow(missing_docs)]
+    interface IErc1363 {
+        function transferAndCall(address to, uint256 value, bytes data) external returns (bool);
+        function transferFromAndCall(address from, address to, uint256 value, bytes data) external returns (bool);
+        function approveAndCall(address spender, uint256 value, bytes data) external returns (bool);
+    }
+}
+
+sol_storage! {
+    /// State of a [`SafeErc20`] Contract, which has none.
+    pub struct SafeErc20 {}
+}
+
+/// NOTE: Implementation of [`TopLevelStorage`] to be able use `&mut self` when
+/// calling other contracts and not `&mut (impl TopLevelStorage +
+/// BorrowMut<Self>)`. Should be fixed in the future by the Stylus team.
+unsafe impl TopLevelStorage for SafeErc20 {}
+
+impl SafeErc20 {
+    /// Transfers `value` of `token` from the calling contract to `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `token` - Address of the ERC-20 token.
+    /// * `to` - Recipient of the tokens.
+    /// * `value` - Amount of tokens to transfer.
+    ///
+    /// # Errors
+    ///
+    /// * If `token` has no code, or returns `false`, then the error
+    ///   [`Error::FailedOperation`] is returned.
+    /// * If the call reverts, then the error [`Error::FailedCall`] is returned,
+    ///   with the revert data of `token`.
+    pub fn safe_transfer(
+        &mut self,
+        token: Address,
+        to: Address,
+        value: U256,
+    ) -> Result<(), Error> {
+        call_optional_return(token, &IErc20::transferCall { to, value })
+    }
+
+    /// Transfers `value` of `token` from `from` to `to`, using the allowance
+    /// of the calling contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `token` - Address of the ERC-20 token.
+    /// * `from` - Account whose tokens are transferred.
+    /// * `to` - Recipient of the tokens.
+    /// * `value` - Amount of tokens to transfer.
+    ///
+    /// # Errors
+    ///
+    /// * If `token` has no code, or returns `false`, then the error
+    ///   [`Error::FailedOperation`] is returned.
+    /// * If the call reverts, then the error [`Error::FailedCall`] is returned,
+    ///   with the revert data of `token`.
+    pub fn safe_transfer_from(
+        &mut self,
+        token: Address,
+        from: Address,
+        to: Address,
+        value: U256,
+    ) -> Result<(), Error> {
+        call_optional_return(
+            token,
+            &IErc20::transferFromCall { from, to, value },
+        )
+    }
+
+    /// Variant of [`Self::safe_transfer`] that returns false instead of
+    /// failing, e.g. to keep processing a batch of transfers when one of
+    /// them fails.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `token` - Address of the ERC-20 token.
+    /// * `to` - Recipient of the tokens.
+    /// * `value` - Amount of tokens to transfer.
+    pub fn try_safe_transfer(
+        &mut self,
+        token: Address,
+        to: Address,
+        value: U256,
+    ) -> bool {
+        self.safe_transfer(token, to, value).is_ok()
+    }
+
+    /// Variant of [`Self::safe_transfer_from`] that returns false instead of
+    /// failing, e.g. to keep processing a batch of transfers when one of
+    /// them fails.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `token` - Address of the ERC-20 token.
+    /// * `from` - Account whose tokens are transferred.
+    /// * `to` - Recipient of the tokens.
+    /// * `value` - Amount of tokens to transfer.
+    pub fn try_safe_transfer_from(
+        &mut self,
+        token: Address,
+        from: Address,
+        to: Address,
+        value: U256,
+    ) -> bool {
+        self.safe_transfer_from(token, from, to, value).is_ok()
+    }
+
+    /// Increases the allowance of `spender` over the tokens of the calling
+    /// contract by `value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `token` - Address of the ERC-20 token.
+    /// * `spender` - Account allowed to spend the tokens.
+    /// * `value` - Amount by which the allowance is increased.
+    ///
+    /// # Errors
+    ///
+    /// * If `token` has no code, or the allowance can't be read or set, then
+    ///   the error [`Error::FailedOperation`] is returned.
+    /// * If a call reverts, then the error [`Error::FailedCall`] is returned,
+    ///   with the revert data of `token`.
+    ///
+    /// # Panics
+    ///
+    /// If the new allowance exceeds `U256::MAX`.
+    pub fn safe_increase_allowance(
+        &mut self,
+        token: Address,
+        spender: Address,
+        value: U256,
+    ) -> Result<(), Error> {
+        let old_allowance = allowance(token, spender)?;
+        let new_allowance = old_allowance
+            .checked_add(value)
+            .expect("should not exceed `U256::MAX` for allowance");
+        self.force_approve(token, spender, new_allowance)
+    }
+
+    /// Decreases the allowance of `spender` over the tokens of the calling
+    /// contract by `requested_decrease`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `token` - Address of the ERC-20 token.
+    /// * `spender` - Account allowed to spend the tokens.
+    /// * `requested_decrease` - Amount by which the allowance is decreased.
+    ///
+    /// # Errors
+    ///
+    /// * If `token` has no code, or the allowance can't be read or set, then
+    ///   the error [`Error::FailedOperation`] is returned.
+    /// * If a call reverts, then the error [`Error::FailedCall`] is returned,
+    ///   with the revert data of `token`.
+    /// * If `requested_decrease` is greater than the current allowance, then
+    ///   the error [`Error::FailedDecreaseAllowance`] is returned.
+    pub fn safe_decrease_allowance(
+        &mut self,
+        token: Address,
+        spender: Address,
+        requested_decrease: U256,
+    ) -> Result<(), Error> {
+        let current_allowance = allowance(token, spender)?;
+        if current_allowance < requested_decrease {
+            return Err(SafeErc20FailedDecreaseAllowance {
+                spender,
+                currentAllowance: current_allowance,
+                requestedDecrease: requested_decrease,
+            }
+            .into());
+        }
+        self.force_approve(
+            token,
+            spender,
+            current_allowance - requested_decrease,
+        )
+    }
+
+    /// Sets the allowance of `spender` over the tokens of the calling
+    /// contract to `value`.
+    ///
+    /// Some tokens, e.g. USDT, revert when an allowance is changed from a
+    /// non-zero value to another one. If approving `value` fails, the
+    /// allowance is reset to zero first, and approved again.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `token` - Address of the ERC-20 token.
+    /// * `spender` - Account allowed to spend the tokens.
+    /// * `value` - New allowance of `spender`.
+    ///
+    /// # Errors
+    ///
+    /// * If `token` has no code, or the allowance can't be set, then the error
+    ///   [`Error::FailedOperation`] is returned.
+    /// * If resetting the allowance to zero, or approving `value` after it,
+    ///   reverts, then the error [`Error::FailedCall`] is returned, with the
+    ///   revert data of `token`.
+    pub fn force_approve(
+        &mut self,
+        token: Address,
+        spender: Address,
+        value: U256,
+    ) -> Result<(), Error> {
+        let approve = IErc20::approveCall { spender, value };
+        if call_optional_return(token, &approve).is_ok() {
+            return Ok(());
+        }
+
+        call_optional_return(
+            token,
+            &IErc20::approveCall { spender, value: U256::ZERO },
+        )?;
+        call_optional_return(token, &approve)
+    }
+
+    /// Transfers `value` of the ERC-1363 `token` from the calling contract to
+    /// `to`, and calls `onTransferReceived` on `to` with `data`, or only
+    /// transfers the tokens with [`Self::safe_transfer`] if `to` has no code.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `token` - Address of the ERC-1363 token.
+    /// * `to` - Recipient of the tokens.
+    /// * `value` - Amount of tokens to transfer.
+    /// * `data` - Data passed to the recipient.
+    ///
+    /// # Errors
+    ///
+    /// * If the transfer fails, or `transferAndCall` doesn't return `true`,
+    ///   then the error [`Error::FailedOperation`] is returned.
+    /// * If the call reverts, then the error [`Error::FailedCall`] is returned,
+    ///   with the revert data of `token`.
+    pub fn transfer_and_call_relaxed(
+        &mut self,
+        token: Address,
+        to: Address,
+        value: U256,
+        data: Bytes,
+    ) -> Result<(), Error> {
+        if !to.has_code() {
+            return self.safe_transfer(token, to, value);
+        }
+        call_returning_true(
+            token,
+            &IErc1363::transferAndCallCall { to, value, data: data.0.into() },
+        )
+    }
+
+    /// Transfers `value` of the ERC-1363 `token` from `from` to `to`, and
+    /// calls `onTransferReceived` on `to` with `data`, or only transfers the
+    /// tokens with [`Self::safe_transfer_from`] if `to` has no code.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `token` - Address of the ERC-1363 token.
+    /// * `from` - Account whose tokens are transferred.
+    /// * `to` - Recipient of the tokens.
+    /// * `value` - Amount of tokens to transfer.
+    /// * `data` - Data passed to the recipient.
+    ///
+    /// # Errors
+    ///
+    /// * If the transfer fails, or `transferFromAndCall` doesn't return `true`,
+    ///   then the error [`Error::FailedOperation`] is returned.
+    /// * If the call reverts, then the error [`Error::FailedCall`] is returned,
+    ///   with the revert data of `token`.
+    pub fn transfer_from_and_call_relaxed(
+        &mut self,
+        token: Address,
+        from: Address,
+        to: Address,
+        value: U256,
+        data: Bytes,
+    ) -> Result<(), Error> {
+        if !to.has_code() {
+            return self.safe_transfer_from(token, from, to, value);
+        }
+        call_returning_true(
+            token,
+            &IErc1363::transferFromAndCallCall {
+                from,
+                to,
+                value,
+                data: data.0.into(),
+            },
+        )
+    }
+
+    /// Sets the allowance of `to` over the ERC-1363 `token` of the calling
+    /// contract to `value`, and calls `onApprovalReceived` on `to` with
+    /// `data`, or only sets the allowance with [`Self::force_approve`] if `to`
+    /// has no code.
+    ///
+    /// NOTE: Unlike [`Self::force_approve`], the allowance isn't reset to zero
+    /// first when `to` has code.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `token` - Address of the ERC-1363 token.
+    /// * `to` - Account allowed to spend the tokens.
+    /// * `value` - New allowance of `to`.
+    /// * `data` - Data passed to the spender.
+    ///
+    /// # Errors
+    ///
+    /// * If the approval fails, or `approveAndCall` doesn't return `true`, then
+    ///   the error [`Error::FailedOperation`] is returned.
+    /// * If the call reverts, then the error [`Error::FailedCall`] is returned,
+    ///   with the revert data of `token`.
+    pub fn approve_and_call_relaxed(
+        &mut self,
+        token: Address,
+        to: Address,
+        value: U256,
+        data: Bytes,
+    ) -> Result<(), Error> {
+        if !to.has_code() {
+            return self.force_approve(token, to, value);
+        }
+        call_returning_true(
+            token,
+            &IErc1363::approveAndCallCall {
+                spender: to,
+                value,
+                data: data.0.into(),
+            },
+        )
+    }
+}
+
+/// Calls `token` with `call`, which must return `true`.
+///
+/// # Errors
+///
+/// * If the call returns anything but `true`, then the error
+///   [`Error::FailedOperation`] is returned.
+/// * If the call reverts, then the error [`Error::FailedCall`] is returned.
+fn call_returning_true(
+    token: Address,
+    call: &impl SolCall,
+) -> Result<(), Error> {
+    let data = RawCall::new()
+        .call(token, &call.abi_encode())
+        .map_err(call::Error::Revert)?;
+    if Bool::abi_decode(&data, true).is_ok_and(|ok| ok) {
+        Ok(())
+    } else {
+        Err(SafeErc20FailedOperation { token }.into())
+    }
+}
+
+/// Returns the allowance of `spender` over the `token` of the calling
+/// contract.
+///
+/// # Errors
+///
+/// * If the call returns invalid data, then the error
+///   [`Error::FailedOperation`] is returned.
+/// * If the call reverts, then the error [`Error::FailedCall`] is returned.
+fn allowance(token: Address, spender: Address) -> Result<U256, Error> {
+    let call = IErc20::allowanceCall { owner: contract::address(), spender };
+    let data = RawCall::new_static()
+        .call(token, &call.abi_encode())
+        .map_err(call::Error::Revert)?;
+    IErc20::allowanceCall::abi_decode_returns(&data, true)
+        .map(|allowance| allowance._0)
+        .map_err(|_| SafeErc20FailedOperation { token }.into())
+}
+
+/// Calls `token` with `call`, accepting either no return data or `true`.
+///
+/// Also used by [`crate::utils::token_rescuer`], to rescue the tokens that
+/// don't return a value.
+///
+/// # Errors
+///
+/// * If `token` has no code, or the call returns anything but `true` or no
+///   data, then the error [`Error::FailedOperation`] is returned.
+/// * If the call reverts, then the error [`Error::FailedCall`] is returned.
+pub(crate) fn call_optional_return(
+    token: Address,
+    call: &impl SolCall,
+) -> Result<(), Error> {
+    if !token.has_code() {
+        return Err(SafeErc20FailedOperation { token }.into());
+    }
+
+    let data = RawCall::new()
+        .call(token, &call.abi_encode())
+        .map_err(call::Error::Revert)?;
+    if data.is_empty() || Bool::abi_decode(&data, true).is_ok_and(|ok| ok) {
+        Ok(())
+    } else {
+        Err(SafeErc20FailedOperation { token }.into())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::vec::Vec;
+
+    use alloy_primitives::{address, Address, U256};
+    use alloy_sol_types::{SolCall, SolValue};
+    use motsu::prelude::{deploy, mock_call, mock_revert, with_contract};
+    use stylus_sdk::{
+        call, msg,
+        prelude::{public, sol_storage},
+        storage::TopLevelStorage,
+    };
+
+    use super::{Error, IErc20, SafeErc20};
+    use crate::token::erc20::{self, Erc20, IErc20 as _};
+
+    const ALICE: Address = address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
+    const TOKEN: Address = address!("B0B0cB49ec2e96DF5F5fFB081acaE66A2cBBc2e2");
+
+    #[motsu::test]
+    fn rejects_tokens_without_code(contract: SafeErc20) {
+        let err = contract
+            .safe_transfer(TOKEN, ALICE, U256::from(1))
+            .expect_err("should reject EOA");
+        assert!(matches!(err, Error::FailedOperation(e) if e.token == TOKEN));
+
+        let err = contract
+            .safe_transfer_from(TOKEN, ALICE, ALICE, U256::from(1))
+            .expect_err("should reject EOA");
+        assert!(matches!(err, Error::FailedOperation(e) if e.token == TOKEN));
+    }
+
+    #[test]
+    fn bubbles_up_revert_data() {
+        let reason = vec![0xe4, 0x50, 0xd3, 0x8c];
+        let err = Error::FailedCall(call::Error::Revert(reason.clone()));
+        assert_eq!(Vec::<u8>::from(err), reason);
+    }
+
+    #[motsu::test]
+    fn tries_transfers(contract: SafeErc20) {
+        assert!(!contract.try_safe_transfer(TOKEN, ALICE, U256::from(1)));
+        assert!(!contract.try_safe_transfer_from(
+            TOKEN,
+            ALICE,
+            ALICE,
+            U256::from(1)
+        ));
+    }
+
+    #[motsu::test]
+    fn rejects_allowance_changes_without_code(contract: SafeErc20) {
+        let err = contract
+            .force_approve(TOKEN, ALICE, U256::from(1))
+            .expect_err("should reject EOA");
+        assert!(matches!(err, Error::FailedOperation(e) if e.token == TOKEN));
+
+        let err = contract
+            .safe_increase_allowance(TOKEN, ALICE, U256::from(1))
+            .expect_err("should fail to read allowance");
+        assert!(matches!(err, Error::FailedOperation(e) if e.token == TOKEN));
+
+        let err = contract
+            .safe_decrease_allowance(TOKEN, ALICE, U256::from(1))
+            .expect_err("should fail to read allowance");
+        assert!(matches!(err, Error::FailedOperation(e) if e.token == TOKEN));
+    }
+
+    #[motsu::test]
+    fn relaxes_calls_to_accounts_without_code(contract: SafeErc20) {
+        // `ALICE` has no code, so the tokens are transferred without calling
+        // it, which fails since `TOKEN` has no code either.
+        let err = contract
+            .transfer_and_call_relaxed(
+                TOKEN,
+                ALICE,
+                U256::from(1),
+                vec![].into(),
+            )
+            .expect_err("should fall back to `safe_transfer`");
+        assert!(matches!(err, Error::FailedOperation(e) if e.token == TOKEN));
+
+        let err = contract
+            .transfer_from_and_call_relaxed(
+                TOKEN,
+                ALICE,
+                ALICE,
+                U256::from(1),
+                vec![].into(),
+            )
+            .expect_err("should fall back to `safe_transfer_from`");
+        assert!(matches!(err, Error::FailedOperation(e) if e.token == TOKEN));
+
+        let err = contract
+            .approve_and_call_relaxed(
+                TOKEN,
+                ALICE,
+                U256::from(1),
+                vec![].into(),
+            )
+            .expect_err("should fall back to `force_approve`");
+        assert!(matches!(err, Error::FailedOperation(e) if e.token == TOKEN));
+    }
+
+    #[motsu::test]
+    fn transfers_with_optional_return(contract: SafeErc20) {
+        let selector = IErc20::transferCall::SELECTOR;
+        mock_call(TOKEN, selector, vec![]);
+        contract
+            .safe_transfer(TOKEN, ALICE, U256::from(1))
+            .expect("should accept no return data");
+
+        mock_call(TOKEN, selector, true.abi_encode());
+        contract
+            .safe_transfer(TOKEN, ALICE, U256::from(1))
+            .expect("should accept `true`");
+
+        mock_call(TOKEN, selector, false.abi_encode());
+        let err = contract
+            .safe_transfer(TOKEN, ALICE, U256::from(1))
+            .expect_err("should reject `false`");
+        assert!(matches!(err, Error::FailedOperation(e) if e.token == TOKEN));
+    }
+
+    #[motsu::test]
+    fn bubbles_up_reverts_of_tokens(contract: SafeErc20) {
+        let reason = vec![0xe4, 0x50, 0xd3, 0x8c];
+        mock_revert(TOKEN, IErc20::transferCall::SELECTOR, reason.clone());
+        let err = contract
+            .safe_transfer(TOKEN, ALICE, U256::from(1))
+            .expect_err("should revert");
+        assert_eq!(Vec::<u8>::from(err), reason);
+    }
+
+    #[motsu::test]
+    fn changes_allowance_read_from_token(contract: SafeErc20) {
+        let allowance = U256::from(1).abi_encode();
+        mock_call(TOKEN, IErc20::allowanceCall::SELECTOR, allowance);
+        mock_call(TOKEN, IErc20::approveCall::SELECTOR, true.abi_encode());
+        contract
+            .safe_increase_allowance(TOKEN, ALICE, U256::from(1))
+            .expect("should increase the allowance");
+        contract
+            .safe_decrease_allowance(TOKEN, ALICE, U256::from(1))
+            .expect("should decrease the allowance");
+
+        let err = contract
+            .safe_decrease_allowance(TOKEN, ALICE, U256::from(2))
+            .expect_err("should not decrease below zero");
+        assert!(matches!(err, Error::FailedDecreaseAllowance(_)));
+    }
+
+    sol_storage! {
+        struct Token {
+            #[borrow]
+            Erc20 erc20;
+        }
+    }
+
+    unsafe impl TopLevelStorage for Token {}
+
+    #[public]
+    #[inherit(Erc20)]
+    impl Token {}
+
+    #[motsu::test]
+    fn transfers_tokens_of_deployed_contract(contract: SafeErc20) {
+        deploy::<Token>(TOKEN);
+        with_contract::<Token, _>(TOKEN, |token| {
+            token.erc20._mint(msg::sender(), U256::from(2)).unwrap();
+        });
+
+        contract
+            .safe_transfer(TOKEN, ALICE, U256::from(1))
+            .expect("should transfer tokens");
+        let balance = with_contract::<Token, _>(TOKEN, |token| {
+            token.erc20.balance_of(ALICE)
+        });
+        assert_eq!(balance, U256::from(1));
+
+        let err = contract
+            .safe_transfer(TOKEN, ALICE, U256::from(2))
+            .expect_err("should bubble up the revert of the token");
+        let expected: Vec<u8> = erc20::Error::InsufficientBalance(
+            erc20::ERC20InsufficientBalance {
+                sender: msg::sender(),
+                balance: U256::from(1),
+                needed: U256::from(2),
+            },
+        )
+        .into();
+        assert_eq!(Vec::<u8>::from(err), expected);
+    }
+}
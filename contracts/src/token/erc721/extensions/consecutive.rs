@@ -26,11 +26,12 @@ use alloc::vec;
 
 use alloy_primitives::{uint, Address, U256};
 use alloy_sol_types::sol;
+use openzeppelin_stylus_proc::{selectors, SolidityError};
 use stylus_sdk::{
     abi::Bytes,
     evm, msg,
     prelude::TopLevelStorage,
-    stylus_proc::{public, sol_storage, SolidityError},
+    stylus_proc::{public, sol_storage},
 };
 
 use crate::{
@@ -73,6 +74,8 @@ sol_storage! {
 }
 
 sol! {
+    #![sol(abi)]
+
     /// Emitted when the tokens from `from_token_id` to `to_token_id` are transferred from `from_address` to `to_address`.
     ///
     /// * `from_token_id` - First token being transferred.
@@ -80,6 +83,7 @@ sol! {
     /// * `from_address` - Address from which tokens will be transferred.
     /// * `to_address` - Address where the tokens will be transferred to.
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     event ConsecutiveTransfer(
         uint256 indexed from_token_id,
         uint256 to_token_id,
@@ -89,26 +93,32 @@ sol! {
 }
 
 sol! {
+    #![sol(abi)]
+
     /// Batch mint is restricted to the constructor.
     /// Any batch mint not emitting the [`Transfer`] event outside of the constructor
     /// is non ERC-721 compliant.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error ERC721ForbiddenBatchMint();
 
     /// Exceeds the max number of mints per batch.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error ERC721ExceededMaxBatchMint(uint256 batch_size, uint256 max_batch);
 
     /// Individual minting is not allowed.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error ERC721ForbiddenMint();
 
     /// Batch burn is not supported.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error ERC721ForbiddenBatchBurn();
 }
 
@@ -135,6 +145,29 @@ unsafe impl TopLevelStorage for Erc721Consecutive {}
 
 // ************** ERC-721 External **************
 
+#[selectors(
+    events(
+        ConsecutiveTransfer,
+        erc721::Transfer,
+        erc721::Approval,
+        erc721::ApprovalForAll
+    ),
+    errors(
+        ERC721ForbiddenBatchMint,
+        ERC721ExceededMaxBatchMint,
+        ERC721ForbiddenMint,
+        ERC721ForbiddenBatchBurn,
+        checkpoints::CheckpointUnorderedInsertion,
+        erc721::ERC721InvalidOwner,
+        erc721::ERC721NonexistentToken,
+        erc721::ERC721IncorrectOwner,
+        erc721::ERC721InvalidSender,
+        erc721::ERC721InvalidReceiver,
+        erc721::ERC721InsufficientApproval,
+        erc721::ERC721InvalidApprover,
+        erc721::ERC721InvalidOperator
+    )
+)]
 #[public]
 impl IErc721 for Erc721Consecutive {
     type Error = Error;
@@ -12,8 +12,8 @@
 
 use alloy_primitives::{uint, Address, FixedBytes, U256};
 use alloy_sol_types::sol;
-use openzeppelin_stylus_proc::interface_id;
-use stylus_sdk::stylus_proc::{public, sol_storage, SolidityError};
+use openzeppelin_stylus_proc::{interface_id, selectors, SolidityError};
+use stylus_sdk::stylus_proc::{public, sol_storage};
 
 use crate::{
     token::{erc721, erc721::IErc721},
@@ -21,6 +21,8 @@ use crate::{
 };
 
 sol! {
+    #![sol(abi)]
+
     /// Indicates an error when an `owner`'s token query
     /// was out of bounds for `index`.
     ///
@@ -28,11 +30,13 @@ sol! {
     /// indicates a global out of bounds index.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error ERC721OutOfBoundsIndex(address owner, uint256 index);
 
     /// Indicates an error related to batch minting not allowed.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error ERC721EnumerableForbiddenBatchMint();
 }
 
@@ -119,6 +123,10 @@ pub trait IErc721Enumerable {
     fn token_by_index(&self, index: U256) -> Result<U256, Self::Error>;
 }
 
+#[selectors(errors(
+    ERC721OutOfBoundsIndex,
+    ERC721EnumerableForbiddenBatchMint
+))]
 #[public]
 impl IErc721Enumerable for Erc721Enumerable {
     type Error = Error;
@@ -127,7 +135,7 @@ impl IErc721Enumerable for Erc721Enumerable {
         &self,
         owner: Address,
         index: U256,
-    ) -> Result<U256, Self::Error> {
+    ) -> Result<U256, Error> {
         let token = self._owned_tokens.getter(owner).get(index);
 
         if token.is_zero() {
@@ -142,7 +150,7 @@ impl IErc721Enumerable for Erc721Enumerable {
         U256::from(tokens_length)
     }
 
-    fn token_by_index(&self, index: U256) -> Result<U256, Self::Error> {
+    fn token_by_index(&self, index: U256) -> Result<U256, Error> {
         self._all_tokens.get(index).ok_or(
             ERC721OutOfBoundsIndex { owner: Address::ZERO, index }.into(),
         )
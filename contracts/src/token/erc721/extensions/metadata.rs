@@ -3,7 +3,7 @@
 use alloc::string::String;
 
 use alloy_primitives::FixedBytes;
-use openzeppelin_stylus_proc::interface_id;
+use openzeppelin_stylus_proc::{interface_id, selectors};
 use stylus_sdk::stylus_proc::{public, sol_storage};
 
 use crate::utils::{introspection::erc165::IErc165, Metadata};
@@ -47,6 +47,7 @@ pub trait IErc721Metadata {
 // FIXME: Apply multi-level inheritance to export Metadata's functions.
 // With the current version of SDK it is not possible.
 // See https://github.com/OffchainLabs/stylus-sdk-rs/pull/120
+#[selectors]
 #[public]
 impl IErc721Metadata for Erc721Metadata {
     fn name(&self) -> String {
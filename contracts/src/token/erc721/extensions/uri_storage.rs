@@ -5,16 +5,20 @@ use alloc::string::String;
 
 use alloy_primitives::U256;
 use alloy_sol_types::sol;
+use openzeppelin_stylus_proc::selectors;
 use stylus_sdk::{
     evm,
     stylus_proc::{public, sol_storage},
 };
 
 sol! {
+    #![sol(abi)]
+
     /// This event gets emitted when the metadata of a token is changed.
     ///
     /// The event comes from IERC4096.
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     event MetadataUpdate(uint256 token_id);
 
     /// This event gets emitted when the metadata of a range of tokens
@@ -22,6 +26,7 @@ sol! {
     ///
     /// The event comes from IERC4096.
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     event BatchMetadataUpdate(uint256 from_token_id, uint256 to_token_id);
 }
 
@@ -50,6 +55,7 @@ impl Erc721UriStorage {
     }
 }
 
+#[selectors(events(MetadataUpdate, BatchMetadataUpdate))]
 #[public]
 impl Erc721UriStorage {
     /// Returns the Uniform Resource Identifier (URI) for `token_id` token.
@@ -2,7 +2,7 @@
 use alloc::vec;
 
 use alloy_primitives::{fixed_bytes, uint, Address, FixedBytes, U128, U256};
-use openzeppelin_stylus_proc::interface_id;
+use openzeppelin_stylus_proc::{interface_id, selectors, SolidityError};
 use stylus_sdk::{
     abi::Bytes,
     alloy_sol_types::sol,
@@ -11,6 +11,7 @@ use stylus_sdk::{
     prelude::*,
 };
 
+pub use crate::interfaces::erc721::IERC721Receiver;
 use crate::utils::{
     introspection::erc165::{Erc165, IErc165},
     math::storage::{AddAssignUnchecked, SubAssignUnchecked},
@@ -19,12 +20,15 @@ use crate::utils::{
 pub mod extensions;
 
 sol! {
+    #![sol(abi)]
+
     /// Emitted when the `token_id` token is transferred from `from` to `to`.
     ///
     /// * `from` - Address from which the token will be transferred.
     /// * `to` - Address where the token will be transferred to.
     /// * `token_id` - Token id as a number.
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     event Transfer(
         address indexed from,
         address indexed to,
@@ -37,6 +41,7 @@ sol! {
     /// * `approved` - Address of the approver.
     /// * `token_id` - Token id as a number.
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     event Approval(
         address indexed owner,
         address indexed approved,
@@ -52,10 +57,13 @@ sol! {
     /// * `approved` - Whether or not permission has been granted. If true,
     ///   this means `operator` will be allowed to manage `owner`'s assets.
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     event ApprovalForAll(address indexed owner, address indexed operator, bool approved);
 }
 
 sol! {
+    #![sol(abi)]
+
     /// Indicates that an address can't be an owner.
     /// For example, `Address::ZERO` is a forbidden owner in [`Erc721`].
     /// Used in balance queries.
@@ -63,6 +71,7 @@ sol! {
     /// * `owner` - The address deemed to be an invalid owner.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error ERC721InvalidOwner(address owner);
 
     /// Indicates a `token_id` whose `owner` is the zero address.
@@ -70,6 +79,7 @@ sol! {
     /// * `token_id` - Token id as a number.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error ERC721NonexistentToken(uint256 token_id);
 
     /// Indicates an error related to the ownership over a particular token.
@@ -80,6 +90,7 @@ sol! {
     /// * `owner` - Address of the owner of the token.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error ERC721IncorrectOwner(address sender, uint256 token_id, address owner);
 
     /// Indicates a failure with the token `sender`. Used in transfers.
@@ -87,6 +98,7 @@ sol! {
     /// * `sender` - An address whose token is being transferred.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error ERC721InvalidSender(address sender);
 
     /// Indicates a failure with the token `receiver`. Used in transfers.
@@ -94,6 +106,7 @@ sol! {
     /// * `receiver` - Address that receives the token.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error ERC721InvalidReceiver(address receiver);
 
     /// Indicates a failure with the `operator`’s approval. Used in transfers.
@@ -103,6 +116,7 @@ sol! {
     /// * `token_id` - Token id as a number.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error ERC721InsufficientApproval(address operator, uint256 token_id);
 
     /// Indicates a failure with the `approver` of a token to be approved.
@@ -111,6 +125,7 @@ sol! {
     /// * `approver` - Address initiating an approval operation.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error ERC721InvalidApprover(address approver);
 
     /// Indicates a failure with the `operator` to be approved.
@@ -120,6 +135,7 @@ sol! {
     ///   without being their owner.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error ERC721InvalidOperator(address operator);
 }
 
@@ -160,28 +176,6 @@ impl MethodError for Error {
     }
 }
 
-sol_interface! {
-    /// [`Erc721`] token receiver interface.
-    ///
-    /// Interface for any contract that wants to support `safe_transfers`
-    /// from [`Erc721`] asset contracts.
-    interface IERC721Receiver {
-        /// Whenever an [`Erc721`] `token_id` token is transferred
-        /// to this contract via [`Erc721::safe_transfer_from`].
-        ///
-        /// It must return its function selector to confirm the token transfer.
-        /// If any other value is returned or the interface is not implemented
-        /// by the recipient, the transfer will be reverted.
-        #[allow(missing_docs)]
-        function onERC721Received(
-            address operator,
-            address from,
-            uint256 token_id,
-            bytes calldata data
-        ) external returns (bytes4);
-    }
-}
-
 sol_storage! {
     /// State of an [`Erc721`] token.
     pub struct Erc721 {
@@ -468,6 +462,19 @@ pub trait IErc721 {
     fn is_approved_for_all(&self, owner: Address, operator: Address) -> bool;
 }
 
+#[selectors(
+    events(Transfer, Approval, ApprovalForAll),
+    errors(
+        ERC721InvalidOwner,
+        ERC721NonexistentToken,
+        ERC721IncorrectOwner,
+        ERC721InvalidSender,
+        ERC721InvalidReceiver,
+        ERC721InsufficientApproval,
+        ERC721InvalidApprover,
+        ERC721InvalidOperator
+    )
+)]
 #[public]
 impl IErc721 for Erc721 {
     type Error = Error;
@@ -570,8 +577,8 @@ impl Erc721 {
     /// not tracked by the core [`Erc721`] logic MUST be matched with the use
     /// of [`Self::_increase_balance`] to keep balances consistent with
     /// ownership. The invariant to preserve is that for any address `a` the
-    /// value returned by [`Self::balance_of(a)`] must be equal to the number of
-    /// tokens such that [`Self::_owner_of(token_id)`] is `a`.
+    /// value returned by `Self::balance_of(a)` must be equal to the number of
+    /// tokens such that `Self::_owner_of(token_id)` is `a`.
     ///
     /// # Arguments
     ///
@@ -1158,6 +1165,7 @@ impl Erc721 {
 #[cfg(all(test, feature = "std"))]
 mod tests {
     use alloy_primitives::{address, uint, Address, U256};
+    use motsu::prelude::assert_storage_layout;
     use stylus_sdk::msg;
 
     use super::{
@@ -1176,6 +1184,15 @@ mod tests {
         U256::from(num)
     }
 
+    #[motsu::test]
+    fn keeps_storage_layout(contract: Erc721) {
+        let token_id = uint!(1_U256);
+        contract._mint(msg::sender(), token_id).unwrap();
+        contract.approve(BOB, token_id).unwrap();
+        contract.set_approval_for_all(DAVE, true).unwrap();
+        assert_storage_layout("erc721");
+    }
+
     #[motsu::test]
     fn error_when_checking_balance_of_invalid_owner(contract: Erc721) {
         let invalid_owner = Address::ZERO;
@@ -0,0 +1,247 @@
+//! Property-based tests of the invariants of the token modules.
+//!
+//! Each test drives random sequences of operations against a token, e.g.
+//! mints, burns, transfers and approvals from a small set of accounts, and
+//! checks the invariants of the token after every operation, whether it
+//! succeeded or not:
+//!
+//! - Supply conservation: the total supply is the sum of the balances, and only
+//!   changes with mints and burns.
+//! - Balance non-negativity: tokens only leave the accounts holding them.
+//! - Allowance monotonicity: allowances only increase when approved by their
+//!   owner, and only decrease by the value spent.
+use alloy_primitives::{
+    address,
+    private::proptest::{collection::vec, prelude::*},
+    Address, U256,
+};
+use motsu::prelude::*;
+use stylus_sdk::msg;
+
+use crate::token::{
+    erc20::{self, Erc20, IErc20},
+    erc721::{self, Erc721, IErc721},
+};
+
+const ALICE: Address = address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
+const BOB: Address = address!("B0B0cB49ec2e96DF5F5fFB081acaE66A2cBBc2e2");
+
+/// Maximum number of operations of a sequence.
+const MAX_OPS: usize = 32;
+
+/// Number of distinct ERC-721 tokens, kept low so that operations collide.
+const TOKEN_IDS: u64 = 8;
+
+/// Accounts operating the tokens, including the invalid `Address::ZERO`.
+fn accounts() -> [Address; 4] {
+    [msg::sender(), ALICE, BOB, Address::ZERO]
+}
+
+fn account() -> impl Strategy<Value = Address> {
+    prop::sample::select(accounts().to_vec())
+}
+
+fn amount() -> impl Strategy<Value = U256> {
+    (0..1000_u64).prop_map(U256::from)
+}
+
+fn token_id() -> impl Strategy<Value = U256> {
+    (0..TOKEN_IDS).prop_map(U256::from)
+}
+
+/// An operation of an [`Erc20`] token, called by `msg::sender()`.
+#[derive(Clone, Debug)]
+enum Erc20Op {
+    Mint(Address, U256),
+    Burn(Address, U256),
+    Transfer(Address, U256),
+    Approve(Address, U256),
+    TransferFrom(Address, Address, U256),
+}
+
+fn erc20_op() -> impl Strategy<Value = Erc20Op> {
+    let allowance = prop_oneof![4 => amount(), 1 => Just(U256::MAX)];
+    prop_oneof![
+        (account(), amount()).prop_map(|(to, v)| Erc20Op::Mint(to, v)),
+        (account(), amount()).prop_map(|(from, v)| Erc20Op::Burn(from, v)),
+        (account(), amount()).prop_map(|(to, v)| Erc20Op::Transfer(to, v)),
+        (account(), allowance).prop_map(|(to, v)| Erc20Op::Approve(to, v)),
+        (account(), account(), amount())
+            .prop_map(|(from, to, v)| Erc20Op::TransferFrom(from, to, v)),
+    ]
+}
+
+impl Erc20Op {
+    fn apply(&self, token: &mut Erc20) -> Result<(), erc20::Error> {
+        match *self {
+            Erc20Op::Mint(to, value) => token._mint(to, value),
+            Erc20Op::Burn(from, value) => token._burn(from, value),
+            Erc20Op::Transfer(to, value) => token.transfer(to, value).map(drop),
+            Erc20Op::Approve(spender, value) => {
+                token.approve(spender, value).map(drop)
+            }
+            Erc20Op::TransferFrom(from, to, value) => {
+                token.transfer_from(from, to, value).map(drop)
+            }
+        }
+    }
+
+    /// Returns the account the tokens leave and their amount, if any.
+    fn debit(&self) -> Option<(Address, U256)> {
+        match *self {
+            Erc20Op::Burn(from, value)
+            | Erc20Op::TransferFrom(from, _, value) => Some((from, value)),
+            Erc20Op::Transfer(_, value) => Some((msg::sender(), value)),
+            Erc20Op::Mint(..) | Erc20Op::Approve(..) => None,
+        }
+    }
+}
+
+#[motsu::test]
+fn erc20_invariants() {
+    proptest!(|(ops in vec(erc20_op(), 1..MAX_OPS))| {
+        reset_storage();
+        let mut token = Erc20::default();
+        let mut supply = U256::ZERO;
+        for op in &ops {
+            let balances = accounts().map(|account| token.balance_of(account));
+            let allowances = accounts().map(|owner| {
+                accounts().map(|spender| token.allowance(owner, spender))
+            });
+
+            let res = op.apply(&mut token);
+            match (op, &res) {
+                (Erc20Op::Mint(_, value), Ok(())) => supply += value,
+                (Erc20Op::Burn(_, value), Ok(())) => supply -= value,
+                _ => {}
+            }
+
+            // Supply conservation.
+            let total = accounts()
+                .iter()
+                .fold(U256::ZERO, |total, &a| total + token.balance_of(a));
+            assert_eq!(token.total_supply(), supply, "{op:?}");
+            assert_eq!(total, supply, "{op:?}");
+
+            // Balance non-negativity.
+            if let Err(erc20::Error::InsufficientBalance(err)) = &res {
+                assert!(err.balance < err.needed, "{op:?}");
+            }
+            if let (Some((from, value)), Ok(())) = (op.debit(), &res) {
+                let i = accounts().iter().position(|&a| a == from).unwrap();
+                assert!(balances[i] >= value, "{op:?}");
+            }
+
+            // Allowance monotonicity.
+            for (i, owner) in accounts().into_iter().enumerate() {
+                for (j, spender) in accounts().into_iter().enumerate() {
+                    let before = allowances[i][j];
+                    let after = token.allowance(owner, spender);
+                    match (op, &res) {
+                        (Erc20Op::Approve(approved, value), Ok(()))
+                            if owner == msg::sender() && spender == *approved =>
+                        {
+                            assert_eq!(after, *value, "{op:?}");
+                        }
+                        (Erc20Op::TransferFrom(from, _, value), Ok(()))
+                            if owner == *from
+                                && spender == msg::sender()
+                                && before != U256::MAX =>
+                        {
+                            assert_eq!(after, before - value, "{op:?}");
+                        }
+                        _ => assert!(after <= before, "{op:?}"),
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// An operation of an [`Erc721`] token, called by `msg::sender()`.
+#[derive(Clone, Debug)]
+enum Erc721Op {
+    Mint(Address, U256),
+    Burn(U256),
+    TransferFrom(Address, Address, U256),
+    Approve(Address, U256),
+    SetApprovalForAll(Address, bool),
+}
+
+fn erc721_op() -> impl Strategy<Value = Erc721Op> {
+    prop_oneof![
+        (account(), token_id()).prop_map(|(to, id)| Erc721Op::Mint(to, id)),
+        token_id().prop_map(Erc721Op::Burn),
+        (account(), account(), token_id())
+            .prop_map(|(from, to, id)| Erc721Op::TransferFrom(from, to, id)),
+        (account(), token_id()).prop_map(|(to, id)| Erc721Op::Approve(to, id)),
+        (account(), any::<bool>()).prop_map(|(operator, approved)| {
+            Erc721Op::SetApprovalForAll(operator, approved)
+        }),
+    ]
+}
+
+impl Erc721Op {
+    fn apply(&self, token: &mut Erc721) -> Result<(), erc721::Error> {
+        match *self {
+            Erc721Op::Mint(to, id) => token._mint(to, id),
+            Erc721Op::Burn(id) => token._burn(id),
+            Erc721Op::TransferFrom(from, to, id) => {
+                token.transfer_from(from, to, id)
+            }
+            Erc721Op::Approve(to, id) => token.approve(to, id),
+            Erc721Op::SetApprovalForAll(operator, approved) => {
+                token.set_approval_for_all(operator, approved)
+            }
+        }
+    }
+}
+
+#[motsu::test]
+fn erc721_invariants() {
+    proptest!(|(ops in vec(erc721_op(), 1..MAX_OPS))| {
+        reset_storage();
+        let mut token = Erc721::default();
+        for op in &ops {
+            let authorized = match *op {
+                Erc721Op::TransferFrom(_, _, id) => {
+                    let owner = token._owner_of(id);
+                    owner == msg::sender()
+                        || token._get_approved(id) == msg::sender()
+                        || token.is_approved_for_all(owner, msg::sender())
+                }
+                _ => true,
+            };
+
+            let res = op.apply(&mut token);
+
+            // Supply conservation: each existing token is counted once in the
+            // balance of its owner.
+            let owners: Vec<Address> = (0..TOKEN_IDS)
+                .map(|id| token._owner_of(U256::from(id)))
+                .collect();
+            for account in accounts().into_iter().filter(|a| !a.is_zero()) {
+                let owned = owners.iter().filter(|&&o| o == account).count();
+                let balance = token.balance_of(account).unwrap();
+                assert_eq!(balance, U256::from(owned), "{op:?}");
+            }
+
+            // Tokens only leave their owner when authorized, and lose their
+            // approval.
+            match (op, &res) {
+                (Erc721Op::Mint(to, id), Ok(())) => {
+                    assert_eq!(token._owner_of(*id), *to, "{op:?}");
+                }
+                (Erc721Op::Burn(id), Ok(())) => {
+                    assert!(token._owner_of(*id).is_zero(), "{op:?}");
+                }
+                (Erc721Op::TransferFrom(_, to, id), Ok(())) => {
+                    assert!(authorized, "{op:?}");
+                    assert_eq!(token._owner_of(*id), *to, "{op:?}");
+                    assert!(token._get_approved(*id).is_zero(), "{op:?}");
+                }
+                _ => {}
+            }
+        }
+    });
+}
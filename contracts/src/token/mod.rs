@@ -1,3 +1,8 @@
 //! Token standards.
+#[cfg(feature = "erc20")]
 pub mod erc20;
+#[cfg(feature = "erc721")]
 pub mod erc721;
+
+#[cfg(all(test, feature = "std", feature = "erc20", feature = "erc721"))]
+mod invariants;
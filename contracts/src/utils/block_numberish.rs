@@ -0,0 +1,82 @@
+//! Sources of block numbers on Arbitrum chains.
+//!
+//! On an Arbitrum chain, `block::number` returns an estimate of the block
+//! number of the parent chain, e.g. Ethereum, which is updated about once a
+//! minute and can stay the same for many blocks of the Arbitrum chain. The
+//! block numbers of the Arbitrum chain itself are read from the `ArbSys`
+//! precompile instead.
+//!
+//! Mixing both sources silently breaks lookups of values recorded by block
+//! number, e.g. in [`crate::utils::structs::checkpoints`], so modules doing
+//! so are generic over their [`BlockNumberish`] source:
+//!
+//! ```rust,ignore
+//! pub struct Votes<C: BlockNumberish> {
+//!     // ...
+//! }
+//!
+//! impl<C: BlockNumberish> Votes<C> {
+//!     pub fn clock(&self) -> u64 {
+//!         C::block_number()
+//!     }
+//! }
+//! ```
+//!
+//! See [Block Numbers and Time] of the Arbitrum documentation.
+//!
+//! [Block Numbers and Time]: https://docs.arbitrum.io/build-decentralized-apps/arbitrum-vs-ethereum/block-numbers-and-time
+use stylus_sdk::block;
+
+use crate::arbitrum::arb_sys;
+
+/// A source of block numbers.
+pub trait BlockNumberish {
+    /// Returns the current block number.
+    fn block_number() -> u64;
+}
+
+/// Block numbers of the parent chain, as returned by `block::number`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ParentChainBlockNumber;
+
+impl BlockNumberish for ParentChainBlockNumber {
+    fn block_number() -> u64 {
+        block::number()
+    }
+}
+
+/// Block numbers of the Arbitrum chain itself, as returned by
+/// [`arb_sys::arb_block_number`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ArbitrumBlockNumber;
+
+impl BlockNumberish for ArbitrumBlockNumber {
+    /// # Panics
+    ///
+    /// * If the call to the `ArbSys` precompile fails.
+    /// * If the block number exceeds `u64::MAX`.
+    fn block_number() -> u64 {
+        arb_sys::arb_block_number()
+            .expect("should read the block number from `ArbSys`")
+            .to::<u64>()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use stylus_sdk::block;
+
+    use super::*;
+
+    #[motsu::test]
+    fn reads_parent_chain_block_number() {
+        assert_eq!(ParentChainBlockNumber::block_number(), block::number());
+    }
+
+    // `ArbSys` doesn't exist in tests, so its block number can't be read.
+    #[motsu::test]
+    #[should_panic = "should read the block number from `ArbSys`"]
+    fn reads_arbitrum_block_number() {
+        ArbitrumBlockNumber::block_number();
+    }
+}
@@ -0,0 +1,234 @@
+//! Helpers for manipulating raw byte sequences, like calldata or return data.
+//!
+//! All accessors are bounds-checked and return a typed [`Error`] instead of
+//! panicking when the requested range is not present in the input.
+use alloc::vec::Vec;
+
+use alloy_primitives::{FixedBytes, B256, U256};
+use alloy_sol_types::sol;
+use openzeppelin_stylus_proc::SolidityError;
+use stylus_sdk::call::MethodError;
+
+/// Length of a function selector in bytes.
+pub const SELECTOR_LENGTH: usize = 4;
+
+sol! {
+    /// The range `start..end` is out of bounds for a byte sequence of the
+    /// given `length`.
+    ///
+    /// * `start` - Start of the requested range (inclusive).
+    /// * `end` - End of the requested range (exclusive).
+    /// * `length` - Length of the byte sequence.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error BytesOutOfBounds(uint256 start, uint256 end, uint256 length);
+}
+
+/// An error that occurred while manipulating a byte sequence.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// The requested range is out of bounds of the byte sequence.
+    OutOfBounds(BytesOutOfBounds),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> Vec<u8> {
+        self.into()
+    }
+}
+
+/// Returns the sub-slice of `data` in the range `start..end`.
+///
+/// # Arguments
+///
+/// * `data` - Byte sequence to slice.
+/// * `start` - Start of the range (inclusive).
+/// * `end` - End of the range (exclusive).
+///
+/// # Errors
+///
+/// If `start` is greater than `end`, or `end` is greater than the length of
+/// `data`, then the error [`Error::OutOfBounds`] is returned.
+pub fn slice(data: &[u8], start: usize, end: usize) -> Result<&[u8], Error> {
+    data.get(start..end).ok_or_else(|| {
+        Error::OutOfBounds(BytesOutOfBounds {
+            start: U256::from(start),
+            end: U256::from(end),
+            length: U256::from(data.len()),
+        })
+    })
+}
+
+/// Returns the sub-slice of `data` starting at `start` until the end.
+///
+/// # Arguments
+///
+/// * `data` - Byte sequence to slice.
+/// * `start` - Start of the range (inclusive).
+///
+/// # Errors
+///
+/// If `start` is greater than the length of `data`, then the error
+/// [`Error::OutOfBounds`] is returned.
+pub fn slice_from(data: &[u8], start: usize) -> Result<&[u8], Error> {
+    slice(data, start, data.len().max(start))
+}
+
+/// Reads `N` bytes of `data` starting at `offset` into a [`FixedBytes`].
+///
+/// # Arguments
+///
+/// * `data` - Byte sequence to read from.
+/// * `offset` - Position of the first byte to read.
+///
+/// # Errors
+///
+/// If `data` does not contain `N` bytes starting at `offset`, then the error
+/// [`Error::OutOfBounds`] is returned.
+pub fn read_fixed<const N: usize>(
+    data: &[u8],
+    offset: usize,
+) -> Result<FixedBytes<N>, Error> {
+    let end = offset.saturating_add(N);
+    slice(data, offset, end).map(FixedBytes::from_slice)
+}
+
+/// Reads a 32-byte word of `data` starting at `offset`.
+///
+/// # Arguments
+///
+/// * `data` - Byte sequence to read from.
+/// * `offset` - Position of the first byte to read.
+///
+/// # Errors
+///
+/// If `data` does not contain 32 bytes starting at `offset`, then the error
+/// [`Error::OutOfBounds`] is returned.
+pub fn read_bytes32(data: &[u8], offset: usize) -> Result<B256, Error> {
+    read_fixed::<32>(data, offset)
+}
+
+/// Returns the function selector, i.e. the first four bytes, of `calldata`.
+///
+/// # Arguments
+///
+/// * `calldata` - Calldata of a contract call.
+///
+/// # Errors
+///
+/// If `calldata` is shorter than [`SELECTOR_LENGTH`], then the error
+/// [`Error::OutOfBounds`] is returned.
+pub fn selector(calldata: &[u8]) -> Result<FixedBytes<4>, Error> {
+    read_fixed::<SELECTOR_LENGTH>(calldata, 0)
+}
+
+/// Concatenates all `parts` into a single byte vector.
+///
+/// # Arguments
+///
+/// * `parts` - Byte sequences to concatenate, in order.
+#[must_use]
+pub fn concat(parts: &[&[u8]]) -> Vec<u8> {
+    let len = parts.iter().map(|part| part.len()).sum();
+    let mut result = Vec::with_capacity(len);
+    for part in parts {
+        result.extend_from_slice(part);
+    }
+    result
+}
+
+/// Returns the position of the first occurrence of `value` in `data`, or
+/// `None` if `data` does not contain `value`.
+///
+/// # Arguments
+///
+/// * `data` - Byte sequence to search.
+/// * `value` - Byte to look for.
+#[must_use]
+pub fn index_of(data: &[u8], value: u8) -> Option<usize> {
+    data.iter().position(|&byte| byte == value)
+}
+
+/// Returns the position of the last occurrence of `value` in `data`, or
+/// `None` if `data` does not contain `value`.
+///
+/// # Arguments
+///
+/// * `data` - Byte sequence to search.
+/// * `value` - Byte to look for.
+#[must_use]
+pub fn last_index_of(data: &[u8], value: u8) -> Option<usize> {
+    data.iter().rposition(|&byte| byte == value)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{b256, fixed_bytes, U256};
+
+    use super::*;
+
+    const DATA: [u8; 6] = [0xa9, 0x05, 0x9c, 0xbb, 0x00, 0xa9];
+
+    #[test]
+    fn slices_within_bounds() {
+        assert_eq!(slice(&DATA, 1, 3).unwrap(), &[0x05, 0x9c]);
+        assert_eq!(slice(&DATA, 0, DATA.len()).unwrap(), &DATA);
+        assert!(slice(&DATA, 6, 6).unwrap().is_empty());
+        assert_eq!(slice_from(&DATA, 4).unwrap(), &[0x00, 0xa9]);
+    }
+
+    #[test]
+    fn rejects_out_of_bounds_slice() {
+        let err = slice(&DATA, 2, 7).expect_err("should return OutOfBounds");
+        assert!(matches!(
+            err,
+            Error::OutOfBounds(BytesOutOfBounds { start, end, length })
+                if start == U256::from(2)
+                    && end == U256::from(7)
+                    && length == U256::from(DATA.len())
+        ));
+
+        let err = slice(&DATA, 3, 2).expect_err("should return OutOfBounds");
+        assert!(matches!(err, Error::OutOfBounds(_)));
+
+        let err = slice_from(&DATA, 7).expect_err("should return OutOfBounds");
+        assert!(matches!(err, Error::OutOfBounds(_)));
+    }
+
+    #[test]
+    fn reads_bytes32() {
+        let word = b256!(
+            "65e72b1cf8e189569963750e10ccb88fe89389daeeb8b735277d59cd6885ee82"
+        );
+        let data = concat(&[&[0x01], word.as_slice()]);
+
+        assert_eq!(read_bytes32(&data, 1).unwrap(), word);
+        assert!(read_bytes32(&data, 2).is_err());
+        assert!(read_bytes32(&data, usize::MAX).is_err());
+    }
+
+    #[test]
+    fn extracts_selector() {
+        assert_eq!(selector(&DATA).unwrap(), fixed_bytes!("a9059cbb"));
+        assert!(selector(&DATA[..3]).is_err());
+    }
+
+    #[test]
+    fn concatenates() {
+        assert_eq!(concat(&[]), Vec::<u8>::new());
+        assert_eq!(
+            concat(&[&DATA[..2], &[], &DATA[4..]]),
+            [0xa9, 0x05, 0x00, 0xa9]
+        );
+    }
+
+    #[test]
+    fn finds_index() {
+        assert_eq!(index_of(&DATA, 0xa9), Some(0));
+        assert_eq!(last_index_of(&DATA, 0xa9), Some(5));
+        assert_eq!(index_of(&DATA, 0x00), Some(4));
+        assert_eq!(index_of(&DATA, 0xff), None);
+        assert_eq!(last_index_of(&[], 0xff), None);
+    }
+}
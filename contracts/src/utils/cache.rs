@@ -0,0 +1,118 @@
+//! Caching of the storage reads of a call.
+//!
+//! Within a call, the values of the storage fields of a contract, e.g. the
+//! `address _owner` of [`crate::access::ownable::Ownable`], are only loaded
+//! from storage once: `stylus_sdk` caches them in the field itself, until the
+//! field is set again. Reading them several times, e.g. `owner()` from each
+//! `only_owner` check, costs the same as reading them once.
+//!
+//! The values of a mapping aren't cached, since each `get` builds a new
+//! accessor, which hashes the key to find its slot and loads the value again.
+//! [`CachedMap`] wraps a mapping read several times with the same keys
+//! within a call, e.g. the balances of the sender and recipients of a batch
+//! of transfers, so that each key is hashed and loaded once:
+//!
+//! ```rust,ignore
+//! let mut balances = CachedMap::new(&self._balances);
+//! let total = recipients
+//!     .iter()
+//!     .fold(U256::ZERO, |total, &account| total + balances.get(account));
+//! ```
+//!
+//! The cache borrows the mapping, so it can't outlive a write to it, and
+//! never returns a stale value.
+use alloc::vec::Vec;
+
+use stylus_sdk::storage::{StorageKey, StorageMap, StorageType};
+
+/// Read-only view of a [`StorageMap`], caching the values it loads.
+pub struct CachedMap<'m, K: StorageKey, V: StorageType> {
+    /// Mapping read through the cache.
+    map: &'m StorageMap<K, V>,
+    /// Values already loaded from `map`, by key.
+    entries: Vec<(K, V::Wraps<'m>)>,
+}
+
+impl<'m, K, V> CachedMap<'m, K, V>
+where
+    K: StorageKey + Copy + PartialEq,
+    V: StorageType,
+    V::Wraps<'m>: Clone,
+{
+    /// Wraps `map`, with an empty cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `map` - Mapping to read through the cache.
+    #[must_use]
+    pub fn new(map: &'m StorageMap<K, V>) -> Self {
+        Self { map, entries: Vec::new() }
+    }
+
+    /// Returns the value of `key` in the mapping, loading it from storage
+    /// only the first time it is read.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the cache.
+    /// * `key` - Key to read.
+    pub fn get(&mut self, key: K) -> V::Wraps<'m> {
+        if let Some((_, value)) = self.entries.iter().find(|(k, _)| *k == key) {
+            return value.clone();
+        }
+
+        let value = self.map.get(key);
+        self.entries.push((key, value.clone()));
+        value
+    }
+
+    /// Returns the number of keys loaded from storage so far.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the cache.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if no key was loaded from storage yet, and false
+    /// otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the cache.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{address, uint, Address, U256};
+    use stylus_sdk::prelude::sol_storage;
+
+    use super::CachedMap;
+
+    const ALICE: Address = address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
+    const BOB: Address = address!("B0B0cB49ec2e96DF5F5fFB081acaE66A2cBBc2e2");
+
+    sol_storage! {
+        struct Balances {
+            mapping(address => uint256) balances;
+        }
+    }
+
+    #[motsu::test]
+    fn loads_each_key_once(contract: Balances) {
+        contract.balances.setter(ALICE).set(uint!(10_U256));
+
+        let mut balances = CachedMap::new(&contract.balances);
+        assert!(balances.is_empty());
+        assert_eq!(balances.get(ALICE), uint!(10_U256));
+        assert_eq!(balances.get(BOB), U256::ZERO);
+        assert_eq!(balances.get(ALICE), uint!(10_U256));
+        assert_eq!(balances.len(), 2);
+    }
+}
@@ -0,0 +1,121 @@
+//! Verification of BLS signatures over the BLS12-381 curve with the
+//! precompiles of [EIP-2537].
+//!
+//! Public keys are points of G1 and signatures are points of G2, all in the
+//! uncompressed encoding of EIP-2537. They can be produced off-chain, or
+//! aggregated, with the `bls` feature of `openzeppelin-crypto`.
+//!
+//! The BLS12-381 precompiles are not available on every chain yet. Where they
+//! are not, [`verify`] returns `None`, and contracts can fall back to the
+//! pure-Rust verification of `openzeppelin_crypto::bls`, at a much higher gas
+//! cost.
+//!
+//! WARNING: The message must be hashed to G2 from data trusted by the
+//! contract, e.g. with `openzeppelin_crypto::bls::eip2537::encode_message`. A
+//! point of G2 provided by the caller proves nothing about the message it
+//! claims to represent.
+//!
+//! [EIP-2537]: https://eips.ethereum.org/EIPS/eip-2537
+use alloc::vec::Vec;
+
+use alloy_primitives::{address, Address, U256};
+use stylus_sdk::{
+    call::{self, Call},
+    storage::TopLevelStorage,
+};
+
+/// Address of the EIP-2537 `BLS12_PAIRING_CHECK` precompile.
+pub const PAIRING_CHECK_ADDR: Address =
+    address!("000000000000000000000000000000000000000f");
+
+/// Negation of the generator of G1, in the encoding of EIP-2537.
+pub const NEG_G1_GENERATOR: [u8; 128] = alloy_primitives::hex!(
+    "0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0f"
+    "c3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"
+    "00000000000000000000000000000000114d1d6855d545a8aa7d76c8cf2e21f2"
+    "67816aef1db507c96655b9d5caac42364e6f38ba0ecb751bad54dcd6b939c2ca"
+);
+
+/// A point of G1, in the encoding of EIP-2537.
+pub type G1Point = [u8; 128];
+
+/// A point of G2, in the encoding of EIP-2537.
+pub type G2Point = [u8; 256];
+
+/// Verifies that `signature` is a signature of `message` by the owner of
+/// `public_key`, with the `BLS12_PAIRING_CHECK` precompile.
+///
+/// Returns `None` if the precompile is not available, or if one of the
+/// points is not a valid point of its group, and whether the signature is
+/// valid otherwise.
+///
+/// # Arguments
+///
+/// * `storage` - Write access to storage.
+/// * `public_key` - Public key of the signer, or an aggregate public key.
+/// * `message` - Signed message, hashed to G2.
+/// * `signature` - Signature, or an aggregate signature.
+pub fn verify(
+    storage: &mut impl TopLevelStorage,
+    public_key: &G1Point,
+    message: &G2Point,
+    signature: &G2Point,
+) -> Option<bool> {
+    let calldata = encode_calldata(public_key, message, signature);
+    let output =
+        call::static_call(Call::new_in(storage), PAIRING_CHECK_ADDR, &calldata)
+            .ok()?;
+    let output: [u8; 32] = output.as_slice().try_into().ok()?;
+    Some(U256::from_be_bytes(output) == U256::from(1))
+}
+
+/// Encodes call data for the `BLS12_PAIRING_CHECK` precompile, checking that
+/// `e(public_key, message) * e(-G1, signature) == 1`.
+///
+/// # Arguments
+///
+/// * `public_key` - Public key of the signer.
+/// * `message` - Signed message, hashed to G2.
+/// * `signature` - Signature.
+fn encode_calldata(
+    public_key: &G1Point,
+    message: &G2Point,
+    signature: &G2Point,
+) -> Vec<u8> {
+    [
+        public_key.as_slice(),
+        message.as_slice(),
+        NEG_G1_GENERATOR.as_slice(),
+        signature.as_slice(),
+    ]
+    .concat()
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use stylus_sdk::{prelude::sol_storage, storage::TopLevelStorage};
+
+    use super::*;
+
+    sol_storage! {
+        struct Verifier {}
+    }
+
+    unsafe impl TopLevelStorage for Verifier {}
+
+    #[test]
+    fn prepares_calldata() {
+        let calldata = encode_calldata(&[1; 128], &[2; 256], &[3; 256]);
+
+        assert_eq!(calldata.len(), 2 * (128 + 256));
+        assert_eq!(calldata[..128], [1; 128]);
+        assert_eq!(calldata[128..384], [2; 256]);
+        assert_eq!(calldata[384..512], NEG_G1_GENERATOR);
+        assert_eq!(calldata[512..], [3; 256]);
+    }
+
+    #[motsu::test]
+    fn returns_none_without_precompile(contract: Verifier) {
+        assert_eq!(verify(contract, &[1; 128], &[2; 256], &[3; 256]), None);
+    }
+}
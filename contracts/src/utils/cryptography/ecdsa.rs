@@ -2,32 +2,44 @@
 //!
 //! These functions can be used to verify that a message was signed
 //! by the holder of the private keys of a given address.
+//!
+//! Signers are recovered with a static call to the `ecrecover` precompile,
+//! so they only need read access to storage and can be used from view
+//! functions. When the `k256` feature is enabled and the precompile returns
+//! no data, e.g. in unit tests, signers are recovered with a pure-Rust
+//! implementation instead. The fallback also runs on-chain: the precompile
+//! returns no data for every invalid signature, which is then recovered a
+//! second time, at the full cost of the pure-Rust recovery.
 use alloc::vec::Vec;
 
 use alloy_primitives::{address, uint, Address, B256, U256};
 use alloy_sol_types::{sol, SolType};
+use openzeppelin_stylus_proc::SolidityError;
 use stylus_sdk::{
-    call::{self, Call, MethodError},
+    call::{self, MethodError},
     storage::TopLevelStorage,
-    stylus_proc::SolidityError,
 };
 
-use crate::utils::cryptography::ecdsa;
+use crate::utils::{bytes, cryptography::ecdsa};
 
 /// Address of the `ecrecover` EVM precompile.
 pub const ECRECOVER_ADDR: Address =
     address!("0000000000000000000000000000000000000001");
 
 /// Upper range for `s` value from the signature.
-/// See [`check_if_malleable`].
+/// Signatures with a greater `s` value are malleable, and rejected by
+/// [`try_recover`].
 pub const SIGNATURE_S_UPPER_BOUND: U256 = uint!(
     0x7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF5D576E7357A4501DDFE92F46681B20A0_U256
 );
 
 sol! {
+    #![sol(abi)]
+
     /// The signature derives the `Address::ZERO`.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error ECDSAInvalidSignature();
 
     /// The signature has an invalid length.
@@ -35,6 +47,7 @@ sol! {
     /// * `length` - Length of the signature.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error ECDSAInvalidSignatureLength(uint256 length);
 
     /// The signature has an `S` value that is in the upper half order.
@@ -42,6 +55,7 @@ sol! {
     /// * `s` - Invalid `S` value.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error ECDSAInvalidSignatureS(bytes32 s);
 }
 
@@ -56,6 +70,36 @@ pub enum Error {
     InvalidSignatureS(ECDSAInvalidSignatureS),
 }
 
+/// The reason why the signer of a signature could not be recovered, returned
+/// by the `try_recover*` functions instead of reverting.
+///
+/// Callers can match on it to tell a malformed signature from one that was
+/// not produced by an EOA, e.g. to fall back to ERC-1271, or convert it into
+/// an [`Error`] to revert with the same errors as [`recover`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RecoverError {
+    /// The signature derives the `Address::ZERO`.
+    InvalidSignature,
+    /// The signature has an invalid length.
+    InvalidSignatureLength(U256),
+    /// The signature has an `S` value that is in the upper half order.
+    InvalidSignatureS(B256),
+}
+
+impl From<RecoverError> for Error {
+    fn from(value: RecoverError) -> Self {
+        match value {
+            RecoverError::InvalidSignature => ECDSAInvalidSignature {}.into(),
+            RecoverError::InvalidSignatureLength(length) => {
+                ECDSAInvalidSignatureLength { length }.into()
+            }
+            RecoverError::InvalidSignatureS(s) => {
+                ECDSAInvalidSignatureS { s }.into()
+            }
+        }
+    }
+}
+
 impl MethodError for ecdsa::Error {
     fn encode(self) -> alloc::vec::Vec<u8> {
         self.into()
@@ -81,7 +125,7 @@ sol! {
 ///
 /// # Arguments
 ///
-/// * `storage` - Write access to storage.
+/// * `storage` - Read access to storage.
 /// * `hash` - Hash of the message.
 /// * `v` - `v` value from the signature.
 /// * `r` - `r` value from the signature.
@@ -98,25 +142,158 @@ sol! {
 ///
 /// * If the `ecrecover` precompile fails to execute.
 pub fn recover(
-    storage: &mut impl TopLevelStorage,
+    storage: &impl TopLevelStorage,
     hash: B256,
     v: u8,
     r: B256,
     s: B256,
 ) -> Result<Address, Error> {
-    check_if_malleable(&s)?;
+    try_recover(storage, hash, v, r, s).map_err(Into::into)
+}
+
+/// Returns the address that signed a hashed message (`hash`), or the reason
+/// why it could not be recovered, without reverting.
+///
+/// # Arguments
+///
+/// * `storage` - Read access to storage.
+/// * `hash` - Hash of the message.
+/// * `v` - `v` value from the signature.
+/// * `r` - `r` value from the signature.
+/// * `s` - `s` value from the signature.
+///
+/// # Errors
+///
+/// * If the `s` value is grater than [`SIGNATURE_S_UPPER_BOUND`], then the
+///   error [`RecoverError::InvalidSignatureS`] is returned.
+/// * If the recovered address is `Address::ZERO`, then the error
+///   [`RecoverError::InvalidSignature`] is returned.
+///
+/// # Panics
+///
+/// * If the `ecrecover` precompile fails to execute.
+pub fn try_recover(
+    storage: &impl TopLevelStorage,
+    hash: B256,
+    v: u8,
+    r: B256,
+    s: B256,
+) -> Result<Address, RecoverError> {
+    check_s(&s)?;
     // If the signature is valid (and not malleable), return the signer address.
     _recover(storage, hash, v, r, s)
 }
 
+/// Returns the address that signed a hashed message (`hash`) with
+/// `signature`, encoded as `r ‖ s ‖ v`, or the reason why it could not be
+/// recovered, without reverting.
+///
+/// # Arguments
+///
+/// * `storage` - Read access to storage.
+/// * `hash` - Hash of the message.
+/// * `signature` - 65-byte signature.
+///
+/// # Errors
+///
+/// * If `signature` is not 65 bytes long, then the error
+///   [`RecoverError::InvalidSignatureLength`] is returned.
+/// * If the `s` value is grater than [`SIGNATURE_S_UPPER_BOUND`], then the
+///   error [`RecoverError::InvalidSignatureS`] is returned.
+/// * If the recovered address is `Address::ZERO`, then the error
+///   [`RecoverError::InvalidSignature`] is returned.
+///
+/// # Panics
+///
+/// * If the `ecrecover` precompile fails to execute.
+pub fn try_recover_bytes(
+    storage: &impl TopLevelStorage,
+    hash: B256,
+    signature: &[u8],
+) -> Result<Address, RecoverError> {
+    let (v, r, s) = split_signature(signature)?;
+    try_recover(storage, hash, v, r, s)
+}
+
+/// Returns the address that signed a hashed message (`hash`) with the
+/// EIP-2098 short signature `(r, vs)`, or the reason why it could not be
+/// recovered, without reverting.
+///
+/// # Arguments
+///
+/// * `storage` - Read access to storage.
+/// * `hash` - Hash of the message.
+/// * `r` - `r` value from the signature.
+/// * `vs` - `s` value from the signature, with the parity of `v` in its highest
+///   bit.
+///
+/// # Errors
+///
+/// * If the recovered address is `Address::ZERO`, then the error
+///   [`RecoverError::InvalidSignature`] is returned.
+///
+/// # Panics
+///
+/// * If the `ecrecover` precompile fails to execute.
+pub fn try_recover_compact(
+    storage: &impl TopLevelStorage,
+    hash: B256,
+    r: B256,
+    vs: B256,
+) -> Result<Address, RecoverError> {
+    let (v, s) = split_vs(vs);
+    try_recover(storage, hash, v, r, s)
+}
+
+/// Splits a 65-byte signature, encoded as `r ‖ s ‖ v`, into its `v`, `r` and
+/// `s` values.
+///
+/// # Arguments
+///
+/// * `signature` - 65-byte signature.
+///
+/// # Errors
+///
+/// * If `signature` is not 65 bytes long, then the error
+///   [`RecoverError::InvalidSignatureLength`] is returned.
+fn split_signature(signature: &[u8]) -> Result<(u8, B256, B256), RecoverError> {
+    let invalid_length =
+        || RecoverError::InvalidSignatureLength(U256::from(signature.len()));
+    if signature.len() != 65 {
+        return Err(invalid_length());
+    }
+    let r = bytes::read_bytes32(signature, 0).map_err(|_| invalid_length())?;
+    let s = bytes::read_bytes32(signature, 32).map_err(|_| invalid_length())?;
+    let v =
+        bytes::read_fixed::<1>(signature, 64).map_err(|_| invalid_length())?;
+    Ok((v[0], r, s))
+}
+
+/// Splits the `vs` value of an EIP-2098 short signature into its `v` and `s`
+/// values.
+///
+/// # Arguments
+///
+/// * `vs` - `s` value from the signature, with the parity of `v` in its highest
+///   bit.
+fn split_vs(vs: B256) -> (u8, B256) {
+    let mut s = vs;
+    s[0] &= 0x7f;
+    let v = 27 + (vs[0] >> 7);
+    (v, s)
+}
+
 /// Calls `ecrecover` EVM precompile.
-/// The `ecrecover` EVM precompile allows for malleable (non-unique) signatures:
-/// this function rejects them by requiring the `s` value to be in the lower
-/// half order, and the `v` value to be either 27 or 28.
+///
+/// Rejects the `v` values 0 and 1, for which the precompile panics. Any other
+/// invalid `v` value, i.e. neither 27 nor 28, makes the precompile return no
+/// data, and the signature is then rejected by [`recover_fallback`]. The `s`
+/// value is not checked: the callers reject malleable signatures, whose `s`
+/// value is in the upper half order, with [`check_s`] beforehand.
 ///
 /// # Arguments
 ///
-/// * `storage` - Write access to storage.
+/// * `storage` - Read access to storage.
 /// * `hash` - Hash of the message.
 /// * `v` - `v` value from the signature.
 /// * `r` - `r` value from the signature.
@@ -124,21 +301,19 @@ pub fn recover(
 ///
 /// # Errors
 ///
-/// * If the `s` value is grater than `EIP2_VALUE`, then the error
-/// [`Error::ECDSAInvalidSignatureS`] is returned.
 /// * If the recovered address is `Address::ZERO`, then the error
-/// [`Error::InvalidSignature`] is returned.
+///   [`RecoverError::InvalidSignature`] is returned.
 ///
 /// # Panics
 ///
 /// * If the `ecrecover` precompile fails to execute.
 fn _recover(
-    storage: &mut impl TopLevelStorage,
+    storage: &impl TopLevelStorage,
     hash: B256,
     v: u8,
     r: B256,
     s: B256,
-) -> Result<Address, Error> {
+) -> Result<Address, RecoverError> {
     let calldata = encode_calldata(hash, v, r, s);
 
     if v == 0 || v == 1 {
@@ -146,21 +321,93 @@ fn _recover(
         // but following the Solidity tests
         // https://github.com/OpenZeppelin/openzeppelin-contracts/blob/master/test/utils/cryptography/ECDSA.test.js
         // it should return `ECDSAInvalidSignature` error.
-        return Err(ECDSAInvalidSignature {}.into());
+        return Err(RecoverError::InvalidSignature);
     }
 
-    let recovered =
-        call::static_call(Call::new_in(storage), ECRECOVER_ADDR, &calldata)
-            .expect("should call `ecrecover` precompile");
+    let recovered = call::static_call(storage, ECRECOVER_ADDR, &calldata)
+        .expect("should call `ecrecover` precompile");
 
-    let recovered = Address::from_slice(&recovered[12..]);
+    // `ecrecover` returns no data when the signature is invalid.
+    let recovered = match bytes::slice(&recovered, 12, 32) {
+        Ok(recovered) => Address::from_slice(recovered),
+        Err(_) => recover_fallback(hash, v, r, s)?,
+    };
 
     if recovered.is_zero() {
-        return Err(ECDSAInvalidSignature {}.into());
+        return Err(RecoverError::InvalidSignature);
     }
     Ok(recovered)
 }
 
+#[cfg(feature = "k256")]
+fn recover_fallback(
+    hash: B256,
+    v: u8,
+    r: B256,
+    s: B256,
+) -> Result<Address, RecoverError> {
+    recover_native(hash, v, r, s)
+}
+
+#[cfg(not(feature = "k256"))]
+fn recover_fallback(
+    _hash: B256,
+    _v: u8,
+    _r: B256,
+    _s: B256,
+) -> Result<Address, RecoverError> {
+    Err(RecoverError::InvalidSignature)
+}
+
+/// Returns the address that signed a hashed message (`hash`), without
+/// calling the `ecrecover` precompile.
+///
+/// Unlike [`try_recover`], this doesn't reject signatures whose `s` value is
+/// in the upper half order. Like the `ecrecover` precompile, it rejects
+/// signatures whose `v` value is neither 27 nor 28.
+///
+/// # Arguments
+///
+/// * `hash` - Hash of the message.
+/// * `v` - `v` value from the signature.
+/// * `r` - `r` value from the signature.
+/// * `s` - `s` value from the signature.
+///
+/// # Errors
+///
+/// * If the signer can't be recovered, then the error
+///   [`RecoverError::InvalidSignature`] is returned.
+#[cfg(feature = "k256")]
+pub fn recover_native(
+    hash: B256,
+    v: u8,
+    r: B256,
+    s: B256,
+) -> Result<Address, RecoverError> {
+    use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+
+    // Like the `ecrecover` precompile, only accept a `v` value of 27 or 28.
+    let recovery_id = match v {
+        27 | 28 => RecoveryId::from_byte(v - 27),
+        _ => None,
+    }
+    .ok_or(RecoverError::InvalidSignature)?;
+    let signature = Signature::from_scalars(r.0, s.0)
+        .map_err(|_| RecoverError::InvalidSignature)?;
+    let key = VerifyingKey::recover_from_prehash(
+        hash.as_slice(),
+        &signature,
+        recovery_id,
+    )
+    .map_err(|_| RecoverError::InvalidSignature)?;
+
+    let point = key.to_encoded_point(false);
+    let hash = alloy_primitives::keccak256(&point.as_bytes()[1..]);
+    let signer = bytes::slice_from(hash.as_slice(), 12)
+        .map_err(|_| RecoverError::InvalidSignature)?;
+    Ok(Address::from_slice(signer))
+}
+
 /// Encodes call data for `ecrecover` EVM precompile.
 ///
 /// # Arguments
@@ -202,12 +449,12 @@ fn encode_calldata(hash: B256, v: u8, r: B256, s: B256) -> Vec<u8> {
 ///
 /// # Errors
 ///
-/// * If the `s` value is grater than `EIP2_VALUE`, then the error
-/// [`Error::ECDSAInvalidSignatureS`] is returned.
-fn check_if_malleable(s: &B256) -> Result<(), Error> {
+/// * If the `s` value is grater than [`SIGNATURE_S_UPPER_BOUND`], then the
+///   error [`RecoverError::InvalidSignatureS`] is returned.
+fn check_s(s: &B256) -> Result<(), RecoverError> {
     let s_u256 = U256::from_be_slice(s.as_slice());
     if s_u256 > SIGNATURE_S_UPPER_BOUND {
-        return Err(ECDSAInvalidSignatureS { s: *s }.into());
+        return Err(RecoverError::InvalidSignatureS(*s));
     }
     Ok(())
 }
@@ -215,9 +462,16 @@ fn check_if_malleable(s: &B256) -> Result<(), Error> {
 #[cfg(all(test, feature = "std"))]
 mod tests {
     use alloy_primitives::{b256, B256};
+    use stylus_sdk::{prelude::sol_storage, storage::TopLevelStorage};
 
     use super::*;
 
+    sol_storage! {
+        struct Recoverer {}
+    }
+
+    unsafe impl TopLevelStorage for Recoverer {}
+
     const MSG_HASH: B256 = b256!(
         "a1de988600a42c4b4ab089b619297c17d53cffae5d5120d82d8a92d0bb3b78f2"
     );
@@ -240,9 +494,12 @@ mod tests {
     fn rejects_invalid_s() {
         let invalid_s = SIGNATURE_S_UPPER_BOUND + uint!(1_U256);
         let invalid_s = B256::from_slice(&invalid_s.to_be_bytes_vec());
-        let err = check_if_malleable(&invalid_s)
+        let err = check_s(&invalid_s)
             .expect_err("should return ECDSAInvalidSignatureS");
 
+        assert_eq!(err, RecoverError::InvalidSignatureS(invalid_s));
+
+        let err: Error = err.into();
         assert!(matches!(err,
                 Error::InvalidSignatureS(ECDSAInvalidSignatureS {
                     s
@@ -250,11 +507,93 @@ mod tests {
         ));
     }
 
+    #[motsu::test]
+    fn try_recover_returns_reason(contract: Recoverer) {
+        let high_s = SIGNATURE_S_UPPER_BOUND + uint!(1_U256);
+        let high_s = B256::from(high_s);
+        assert_eq!(
+            try_recover(contract, MSG_HASH, V, R, high_s),
+            Err(RecoverError::InvalidSignatureS(high_s))
+        );
+        assert_eq!(
+            try_recover(contract, MSG_HASH, 0, R, S),
+            Err(RecoverError::InvalidSignature)
+        );
+        assert_eq!(
+            try_recover_bytes(contract, MSG_HASH, &[0; 64]),
+            Err(RecoverError::InvalidSignatureLength(uint!(64_U256)))
+        );
+
+        let err: Error =
+            RecoverError::InvalidSignatureLength(uint!(64_U256)).into();
+        assert!(matches!(err,
+                Error::InvalidSignatureLength(ECDSAInvalidSignatureLength {
+                    length
+                }) if length == uint!(64_U256)
+        ));
+    }
+
+    #[cfg(feature = "k256")]
+    mod native {
+        use alloy_primitives::{address, Address};
+
+        use super::*;
+
+        const ADDRESS: Address =
+            address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        #[test]
+        fn recovers_signer() {
+            assert_eq!(recover_native(MSG_HASH, V, R, S), Ok(ADDRESS));
+            assert_ne!(recover_native(MSG_HASH, 27, R, S), Ok(ADDRESS));
+        }
+
+        #[test]
+        fn rejects_v_other_than_27_or_28() {
+            for v in [0, 1, 26, 29, 30] {
+                assert_eq!(
+                    recover_native(MSG_HASH, v, R, S),
+                    Err(RecoverError::InvalidSignature)
+                );
+            }
+        }
+
+        #[motsu::test]
+        fn recovers_signer_from_view(contract: Recoverer) {
+            let contract: &Recoverer = contract;
+            assert_eq!(
+                recover(contract, MSG_HASH, V, R, S).ok(),
+                Some(ADDRESS)
+            );
+
+            let signature = [R.as_slice(), S.as_slice(), &[V]].concat();
+            assert_eq!(
+                try_recover_bytes(contract, MSG_HASH, &signature),
+                Ok(ADDRESS)
+            );
+        }
+    }
+
+    #[test]
+    fn splits_signatures() {
+        let signature = [R.as_slice(), S.as_slice(), &[V]].concat();
+        assert_eq!(split_signature(&signature), Ok((V, R, S)));
+        assert_eq!(
+            split_signature(&[0; 66]),
+            Err(RecoverError::InvalidSignatureLength(uint!(66_U256)))
+        );
+
+        let mut vs = S;
+        vs[0] |= 0x80;
+        assert_eq!(split_vs(vs), (28, S));
+        assert_eq!(split_vs(S), (27, S));
+    }
+
     #[test]
     fn validates_s() {
         let valid_s = SIGNATURE_S_UPPER_BOUND - uint!(1_U256);
         let invalid_s = B256::from_slice(&valid_s.to_be_bytes_vec());
-        let result = check_if_malleable(&invalid_s);
+        let result = check_s(&invalid_s);
         assert!(result.is_ok());
     }
 }
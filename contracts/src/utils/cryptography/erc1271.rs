@@ -0,0 +1,318 @@
+//! Signature validation for smart contracts, as defined in [ERC-1271].
+//!
+//! Contracts can't sign messages, so protocols relying on signatures, like
+//! ERC-2612 permits or Seaport orders, ask the signing contract itself
+//! whether a signature is valid through `isValidSignature`. With [`Erc1271`],
+//! a contract accepts the ECDSA signatures of the accounts it authorizes,
+//! e.g. its owner, or short-lived session keys registered in a
+//! [`SessionKeys`] registry:
+//!
+//! ```rust,ignore
+//! impl Erc1271 for MyWallet {
+//!     fn _is_authorized_signer(&self, signer: Address) -> bool {
+//!         signer == self.ownable.owner()
+//!             || self.session_keys.is_valid_session_key(signer)
+//!     }
+//! }
+//!
+//! #[public]
+//! impl MyWallet {
+//!     #[selector(name = "isValidSignature")]
+//!     fn is_valid_signature(&self, hash: B256, signature: Bytes) -> FixedBytes<4> {
+//!         Erc1271::is_valid_signature(self, hash, &signature)
+//!     }
+//! }
+//! ```
+//!
+//! [ERC-1271]: https://eips.ethereum.org/EIPS/eip-1271
+use alloy_primitives::{fixed_bytes, Address, FixedBytes, B256, U256, U64};
+use alloy_sol_types::sol;
+use openzeppelin_stylus_proc::SolidityError;
+use stylus_sdk::{
+    block, call::MethodError, evm, storage::TopLevelStorage,
+    stylus_proc::sol_storage,
+};
+
+pub use crate::interfaces::erc1271::IERC1271;
+use crate::utils::{cryptography::ecdsa, deadline};
+
+/// Value returned by `isValidSignature` for a valid signature, i.e.
+/// `bytes4(keccak256("isValidSignature(bytes32,bytes)"))`.
+pub const MAGIC_VALUE: FixedBytes<4> = fixed_bytes!("1626ba7e");
+
+/// Value returned by `isValidSignature` for an invalid signature.
+pub const INVALID_VALUE: FixedBytes<4> = fixed_bytes!("ffffffff");
+
+/// Signature validation mixin of the contracts acting as signers.
+pub trait Erc1271: TopLevelStorage + Sized {
+    /// Returns true if the signatures of `signer` are valid signatures of the
+    /// contract, and false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `signer` - Account that signed a hash.
+    fn _is_authorized_signer(&self, signer: Address) -> bool;
+
+    /// Returns [`MAGIC_VALUE`] if `signature` is a 65-byte ECDSA signature of
+    /// `hash` by an account authorized by [`Self::_is_authorized_signer`],
+    /// and [`INVALID_VALUE`] otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `hash` - Hash of the signed data.
+    /// * `signature` - Signature of `hash`.
+    ///
+    /// # Panics
+    ///
+    /// * If the `ecrecover` precompile fails to execute.
+    fn is_valid_signature(
+        &self,
+        hash: B256,
+        signature: &[u8],
+    ) -> FixedBytes<4> {
+        match ecdsa::try_recover_bytes(self, hash, signature) {
+            Ok(signer) if self._is_authorized_signer(signer) => MAGIC_VALUE,
+            _ => INVALID_VALUE,
+        }
+    }
+}
+
+sol! {
+    /// Emitted when `key` is registered as a session key until `valid_until`.
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    event SessionKeyAdded(address indexed key, uint64 valid_until);
+
+    /// Emitted when the session key `key` is revoked.
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    event SessionKeyRevoked(address indexed key);
+}
+
+sol! {
+    /// The session `key` can't be registered: it is `Address::ZERO`, or
+    /// `valid_until` has already passed.
+    ///
+    /// * `key` - Session key.
+    /// * `valid_until` - Last timestamp at which the key is valid.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error SessionKeyInvalid(address key, uint64 valid_until);
+}
+
+/// A Session Keys error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// The session key can't be registered.
+    Invalid(SessionKeyInvalid),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+sol_storage! {
+    /// State of a registry of session keys, i.e. accounts allowed to sign on
+    /// behalf of a contract until an expiration timestamp.
+    ///
+    /// The registry doesn't restrict who registers keys: contracts using it
+    /// must guard [`SessionKeys::add_session_key`] and
+    /// [`SessionKeys::revoke_session_key`], e.g. with
+    /// [`crate::access::ownable::Ownable::only_owner`].
+    pub struct SessionKeys {
+        /// Last timestamp at which each session key is valid.
+        mapping(address => uint64) _valid_until;
+    }
+}
+
+impl SessionKeys {
+    /// Returns the last timestamp at which `key` is valid, or zero if it is
+    /// not a session key.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `key` - Session key.
+    #[must_use]
+    pub fn session_key_valid_until(&self, key: Address) -> u64 {
+        self._valid_until.get(key).to::<u64>()
+    }
+
+    /// Returns true if `key` is a session key that has not expired yet, and
+    /// false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `key` - Session key.
+    #[must_use]
+    pub fn is_valid_session_key(&self, key: Address) -> bool {
+        let valid_until = self.session_key_valid_until(key);
+        valid_until != 0
+            && !deadline::is_expired(
+                U256::from(valid_until),
+                block::timestamp(),
+            )
+    }
+
+    /// Registers `key` as a session key until `valid_until`, replacing its
+    /// previous expiration if it was already registered.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `key` - Session key.
+    /// * `valid_until` - Last timestamp at which the key is valid.
+    ///
+    /// # Errors
+    ///
+    /// If `key` is `Address::ZERO`, or `valid_until` has already passed, then
+    /// the error [`Error::Invalid`] is returned.
+    ///
+    /// # Events
+    ///
+    /// Emits a [`SessionKeyAdded`] event.
+    pub fn add_session_key(
+        &mut self,
+        key: Address,
+        valid_until: u64,
+    ) -> Result<(), Error> {
+        if key.is_zero()
+            || deadline::is_expired(U256::from(valid_until), block::timestamp())
+        {
+            return Err(SessionKeyInvalid { key, valid_until }.into());
+        }
+        self._valid_until.setter(key).set(U64::from(valid_until));
+        evm::log(SessionKeyAdded { key, valid_until });
+        Ok(())
+    }
+
+    /// Revokes the session key `key`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `key` - Session key.
+    ///
+    /// # Events
+    ///
+    /// Emits a [`SessionKeyRevoked`] event.
+    pub fn revoke_session_key(&mut self, key: Address) {
+        self._valid_until.delete(key);
+        evm::log(SessionKeyRevoked { key });
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{address, b256, Address, B256};
+    use stylus_sdk::{block, prelude::sol_storage, storage::TopLevelStorage};
+
+    use super::*;
+
+    const ALICE: Address = address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
+    // Signer of `SIGNATURE`, for the test vector of the `ecdsa` module.
+    const SIGNER: Address =
+        address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+    const HASH: B256 = b256!(
+        "a1de988600a42c4b4ab089b619297c17d53cffae5d5120d82d8a92d0bb3b78f2"
+    );
+
+    sol_storage! {
+        struct Wallet {
+            address owner;
+            SessionKeys session_keys;
+        }
+    }
+
+    unsafe impl TopLevelStorage for Wallet {}
+
+    impl Erc1271 for Wallet {
+        fn _is_authorized_signer(&self, signer: Address) -> bool {
+            signer == self.owner.get()
+                || self.session_keys.is_valid_session_key(signer)
+        }
+    }
+
+    fn signature() -> alloc::vec::Vec<u8> {
+        [
+            b256!(
+                "65e72b1cf8e189569963750e10ccb88fe89389daeeb8b735277d59cd6885ee82"
+            )
+            .as_slice(),
+            b256!(
+                "3eb5a6982b540f185703492dab77b863a88ce01f27e21ade8b2879c10fc9e653"
+            )
+            .as_slice(),
+            &[28],
+        ]
+        .concat()
+    }
+
+    #[motsu::test]
+    fn registers_session_keys(contract: SessionKeys) {
+        let now = block::timestamp();
+        contract.add_session_key(ALICE, now).expect("should add key");
+        assert_eq!(contract.session_key_valid_until(ALICE), now);
+        assert!(contract.is_valid_session_key(ALICE));
+
+        contract.revoke_session_key(ALICE);
+        assert_eq!(contract.session_key_valid_until(ALICE), 0);
+        assert!(!contract.is_valid_session_key(ALICE));
+    }
+
+    #[motsu::test]
+    fn rejects_invalid_session_keys(contract: SessionKeys) {
+        let now = block::timestamp();
+        let err = contract
+            .add_session_key(ALICE, now - 1)
+            .expect_err("should reject expired key");
+        assert!(matches!(err, Error::Invalid(_)));
+
+        let err = contract
+            .add_session_key(Address::ZERO, now + 1)
+            .expect_err("should reject zero key");
+        assert!(matches!(err, Error::Invalid(_)));
+        assert!(!contract.is_valid_session_key(Address::ZERO));
+    }
+
+    #[motsu::test]
+    fn rejects_malformed_signatures(contract: Wallet) {
+        contract.owner.set(SIGNER);
+        let signature = signature();
+        assert_eq!(
+            contract.is_valid_signature(HASH, &signature[..64]),
+            INVALID_VALUE
+        );
+    }
+
+    #[cfg(feature = "k256")]
+    #[motsu::test]
+    fn validates_authorized_signers(contract: Wallet) {
+        assert_eq!(
+            contract.is_valid_signature(HASH, &signature()),
+            INVALID_VALUE
+        );
+
+        contract.owner.set(SIGNER);
+        assert_eq!(
+            contract.is_valid_signature(HASH, &signature()),
+            MAGIC_VALUE
+        );
+
+        contract.owner.set(ALICE);
+        contract
+            .session_keys
+            .add_session_key(SIGNER, block::timestamp())
+            .expect("should add key");
+        assert_eq!(
+            contract.is_valid_signature(HASH, &signature()),
+            MAGIC_VALUE
+        );
+    }
+}
@@ -1,3 +1,8 @@
 //! Smart Contracts with cryptography.
+pub mod bls;
 pub mod ecdsa;
 pub mod eip712;
+pub mod erc1271;
+pub mod p256;
+#[cfg(feature = "schnorr")]
+pub mod schnorr;
@@ -0,0 +1,272 @@
+//! Verification of signatures over the secp256r1 (P-256) curve.
+//!
+//! P-256 is the curve used by passkeys (`WebAuthn`) and secure enclaves, so
+//! these functions let accounts, e.g. ERC-4337 accounts, be controlled by
+//! such signers.
+//!
+//! Signatures are verified by the RIP-7212 precompile when the chain provides
+//! it. Otherwise, when the `p256` feature is enabled, they are verified with a
+//! pure-Rust implementation, which costs significantly more gas.
+use alloc::vec::Vec;
+
+use alloy_primitives::{address, uint, Address, B256, U256};
+use stylus_sdk::{
+    call::{self, Call},
+    storage::TopLevelStorage,
+};
+
+/// Address of the RIP-7212 `P256VERIFY` precompile.
+pub const P256_VERIFY_ADDR: Address =
+    address!("0000000000000000000000000000000000000100");
+
+/// Order of the P-256 curve.
+pub const P256_N: U256 = uint!(
+    0xFFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551_U256
+);
+
+/// Upper range for `s` value from the signature, i.e. half of [`P256_N`].
+/// See [`is_proper_signature`].
+pub const P256_S_UPPER_BOUND: U256 = uint!(
+    0x7FFFFFFF800000007FFFFFFFFFFFFFFFDE737D56D38BCF4279DCE5617E3192A8_U256
+);
+
+/// Returns true if the signature (`r`, `s`) of `hash` is valid for the public
+/// key (`x`, `y`), and false otherwise.
+///
+/// Signatures whose `s` value is in the upper half order are rejected, to
+/// prevent signature malleability.
+///
+/// # Arguments
+///
+/// * `storage` - Write access to storage.
+/// * `hash` - Hash of the message.
+/// * `r` - `r` value from the signature.
+/// * `s` - `s` value from the signature.
+/// * `x` - `x` coordinate of the public key.
+/// * `y` - `y` coordinate of the public key.
+pub fn verify(
+    storage: &mut impl TopLevelStorage,
+    hash: B256,
+    r: B256,
+    s: B256,
+    x: B256,
+    y: B256,
+) -> bool {
+    if !is_proper_signature(&r, &s) {
+        return false;
+    }
+
+    match verify_precompile(storage, hash, r, s, x, y) {
+        Some(valid) => valid,
+        None => verify_fallback(hash, r, s, x, y),
+    }
+}
+
+/// Calls the RIP-7212 precompile.
+///
+/// Returns `None` if the precompile returned no data, which happens both
+/// when the signature is invalid and when the precompile is not available.
+///
+/// # Arguments
+///
+/// * `storage` - Write access to storage.
+/// * `hash` - Hash of the message.
+/// * `r` - `r` value from the signature.
+/// * `s` - `s` value from the signature.
+/// * `x` - `x` coordinate of the public key.
+/// * `y` - `y` coordinate of the public key.
+fn verify_precompile(
+    storage: &mut impl TopLevelStorage,
+    hash: B256,
+    r: B256,
+    s: B256,
+    x: B256,
+    y: B256,
+) -> Option<bool> {
+    let calldata = encode_calldata(hash, r, s, x, y);
+    let output =
+        call::static_call(Call::new_in(storage), P256_VERIFY_ADDR, &calldata)
+            .ok()?;
+    let output: [u8; 32] = output.as_slice().try_into().ok()?;
+    Some(U256::from_be_bytes(output) == uint!(1_U256))
+}
+
+#[cfg(feature = "p256")]
+fn verify_fallback(hash: B256, r: B256, s: B256, x: B256, y: B256) -> bool {
+    verify_native(hash, r, s, x, y)
+}
+
+#[cfg(not(feature = "p256"))]
+fn verify_fallback(
+    _hash: B256,
+    _r: B256,
+    _s: B256,
+    _x: B256,
+    _y: B256,
+) -> bool {
+    false
+}
+
+/// Returns true if the signature (`r`, `s`) of `hash` is valid for the public
+/// key (`x`, `y`), and false otherwise, without calling the RIP-7212
+/// precompile.
+///
+/// Unlike [`verify`], this doesn't reject signatures whose `s` value is in
+/// the upper half order.
+///
+/// # Arguments
+///
+/// * `hash` - Hash of the message.
+/// * `r` - `r` value from the signature.
+/// * `s` - `s` value from the signature.
+/// * `x` - `x` coordinate of the public key.
+/// * `y` - `y` coordinate of the public key.
+#[cfg(feature = "p256")]
+#[must_use]
+pub fn verify_native(hash: B256, r: B256, s: B256, x: B256, y: B256) -> bool {
+    use p256::{
+        ecdsa::{signature::hazmat::PrehashVerifier, Signature, VerifyingKey},
+        EncodedPoint, FieldBytes,
+    };
+
+    let point = EncodedPoint::from_affine_coordinates(
+        &FieldBytes::from(x.0),
+        &FieldBytes::from(y.0),
+        false,
+    );
+    let Ok(key) = VerifyingKey::from_encoded_point(&point) else {
+        return false;
+    };
+    let Ok(signature) =
+        Signature::from_scalars(FieldBytes::from(r.0), FieldBytes::from(s.0))
+    else {
+        return false;
+    };
+    key.verify_prehash(hash.as_slice(), &signature).is_ok()
+}
+
+/// Returns true if `r` and `s` are in the range of valid signature values,
+/// i.e. `0 < r < N` and `0 < s <= N / 2`, and false otherwise.
+///
+/// # Arguments
+///
+/// * `r` - `r` value from the signature.
+/// * `s` - `s` value from the signature.
+#[must_use]
+pub fn is_proper_signature(r: &B256, s: &B256) -> bool {
+    let r = U256::from_be_bytes(r.0);
+    let s = U256::from_be_bytes(s.0);
+    !r.is_zero() && r < P256_N && !s.is_zero() && s <= P256_S_UPPER_BOUND
+}
+
+/// Encodes call data for the RIP-7212 precompile.
+///
+/// # Arguments
+///
+/// * `hash` - Hash of the message.
+/// * `r` - `r` value from the signature.
+/// * `s` - `s` value from the signature.
+/// * `x` - `x` coordinate of the public key.
+/// * `y` - `y` coordinate of the public key.
+fn encode_calldata(hash: B256, r: B256, s: B256, x: B256, y: B256) -> Vec<u8> {
+    [hash, r, s, x, y].concat()
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{b256, uint, B256, U256};
+
+    use super::*;
+
+    const HASH: B256 = b256!(
+        "a1de988600a42c4b4ab089b619297c17d53cffae5d5120d82d8a92d0bb3b78f2"
+    );
+
+    fn to_b256(value: U256) -> B256 {
+        B256::from(value.to_be_bytes())
+    }
+
+    #[test]
+    fn prepares_calldata() {
+        let calldata = encode_calldata(
+            HASH,
+            B256::repeat_byte(1),
+            B256::repeat_byte(2),
+            B256::repeat_byte(3),
+            B256::repeat_byte(4),
+        );
+        assert_eq!(calldata.len(), 160);
+        assert_eq!(&calldata[..32], HASH.as_slice());
+        assert_eq!(&calldata[128..], B256::repeat_byte(4).as_slice());
+    }
+
+    #[test]
+    fn checks_signature_range() {
+        let one = to_b256(uint!(1_U256));
+        assert!(is_proper_signature(&one, &one));
+        assert!(is_proper_signature(&one, &to_b256(P256_S_UPPER_BOUND)));
+
+        assert!(!is_proper_signature(&B256::ZERO, &one));
+        assert!(!is_proper_signature(&one, &B256::ZERO));
+        assert!(!is_proper_signature(&to_b256(P256_N), &one));
+        let high_s = to_b256(P256_S_UPPER_BOUND + uint!(1_U256));
+        assert!(!is_proper_signature(&one, &high_s));
+    }
+
+    #[cfg(feature = "p256")]
+    mod native {
+        use alloy_primitives::{B256, U256};
+        use p256::ecdsa::{
+            signature::hazmat::PrehashSigner, Signature, SigningKey,
+        };
+        use stylus_sdk::{prelude::sol_storage, storage::TopLevelStorage};
+
+        use super::{to_b256, HASH};
+        use crate::utils::cryptography::p256::{verify, verify_native, P256_N};
+
+        sol_storage! {
+            struct Signer {}
+        }
+
+        unsafe impl TopLevelStorage for Signer {}
+
+        /// Signs `HASH` with a fixed key, returning the signature with a low
+        /// `s` value and the public key.
+        fn sign() -> ([B256; 2], [B256; 2]) {
+            let key = SigningKey::from_slice(&[0x42; 32]).unwrap();
+            let signature: Signature =
+                key.sign_prehash(HASH.as_slice()).unwrap();
+            let signature = signature.normalize_s().unwrap_or(signature);
+            let (r, s) = signature.split_bytes();
+
+            let point = key.verifying_key().to_encoded_point(false);
+            let x = B256::from_slice(point.x().unwrap());
+            let y = B256::from_slice(point.y().unwrap());
+            ([B256::from_slice(&r), B256::from_slice(&s)], [x, y])
+        }
+
+        #[test]
+        fn verifies_native_signatures() {
+            let ([r, s], [x, y]) = sign();
+            assert!(verify_native(HASH, r, s, x, y));
+
+            assert!(!verify_native(B256::ZERO, r, s, x, y));
+            assert!(!verify_native(HASH, s, r, x, y));
+            assert!(!verify_native(HASH, r, s, y, x));
+
+            // High `s` values are accepted by the native verification.
+            let high_s = P256_N - U256::from_be_bytes(s.0);
+            assert!(verify_native(HASH, r, to_b256(high_s), x, y));
+        }
+
+        #[motsu::test]
+        fn verifies_with_fallback(contract: Signer) {
+            let ([r, s], [x, y]) = sign();
+            assert!(verify(contract, HASH, r, s, x, y));
+            assert!(!verify(contract, HASH, r, s, B256::ZERO, y));
+
+            let high_s = P256_N - U256::from_be_bytes(s.0);
+            assert!(!verify(contract, HASH, r, to_b256(high_s), x, y));
+        }
+    }
+}
@@ -0,0 +1,140 @@
+//! Verification of [BIP-340] Schnorr signatures over the secp256k1 curve.
+//!
+//! Adapts `openzeppelin_crypto::schnorr` to the types and errors of
+//! contracts, e.g. to verify the aggregated signature of a threshold-signing
+//! committee, produced with `MuSig2` or FROST, for its Taproot public key.
+//!
+//! There is no precompile for Schnorr signatures, so they are verified in
+//! Rust, at a higher gas cost than ECDSA signatures.
+//!
+//! [BIP-340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+use alloy_primitives::{B256, U256};
+use alloy_sol_types::sol;
+use openzeppelin_crypto::schnorr::{self, SchnorrError};
+use openzeppelin_stylus_proc::SolidityError;
+use stylus_sdk::call::MethodError;
+
+sol! {
+    /// The signature has an invalid length.
+    ///
+    /// * `length` - Length of the signature.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error SchnorrInvalidSignatureLength(uint256 length);
+
+    /// The public key is not the x coordinate of a point of the curve.
+    ///
+    /// * `public_key` - Invalid public key.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error SchnorrInvalidPublicKey(bytes32 public_key);
+
+    /// The signature has an `r` value that is not a field element, or an `s`
+    /// value that is not lower than the order of the curve.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error SchnorrInvalidSignature();
+}
+
+/// An error that occurred while verifying a Schnorr signature.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// The signature has an invalid length.
+    InvalidSignatureLength(SchnorrInvalidSignatureLength),
+    /// The public key is not the x coordinate of a point of the curve.
+    InvalidPublicKey(SchnorrInvalidPublicKey),
+    /// The signature is malformed.
+    InvalidSignature(SchnorrInvalidSignature),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// Returns true if `signature` is a BIP-340 signature of `message` by the
+/// owner of the x-only `public_key`, and false otherwise.
+///
+/// # Arguments
+///
+/// * `public_key` - X-only public key of the signer.
+/// * `message` - The signed message, usually a 32-byte hash.
+/// * `signature` - The signature, encoded as `r || s`.
+///
+/// # Errors
+///
+/// * If `signature` is not 64 bytes long, then the error
+///   [`Error::InvalidSignatureLength`] is returned.
+/// * If `public_key` is not a valid x-only public key, then the error
+///   [`Error::InvalidPublicKey`] is returned.
+/// * If `signature` is malformed, then the error [`Error::InvalidSignature`] is
+///   returned.
+pub fn verify(
+    public_key: B256,
+    message: &[u8],
+    signature: &[u8],
+) -> Result<bool, Error> {
+    let signature: &schnorr::Signature =
+        signature.try_into().map_err(|_| SchnorrInvalidSignatureLength {
+            length: U256::from(signature.len()),
+        })?;
+
+    schnorr::verify(&public_key.0, message, signature).map_err(|e| match e {
+        SchnorrError::InvalidPublicKey => {
+            SchnorrInvalidPublicKey { public_key }.into()
+        }
+        SchnorrError::InvalidSignature => SchnorrInvalidSignature {}.into(),
+    })
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{b256, hex, uint, B256};
+
+    use super::*;
+
+    const PUBLIC_KEY: B256 = b256!(
+        "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659"
+    );
+    const MESSAGE: B256 = b256!(
+        "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89"
+    );
+    const SIGNATURE: [u8; 64] = hex!("6896BD60EEAE296DB48A229FF71DFE071BDE413E6D43F917DC8DCF8C78DE33418906D11AC976ABCCB20B091292BFF4EA897EFCB639EA871CFA95F6DE339E4B0A");
+
+    #[test]
+    fn verifies_signatures() {
+        assert!(verify(PUBLIC_KEY, MESSAGE.as_slice(), &SIGNATURE).unwrap());
+        assert!(!verify(PUBLIC_KEY, &[0; 32], &SIGNATURE).unwrap());
+    }
+
+    #[test]
+    fn rejects_invalid_signature_length() {
+        let err = verify(PUBLIC_KEY, MESSAGE.as_slice(), &SIGNATURE[..63])
+            .expect_err("should return SchnorrInvalidSignatureLength");
+
+        assert!(matches!(err,
+            Error::InvalidSignatureLength(SchnorrInvalidSignatureLength {
+                length
+            }) if length == uint!(63_U256)
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_public_key() {
+        let public_key = b256!(
+            "EEFDEA4CDB677750A420FEE807EACF21EB9898AE79B9768766E4FAA04A2D4A34"
+        );
+        let err = verify(public_key, MESSAGE.as_slice(), &SIGNATURE)
+            .expect_err("should return SchnorrInvalidPublicKey");
+
+        assert!(matches!(err,
+            Error::InvalidPublicKey(SchnorrInvalidPublicKey {
+                public_key: key
+            }) if key == public_key
+        ));
+    }
+}
@@ -0,0 +1,106 @@
+//! Deadline guard for time-bounded operations.
+//!
+//! Functions taking a `deadline` argument, like signed permits, meta
+//! transactions or auction bids, should reject the operation once the
+//! deadline has passed. [`ensure_deadline`] implements this check uniformly,
+//! with the same boundary semantics everywhere: an operation is still valid
+//! in the block whose timestamp equals the deadline.
+
+use alloy_primitives::U256;
+use alloy_sol_types::sol;
+use openzeppelin_stylus_proc::SolidityError;
+use stylus_sdk::{block, call::MethodError};
+
+sol! {
+    /// Indicates an error related to an operation whose `deadline` is before
+    /// the current block `timestamp`.
+    ///
+    /// * `deadline` - Deadline of the operation.
+    /// * `timestamp` - Timestamp of the current block.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error ExpiredDeadline(uint256 deadline, uint256 timestamp);
+}
+
+/// A Deadline error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// The deadline of the operation has passed.
+    ExpiredDeadline(ExpiredDeadline),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+/// Returns `true` if `deadline` is before `timestamp`.
+///
+/// # Arguments
+///
+/// * `deadline` - Deadline of the operation.
+/// * `timestamp` - Timestamp to compare the deadline against.
+#[must_use]
+pub fn is_expired(deadline: U256, timestamp: u64) -> bool {
+    U256::from(timestamp) > deadline
+}
+
+/// Checks that `deadline` has not passed yet, i.e. that it is not before the
+/// current block timestamp.
+///
+/// # Arguments
+///
+/// * `deadline` - Deadline of the operation.
+///
+/// # Errors
+///
+/// If `deadline` is before the current block timestamp, then the error
+/// [`Error::ExpiredDeadline`] is returned.
+pub fn ensure_deadline(deadline: U256) -> Result<(), Error> {
+    let timestamp = block::timestamp();
+    if is_expired(deadline, timestamp) {
+        return Err(Error::ExpiredDeadline(ExpiredDeadline {
+            deadline,
+            timestamp: U256::from(timestamp),
+        }));
+    }
+    Ok(())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::U256;
+    use stylus_sdk::block;
+
+    use super::{ensure_deadline, is_expired, Error, ExpiredDeadline};
+
+    #[test]
+    fn checks_expiration() {
+        assert!(!is_expired(U256::from(10), 9));
+        assert!(!is_expired(U256::from(10), 10));
+        assert!(is_expired(U256::from(10), 11));
+        assert!(!is_expired(U256::MAX, u64::MAX));
+    }
+
+    #[motsu::test]
+    fn accepts_future_or_current_deadline() {
+        let now = block::timestamp();
+        assert!(ensure_deadline(U256::from(now)).is_ok());
+        assert!(ensure_deadline(U256::from(now + 1)).is_ok());
+    }
+
+    #[motsu::test]
+    fn rejects_past_deadline() {
+        let now = block::timestamp();
+        let deadline = U256::from(now - 1);
+
+        let err = ensure_deadline(deadline).expect_err("should be expired");
+        assert!(matches!(
+            err,
+            Error::ExpiredDeadline(ExpiredDeadline { deadline: d, timestamp })
+                if d == deadline && timestamp == U256::from(now)
+        ));
+    }
+}
@@ -0,0 +1,294 @@
+//! Execution hooks for the public functions of a contract.
+//!
+//! Some logic, like a global pause switch, fee skimming or telemetry events,
+//! applies to every public function of a contract, including the ones
+//! inherited from other contracts through `#[inherit]`. Instead of overriding
+//! every inherited function, the top-level contract implements
+//! [`ExecutionHooks`], whose functions run before and after any call routed
+//! to the contract. Both hooks are no-ops by default.
+//!
+//! Since the router generated by `#[entrypoint]` on a struct can't be
+//! extended, the hooks are wired through a custom entrypoint function that
+//! dispatches calls with [`route_with_hooks`].
+//!
+//! Unlike `#[entrypoint]` on a struct, `#[entrypoint]` on a function neither
+//! asserts that the functions of the contract override the inherited ones
+//! with a compatible mutability, nor generates the `print_abi` function of
+//! the `export-abi` feature of `stylus-sdk`, so the contract does both itself:
+//!
+//! ```rust,ignore
+//! sol_storage! {
+//!     struct MyToken {
+//!         #[borrow]
+//!         Erc20 erc20;
+//!         #[borrow]
+//!         Pausable pausable;
+//!     }
+//! }
+//!
+//! // Implemented by `#[entrypoint]` for structs.
+//! unsafe impl TopLevelStorage for MyToken {}
+//!
+//! #[public]
+//! #[inherit(Erc20, Pausable)]
+//! impl MyToken {}
+//!
+//! impl ExecutionHooks for MyToken {
+//!     fn before_call(
+//!         &mut self,
+//!         _selector: u32,
+//!         _input: &[u8],
+//!     ) -> Result<(), Vec<u8>> {
+//!         self.pausable.when_not_paused()?;
+//!         Ok(())
+//!     }
+//! }
+//!
+//! const _: () = MyToken::__stylus_assert_overrides();
+//!
+//! #[entrypoint]
+//! fn entrypoint(input: Vec<u8>) -> ArbResult {
+//!     route_with_hooks::<MyToken>(&input)
+//! }
+//!
+//! #[cfg(feature = "export-abi")]
+//! pub fn print_abi(license: &str, pragma: &str) {
+//!     stylus_sdk::abi::export::print_abi::<MyToken>(license, pragma);
+//! }
+//! ```
+use alloc::{vec, vec::Vec};
+use core::borrow::BorrowMut;
+
+use alloy_primitives::U256;
+use stylus_sdk::{
+    abi::Router,
+    storage::{StorageType, TopLevelStorage},
+    ArbResult,
+};
+
+/// Hooks executed around every call routed by [`route_with_hooks`].
+pub trait ExecutionHooks {
+    /// Runs before the public function matching `selector` is executed.
+    ///
+    /// Returning an error reverts the call without executing the function.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `selector` - Selector of the called function.
+    /// * `input` - ABI-encoded arguments of the call, without the selector.
+    ///
+    /// # Errors
+    ///
+    /// Should return an error, encoded as revert data, to reject the call.
+    #[allow(unused_variables)]
+    fn before_call(
+        &mut self,
+        selector: u32,
+        input: &[u8],
+    ) -> Result<(), Vec<u8>> {
+        Ok(())
+    }
+
+    /// Runs after the public function matching `selector` has been
+    /// successfully executed.
+    ///
+    /// Returning an error reverts the whole call.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `selector` - Selector of the called function.
+    /// * `output` - ABI-encoded return data of the function.
+    ///
+    /// # Errors
+    ///
+    /// Should return an error, encoded as revert data, to reject the call.
+    #[allow(unused_variables)]
+    fn after_call(
+        &mut self,
+        selector: u32,
+        output: &[u8],
+    ) -> Result<(), Vec<u8>> {
+        Ok(())
+    }
+}
+
+/// Routes `input` calldata to the public functions of `S`, running the
+/// [`ExecutionHooks`] of `S` before and after the call.
+///
+/// Matches the behavior of the router generated by `#[entrypoint]` for
+/// structs: calls with calldata shorter than a selector, or with an unknown
+/// selector, revert with empty data. The checks of the overrides of `S` are
+/// not run, see the [module docs](self).
+///
+/// # Arguments
+///
+/// * `input` - Calldata of the call.
+///
+/// # Errors
+///
+/// If the calldata doesn't match a public function, the function reverts,
+/// or one of the hooks returns an error, then the revert data is returned.
+pub fn route_with_hooks<S>(input: &[u8]) -> ArbResult
+where
+    S: StorageType
+        + TopLevelStorage
+        + ExecutionHooks
+        + Router<S>
+        + BorrowMut<<S as Router<S>>::Storage>,
+{
+    let Some((selector, args)) = input.split_first_chunk::<4>() else {
+        return Err(vec![]);
+    };
+    let selector = u32::from_be_bytes(*selector);
+
+    // SAFETY: The contract's state lives at the root slot, as for the
+    // storage created by `#[entrypoint]`.
+    let mut storage = unsafe { S::new(U256::ZERO, 0) };
+
+    storage.before_call(selector, args)?;
+    let output =
+        S::route(&mut storage, selector, args).unwrap_or(Err(vec![]))?;
+    storage.after_call(selector, &output)?;
+    Ok(output)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use alloy_primitives::{uint, U256};
+    use alloy_sol_types::SolValue;
+    use stylus_sdk::{
+        function_selector,
+        prelude::{public, sol_storage},
+        storage::TopLevelStorage,
+    };
+
+    use super::{route_with_hooks, ExecutionHooks};
+
+    const INCREMENT: [u8; 4] = function_selector!("increment");
+    const COUNTER: [u8; 4] = function_selector!("counter");
+    const BLOCKED: [u8; 4] = function_selector!("blocked");
+
+    sol_storage! {
+        struct Hooked {
+            uint256 counter;
+            uint256 before_calls;
+            uint256 after_calls;
+        }
+    }
+
+    unsafe impl TopLevelStorage for Hooked {}
+
+    // Functions are payable, so that the router doesn't need to read the
+    // call value, which is not available in unit tests.
+    #[public]
+    impl Hooked {
+        #[payable]
+        fn increment(&mut self) {
+            let counter = self.counter.get();
+            self.counter.set(counter + uint!(1_U256));
+        }
+
+        #[payable]
+        fn counter(&mut self) -> U256 {
+            self.counter.get()
+        }
+
+        #[payable]
+        fn blocked(&mut self) {
+            let counter = self.counter.get();
+            self.counter.set(counter + uint!(100_U256));
+        }
+    }
+
+    const _: () = Hooked::__stylus_assert_overrides();
+
+    impl ExecutionHooks for Hooked {
+        fn before_call(
+            &mut self,
+            selector: u32,
+            _input: &[u8],
+        ) -> Result<(), Vec<u8>> {
+            if selector == u32::from_be_bytes(BLOCKED) {
+                return Err(vec![0xde, 0xad]);
+            }
+            let calls = self.before_calls.get();
+            self.before_calls.set(calls + uint!(1_U256));
+            Ok(())
+        }
+
+        fn after_call(
+            &mut self,
+            _selector: u32,
+            _output: &[u8],
+        ) -> Result<(), Vec<u8>> {
+            let calls = self.after_calls.get();
+            self.after_calls.set(calls + uint!(1_U256));
+            Ok(())
+        }
+    }
+
+    sol_storage! {
+        struct Unhooked {
+            uint256 counter;
+        }
+    }
+
+    unsafe impl TopLevelStorage for Unhooked {}
+
+    #[public]
+    impl Unhooked {
+        #[payable]
+        fn increment(&mut self) {
+            let counter = self.counter.get();
+            self.counter.set(counter + uint!(1_U256));
+        }
+    }
+
+    const _: () = Unhooked::__stylus_assert_overrides();
+
+    impl ExecutionHooks for Unhooked {}
+
+    #[motsu::test]
+    fn runs_hooks_around_call(contract: Hooked) {
+        route_with_hooks::<Hooked>(&INCREMENT).expect("should route");
+        let output =
+            route_with_hooks::<Hooked>(&COUNTER).expect("should route");
+
+        assert_eq!(output, uint!(1_U256).abi_encode());
+        assert_eq!(contract.counter.get(), uint!(1_U256));
+        assert_eq!(contract.before_calls.get(), uint!(2_U256));
+        assert_eq!(contract.after_calls.get(), uint!(2_U256));
+    }
+
+    #[motsu::test]
+    fn before_hook_rejects_call(contract: Hooked) {
+        let err = route_with_hooks::<Hooked>(&BLOCKED)
+            .expect_err("should be rejected by the hook");
+
+        assert_eq!(err, vec![0xde, 0xad]);
+        assert_eq!(contract.counter.get(), U256::ZERO);
+        assert_eq!(contract.after_calls.get(), U256::ZERO);
+    }
+
+    #[motsu::test]
+    fn rejects_invalid_calldata(contract: Hooked) {
+        let err = route_with_hooks::<Hooked>(&[0x01, 0x02])
+            .expect_err("should reject short calldata");
+        assert!(err.is_empty());
+
+        let err = route_with_hooks::<Hooked>(&[0xff; 4])
+            .expect_err("should reject unknown selector");
+        assert!(err.is_empty());
+        assert_eq!(contract.after_calls.get(), U256::ZERO);
+    }
+
+    #[motsu::test]
+    fn default_hooks_are_noop(contract: Unhooked) {
+        route_with_hooks::<Unhooked>(&INCREMENT).expect("should route");
+        assert_eq!(contract.counter.get(), uint!(1_U256));
+    }
+}
@@ -0,0 +1,219 @@
+//! Initializable Contract.
+//!
+//! Contract module which helps writing initialization logic for contracts
+//! that can not rely on a constructor, e.g. contracts deployed behind a proxy,
+//! or contracts whose state is set up in a separate transaction after
+//! deployment.
+//!
+//! It provides the functions [`Initializable::initializer`] and
+//! [`Initializable::reinitializer`], which should be called at the very
+//! beginning of the initialization function of your contract. Each of them
+//! can only succeed once per version, so the function calling it can not be
+//! invoked twice.
+//!
+//! Calling [`Initializable::_disable_initializers`] locks the contract,
+//! preventing any future (re)initialization. It is recommended to call it in
+//! the constructor of implementation contracts meant to be used behind a
+//! proxy.
+
+use alloy_primitives::U64;
+use alloy_sol_types::sol;
+use openzeppelin_stylus_proc::SolidityError;
+use stylus_sdk::{evm, stylus_proc::sol_storage};
+
+/// Version that an initialized contract receives after
+/// [`Initializable::initializer`] is called.
+pub const INITIAL_VERSION: u64 = 1;
+
+/// Version that locks the contract from any future (re)initialization.
+pub const DISABLED_VERSION: u64 = u64::MAX;
+
+sol! {
+    /// Emitted when the contract has been initialized or reinitialized.
+    ///
+    /// * `version` - New initialized version.
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    event Initialized(uint64 version);
+}
+
+sol! {
+    /// The contract is already initialized, or was initialized with a version
+    /// greater than or equal to the requested one.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error InvalidInitialization();
+}
+
+/// An Initializable error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// The contract is already initialized, or was initialized with a version
+    /// greater than or equal to the requested one.
+    InvalidInitialization(InvalidInitialization),
+}
+
+sol_storage! {
+    /// State of an Initializable Contract.
+    pub struct Initializable {
+        /// Latest version the contract has been initialized with.
+        /// Zero means the contract has not been initialized yet.
+        uint64 _initialized;
+    }
+}
+
+impl Initializable {
+    /// Marks the contract as initialized with [`INITIAL_VERSION`].
+    ///
+    /// Should be called at the beginning of the function that initializes
+    /// the contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    ///
+    /// # Errors
+    ///
+    /// If the contract has already been initialized, then the error
+    /// [`Error::InvalidInitialization`] is returned.
+    ///
+    /// # Events
+    ///
+    /// Emits an [`Initialized`] event.
+    pub fn initializer(&mut self) -> Result<(), Error> {
+        if self._initialized.get() != U64::ZERO {
+            return Err(Error::InvalidInitialization(InvalidInitialization {}));
+        }
+        self.set_initialized(INITIAL_VERSION);
+        Ok(())
+    }
+
+    /// Marks the contract as initialized with `version`.
+    ///
+    /// Should be called at the beginning of a function that reinitializes
+    /// the contract, e.g. to set up state introduced by an upgrade. Versions
+    /// can be skipped, but each one can only be used once and only in
+    /// increasing order.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `version` - Version to initialize the contract with.
+    ///
+    /// # Errors
+    ///
+    /// If the contract has already been initialized with `version` or a
+    /// greater one, then the error [`Error::InvalidInitialization`] is
+    /// returned.
+    ///
+    /// # Events
+    ///
+    /// Emits an [`Initialized`] event.
+    pub fn reinitializer(&mut self, version: u64) -> Result<(), Error> {
+        if self._initialized.get() >= U64::from(version) {
+            return Err(Error::InvalidInitialization(InvalidInitialization {}));
+        }
+        self.set_initialized(version);
+        Ok(())
+    }
+
+    /// Locks the contract, preventing any future (re)initialization.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    ///
+    /// # Events
+    ///
+    /// Emits an [`Initialized`] event the first time it is successfully
+    /// executed.
+    pub fn _disable_initializers(&mut self) {
+        if self._initialized.get() != U64::from(DISABLED_VERSION) {
+            self.set_initialized(DISABLED_VERSION);
+        }
+    }
+
+    /// Returns the highest version that has been initialized.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[must_use]
+    pub fn _get_initialized_version(&self) -> u64 {
+        self._initialized.get().to::<u64>()
+    }
+
+    /// Stores `version` as the initialized version and emits an
+    /// [`Initialized`] event.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `version` - New initialized version.
+    fn set_initialized(&mut self, version: u64) {
+        self._initialized.set(U64::from(version));
+        evm::log(Initialized { version });
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{Error, Initializable, DISABLED_VERSION, INITIAL_VERSION};
+
+    #[motsu::test]
+    fn initializer_works(contract: Initializable) {
+        assert_eq!(contract._get_initialized_version(), 0);
+
+        let result = contract.initializer();
+        assert!(result.is_ok());
+        assert_eq!(contract._get_initialized_version(), INITIAL_VERSION);
+    }
+
+    #[motsu::test]
+    fn initializer_errors_when_initialized(contract: Initializable) {
+        contract.initializer().expect("should initialize");
+
+        let result = contract.initializer();
+        assert!(matches!(result, Err(Error::InvalidInitialization(_))));
+    }
+
+    #[motsu::test]
+    fn reinitializer_works(contract: Initializable) {
+        contract.initializer().expect("should initialize");
+
+        let result = contract.reinitializer(3);
+        assert!(result.is_ok());
+        assert_eq!(contract._get_initialized_version(), 3);
+    }
+
+    #[motsu::test]
+    fn reinitializer_errors_for_used_version(contract: Initializable) {
+        contract.reinitializer(2).expect("should reinitialize");
+
+        let result = contract.reinitializer(2);
+        assert!(matches!(result, Err(Error::InvalidInitialization(_))));
+
+        let result = contract.reinitializer(1);
+        assert!(matches!(result, Err(Error::InvalidInitialization(_))));
+
+        let result = contract.initializer();
+        assert!(matches!(result, Err(Error::InvalidInitialization(_))));
+    }
+
+    #[motsu::test]
+    fn disable_initializers_locks_contract(contract: Initializable) {
+        contract._disable_initializers();
+        assert_eq!(contract._get_initialized_version(), DISABLED_VERSION);
+
+        let result = contract.initializer();
+        assert!(matches!(result, Err(Error::InvalidInitialization(_))));
+
+        let result = contract.reinitializer(DISABLED_VERSION);
+        assert!(matches!(result, Err(Error::InvalidInitialization(_))));
+
+        // Disabling twice is a no-op.
+        contract._disable_initializers();
+        assert_eq!(contract._get_initialized_version(), DISABLED_VERSION);
+    }
+}
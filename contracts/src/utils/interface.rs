@@ -0,0 +1,252 @@
+//! Solidity interfaces of composed contracts.
+//!
+//! The `#[selectors]` attribute implements [`Interface`] for each `#[public]`
+//! implementation it annotates, listing its functions, followed by the ones
+//! of the contracts it inherits, along with the events and errors passed to
+//! it:
+//!
+//! ```rust,ignore
+//! #[selectors(
+//!     events(Transfer, Approval),
+//!     errors(ERC20InsufficientBalance, ERC20InvalidSender)
+//! )]
+//! #[public]
+//! impl IErc20 for Erc20 {
+//!     // ...
+//! }
+//! ```
+//!
+//! [`export`] then prints the interface of a whole entrypoint, e.g. to
+//! generate the ABI used by its e2e tests instead of maintaining it by hand:
+//!
+//! ```rust,ignore
+//! let interface = export::<Erc20Example>("IErc20Example");
+//! std::fs::write("IErc20Example.sol", interface)?;
+//! ```
+//!
+//! Events and errors are declared with `#![sol(abi)]` in their `sol!` block,
+//! so that their parameter names and indexed parameters are exported as
+//! well.
+// Re-exported for the code generated by `#[selectors]`.
+#[doc(hidden)]
+pub use alloc::vec::Vec;
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+use alloy_json_abi::JsonAbi;
+pub use alloy_json_abi::{Error, Event, Function, StateMutability};
+pub use alloy_sol_types::JsonAbiExt;
+
+/// Lists the functions, events and errors of the Solidity interface of a
+/// contract.
+pub trait Interface {
+    /// Functions of the `#[public]` methods of the contract, followed by the
+    /// functions of the contracts it inherits, in order.
+    fn functions() -> Vec<Function>;
+
+    /// Events emitted by the contract and the contracts it inherits.
+    fn events() -> Vec<Event>;
+
+    /// Errors returned by the contract and the contracts it inherits.
+    fn errors() -> Vec<Error>;
+}
+
+/// Returns the Solidity interface `name` of the contract `T`, with its
+/// functions, events and errors.
+///
+/// Like the router of an entrypoint, a function overrides the following ones
+/// with the same selector, e.g. the ones of the inherited contracts.
+#[must_use]
+pub fn export<T: Interface>(name: &str) -> String {
+    let mut abi = JsonAbi::default();
+
+    let mut selectors = Vec::new();
+    for function in T::functions() {
+        if !selectors.contains(&function.selector()) {
+            selectors.push(function.selector());
+            abi.functions
+                .entry(function.name.clone())
+                .or_default()
+                .push(function);
+        }
+    }
+
+    let mut selectors = Vec::new();
+    for event in T::events() {
+        if !selectors.contains(&event.selector()) {
+            selectors.push(event.selector());
+            abi.events.entry(event.name.clone()).or_default().push(event);
+        }
+    }
+
+    let mut selectors = Vec::new();
+    for error in T::errors() {
+        if !selectors.contains(&error.selector()) {
+            selectors.push(error.selector());
+            abi.errors.entry(error.name.clone()).or_default().push(error);
+        }
+    }
+
+    abi.to_sol(name, None)
+}
+
+/// Returns the function `name`, taking the named `inputs` and returning
+/// `output`, as the ABI types of the Rust method exporting it.
+///
+/// # Panics
+///
+/// If the ABI types aren't valid Solidity types.
+#[must_use]
+pub fn function(
+    name: &str,
+    inputs: &[(&str, &str)],
+    output: &str,
+    state_mutability: StateMutability,
+) -> Function {
+    let inputs: Vec<String> = inputs
+        .iter()
+        .map(|(name, ty)| format!("{ty} {}", param_name(name)))
+        .collect();
+    let returns = if output == "()" {
+        String::new()
+    } else if output.starts_with('(') {
+        format!(" returns {output}")
+    } else {
+        format!(" returns ({output})")
+    };
+    let signature = format!("{name}({}){returns}", inputs.join(", "));
+
+    let mut function = Function::parse(&signature)
+        .unwrap_or_else(|_| panic!("`{signature}` should be a valid function"));
+    function.state_mutability = state_mutability;
+    function
+}
+
+/// Prefixes `name` with an underscore if it is a Solidity keyword.
+fn param_name(name: &str) -> String {
+    match name {
+        "address" | "bool" | "string" | "bytes" | "int" | "uint" | "is"
+        | "contract" | "interface" | "function" | "event" | "error" => {
+            format!("_{name}")
+        }
+        _ => name.to_string(),
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::vec::Vec;
+
+    use alloy_primitives::{Address, U256};
+    use openzeppelin_stylus_proc::selectors;
+    use stylus_sdk::prelude::{public, sol_storage};
+
+    use super::{export, function, Interface, StateMutability};
+    use crate::{
+        access::ownable::Ownable,
+        token::erc20::{Erc20, IErc20},
+    };
+
+    sol_storage! {
+        struct Token {
+            #[borrow]
+            Erc20 erc20;
+            #[borrow]
+            Ownable ownable;
+        }
+    }
+
+    #[selectors]
+    #[public]
+    #[inherit(Erc20, Ownable)]
+    impl Token {
+        fn transfer(
+            &mut self,
+            to: Address,
+            value: U256,
+        ) -> Result<bool, Vec<u8>> {
+            self.ownable.only_owner()?;
+            Ok(self.erc20.transfer(to, value)?)
+        }
+    }
+
+    #[test]
+    fn builds_function() {
+        let transfer = function(
+            "transfer",
+            &[("to", "address"), ("value", "uint256")],
+            "bool",
+            StateMutability::NonPayable,
+        );
+        assert_eq!(
+            transfer.signature(),
+            "transfer(address,uint256)".to_string()
+        );
+        assert_eq!(transfer.outputs.len(), 1);
+
+        let pair =
+            function("pair", &[], "(uint256,bool)", StateMutability::Pure);
+        assert_eq!(pair.outputs.len(), 2);
+
+        let unit = function(
+            "unit",
+            &[("address", "address")],
+            "()",
+            StateMutability::View,
+        );
+        assert_eq!(unit.inputs[0].name, "_address");
+        assert!(unit.outputs.is_empty());
+    }
+
+    #[test]
+    fn lists_interface_of_contract() {
+        let functions = Erc20::functions();
+        let transfer = functions.iter().find(|f| f.name == "transfer").unwrap();
+        assert_eq!(transfer.inputs[0].name, "to");
+        assert_eq!(transfer.state_mutability, StateMutability::NonPayable);
+        let balance_of =
+            functions.iter().find(|f| f.name == "balanceOf").unwrap();
+        assert_eq!(balance_of.state_mutability, StateMutability::View);
+
+        let transfer =
+            Erc20::events().into_iter().find(|e| e.name == "Transfer").unwrap();
+        assert!(transfer.inputs[0].indexed);
+        assert!(!transfer.inputs[2].indexed);
+
+        assert!(Erc20::errors()
+            .iter()
+            .any(|e| e.name == "ERC20InsufficientBalance"));
+        assert!(Ownable::functions().iter().any(|f| f.name == "owner"));
+    }
+
+    #[test]
+    fn exports_interface() {
+        let interface = export::<Erc20>("IErc20");
+        assert!(interface.starts_with("interface IErc20 {"));
+        assert!(interface.contains(
+            "event Transfer(address indexed from, address indexed to, uint256 value);"
+        ));
+        assert!(interface.contains("error ERC20InvalidSender(address sender);"));
+        assert!(interface.contains(
+            "function balanceOf(address account) external view returns (uint256);"
+        ));
+    }
+
+    #[test]
+    fn exports_interface_of_inheritance_chain() {
+        let interface = export::<Token>("IToken");
+        let transfers = interface.matches("function transfer(").count();
+        assert_eq!(transfers, 1);
+        assert!(interface.contains(
+            "function transfer(address to, uint256 value) external returns (bool);"
+        ));
+        assert!(interface.contains("function owner() external view"));
+        assert!(interface.contains("event OwnershipTransferred("));
+        assert!(interface.contains("error OwnableInvalidOwner(address owner);"));
+        assert!(
+            interface.contains("error ERC20InvalidSpender(address spender);")
+        );
+    }
+}
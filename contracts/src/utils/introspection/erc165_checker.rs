@@ -0,0 +1,203 @@
+//! Library used to query support of an interface declared via [`IErc165`],
+//! by other contracts.
+//!
+//! Like Solidity's `ERC165Checker`, the queries never revert: accounts
+//! without code, contracts without a `supportsInterface` function, and
+//! contracts reverting or returning malformed data are reported as not
+//! supporting the interface. Each query is a static call limited to
+//! [`QUERY_GAS`], so that a malicious target can't consume all the gas of
+//! the caller.
+//!
+//! [`IErc165`]: super::erc165::IErc165
+use alloy_primitives::{Address, FixedBytes};
+use alloy_sol_types::{sol, SolCall};
+use stylus_sdk::{call::RawCall, types::AddressVM};
+
+/// Gas forwarded to each `supportsInterface` call, as in the [ERC].
+///
+/// [ERC]: https://eips.ethereum.org/EIPS/eip-165#how-to-detect-if-a-contract-implements-erc-165
+pub const QUERY_GAS: u64 = 30_000;
+
+/// Interface id of ERC-165 itself.
+const ERC165_INTERFACE_ID: FixedBytes<4> =
+    FixedBytes::new([0x01, 0xff, 0xc9, 0xa7]);
+
+/// Interface id that no ERC-165 compliant contract supports.
+const INVALID_INTERFACE_ID: FixedBytes<4> = FixedBytes::new([0xff; 4]);
+
+sol! {
+    /// ERC-165 function called by the checker.
+    #[allow(missing_docs)]
+    interface IErc165 {
+        function supportsInterface(bytes4 interfaceId) external view returns (bool);
+    }
+}
+
+/// Returns true if `account` supports [`IErc165`], and false otherwise.
+///
+/// # Arguments
+///
+/// * `account` - Account to query.
+///
+/// [`IErc165`]: super::erc165::IErc165
+#[must_use]
+pub fn supports_erc165(account: Address) -> bool {
+    supports_erc165_interface_unchecked(account, ERC165_INTERFACE_ID)
+        && !supports_erc165_interface_unchecked(account, INVALID_INTERFACE_ID)
+}
+
+/// Returns true if `account` supports [`IErc165`] and the interface
+/// `interface_id`, and false otherwise.
+///
+/// # Arguments
+///
+/// * `account` - Account to query.
+/// * `interface_id` - Identifier of the interface.
+///
+/// [`IErc165`]: super::erc165::IErc165
+#[must_use]
+pub fn supports_interface(
+    account: Address,
+    interface_id: FixedBytes<4>,
+) -> bool {
+    supports_erc165(account)
+        && supports_erc165_interface_unchecked(account, interface_id)
+}
+
+/// Returns true if `account` supports [`IErc165`] and all the
+/// `interface_ids`, and false otherwise.
+///
+/// Cheaper than calling [`supports_interface`] for each interface, since
+/// support of [`IErc165`] is only queried once.
+///
+/// # Arguments
+///
+/// * `account` - Account to query.
+/// * `interface_ids` - Identifiers of the interfaces.
+///
+/// [`IErc165`]: super::erc165::IErc165
+#[must_use]
+pub fn supports_all_interfaces(
+    account: Address,
+    interface_ids: &[FixedBytes<4>],
+) -> bool {
+    supports_erc165(account)
+        && interface_ids
+            .iter()
+            .all(|&id| supports_erc165_interface_unchecked(account, id))
+}
+
+/// Returns true if `account` supports the interface `interface_id`, without
+/// checking that it supports [`IErc165`] first, and false otherwise.
+///
+/// Meant for callers that already checked the support of [`IErc165`] with
+/// [`supports_erc165`]: it can't tell apart a contract supporting the
+/// interface from a contract returning true for any call, e.g. with a
+/// fallback function.
+///
+/// # Arguments
+///
+/// * `account` - Account to query.
+/// * `interface_id` - Identifier of the interface.
+///
+/// [`IErc165`]: super::erc165::IErc165
+#[must_use]
+pub fn supports_erc165_interface_unchecked(
+    account: Address,
+    interface_id: FixedBytes<4>,
+) -> bool {
+    if !account.has_code() {
+        return false;
+    }
+
+    let call = IErc165::supportsInterfaceCall { interfaceId: interface_id };
+    // Like Solidity's `ERC165Checker`, any non-zero value is true.
+    RawCall::new_static()
+        .gas(QUERY_GAS)
+        .call(account, &call.abi_encode())
+        .is_ok_and(|data| data.len() >= 32 && data[..32] != [0; 32])
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{address, Address, FixedBytes};
+    use alloy_sol_types::{SolCall, SolValue};
+    use motsu::prelude::{deploy, mock_call, mock_revert};
+    use stylus_sdk::{
+        prelude::{public, sol_storage},
+        storage::TopLevelStorage,
+    };
+
+    use super::{
+        supports_all_interfaces, supports_erc165,
+        supports_erc165_interface_unchecked, supports_interface, IErc165,
+    };
+    use crate::utils::introspection::erc165::{Erc165, IErc165 as _};
+
+    const ALICE: Address = address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
+    const TOKEN: Address = address!("B0B0cB49ec2e96DF5F5fFB081acaE66A2cBBc2e2");
+    const TOKEN_INTERFACE_ID: FixedBytes<4> =
+        FixedBytes::new([0x36, 0x37, 0x2b, 0x07]);
+
+    sol_storage! {
+        struct Token {}
+    }
+
+    unsafe impl TopLevelStorage for Token {}
+
+    #[public]
+    impl Token {
+        fn supports_interface(interface_id: FixedBytes<4>) -> bool {
+            interface_id == TOKEN_INTERFACE_ID
+                || Erc165::supports_interface(interface_id)
+        }
+    }
+
+    #[motsu::test]
+    fn checks_interfaces_of_deployed_contract() {
+        deploy::<Token>(TOKEN);
+        let erc165_interface_id: FixedBytes<4> =
+            Erc165::INTERFACE_ID.to_be_bytes().into();
+
+        assert!(supports_erc165(TOKEN));
+        assert!(supports_interface(TOKEN, TOKEN_INTERFACE_ID));
+        assert!(supports_all_interfaces(
+            TOKEN,
+            &[TOKEN_INTERFACE_ID, erc165_interface_id]
+        ));
+        assert!(!supports_interface(TOKEN, FixedBytes::new([0x12; 4])));
+        assert!(!supports_all_interfaces(
+            TOKEN,
+            &[TOKEN_INTERFACE_ID, FixedBytes::new([0x12; 4])]
+        ));
+    }
+
+    #[motsu::test]
+    fn rejects_accounts_without_code() {
+        assert!(!supports_erc165(ALICE));
+        assert!(!supports_erc165_interface_unchecked(
+            ALICE,
+            TOKEN_INTERFACE_ID
+        ));
+    }
+
+    #[motsu::test]
+    fn rejects_contracts_reverting_or_returning_true_for_all() {
+        let selector = IErc165::supportsInterfaceCall::SELECTOR;
+
+        mock_revert(TOKEN, selector, vec![]);
+        assert!(!supports_erc165(TOKEN));
+
+        mock_call(TOKEN, selector, vec![0x01]);
+        assert!(!supports_erc165_interface_unchecked(
+            TOKEN,
+            TOKEN_INTERFACE_ID
+        ));
+
+        // Claims to support the invalid interface id too.
+        mock_call(TOKEN, selector, true.abi_encode());
+        assert!(supports_erc165_interface_unchecked(TOKEN, TOKEN_INTERFACE_ID));
+        assert!(!supports_erc165(TOKEN));
+        assert!(!supports_interface(TOKEN, TOKEN_INTERFACE_ID));
+    }
+}
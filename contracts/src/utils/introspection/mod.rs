@@ -1,2 +1,3 @@
 //! Stylus contract's introspection helpers library.
 pub mod erc165;
+pub mod erc165_checker;
@@ -1,3 +1,4 @@
 //! Math helpers for `alloy` and Solidity storage types.
 pub mod alloy;
+#[cfg(any(feature = "erc20", feature = "erc721"))]
 pub mod storage;
@@ -1,29 +1,78 @@
 //! Simple math operations missing in `stylus_sdk::storage`.
+//!
+//! The operations don't check for overflows, which saves the checks of the
+//! hot paths of the tokens, e.g. the balance updates of every transfer, where
+//! their callers prove that overflows are impossible. Overflows still panic
+//! in builds with debug assertions, e.g. in tests, so that a wrong proof
+//! doesn't go unnoticed.
 use alloy_primitives::Uint;
-use stylus_sdk::storage::{StorageGuardMut, StorageUint};
+use stylus_sdk::storage::StorageUint;
 
+/// Adds to a storage value without checking for overflows.
 pub(crate) trait AddAssignUnchecked<T> {
+    /// Adds `rhs` to the stored value, wrapping around on overflow.
+    ///
+    /// # Panics
+    ///
+    /// If the addition overflows, in builds with debug assertions.
     fn add_assign_unchecked(&mut self, rhs: T);
 }
 
-impl<'a, const B: usize, const L: usize> AddAssignUnchecked<Uint<B, L>>
-    for StorageGuardMut<'a, StorageUint<B, L>>
+impl<const B: usize, const L: usize> AddAssignUnchecked<Uint<B, L>>
+    for StorageUint<B, L>
 {
     fn add_assign_unchecked(&mut self, rhs: Uint<B, L>) {
-        let new_balance = self.get() + rhs;
-        self.set(new_balance);
+        let (new_value, overflow) = self.get().overflowing_add(rhs);
+        debug_assert!(!overflow, "addition should not overflow");
+        self.set(new_value);
     }
 }
 
+/// Subtracts from a storage value without checking for overflows.
 pub(crate) trait SubAssignUnchecked<T> {
+    /// Subtracts `rhs` from the stored value, wrapping around on overflow.
+    ///
+    /// # Panics
+    ///
+    /// If the subtraction overflows, in builds with debug assertions.
     fn sub_assign_unchecked(&mut self, rhs: T);
 }
 
-impl<'a, const B: usize, const L: usize> SubAssignUnchecked<Uint<B, L>>
-    for StorageGuardMut<'a, StorageUint<B, L>>
+impl<const B: usize, const L: usize> SubAssignUnchecked<Uint<B, L>>
+    for StorageUint<B, L>
 {
     fn sub_assign_unchecked(&mut self, rhs: Uint<B, L>) {
-        let new_balance = self.get() - rhs;
-        self.set(new_balance);
+        let (new_value, overflow) = self.get().overflowing_sub(rhs);
+        debug_assert!(!overflow, "subtraction should not overflow");
+        self.set(new_value);
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{uint, U256};
+    use stylus_sdk::prelude::sol_storage;
+
+    use super::{AddAssignUnchecked, SubAssignUnchecked};
+
+    sol_storage! {
+        struct Counter {
+            uint256 value;
+        }
+    }
+
+    #[motsu::test]
+    fn adds_and_subtracts(contract: Counter) {
+        contract.value.add_assign_unchecked(uint!(10_U256));
+        contract.value.sub_assign_unchecked(uint!(4_U256));
+        assert_eq!(contract.value.get(), uint!(6_U256));
+    }
+
+    #[motsu::test]
+    #[cfg_attr(debug_assertions, should_panic = "should not overflow")]
+    fn wraps_on_overflow_without_debug_assertions(contract: Counter) {
+        contract.value.set(U256::MAX);
+        contract.value.add_assign_unchecked(uint!(1_U256));
+        assert_eq!(contract.value.get(), U256::ZERO);
     }
 }
@@ -1,6 +1,7 @@
 //! Common Metadata Smart Contract.
 use alloc::string::String;
 
+use openzeppelin_stylus_proc::selectors;
 use stylus_sdk::stylus_proc::{public, sol_storage};
 
 sol_storage! {
@@ -13,6 +14,7 @@ sol_storage! {
     }
 }
 
+#[selectors]
 #[public]
 impl Metadata {
     /// Returns the name of the token.
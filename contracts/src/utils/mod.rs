@@ -1,11 +1,29 @@
 //! Common Smart Contracts utilities.
+pub mod block_numberish;
+pub mod bytes;
+pub mod cache;
+#[cfg(feature = "crypto")]
 pub mod cryptography;
+pub mod deadline;
+pub mod hooks;
+pub mod initializable;
+pub mod interface;
 pub mod introspection;
 pub mod math;
 pub mod metadata;
+pub mod namespaced;
 pub mod nonces;
 pub mod pausable;
+pub mod price_feed;
+pub mod random;
+pub mod rate_limiter;
+pub mod reentrancy_guard;
+pub mod revert;
+pub mod sanctions;
+pub mod selectors;
 pub mod structs;
+#[cfg(feature = "safe-erc20")]
+pub mod token_rescuer;
 
 pub use metadata::Metadata;
 pub use pausable::Pausable;
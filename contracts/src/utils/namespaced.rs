@@ -0,0 +1,196 @@
+//! Namespaced storage layout, as defined in [ERC-7201].
+//!
+//! By default, the fields of a `#[storage]` struct are laid out sequentially
+//! starting from the slot of their parent, so adding, removing or reordering
+//! a field in an extension shifts the layout of every contract composing it.
+//! For upgradeable contracts this leads to storage collisions between
+//! versions.
+//!
+//! Wrapping a storage struct in [`Namespaced`] places it at the slot derived
+//! from its namespace id instead, independently from the surrounding layout.
+//! The namespace is attached to the struct by implementing [`Namespace`],
+//! usually through the `#[namespace]` attribute:
+//!
+//! ```rust,ignore
+//! #[namespace(id = "example.main")]
+//! #[storage]
+//! pub struct MainStorage {
+//!     x: StorageU256,
+//!     y: StorageU256,
+//! }
+//!
+//! sol_storage! {
+//!     #[entrypoint]
+//!     struct Example {
+//!         Namespaced<MainStorage> main;
+//!     }
+//! }
+//! ```
+//!
+//! [ERC-7201]: https://eips.ethereum.org/EIPS/eip-7201
+use core::ops::{Deref, DerefMut};
+
+use alloy_primitives::U256;
+use keccak_const::Keccak256;
+use stylus_sdk::storage::{StorageGuard, StorageGuardMut, StorageType};
+
+/// A storage struct bound to an [ERC-7201] namespace.
+///
+/// [ERC-7201]: https://eips.ethereum.org/EIPS/eip-7201
+pub trait Namespace {
+    /// Namespace id, e.g. `"openzeppelin.storage.ERC20"`.
+    const NAMESPACE_ID: &'static str;
+
+    /// Root slot of the namespace, computed from [`Self::NAMESPACE_ID`].
+    const STORAGE_SLOT: U256 = erc7201_slot(Self::NAMESPACE_ID);
+}
+
+/// Computes the [ERC-7201] root slot of the namespace `id`, i.e.
+/// `keccak256(keccak256(id) - 1) & ~0xff`.
+///
+/// # Arguments
+///
+/// * `id` - Namespace id.
+///
+/// [ERC-7201]: https://eips.ethereum.org/EIPS/eip-7201
+#[must_use]
+pub const fn erc7201_slot(id: &str) -> U256 {
+    let mut hash = Keccak256::new().update(id.as_bytes()).finalize();
+
+    // Subtract one from the big-endian hash, propagating the borrow.
+    // Wrapping on an all-zero hash matches the EVM's modular arithmetic.
+    let mut i = hash.len();
+    while i > 0 {
+        i -= 1;
+        let (byte, borrow) = hash[i].overflowing_sub(1);
+        hash[i] = byte;
+        if !borrow {
+            break;
+        }
+    }
+
+    let mut slot = Keccak256::new().update(&hash).finalize();
+    slot[31] = 0;
+    U256::from_be_bytes(slot)
+}
+
+/// Storage accessor that places `T` at its namespace root slot
+/// [`Namespace::STORAGE_SLOT`].
+///
+/// It occupies no space in the layout of its parent, so wrapped fields can be
+/// added to or removed from a contract without affecting the slots of the
+/// remaining fields.
+pub struct Namespaced<T> {
+    inner: T,
+}
+
+impl<T: StorageType + Namespace> StorageType for Namespaced<T> {
+    type Wraps<'a>
+        = StorageGuard<'a, Self>
+    where
+        Self: 'a;
+    type WrapsMut<'a>
+        = StorageGuardMut<'a, Self>
+    where
+        Self: 'a;
+
+    // Namespaced storage does not take any space in its parent.
+    const REQUIRED_SLOTS: usize = 0;
+    const SLOT_BYTES: usize = 0;
+
+    unsafe fn new(_slot: U256, _offset: u8) -> Self {
+        Self { inner: T::new(T::STORAGE_SLOT, 0) }
+    }
+
+    fn load<'s>(self) -> Self::Wraps<'s> {
+        StorageGuard::new(self)
+    }
+
+    fn load_mut<'s>(self) -> Self::WrapsMut<'s> {
+        StorageGuardMut::new(self)
+    }
+}
+
+impl<T> Deref for Namespaced<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> DerefMut for Namespaced<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{uint, U256};
+    use openzeppelin_stylus_proc::namespace;
+    use stylus_sdk::{
+        prelude::storage,
+        storage::{StorageType, StorageU256},
+    };
+
+    use super::{erc7201_slot, Namespace, Namespaced};
+
+    #[namespace(id = "example.main")]
+    #[storage]
+    struct MainStorage {
+        x: StorageU256,
+        y: StorageU256,
+    }
+
+    #[storage]
+    struct Example {
+        counter: StorageU256,
+        main: Namespaced<MainStorage>,
+        total: StorageU256,
+    }
+
+    #[test]
+    fn computes_erc7201_slot() {
+        // Reference values from ERC-7201 and OpenZeppelin Contracts.
+        assert_eq!(
+            erc7201_slot("example.main"),
+            uint!(0x183a6125c38840424c4a85fa12bab2ab606c4b6d0e7cc73c0c06ba5300eab500_U256)
+        );
+        assert_eq!(
+            erc7201_slot("openzeppelin.storage.ERC20"),
+            uint!(0x52c63247e1f47db19d5ce0460030c497f067ca4cebf71ba98eeadabe20bace00_U256)
+        );
+    }
+
+    #[test]
+    fn namespace_attribute_sets_slot() {
+        assert_eq!(MainStorage::NAMESPACE_ID, "example.main");
+        assert_eq!(MainStorage::STORAGE_SLOT, erc7201_slot("example.main"));
+    }
+
+    #[motsu::test]
+    fn namespaced_fields_do_not_collide(contract: Example) {
+        contract.counter.set(uint!(1_U256));
+        contract.main.x.set(uint!(2_U256));
+        contract.main.y.set(uint!(3_U256));
+        contract.total.set(uint!(4_U256));
+
+        assert_eq!(contract.counter.get(), uint!(1_U256));
+        assert_eq!(contract.main.x.get(), uint!(2_U256));
+        assert_eq!(contract.main.y.get(), uint!(3_U256));
+        assert_eq!(contract.total.get(), uint!(4_U256));
+
+        // The namespaced field takes no slot in its parent.
+        assert_eq!(<Example as StorageType>::REQUIRED_SLOTS, 2);
+
+        // Fields of the namespace are laid out from the namespace root.
+        let main = unsafe { MainStorage::new(MainStorage::STORAGE_SLOT, 0) };
+        assert_eq!(main.x.get(), uint!(2_U256));
+        assert_eq!(main.y.get(), uint!(3_U256));
+
+        let unrelated = unsafe { MainStorage::new(U256::ZERO, 0) };
+        assert_eq!(unrelated.x.get(), uint!(1_U256));
+        assert_eq!(unrelated.y.get(), uint!(4_U256));
+    }
+}
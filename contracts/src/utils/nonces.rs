@@ -4,14 +4,18 @@
 
 use alloy_primitives::{uint, Address, U256};
 use alloy_sol_types::sol;
-use stylus_sdk::stylus_proc::{public, sol_storage, SolidityError};
+use openzeppelin_stylus_proc::{selectors, SolidityError};
+use stylus_sdk::stylus_proc::{public, sol_storage};
 
 const ONE: U256 = uint!(1_U256);
 
 sol! {
+    #![sol(abi)]
+
     /// The nonce used for an `account` is not the expected current nonce.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error InvalidAccountNonce(address account, uint256 currentNonce);
 }
 
@@ -30,6 +34,7 @@ sol_storage! {
     }
 }
 
+#[selectors(errors(InvalidAccountNonce))]
 #[public]
 impl Nonces {
     /// Returns the unused nonce for the given account.
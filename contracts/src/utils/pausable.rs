@@ -11,32 +11,41 @@
 //! only once the modifiers are put in place.
 
 use alloy_sol_types::sol;
+use openzeppelin_stylus_proc::{selectors, SolidityError};
 use stylus_sdk::{
     evm, msg,
-    stylus_proc::{public, sol_storage, SolidityError},
+    stylus_proc::{public, sol_storage},
 };
 
 sol! {
+    #![sol(abi)]
+
     /// Emitted when pause is triggered by `account`.
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     event Paused(address account);
 
     /// Emitted when the pause is lifted by `account`.
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     event Unpaused(address account);
 }
 
 sol! {
+    #![sol(abi)]
+
     /// Indicates an error related to the operation that failed
     /// because the contract is paused.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error EnforcedPause();
 
     /// Indicates an error related to the operation that failed
     /// because the contract is not paused.
     #[derive(Debug)]
     #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error ExpectedPause();
 }
 
@@ -59,6 +68,7 @@ sol_storage! {
     }
 }
 
+#[selectors(events(Paused, Unpaused), errors(EnforcedPause, ExpectedPause))]
 #[public]
 impl Pausable {
     /// Returns true if the contract is paused, and false otherwise.
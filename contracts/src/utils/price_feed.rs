@@ -0,0 +1,416 @@
+//! Price Feed Contract.
+//!
+//! Contract module which reads prices from an external price feed exposing
+//! the `AggregatorV3Interface` of [Chainlink], e.g. to price a crowdsale or
+//! to value a collateral.
+//!
+//! It provides the function [`PriceFeed::latest_price`], which only returns
+//! positive prices updated within the configured maximum staleness, either
+//! with the decimals of the feed, or normalized to other decimals with
+//! [`PriceFeed::latest_price_with_decimals`]. The helper [`check_deviation`]
+//! rejects prices too far from a reference price, e.g. the price of a second
+//! feed, or the last price seen by the contract.
+//!
+//! [Chainlink]: https://docs.chain.link/data-feeds/api-reference
+
+use alloy_primitives::{Address, U256, U512, U64};
+use alloy_sol_types::sol;
+use openzeppelin_stylus_proc::SolidityError;
+use stylus_sdk::{
+    block, evm, prelude::sol_interface, storage::TopLevelStorage,
+    stylus_proc::sol_storage,
+};
+
+/// Denominator of the deviations, in basis points.
+const BPS: u64 = 10_000;
+
+sol_interface! {
+    /// Interface of a price feed, as defined by Chainlink's
+    /// `AggregatorV3Interface`.
+    interface IAggregatorV3 {
+        /// Returns the number of decimals of the answers of the feed.
+        #[allow(missing_docs)]
+        function decimals() external view returns (uint8);
+
+        /// Returns the data of the latest round of the feed.
+        #[allow(missing_docs)]
+        function latestRoundData() external view returns (uint80 round_id, int256 answer, uint256 started_at, uint256 updated_at, uint80 answered_in_round);
+    }
+}
+
+sol! {
+    /// Emitted when the price feed is set to `aggregator`, whose prices are
+    /// stale after `max_staleness` seconds.
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    event PriceFeedSet(address aggregator, uint64 max_staleness);
+}
+
+sol! {
+    /// Indicates an error related to a price `aggregator` that could not be
+    /// queried, e.g. because it isn't set or reverted.
+    ///
+    /// * `aggregator` - Address of the price feed.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error PriceFeedUnavailable(address aggregator);
+
+    /// Indicates an error related to a price `answer` which isn't positive.
+    ///
+    /// * `aggregator` - Address of the price feed.
+    /// * `answer` - Answer of the price feed.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error PriceFeedInvalidPrice(address aggregator, int256 answer);
+
+    /// Indicates an error related to a price updated at `updated_at`, more
+    /// than `max_staleness` seconds ago.
+    ///
+    /// * `aggregator` - Address of the price feed.
+    /// * `updated_at` - Timestamp of the last update of the price.
+    /// * `max_staleness` - Maximum age of a price, in seconds.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error PriceFeedStalePrice(address aggregator, uint256 updated_at, uint64 max_staleness);
+
+    /// Indicates an error related to a `price` deviating from a `reference`
+    /// price by more than `max_deviation` basis points.
+    ///
+    /// * `price` - Price checked.
+    /// * `reference` - Reference price.
+    /// * `max_deviation` - Maximum deviation, in basis points.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error PriceFeedDeviationExceeded(uint256 price, uint256 reference, uint256 max_deviation);
+}
+
+/// A Price Feed error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// The price feed isn't set, or could not be queried.
+    Unavailable(PriceFeedUnavailable),
+    /// The price isn't positive.
+    InvalidPrice(PriceFeedInvalidPrice),
+    /// The price is older than the maximum staleness.
+    StalePrice(PriceFeedStalePrice),
+    /// The price deviates too much from the reference price.
+    DeviationExceeded(PriceFeedDeviationExceeded),
+}
+
+sol_storage! {
+    /// State of a Price Feed Contract.
+    pub struct PriceFeed {
+        /// Address of the price feed.
+        address _aggregator;
+        /// Maximum age of a price, in seconds.
+        uint64 _max_staleness;
+    }
+}
+
+/// NOTE: Implementation of [`TopLevelStorage`] to be able use `&self` when
+/// calling other contracts and not `&(impl TopLevelStorage + Borrow<Self>)`.
+/// Should be fixed in the future by the Stylus team.
+unsafe impl TopLevelStorage for PriceFeed {}
+
+impl PriceFeed {
+    /// Returns the address of the price feed.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[must_use]
+    pub fn aggregator(&self) -> Address {
+        self._aggregator.get()
+    }
+
+    /// Returns the maximum age of a price, in seconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[must_use]
+    pub fn max_staleness(&self) -> u64 {
+        self._max_staleness.get().to::<u64>()
+    }
+
+    /// Returns the latest price of the feed, along with its decimals.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    ///
+    /// # Errors
+    ///
+    /// * If the price feed isn't set, or can't be queried, then the error
+    ///   [`Error::Unavailable`] is returned.
+    /// * If the price isn't positive, then the error [`Error::InvalidPrice`] is
+    ///   returned.
+    /// * If the price was updated more than the maximum staleness ago, then the
+    ///   error [`Error::StalePrice`] is returned.
+    pub fn latest_price(&self) -> Result<(U256, u8), Error> {
+        let aggregator = self._aggregator.get();
+        if aggregator.is_zero() {
+            return Err(PriceFeedUnavailable { aggregator }.into());
+        }
+
+        let feed = IAggregatorV3::new(aggregator);
+        let (_, answer, _, updated_at, _) = feed
+            .latest_round_data(self)
+            .map_err(|_| PriceFeedUnavailable { aggregator })?;
+        let decimals = feed
+            .decimals(self)
+            .map_err(|_| PriceFeedUnavailable { aggregator })?;
+
+        if !answer.is_positive() {
+            return Err(PriceFeedInvalidPrice { aggregator, answer }.into());
+        }
+
+        let max_staleness = self.max_staleness();
+        let now = U256::from(block::timestamp());
+        if updated_at.is_zero()
+            || now.saturating_sub(updated_at) > U256::from(max_staleness)
+        {
+            return Err(PriceFeedStalePrice {
+                aggregator,
+                updated_at,
+                max_staleness,
+            }
+            .into());
+        }
+
+        Ok((answer.into_raw(), decimals))
+    }
+
+    /// Returns the latest price of the feed, normalized to `decimals`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `decimals` - Decimals of the returned price.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::latest_price`].
+    ///
+    /// # Panics
+    ///
+    /// See [`normalize`].
+    pub fn latest_price_with_decimals(
+        &self,
+        decimals: u8,
+    ) -> Result<U256, Error> {
+        let (price, feed_decimals) = self.latest_price()?;
+        Ok(normalize(price, feed_decimals, decimals))
+    }
+
+    /// Sets the price feed to `aggregator`, whose prices are stale after
+    /// `max_staleness` seconds.
+    ///
+    /// The maximum staleness should be slightly greater than the heartbeat of
+    /// the feed, i.e. the longest time between two of its updates.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `aggregator` - Address of the price feed.
+    /// * `max_staleness` - Maximum age of a price, in seconds.
+    ///
+    /// # Events
+    ///
+    /// Emits a [`PriceFeedSet`] event.
+    pub fn _set_price_feed(&mut self, aggregator: Address, max_staleness: u64) {
+        self._aggregator.set(aggregator);
+        self._max_staleness.set(U64::from(max_staleness));
+        evm::log(PriceFeedSet { aggregator, max_staleness });
+    }
+}
+
+/// Converts `value` from `from_decimals` to `to_decimals`, rounding down.
+///
+/// # Arguments
+///
+/// * `value` - Value with `from_decimals` decimals.
+/// * `from_decimals` - Decimals of `value`.
+/// * `to_decimals` - Decimals of the returned value.
+///
+/// # Panics
+///
+/// If the converted value exceeds `U256::MAX`, or if `to_decimals` exceeds
+/// `from_decimals` by more than 77, i.e. if the scale exceeds `U256::MAX`.
+#[must_use]
+pub fn normalize(value: U256, from_decimals: u8, to_decimals: u8) -> U256 {
+    let ten = U256::from(10);
+    if to_decimals >= from_decimals {
+        let scale = ten
+            .checked_pow(U256::from(to_decimals - from_decimals))
+            .expect("scale should not exceed `U256::MAX`");
+        value
+            .checked_mul(scale)
+            .expect("normalized value should not exceed `U256::MAX`")
+    } else {
+        // Any value is lower than a scale exceeding `U256::MAX`, i.e. greater
+        // than 10^77.
+        ten.checked_pow(U256::from(from_decimals - to_decimals))
+            .map_or(U256::ZERO, |scale| value / scale)
+    }
+}
+
+/// Checks that `price` deviates from `reference` by at most `max_deviation`
+/// basis points of `reference`.
+///
+/// # Arguments
+///
+/// * `price` - Price to check.
+/// * `reference` - Reference price, e.g. of another feed.
+/// * `max_deviation` - Maximum deviation, in basis points.
+///
+/// # Errors
+///
+/// If `price` deviates from `reference` by more than `max_deviation` basis
+/// points, then the error [`Error::DeviationExceeded`] is returned.
+pub fn check_deviation(
+    price: U256,
+    reference: U256,
+    max_deviation: U256,
+) -> Result<(), Error> {
+    let deviation = price.abs_diff(reference);
+    // Computed on 512 bits, where the products can't overflow.
+    let deviation = U512::from(deviation) * U512::from(BPS);
+    let allowed = U512::from(reference) * U512::from(max_deviation);
+    if deviation > allowed {
+        return Err(PriceFeedDeviationExceeded {
+            price,
+            reference,
+            max_deviation,
+        }
+        .into());
+    }
+    Ok(())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{address, uint, Address, I256, U256};
+    use alloy_sol_types::{sol_data, SolType, SolValue};
+    use motsu::prelude::{mock_call, mock_revert};
+    use stylus_sdk::{block, function_selector};
+
+    use super::{check_deviation, normalize, Error, PriceFeed};
+
+    const FEED: Address = address!("40C57923924B5c5c5455c48D93317139ADDaC8fb");
+    const HOUR: u64 = 3_600;
+
+    type RoundData = (
+        sol_data::Uint<80>,
+        sol_data::Int<256>,
+        sol_data::Uint<256>,
+        sol_data::Uint<256>,
+        sol_data::Uint<80>,
+    );
+
+    /// Mocks the feed, with `answer` updated at `updated_at` and 8 decimals.
+    fn mock_feed(answer: i64, updated_at: u64) {
+        let round_data = RoundData::abi_encode_params(&(
+            1,
+            I256::try_from(answer).unwrap(),
+            U256::from(updated_at),
+            U256::from(updated_at),
+            1,
+        ));
+        mock_call(FEED, function_selector!("latestRoundData"), round_data);
+        mock_call(
+            FEED,
+            function_selector!("decimals"),
+            U256::from(8).abi_encode(),
+        );
+    }
+
+    #[motsu::test]
+    fn returns_latest_price(contract: PriceFeed) {
+        contract._set_price_feed(FEED, HOUR);
+        assert_eq!(contract.aggregator(), FEED);
+        assert_eq!(contract.max_staleness(), HOUR);
+        mock_feed(200_000_000_000, block::timestamp() - HOUR);
+
+        let (price, decimals) = contract.latest_price().expect("should read");
+        assert_eq!(price, uint!(200_000_000_000_U256));
+        assert_eq!(decimals, 8);
+
+        let price =
+            contract.latest_price_with_decimals(18).expect("should read");
+        assert_eq!(price, uint!(2_000_000_000_000_000_000_000_U256));
+    }
+
+    #[motsu::test]
+    fn rejects_stale_and_invalid_prices(contract: PriceFeed) {
+        contract._set_price_feed(FEED, HOUR);
+        mock_feed(200_000_000_000, block::timestamp() - HOUR - 1);
+        let err = contract.latest_price().expect_err("should be stale");
+        assert!(matches!(err, Error::StalePrice(e) if e.max_staleness == HOUR));
+
+        mock_feed(0, block::timestamp());
+        let err = contract.latest_price().expect_err("should be invalid");
+        assert!(matches!(err, Error::InvalidPrice(e) if e.aggregator == FEED));
+
+        mock_feed(-1, block::timestamp());
+        let err = contract.latest_price().expect_err("should be invalid");
+        assert!(matches!(err, Error::InvalidPrice(_)));
+    }
+
+    #[motsu::test]
+    fn rejects_unavailable_feeds(contract: PriceFeed) {
+        let err = contract.latest_price().expect_err("should not be set");
+        assert!(matches!(err, Error::Unavailable(e) if e.aggregator.is_zero()));
+
+        contract._set_price_feed(FEED, HOUR);
+        mock_revert(FEED, function_selector!("latestRoundData"), vec![]);
+        let err = contract.latest_price().expect_err("should revert");
+        assert!(matches!(err, Error::Unavailable(e) if e.aggregator == FEED));
+    }
+
+    #[test]
+    fn normalizes_decimals() {
+        let value = uint!(123456789_U256);
+        assert_eq!(normalize(value, 8, 8), value);
+        assert_eq!(normalize(value, 8, 10), uint!(12345678900_U256));
+        assert_eq!(normalize(value, 8, 6), uint!(1234567_U256));
+    }
+
+    #[test]
+    fn normalizes_to_fewer_decimals_past_max_scale() {
+        assert_eq!(normalize(U256::MAX, 77, 0), uint!(1_U256));
+        assert_eq!(normalize(U256::MAX, 78, 0), U256::ZERO);
+        assert_eq!(normalize(U256::MAX, u8::MAX, 0), U256::ZERO);
+    }
+
+    #[test]
+    #[should_panic = "scale should not exceed `U256::MAX`"]
+    fn panics_when_scale_exceeds_max() {
+        assert_eq!(
+            normalize(uint!(1_U256), 0, 77),
+            U256::from(10).pow(U256::from(77))
+        );
+        _ = normalize(uint!(1_U256), 0, 78);
+    }
+
+    #[test]
+    fn checks_deviation() {
+        let reference = uint!(1000_U256);
+        let max_deviation = uint!(500_U256);
+        check_deviation(uint!(1050_U256), reference, max_deviation)
+            .expect("should be within deviation");
+        check_deviation(uint!(950_U256), reference, max_deviation)
+            .expect("should be within deviation");
+
+        let err = check_deviation(uint!(1051_U256), reference, max_deviation)
+            .expect_err("should exceed deviation");
+        assert!(
+            matches!(err, Error::DeviationExceeded(e) if e.reference == reference)
+        );
+        assert!(check_deviation(U256::MAX, reference, max_deviation).is_err());
+    }
+}
@@ -0,0 +1,341 @@
+//! Deterministic pseudo-random numbers, and a commit-reveal scheme to make
+//! them harder to manipulate.
+//!
+//! A [`Prng`] expands a 32-byte seed into a stream of numbers with
+//! `keccak256`. Seeded with [`Prng::from_block`], it saves contracts like
+//! raffles or auctions from hand-rolling their own randomness, but it is only
+//! as unpredictable as its seed:
+//!
+//! - The sequencer chooses the block timestamp, within the bounds set by the
+//!   chain, and the order of the transactions.
+//! - Callers choose their salt, and can simulate the transaction before sending
+//!   it, or revert it from another contract when they don't like the outcome.
+//!
+//! Only use block-seeded numbers when the value at stake is lower than the
+//! cost of such manipulations. Otherwise, derive the seed from values that
+//! participants committed to with a [`CommitReveal`] before the outcome could
+//! be predicted, or from the output of a verifiable random function, e.g.
+//! with the `vrf` feature of `openzeppelin-crypto`.
+//!
+//! ```rust,ignore
+//! let mut prng = Prng::from_block(salt);
+//! let winner = prng.next_below(U256::from(participants.len()));
+//! ```
+use alloy_primitives::{uint, Address, B256, U256};
+use alloy_sol_types::sol;
+use openzeppelin_stylus_proc::SolidityError;
+use stylus_sdk::{
+    block, call::MethodError, contract, crypto::keccak, msg,
+    stylus_proc::sol_storage,
+};
+
+/// A deterministic pseudo-random number generator.
+///
+/// Each number is the `keccak256` hash of the seed and of a counter, so the
+/// same seed always produces the same stream of numbers.
+#[derive(Clone, Debug)]
+pub struct Prng {
+    /// Seed of the generator.
+    seed: B256,
+    /// Number of values generated so far.
+    counter: u64,
+}
+
+impl Prng {
+    /// Creates a generator from `seed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - Seed of the generator.
+    #[must_use]
+    pub fn new(seed: B256) -> Self {
+        Self { seed, counter: 0 }
+    }
+
+    /// Creates a generator seeded from the current block, the current
+    /// contract, the caller and `salt`.
+    ///
+    /// See the [module documentation](self) for the ways this seed can be
+    /// manipulated.
+    ///
+    /// # Arguments
+    ///
+    /// * `salt` - Value mixed into the seed, e.g. an identifier of the draw.
+    #[must_use]
+    pub fn from_block(salt: B256) -> Self {
+        let seed = keccak(
+            [
+                &block::chainid().to_be_bytes()[..],
+                &block::timestamp().to_be_bytes(),
+                contract::address().as_slice(),
+                msg::sender().as_slice(),
+                salt.as_slice(),
+            ]
+            .concat(),
+        );
+        Self::new(seed)
+    }
+
+    /// Returns the next 32 bytes of the stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the generator.
+    pub fn next_b256(&mut self) -> B256 {
+        let value = keccak(
+            [self.seed.as_slice(), &self.counter.to_be_bytes()].concat(),
+        );
+        self.counter += 1;
+        value
+    }
+
+    /// Returns the next number of the stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the generator.
+    pub fn next_u256(&mut self) -> U256 {
+        U256::from_be_bytes(self.next_b256().0)
+    }
+
+    /// Returns the next number of the stream lower than `bound`, without
+    /// modulo bias.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the generator.
+    /// * `bound` - Exclusive upper bound of the number.
+    ///
+    /// # Panics
+    ///
+    /// * If `bound` is zero.
+    pub fn next_below(&mut self, bound: U256) -> U256 {
+        assert!(!bound.is_zero(), "bound should not be zero");
+        // Numbers below `threshold` would make the lowest values more likely,
+        // since `U256::MAX + 1` is not a multiple of `bound`.
+        let threshold = (U256::MAX - bound + uint!(1_U256)) % bound;
+        loop {
+            let value = self.next_u256();
+            if value >= threshold {
+                return value % bound;
+            }
+        }
+    }
+}
+
+sol! {
+    /// The `account` already has a pending commitment.
+    ///
+    /// * `account` - Account that committed.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error CommitRevealAlreadyCommitted(address account);
+
+    /// The `account` has no pending commitment.
+    ///
+    /// * `account` - Account that revealed.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error CommitRevealNoCommitment(address account);
+
+    /// The revealed value doesn't match the commitment of `account`.
+    ///
+    /// * `account` - Account that revealed.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error CommitRevealInvalidReveal(address account);
+}
+
+/// A Commit-Reveal error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// The account already has a pending commitment.
+    AlreadyCommitted(CommitRevealAlreadyCommitted),
+    /// The account has no pending commitment.
+    NoCommitment(CommitRevealNoCommitment),
+    /// The revealed value doesn't match the commitment.
+    InvalidReveal(CommitRevealInvalidReveal),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+sol_storage! {
+    /// State of a commit-reveal scheme, where accounts commit to a secret
+    /// value, and reveal it once no more commitments are accepted.
+    ///
+    /// Contracts using it are responsible for separating the two phases,
+    /// e.g. with deadlines, and for dealing with accounts that never reveal,
+    /// since withholding a value is the only way left to influence the
+    /// outcome.
+    pub struct CommitReveal {
+        /// Pending commitment of each account.
+        mapping(address => bytes32) _commitments;
+    }
+}
+
+impl CommitReveal {
+    /// Returns the pending commitment of `account`, or `B256::ZERO` if it
+    /// has none.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `account` - Account that committed.
+    #[must_use]
+    pub fn commitment_of(&self, account: Address) -> B256 {
+        self._commitments.get(account)
+    }
+
+    /// Stores the `commitment` of `account`, as computed off-chain with
+    /// [`commitment`].
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `account` - Account that commits.
+    /// * `commitment` - Commitment to a value.
+    ///
+    /// # Errors
+    ///
+    /// If `account` already has a pending commitment, then the error
+    /// [`Error::AlreadyCommitted`] is returned.
+    pub fn commit(
+        &mut self,
+        account: Address,
+        commitment: B256,
+    ) -> Result<(), Error> {
+        if !self._commitments.get(account).is_zero() {
+            return Err(CommitRevealAlreadyCommitted { account }.into());
+        }
+        self._commitments.insert(account, commitment);
+        Ok(())
+    }
+
+    /// Checks `value` and `salt` against the pending commitment of
+    /// `account`, and clears it.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `account` - Account that reveals.
+    /// * `value` - Value committed to.
+    /// * `salt` - Salt of the commitment.
+    ///
+    /// # Errors
+    ///
+    /// If `account` has no pending commitment, then the error
+    /// [`Error::NoCommitment`] is returned.
+    /// If `value` and `salt` don't match the commitment, then the error
+    /// [`Error::InvalidReveal`] is returned.
+    pub fn reveal(
+        &mut self,
+        account: Address,
+        value: B256,
+        salt: B256,
+    ) -> Result<(), Error> {
+        let pending = self._commitments.get(account);
+        if pending.is_zero() {
+            return Err(CommitRevealNoCommitment { account }.into());
+        }
+        if pending != commitment(account, value, salt) {
+            return Err(CommitRevealInvalidReveal { account }.into());
+        }
+        self._commitments.delete(account);
+        Ok(())
+    }
+}
+
+/// Computes the commitment of `account` to `value`, i.e.
+/// `keccak256(abi.encodePacked(account, value, salt))`.
+///
+/// Including `account` prevents others from copying the commitment, and a
+/// random `salt` prevents guessing `value` from it.
+///
+/// # Arguments
+///
+/// * `account` - Account that commits.
+/// * `value` - Value committed to.
+/// * `salt` - Random salt, kept secret until the reveal.
+#[must_use]
+pub fn commitment(account: Address, value: B256, salt: B256) -> B256 {
+    keccak([account.as_slice(), value.as_slice(), salt.as_slice()].concat())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{address, uint, Address, B256, U256};
+
+    use super::{commitment, CommitReveal, Error, Prng};
+
+    const ALICE: Address = address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
+    const VALUE: B256 = B256::repeat_byte(7);
+    const SALT: B256 = B256::repeat_byte(9);
+
+    #[test]
+    fn generates_deterministic_stream() {
+        let mut a = Prng::new(SALT);
+        let mut b = Prng::new(SALT);
+        let first = a.next_b256();
+
+        assert_eq!(first, b.next_b256());
+        assert_ne!(first, a.next_b256());
+        assert_ne!(Prng::new(VALUE).next_b256(), first);
+        assert_eq!(
+            Prng::from_block(SALT).next_b256(),
+            Prng::from_block(SALT).next_b256()
+        );
+    }
+
+    #[test]
+    fn generates_bounded_numbers() {
+        let mut prng = Prng::new(SALT);
+        for bound in [1, 2, 3, 10, 1000] {
+            let bound = U256::from(bound);
+            for _ in 0..20 {
+                assert!(prng.next_below(bound) < bound);
+            }
+        }
+        assert_eq!(prng.next_below(uint!(1_U256)), U256::ZERO);
+    }
+
+    #[test]
+    #[should_panic = "bound should not be zero"]
+    fn panics_on_zero_bound() {
+        Prng::new(SALT).next_below(U256::ZERO);
+    }
+
+    #[motsu::test]
+    fn commits_and_reveals(contract: CommitReveal) {
+        let hash = commitment(ALICE, VALUE, SALT);
+        contract.commit(ALICE, hash).expect("should commit");
+        assert_eq!(contract.commitment_of(ALICE), hash);
+
+        let err = contract.commit(ALICE, hash).expect_err("should be pending");
+        assert!(matches!(err, Error::AlreadyCommitted(_)));
+
+        contract.reveal(ALICE, VALUE, SALT).expect("should reveal");
+        assert_eq!(contract.commitment_of(ALICE), B256::ZERO);
+    }
+
+    #[motsu::test]
+    fn rejects_invalid_reveals(contract: CommitReveal) {
+        let err = contract
+            .reveal(ALICE, VALUE, SALT)
+            .expect_err("should have no commitment");
+        assert!(matches!(err, Error::NoCommitment(_)));
+
+        contract.commit(ALICE, commitment(ALICE, VALUE, SALT)).unwrap();
+        let err = contract
+            .reveal(ALICE, VALUE, B256::ZERO)
+            .expect_err("should reject salt");
+        assert!(matches!(err, Error::InvalidReveal(_)));
+        assert_ne!(contract.commitment_of(ALICE), B256::ZERO);
+    }
+}
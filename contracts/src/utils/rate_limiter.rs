@@ -0,0 +1,301 @@
+//! Rate Limiter Contract.
+//!
+//! Contract module which caps the amount that can be consumed by an account
+//! within a rolling period of time, e.g. the daily amount of tokens released
+//! by a vesting wallet or minted by a token.
+//!
+//! Each account can consume up to `cap` at once, and the amount it consumed
+//! is released linearly over the following `period` seconds, like a token
+//! bucket of `cap` refilled at a rate of `cap` per `period`. Unlike epochs
+//! that reset at fixed times, an account that consumed the whole cap has to
+//! wait `period` seconds to consume it again, and can consume at most `cap`,
+//! plus the amount released in the meantime, within any interval of time.
+//!
+//! It provides the function [`RateLimiter::consume`], which should be called
+//! with the amount of every rate-limited operation.
+
+use alloy_primitives::{Address, U256, U64};
+use alloy_sol_types::sol;
+use openzeppelin_stylus_proc::SolidityError;
+use stylus_sdk::{block, evm, stylus_proc::sol_storage};
+
+sol! {
+    #![sol(abi)]
+
+    /// Emitted when the rate limit is set to `cap` per `period` seconds.
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    event RateLimitSet(uint256 cap, uint64 period);
+}
+
+sol! {
+    #![sol(abi)]
+
+    /// Indicates an error related to a rate-limited operation of `account`
+    /// that requested more than the `available` amount.
+    ///
+    /// * `account` - Account performing the operation.
+    /// * `requested` - Amount requested.
+    /// * `available` - Amount the account can currently consume.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error RateLimitExceeded(address account, uint256 requested, uint256 available);
+
+    /// Indicates an error related to the `period` of the rate limit, which
+    /// cannot be zero.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error RateLimiterInvalidPeriod(uint64 period);
+}
+
+/// A Rate Limiter error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// The operation requested more than the amount available to the
+    /// account.
+    RateLimitExceeded(RateLimitExceeded),
+    /// The period of the rate limit is zero.
+    InvalidPeriod(RateLimiterInvalidPeriod),
+}
+
+sol_storage! {
+    /// State of a Rate Limiter Contract.
+    pub struct RateLimiter {
+        /// Maximum amount that can be consumed by an account at once.
+        uint256 _cap;
+        /// Duration over which a consumed amount is released, in seconds.
+        uint64 _period;
+        /// Mapping from account to the amount it consumed that was not yet
+        /// released at the time of its last consumption.
+        mapping(address => uint256) _consumed;
+        /// Mapping from account to the timestamp of its last consumption.
+        mapping(address => uint64) _updated_at;
+    }
+}
+
+impl RateLimiter {
+    /// Returns the maximum amount that can be consumed by an account at
+    /// once.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[must_use]
+    pub fn cap(&self) -> U256 {
+        self._cap.get()
+    }
+
+    /// Returns the duration over which a consumed amount is released, in
+    /// seconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[must_use]
+    pub fn period(&self) -> u64 {
+        self._period.get().to::<u64>()
+    }
+
+    /// Returns the amount `account` can currently consume.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `account` - Account to query.
+    #[must_use]
+    pub fn available(&self, account: Address) -> U256 {
+        // `consumed` is never greater than `cap`, unless the cap was lowered
+        // since the last consumption of `account`.
+        self.cap().saturating_sub(self.consumed(account))
+    }
+
+    /// Consumes `amount` from the amount available to `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `account` - Account performing the rate-limited operation.
+    /// * `amount` - Amount to consume.
+    ///
+    /// # Errors
+    ///
+    /// If `amount` is greater than the amount available to `account`, then
+    /// the error [`Error::RateLimitExceeded`] is returned.
+    pub fn consume(
+        &mut self,
+        account: Address,
+        amount: U256,
+    ) -> Result<(), Error> {
+        let available = self.available(account);
+        if amount > available {
+            return Err(Error::RateLimitExceeded(RateLimitExceeded {
+                account,
+                requested: amount,
+                available,
+            }));
+        }
+
+        // Overflow not possible: `amount` is not greater than `available`,
+        // so the sum is at most `cap`.
+        let consumed = self.consumed(account) + amount;
+        self._consumed.setter(account).set(consumed);
+        self._updated_at.setter(account).set(U64::from(block::timestamp()));
+        Ok(())
+    }
+
+    /// Sets the rate limit to `cap` per `period` seconds.
+    ///
+    /// Amounts already consumed are kept and count towards the new `cap`,
+    /// and are released at the rate of the new limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `cap` - Maximum amount that can be consumed at once.
+    /// * `period` - Duration over which a consumed amount is released, in
+    ///   seconds.
+    ///
+    /// # Errors
+    ///
+    /// If `period` is zero, then the error [`Error::InvalidPeriod`] is
+    /// returned.
+    ///
+    /// # Events
+    ///
+    /// Emits a [`RateLimitSet`] event.
+    pub fn _set_rate_limit(
+        &mut self,
+        cap: U256,
+        period: u64,
+    ) -> Result<(), Error> {
+        if period == 0 {
+            return Err(Error::InvalidPeriod(RateLimiterInvalidPeriod {
+                period,
+            }));
+        }
+        self._cap.set(cap);
+        self._period.set(U64::from(period));
+        evm::log(RateLimitSet { cap, period });
+        Ok(())
+    }
+
+    /// Returns the amount consumed by `account` that is not released yet,
+    /// i.e. the amount it last consumed, minus `cap` for every `period`
+    /// seconds elapsed since then.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `account` - Account to query.
+    fn consumed(&self, account: Address) -> U256 {
+        let consumed = self._consumed.get(account);
+        let period = self.period();
+        if period == 0 {
+            return consumed;
+        }
+
+        let updated_at = self._updated_at.get(account).to::<u64>();
+        let elapsed = block::timestamp().saturating_sub(updated_at);
+        let released =
+            self.cap().saturating_mul(U256::from(elapsed)) / U256::from(period);
+        consumed.saturating_sub(released)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{address, uint, Address, U256, U64};
+    use stylus_sdk::block;
+
+    use super::{Error, RateLimitExceeded, RateLimiter};
+
+    const ALICE: Address = address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
+    const CAP: U256 = uint!(1000_U256);
+    const DAY: u64 = 86_400;
+
+    #[motsu::test]
+    fn sets_rate_limit(contract: RateLimiter) {
+        contract._set_rate_limit(CAP, DAY).expect("should set rate limit");
+        assert_eq!(contract.cap(), CAP);
+        assert_eq!(contract.period(), DAY);
+        assert_eq!(contract.available(ALICE), CAP);
+    }
+
+    #[motsu::test]
+    fn rejects_zero_period(contract: RateLimiter) {
+        let result = contract._set_rate_limit(CAP, 0);
+        assert!(matches!(result, Err(Error::InvalidPeriod(_))));
+    }
+
+    #[motsu::test]
+    fn consumes_up_to_cap(contract: RateLimiter) {
+        contract._set_rate_limit(CAP, DAY).expect("should set rate limit");
+
+        contract
+            .consume(ALICE, uint!(600_U256))
+            .expect("should consume within cap");
+        assert_eq!(contract.available(ALICE), uint!(400_U256));
+
+        contract
+            .consume(ALICE, uint!(400_U256))
+            .expect("should consume the rest of the cap");
+        assert_eq!(contract.available(ALICE), U256::ZERO);
+    }
+
+    #[motsu::test]
+    fn errors_when_cap_exceeded(contract: RateLimiter) {
+        contract._set_rate_limit(CAP, DAY).expect("should set rate limit");
+        contract
+            .consume(ALICE, uint!(600_U256))
+            .expect("should consume within cap");
+
+        let err = contract
+            .consume(ALICE, uint!(401_U256))
+            .expect_err("should exceed cap");
+        assert!(matches!(
+            err,
+            Error::RateLimitExceeded(RateLimitExceeded {
+                account,
+                requested,
+                available,
+            }) if account == ALICE
+                && requested == uint!(401_U256)
+                && available == uint!(400_U256)
+        ));
+        assert_eq!(contract.available(ALICE), uint!(400_U256));
+    }
+
+    #[motsu::test]
+    fn releases_consumed_amount_over_period(contract: RateLimiter) {
+        contract._set_rate_limit(CAP, DAY).expect("should set rate limit");
+        contract.consume(ALICE, CAP).expect("should consume the whole cap");
+        assert_eq!(contract.available(ALICE), U256::ZERO);
+
+        // Move the last consumption a quarter of a period back.
+        let quarter_ago = U64::from(block::timestamp() - DAY / 4);
+        contract._updated_at.setter(ALICE).set(quarter_ago);
+        assert_eq!(contract.available(ALICE), uint!(250_U256));
+        contract
+            .consume(ALICE, uint!(250_U256))
+            .expect("should consume the released amount");
+        assert!(contract.consume(ALICE, uint!(1_U256)).is_err());
+
+        // Move the last consumption a whole period back.
+        let period_ago = U64::from(block::timestamp() - DAY);
+        contract._updated_at.setter(ALICE).set(period_ago);
+        assert_eq!(contract.available(ALICE), CAP);
+        contract.consume(ALICE, CAP).expect("should consume the whole cap");
+    }
+
+    #[motsu::test]
+    fn zero_cap_blocks_consumption(contract: RateLimiter) {
+        let result = contract.consume(ALICE, uint!(1_U256));
+        assert!(matches!(result, Err(Error::RateLimitExceeded(_))));
+
+        contract
+            .consume(ALICE, U256::ZERO)
+            .expect("should consume zero amount");
+    }
+}
@@ -0,0 +1,160 @@
+//! Reentrancy Guard Contract.
+//!
+//! Contract module that helps prevent reentrant calls to a function.
+//!
+//! By default, the Stylus SDK already rejects reentrant calls to a contract.
+//! This module is only needed by contracts that opt into reentrancy through
+//! the `reentrant` feature of the SDK, and want to forbid it for specific
+//! functions.
+//!
+//! It provides the functions [`ReentrancyGuard::_non_reentrant_before`] and
+//! [`ReentrancyGuard::_non_reentrant_after`], which should wrap the body of a
+//! function that must not be entered twice, usually through the
+//! `#[non_reentrant]` attribute.
+//!
+//! Note that a guarded function can not call another guarded function of the
+//! same contract. This can be worked around by making those functions
+//! `private`, and then adding `external` `non_reentrant` entry points to them.
+
+use alloy_sol_types::sol;
+use openzeppelin_stylus_proc::SolidityError;
+use stylus_sdk::stylus_proc::sol_storage;
+
+sol! {
+    /// Unauthorized reentrant call.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error ReentrancyGuardReentrantCall();
+}
+
+/// A Reentrancy Guard error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// Unauthorized reentrant call.
+    ReentrantCall(ReentrancyGuardReentrantCall),
+}
+
+sol_storage! {
+    /// State of a Reentrancy Guard Contract.
+    pub struct ReentrancyGuard {
+        /// Indicates whether a guarded function is being executed.
+        bool _entered;
+    }
+}
+
+impl ReentrancyGuard {
+    /// Marks the start of a guarded function.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    ///
+    /// # Errors
+    ///
+    /// If a guarded function is already being executed, then the error
+    /// [`Error::ReentrantCall`] is returned.
+    pub fn _non_reentrant_before(&mut self) -> Result<(), Error> {
+        if self._entered.get() {
+            return Err(Error::ReentrantCall(ReentrancyGuardReentrantCall {}));
+        }
+        self._entered.set(true);
+        Ok(())
+    }
+
+    /// Marks the end of a guarded function.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    pub fn _non_reentrant_after(&mut self) {
+        self._entered.set(false);
+    }
+
+    /// Returns true if a guarded function is being executed, and false
+    /// otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[must_use]
+    pub fn _reentrancy_guard_entered(&self) -> bool {
+        self._entered.get()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use alloy_primitives::uint;
+    use openzeppelin_stylus_proc::non_reentrant;
+    use stylus_sdk::prelude::sol_storage;
+
+    use super::{Error, ReentrancyGuard, ReentrancyGuardReentrantCall};
+
+    sol_storage! {
+        struct Guarded {
+            ReentrancyGuard reentrancy_guard;
+            uint256 counter;
+        }
+    }
+
+    impl Guarded {
+        #[non_reentrant]
+        fn increment(&mut self) -> Result<(), Vec<u8>> {
+            let counter = self.counter.get();
+            self.counter.set(counter + uint!(1_U256));
+            Ok(())
+        }
+
+        #[non_reentrant]
+        fn reenter(&mut self) -> Result<(), Vec<u8>> {
+            self.increment()
+        }
+
+        #[non_reentrant]
+        fn fail_early(&mut self, fail: bool) -> Result<u8, Vec<u8>> {
+            assert!(self.reentrancy_guard._reentrancy_guard_entered());
+            if fail {
+                return Err(vec![1]);
+            }
+            Ok(2)
+        }
+    }
+
+    #[motsu::test]
+    fn guard_rejects_reentrant_call(contract: ReentrancyGuard) {
+        contract._non_reentrant_before().expect("should enter");
+        assert!(contract._reentrancy_guard_entered());
+
+        let result = contract._non_reentrant_before();
+        assert!(matches!(result, Err(Error::ReentrantCall(_))));
+
+        contract._non_reentrant_after();
+        assert!(!contract._reentrancy_guard_entered());
+        contract._non_reentrant_before().expect("should enter again");
+    }
+
+    #[motsu::test]
+    fn attribute_guards_function(contract: Guarded) {
+        contract.increment().expect("should not be reentrant");
+        contract.increment().expect("should exit the guard");
+        assert_eq!(contract.counter.get(), uint!(2_U256));
+
+        let err = contract.reenter().expect_err("should reject reentrancy");
+        let expected: Vec<u8> =
+            Error::ReentrantCall(ReentrancyGuardReentrantCall {}).into();
+        assert_eq!(err, expected);
+        assert!(!contract.reentrancy_guard._reentrancy_guard_entered());
+    }
+
+    #[motsu::test]
+    fn attribute_exits_on_early_return(contract: Guarded) {
+        assert_eq!(contract.fail_early(false), Ok(2));
+        assert!(!contract.reentrancy_guard._reentrancy_guard_entered());
+
+        assert_eq!(contract.fail_early(true), Err(vec![1]));
+        assert!(!contract.reentrancy_guard._reentrancy_guard_entered());
+    }
+}
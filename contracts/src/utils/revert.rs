@@ -0,0 +1,213 @@
+//! Revert data of the errors of the library.
+//!
+//! The error enums of the library derive `SolidityError` from
+//! `openzeppelin_stylus_proc`, which encodes the Solidity error wrapped by each
+//! variant with [`RevertData`].
+//!
+//! By default, the revert data is the selector of the error followed by its
+//! ABI-encoded fields, like in Solidity. With the `compact-errors` feature,
+//! it is the selector of the error only, which saves the encoding of the
+//! fields of every error from the binary, for deployments close to the limit
+//! of the size of their compressed WASM:
+//!
+//! ```toml
+//! [dependencies]
+//! openzeppelin-stylus = { version = "x.x.x", features = ["compact-errors"] }
+//! ```
+//!
+//! Since each contract is its own crate, the feature is enabled per contract.
+//! Callers can still tell errors apart by their selector, but lose the
+//! arguments of the errors, e.g. the balance of an account for
+//! `ERC20InsufficientBalance`.
+//!
+//! The derive also implements [`core::fmt::Display`] and [`core::error::Error`]
+//! for the error enums, formatting each wrapped error with [`ErrorMessage`],
+//! so that they can be printed, and propagated with `?` to the error types of
+//! off-chain code, e.g. `eyre::Report`.
+// Re-exported for the code generated by `#[derive(SolidityError)]`.
+#[doc(hidden)]
+pub use alloc::{format, vec::Vec};
+use core::fmt;
+
+use alloy_primitives::hex;
+use alloy_sol_types::SolError;
+#[cfg(feature = "export-abi")]
+#[doc(hidden)]
+pub use stylus_sdk::abi::export::internal::{InnerType, InnerTypes};
+use stylus_sdk::call::{self, MethodError};
+
+/// Encodes an error as the data of a revert.
+///
+/// `M` tells apart the implementation for Solidity errors from the ones for
+/// the other errors, e.g. error enums, and is inferred by the callers.
+pub trait RevertData<M> {
+    /// Returns the revert data of the error.
+    fn revert_data(self) -> Vec<u8>;
+}
+
+/// Formats an error as a human-readable message.
+///
+/// `M` plays the same role as for [`RevertData`].
+pub trait ErrorMessage<M> {
+    /// Writes the message of the error to `f`.
+    ///
+    /// # Errors
+    ///
+    /// If writing to `f` fails.
+    fn fmt_message(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+/// Lists the Solidity errors an error can revert with, in the ABI exported
+/// by the `export-abi` feature.
+///
+/// `M` plays the same role as for [`RevertData`].
+#[cfg(feature = "export-abi")]
+pub trait InnerErrors<M> {
+    /// Returns the Solidity errors of the error.
+    fn inner_errors() -> Vec<InnerType>;
+}
+
+/// Marker of the implementations for Solidity errors.
+#[doc(hidden)]
+pub enum Solidity {}
+
+/// Marker of the implementations for the errors wrapping other errors.
+#[doc(hidden)]
+pub enum Wrapped {}
+
+impl<T: SolError> RevertData<Solidity> for T {
+    fn revert_data(self) -> Vec<u8> {
+        if cfg!(feature = "compact-errors") {
+            T::SELECTOR.to_vec()
+        } else {
+            self.abi_encode()
+        }
+    }
+}
+
+/// Forwards the revert data of the called contract as is.
+impl RevertData<Wrapped> for call::Error {
+    fn revert_data(self) -> Vec<u8> {
+        self.encode()
+    }
+}
+
+/// Prints the Solidity error with its fields, e.g.
+/// `ERC20InvalidReceiver { receiver: 0x0000000000000000000000000000000000000000
+/// }`.
+impl<T: SolError + fmt::Debug> ErrorMessage<Solidity> for T {
+    fn fmt_message(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl ErrorMessage<Wrapped> for call::Error {
+    fn fmt_message(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            call::Error::Revert(data) => {
+                write!(f, "call reverted with {}", hex::encode_prefixed(data))
+            }
+            call::Error::AbiDecodingFailed(error) => {
+                write!(f, "failed to decode the return data of a call: {error}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "export-abi")]
+impl<T: SolError + 'static> InnerErrors<Solidity> for T {
+    fn inner_errors() -> Vec<InnerType> {
+        Vec::from([InnerType {
+            name: format!("error {};", T::SIGNATURE.replace(',', ", ")),
+            id: core::any::TypeId::of::<T>(),
+        }])
+    }
+}
+
+/// The errors of the called contract are not known in advance.
+#[cfg(feature = "export-abi")]
+impl InnerErrors<Wrapped> for call::Error {
+    fn inner_errors() -> Vec<InnerType> {
+        Vec::new()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::vec::Vec;
+
+    use alloy_primitives::{address, Address, U256};
+    use alloy_sol_types::SolError;
+    use stylus_sdk::call;
+
+    use super::RevertData;
+    use crate::token::erc20::{
+        extensions::permit, utils::safe_erc20, ERC20InsufficientBalance,
+        ERC20InvalidReceiver, Error,
+    };
+
+    #[test]
+    fn encodes_errors() {
+        let error = ERC20InsufficientBalance {
+            sender: address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d"),
+            balance: U256::from(1),
+            needed: U256::from(2),
+        };
+        let expected = if cfg!(feature = "compact-errors") {
+            ERC20InsufficientBalance::SELECTOR.to_vec()
+        } else {
+            error.abi_encode()
+        };
+
+        let data: Vec<u8> = Error::InsufficientBalance(error.clone()).into();
+        assert_eq!(data, expected);
+        assert_eq!(error.revert_data(), expected);
+    }
+
+    #[test]
+    fn displays_errors() {
+        let error = Error::InvalidReceiver(ERC20InvalidReceiver {
+            receiver: Address::ZERO,
+        });
+        assert_eq!(
+            error.to_string(),
+            "ERC20InvalidReceiver { receiver: 0x0000000000000000000000000000000000000000 }"
+        );
+
+        let nested = permit::Error::Erc20(error);
+        assert!(nested.to_string().starts_with("ERC20InvalidReceiver {"));
+
+        let reverted = call::Error::Revert(alloc::vec![0x12, 0x34]);
+        let error: &dyn core::error::Error =
+            &safe_erc20::Error::FailedCall(reverted);
+        assert_eq!(error.to_string(), "call reverted with 0x1234");
+    }
+
+    #[cfg(feature = "export-abi")]
+    #[test]
+    fn lists_errors_of_wrapped_enums() {
+        use super::InnerTypes;
+
+        let names: Vec<_> = permit::Error::inner_types()
+            .into_iter()
+            .map(|error| error.name)
+            .collect();
+        assert!(
+            names.contains(&"error ERC20InvalidReceiver(address);".to_string())
+        );
+        assert!(names
+            .iter()
+            .any(|name| name.starts_with("error ERC2612ExpiredSignature(")));
+        assert!(names
+            .iter()
+            .any(|name| name.starts_with("error ECDSAInvalidSignature(")));
+
+        // Errors of called contracts are not known in advance.
+        let names: Vec<_> = safe_erc20::Error::inner_types()
+            .into_iter()
+            .map(|error| error.name)
+            .collect();
+        assert!(!names.is_empty());
+        assert!(names.iter().all(|name| name.starts_with("error SafeErc20")));
+    }
+}
@@ -0,0 +1,210 @@
+//! Sanctions Contract.
+//!
+//! Contract module which checks accounts against an external compliance
+//! oracle, e.g. the [Chainalysis sanctions oracle], exposing the
+//! `isSanctioned(address)` function.
+//!
+//! It provides the function [`Sanctions::when_not_sanctioned`], which can be
+//! added to the functions of your contract, e.g. to the `_update` hook of a
+//! token so that sanctioned accounts can neither send nor receive tokens.
+//!
+//! When the oracle cannot be queried (e.g. it reverts or returns malformed
+//! data), the configured policy decides the outcome: a fail-open guard lets
+//! the operation through, while a fail-closed guard rejects it. When no oracle
+//! is configured, every account is allowed.
+//!
+//! [Chainalysis sanctions oracle]: https://go.chainalysis.com/chainalysis-oracle-docs.html
+
+use alloy_primitives::Address;
+use alloy_sol_types::sol;
+use openzeppelin_stylus_proc::SolidityError;
+use stylus_sdk::{
+    evm, prelude::sol_interface, storage::TopLevelStorage,
+    stylus_proc::sol_storage,
+};
+
+sol_interface! {
+    /// Interface of a compliance oracle listing sanctioned accounts.
+    interface ISanctionsList {
+        /// Returns true if `addr` is sanctioned, and false otherwise.
+        #[allow(missing_docs)]
+        function isSanctioned(address addr) external view returns (bool);
+    }
+}
+
+sol! {
+    /// Emitted when the sanctions `oracle` and its `fail_closed` policy are
+    /// set.
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    event SanctionsOracleSet(address oracle, bool fail_closed);
+}
+
+sol! {
+    /// Indicates an error related to an operation involving a sanctioned
+    /// `account`.
+    ///
+    /// * `account` - Sanctioned account.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error SanctionedAccount(address account);
+
+    /// Indicates an error related to a fail-closed guard whose sanctions
+    /// `oracle` could not be queried.
+    ///
+    /// * `oracle` - Address of the sanctions oracle.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error SanctionsOracleUnavailable(address oracle);
+}
+
+/// A Sanctions error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// The account is sanctioned.
+    SanctionedAccount(SanctionedAccount),
+    /// The oracle could not be queried and the guard is fail-closed.
+    OracleUnavailable(SanctionsOracleUnavailable),
+}
+
+sol_storage! {
+    /// State of a Sanctions Contract.
+    pub struct Sanctions {
+        /// Address of the sanctions oracle. Zero disables the checks.
+        address _oracle;
+        /// Whether operations are rejected when the oracle can't be queried.
+        bool _fail_closed;
+    }
+}
+
+/// NOTE: Implementation of [`TopLevelStorage`] to be able use `&self` when
+/// calling other contracts and not `&(impl TopLevelStorage + Borrow<Self>)`.
+/// Should be fixed in the future by the Stylus team.
+unsafe impl TopLevelStorage for Sanctions {}
+
+impl Sanctions {
+    /// Returns the address of the sanctions oracle.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[must_use]
+    pub fn sanctions_oracle(&self) -> Address {
+        self._oracle.get()
+    }
+
+    /// Returns true if operations are rejected when the oracle can't be
+    /// queried, and false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    #[must_use]
+    pub fn is_fail_closed(&self) -> bool {
+        self._fail_closed.get()
+    }
+
+    /// Modifier to make a function callable only when `account` is not
+    /// sanctioned.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `account` - Account to check.
+    ///
+    /// # Errors
+    ///
+    /// If `account` is sanctioned, then the error
+    /// [`Error::SanctionedAccount`] is returned.
+    /// If the oracle can't be queried and the guard is fail-closed, then the
+    /// error [`Error::OracleUnavailable`] is returned.
+    pub fn when_not_sanctioned(&self, account: Address) -> Result<(), Error> {
+        let oracle = self._oracle.get();
+        if oracle.is_zero() {
+            return Ok(());
+        }
+
+        match ISanctionsList::new(oracle).is_sanctioned(self, account) {
+            Ok(true) => Err(SanctionedAccount { account }.into()),
+            Err(_) if self._fail_closed.get() => {
+                Err(SanctionsOracleUnavailable { oracle }.into())
+            }
+            // Either not sanctioned, or the oracle failed and the guard is
+            // fail-open.
+            Ok(false) | Err(_) => Ok(()),
+        }
+    }
+
+    /// Sets the sanctions `oracle` and its failure policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `oracle` - Address of the sanctions oracle. Zero disables the checks.
+    /// * `fail_closed` - Whether operations are rejected when the oracle can't
+    ///   be queried.
+    ///
+    /// # Events
+    ///
+    /// Emits a [`SanctionsOracleSet`] event.
+    pub fn _set_sanctions_oracle(
+        &mut self,
+        oracle: Address,
+        fail_closed: bool,
+    ) {
+        self._oracle.set(oracle);
+        self._fail_closed.set(fail_closed);
+        evm::log(SanctionsOracleSet { oracle, fail_closed });
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{address, Address};
+
+    use super::{Error, Sanctions, SanctionsOracleUnavailable};
+
+    const ALICE: Address = address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
+    const ORACLE: Address =
+        address!("40C57923924B5c5c5455c48D93317139ADDaC8fb");
+
+    #[motsu::test]
+    fn allows_everyone_without_oracle(contract: Sanctions) {
+        assert_eq!(contract.sanctions_oracle(), Address::ZERO);
+        assert!(contract.when_not_sanctioned(ALICE).is_ok());
+
+        contract._set_sanctions_oracle(Address::ZERO, true);
+        assert!(contract.when_not_sanctioned(ALICE).is_ok());
+    }
+
+    #[motsu::test]
+    fn sets_oracle(contract: Sanctions) {
+        contract._set_sanctions_oracle(ORACLE, true);
+        assert_eq!(contract.sanctions_oracle(), ORACLE);
+        assert!(contract.is_fail_closed());
+    }
+
+    // Calls to other contracts return no data in unit tests, so the oracle
+    // can never be queried successfully.
+    #[motsu::test]
+    fn fail_open_allows_when_oracle_unavailable(contract: Sanctions) {
+        contract._set_sanctions_oracle(ORACLE, false);
+        assert!(contract.when_not_sanctioned(ALICE).is_ok());
+    }
+
+    #[motsu::test]
+    fn fail_closed_rejects_when_oracle_unavailable(contract: Sanctions) {
+        contract._set_sanctions_oracle(ORACLE, true);
+
+        let err = contract
+            .when_not_sanctioned(ALICE)
+            .expect_err("should reject when oracle is unavailable");
+        assert!(matches!(
+            err,
+            Error::OracleUnavailable(SanctionsOracleUnavailable { oracle })
+                if oracle == ORACLE
+        ));
+    }
+}
@@ -0,0 +1,146 @@
+//! Selectors exported by the `#[public]` methods of contracts.
+//!
+//! The router of an entrypoint tries its own methods first, then each
+//! inherited contract in order, so when two inherited contracts export the
+//! same selector, e.g. `#[selector(name = "release")]` overloads with the
+//! same arguments, the second one is silently unreachable.
+//!
+//! The `#[selectors]` attribute, placed above `#[public]`, lists the
+//! selectors of an implementation in [`Selectors`], including the ones of
+//! the contracts it inherits, and fails to compile when:
+//!
+//! - two of its own methods export the same selector;
+//! - two inherited contracts export the same selector, and the contract doesn't
+//!   override it with one of its own methods.
+//!
+//! ```rust,ignore
+//! #[selectors]
+//! #[public]
+//! #[inherit(Erc20, Ownable)]
+//! impl Token {
+//!     // ...
+//! }
+//! ```
+//!
+//! Each inherited contract should implement [`Selectors`] as well, e.g.
+//! with the same attribute.
+
+/// Lists the selectors exported by a contract.
+pub trait Selectors {
+    /// Selectors of the `#[public]` methods of the contract, followed by the
+    /// selectors of the contracts it inherits, in order.
+    const SELECTORS: &'static [u32];
+}
+
+/// Returns true if `selectors` contains `selector`, and false otherwise.
+#[must_use]
+pub const fn contains(selectors: &[u32], selector: u32) -> bool {
+    let mut i = 0;
+    while i < selectors.len() {
+        if selectors[i] == selector {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Returns true if a selector appears more than once in `selectors`, and
+/// false otherwise.
+#[must_use]
+pub const fn has_duplicates(selectors: &[u32]) -> bool {
+    let mut i = 0;
+    while i < selectors.len() {
+        let (_, rest) = selectors.split_at(i + 1);
+        if contains(rest, selectors[i]) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Returns true if `a` and `b` export a common selector which isn't in
+/// `overrides`, and false otherwise.
+#[must_use]
+pub const fn collide(overrides: &[u32], a: &[u32], b: &[u32]) -> bool {
+    let mut i = 0;
+    while i < a.len() {
+        if contains(b, a[i]) && !contains(overrides, a[i]) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Concatenates `lists` into an array of their `N` selectors.
+///
+/// # Panics
+///
+/// If `N` isn't the total length of `lists`.
+#[must_use]
+pub const fn concat<const N: usize>(lists: &[&[u32]]) -> [u32; N] {
+    let mut out = [0; N];
+    let mut len = 0;
+    let mut i = 0;
+    while i < lists.len() {
+        let mut j = 0;
+        while j < lists[i].len() {
+            out[len] = lists[i][j];
+            len += 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    assert!(len == N, "length should be the total length of the lists");
+    out
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use stylus_sdk::function_selector;
+
+    use super::{collide, concat, has_duplicates, Selectors};
+    use crate::{access::ownable::Ownable, token::erc20::Erc20};
+
+    const fn selector(bytes: [u8; 4]) -> u32 {
+        u32::from_be_bytes(bytes)
+    }
+
+    #[test]
+    fn lists_selectors_of_contract() {
+        let transfer = selector(function_selector!(
+            "transfer",
+            alloy_primitives::Address,
+            alloy_primitives::U256
+        ));
+        let owner = selector(function_selector!("owner"));
+
+        assert!(Erc20::SELECTORS.contains(&transfer));
+        assert!(!Erc20::SELECTORS.contains(&owner));
+        assert!(Ownable::SELECTORS.contains(&owner));
+        assert!(!has_duplicates(Erc20::SELECTORS));
+    }
+
+    #[test]
+    fn detects_duplicates() {
+        assert!(!has_duplicates(&[]));
+        assert!(!has_duplicates(&[1, 2, 3]));
+        assert!(has_duplicates(&[1, 2, 1]));
+    }
+
+    #[test]
+    fn detects_collisions_not_overridden() {
+        assert!(!collide(&[], &[1, 2], &[3]));
+        assert!(collide(&[], &[1, 2], &[2, 3]));
+        assert!(!collide(&[2], &[1, 2], &[2, 3]));
+        assert!(!collide(&[], Erc20::SELECTORS, Ownable::SELECTORS));
+    }
+
+    #[test]
+    fn concatenates_lists() {
+        let all: [u32; 4] = concat(&[&[1, 2], &[], &[3, 4]]);
+        assert_eq!(all, [1, 2, 3, 4]);
+    }
+}
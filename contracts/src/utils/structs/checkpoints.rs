@@ -6,10 +6,11 @@
 //! block using the [`Trace160::push`] function.
 use alloy_primitives::{uint, Uint, U256, U32};
 use alloy_sol_types::sol;
+use openzeppelin_stylus_proc::SolidityError;
 use stylus_sdk::{
     call::MethodError,
     storage::{StorageGuard, StorageGuardMut},
-    stylus_proc::{sol_storage, SolidityError},
+    stylus_proc::sol_storage,
 };
 
 use crate::utils::math::alloy::Math;
@@ -23,8 +24,11 @@ pub type U96 = Uint<96, 2>;
 pub type U160 = Uint<160, 3>;
 
 sol! {
+    #![sol(abi)]
+
     /// A value was attempted to be inserted into a past checkpoint.
     #[derive(Debug)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     error CheckpointUnorderedInsertion();
 }
 
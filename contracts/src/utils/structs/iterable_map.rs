@@ -0,0 +1,297 @@
+//! Storage mapping whose keys can be enumerated on-chain.
+//!
+//! A regular [`StorageMap`] only stores values at the slots derived from
+//! their keys, so a contract has no way to list the keys it has written to.
+//! [`IterableMap`] additionally keeps an index of its keys, e.g. letting a
+//! vesting wallet expose the list of ERC-20 tokens it has released.
+//!
+//! Insertion and removal run in constant time. Removal swaps the removed key
+//! with the last one, so the order of the keys is not preserved.
+//!
+//! ```rust,ignore
+//! sol_storage! {
+//!     pub struct VestingWallet {
+//!         IterableMap<Address, StorageU256> _erc20_released;
+//!     }
+//! }
+//! ```
+use alloc::vec::Vec;
+
+use alloy_primitives::{uint, Address, B256, U256};
+use stylus_sdk::{
+    prelude::sol_storage,
+    storage::{
+        Erase, SimpleStorageType, StorageAddress, StorageB256, StorageGuard,
+        StorageGuardMut, StorageKey, StorageMap, StorageType, StorageU256,
+    },
+};
+
+use super::list::StorageList;
+
+/// Key of an [`IterableMap`], which can be stored in its index of keys.
+pub trait IterableKey: StorageKey + Copy + PartialEq {
+    /// Storage type used to store the key in the index of keys.
+    type Storage: 'static
+        + for<'a> SimpleStorageType<'a>
+        + for<'a> StorageType<Wraps<'a> = Self>;
+}
+
+impl IterableKey for Address {
+    type Storage = StorageAddress;
+}
+
+impl IterableKey for U256 {
+    type Storage = StorageU256;
+}
+
+impl IterableKey for B256 {
+    type Storage = StorageB256;
+}
+
+sol_storage! {
+    /// State of an iterable mapping from `K` keys to `V` values.
+    pub struct IterableMap<K: IterableKey, V: StorageType> {
+        /// Keys of the mapping, in insertion order up to removals.
+        StorageList<K::Storage> _keys;
+        /// Position of each key in `_keys`, plus one. Zero for absent keys.
+        StorageMap<K, StorageU256> _positions;
+        /// Values of the mapping.
+        StorageMap<K, V> _values;
+    }
+}
+
+impl<K: IterableKey, V: StorageType> IterableMap<K, V> {
+    /// Returns the number of keys in the mapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the mapping's state.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self._keys.len()
+    }
+
+    /// Returns true if the mapping contains no keys, and false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the mapping's state.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self._keys.is_empty()
+    }
+
+    /// Returns true if `key` is in the mapping, and false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the mapping's state.
+    /// * `key` - Key to look for.
+    #[must_use]
+    pub fn contains_key(&self, key: K) -> bool {
+        !self._positions.get(key).is_zero()
+    }
+
+    /// Returns the key at `index` of the index of keys, or `None` if `index`
+    /// is out of bounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the mapping's state.
+    /// * `index` - Index of the key.
+    #[must_use]
+    pub fn key_at(&self, index: usize) -> Option<K> {
+        self._keys.get(index)
+    }
+
+    /// Copies all the keys of the mapping to memory.
+    ///
+    /// NOTE: This reads the whole index of keys, which may exceed the gas
+    /// limit for large mappings. Prefer [`Self::keys_page`] in public
+    /// functions.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the mapping's state.
+    #[must_use]
+    pub fn keys(&self) -> Vec<K> {
+        self._keys.to_vec()
+    }
+
+    /// Copies at most `limit` keys of the mapping to memory, starting from
+    /// `offset`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the mapping's state.
+    /// * `offset` - Index of the first key to copy.
+    /// * `limit` - Maximum number of keys to copy.
+    #[must_use]
+    pub fn keys_page(&self, offset: usize, limit: usize) -> Vec<K> {
+        self._keys.page(offset, limit)
+    }
+
+    /// Returns the value of `key`, or the zero value if `key` is absent.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the mapping's state.
+    /// * `key` - Key of the value.
+    #[must_use]
+    pub fn get(&self, key: K) -> V::Wraps<'_> {
+        self._values.get(key)
+    }
+
+    /// Returns an accessor to the value of `key`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the mapping's state.
+    /// * `key` - Key of the value.
+    #[must_use]
+    pub fn getter(&self, key: K) -> StorageGuard<'_, V> {
+        self._values.getter(key)
+    }
+
+    /// Returns a mutable accessor to the value of `key`, adding `key` to the
+    /// index of keys if it is absent.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the mapping's state.
+    /// * `key` - Key of the value.
+    pub fn setter(&mut self, key: K) -> StorageGuardMut<'_, V> {
+        self.track(key);
+        self._values.setter(key)
+    }
+
+    /// Adds `key` to the index of keys if it is absent.
+    ///
+    /// Returns true if `key` was added, and false otherwise.
+    fn track(&mut self, key: K) -> bool {
+        if self.contains_key(key) {
+            return false;
+        }
+        self._keys.push(key);
+        let position = U256::from(self._keys.len());
+        self._positions.insert(key, position);
+        true
+    }
+}
+
+impl<'a, K: IterableKey, V: SimpleStorageType<'a>> IterableMap<K, V> {
+    /// Sets the value of `key` to `value`.
+    ///
+    /// Returns true if `key` was absent, and false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the mapping's state.
+    /// * `key` - Key of the value.
+    /// * `value` - New value of `key`.
+    pub fn insert(&mut self, key: K, value: V::Wraps<'a>) -> bool {
+        let added = self.track(key);
+        self._values.insert(key, value);
+        added
+    }
+}
+
+impl<K: IterableKey, V: Erase> IterableMap<K, V> {
+    /// Removes `key` and erases its value.
+    ///
+    /// Returns true if `key` was present, and false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the mapping's state.
+    /// * `key` - Key to remove.
+    ///
+    /// # Panics
+    ///
+    /// If the index of keys is inconsistent with the stored positions, which
+    /// should never happen.
+    pub fn remove(&mut self, key: K) -> bool {
+        let position = self._positions.get(key);
+        if position.is_zero() {
+            return false;
+        }
+
+        let index = usize::try_from(position - uint!(1_U256))
+            .expect("position should fit in usize");
+        let last = self._keys.len() - 1;
+        if index != last {
+            let moved = self._keys.get(last).expect("last key should exist");
+            self._keys.set(index, moved);
+            self._positions.insert(moved, position);
+        }
+        self._keys.pop();
+        self._positions.delete(key);
+        self._values.delete(key);
+        true
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::vec;
+
+    use alloy_primitives::{address, uint, Address, U256};
+    use stylus_sdk::storage::StorageU256;
+
+    use super::IterableMap;
+
+    const ALICE: Address = address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
+    const BOB: Address = address!("B0B0cB49ec2e96DF5F5fFB081acaE66A2cBBc2e2");
+    const CAROL: Address = address!("CA701c8C5c4F9ba4A13B0D9E6D6dB6C0b1B2c3D4");
+
+    type Map = IterableMap<Address, StorageU256>;
+
+    #[motsu::test]
+    fn insert_and_enumerate(map: Map) {
+        assert!(map.is_empty());
+        assert!(map.insert(ALICE, uint!(1_U256)));
+        assert!(map.insert(BOB, uint!(2_U256)));
+        assert!(!map.insert(ALICE, uint!(3_U256)));
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(ALICE), uint!(3_U256));
+        assert_eq!(map.get(CAROL), U256::ZERO);
+        assert!(map.contains_key(BOB));
+        assert!(!map.contains_key(CAROL));
+        assert_eq!(map.keys(), vec![ALICE, BOB]);
+        assert_eq!(map.key_at(1), Some(BOB));
+        assert_eq!(map.key_at(2), None);
+        assert_eq!(map.keys_page(1, 5), vec![BOB]);
+    }
+
+    #[motsu::test]
+    fn setter_tracks_key(map: Map) {
+        map.setter(CAROL).set(uint!(5_U256));
+
+        assert!(map.contains_key(CAROL));
+        assert_eq!(map.get(CAROL), uint!(5_U256));
+        assert_eq!(map.keys(), vec![CAROL]);
+    }
+
+    #[motsu::test]
+    fn remove(map: Map) {
+        map.insert(ALICE, uint!(1_U256));
+        map.insert(BOB, uint!(2_U256));
+        map.insert(CAROL, uint!(3_U256));
+
+        assert!(map.remove(ALICE));
+        assert!(!map.remove(ALICE));
+        assert_eq!(map.keys(), vec![CAROL, BOB]);
+        assert_eq!(map.get(ALICE), U256::ZERO);
+        assert!(!map.contains_key(ALICE));
+
+        assert!(map.remove(BOB));
+        assert_eq!(map.keys(), vec![CAROL]);
+
+        assert!(map.insert(ALICE, uint!(4_U256)));
+        assert_eq!(map.keys(), vec![CAROL, ALICE]);
+        assert!(map.remove(CAROL));
+        assert!(map.remove(ALICE));
+        assert!(map.is_empty());
+    }
+}
@@ -0,0 +1,280 @@
+//! Storage list with the operations commonly needed on top of a dynamic
+//! storage array.
+//!
+//! [`StorageList`] wraps a [`StorageVec`] of simple values (e.g. addresses or
+//! numbers) and adds removal in constant time with
+//! [`StorageList::swap_remove`], membership checks, copying to memory and
+//! bounded pagination with [`StorageList::page`], so that contracts can expose
+//! lists, like payees or token ids, without ever reading an unbounded amount
+//! of storage in a single call.
+//!
+//! ```rust,ignore
+//! sol_storage! {
+//!     pub struct Splitter {
+//!         StorageList<StorageAddress> _payees;
+//!     }
+//! }
+//! ```
+//!
+//! NOTE: Checking membership with [`StorageList::contains`] reads the whole
+//! list. Pair the list with a mapping when membership is checked on every
+//! call.
+use alloc::vec::Vec;
+
+use alloy_primitives::U256;
+use stylus_sdk::storage::{
+    SimpleStorageType, StorageGuard, StorageGuardMut, StorageType, StorageVec,
+};
+
+/// Accessor for a storage-backed list of simple values.
+pub struct StorageList<S: StorageType> {
+    inner: StorageVec<S>,
+}
+
+impl<S: StorageType> StorageType for StorageList<S> {
+    type Wraps<'a>
+        = StorageGuard<'a, StorageList<S>>
+    where
+        Self: 'a;
+    type WrapsMut<'a>
+        = StorageGuardMut<'a, StorageList<S>>
+    where
+        Self: 'a;
+
+    unsafe fn new(slot: U256, offset: u8) -> Self {
+        Self { inner: StorageVec::new(slot, offset) }
+    }
+
+    fn load<'s>(self) -> Self::Wraps<'s> {
+        StorageGuard::new(self)
+    }
+
+    fn load_mut<'s>(self) -> Self::WrapsMut<'s> {
+        StorageGuardMut::new(self)
+    }
+}
+
+impl<S: StorageType> StorageList<S> {
+    /// Returns the number of elements in the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the list's state.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns true if the list contains no elements, and false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the list's state.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl<S, T> StorageList<S>
+where
+    S: for<'a> SimpleStorageType<'a> + for<'a> StorageType<Wraps<'a> = T>,
+    T: PartialEq,
+{
+    /// Returns the element at `index`, or `None` if it is out of bounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the list's state.
+    /// * `index` - Index of the element.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<T> {
+        self.inner.get(index)
+    }
+
+    /// Sets the element at `index` to `value`.
+    ///
+    /// Returns false if `index` is out of bounds, and true otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the list's state.
+    /// * `index` - Index of the element.
+    /// * `value` - New value of the element.
+    pub fn set(&mut self, index: usize, value: T) -> bool {
+        match self.inner.setter(index) {
+            Some(mut element) => {
+                element.set_by_wrapped(value);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Appends `value` to the end of the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the list's state.
+    /// * `value` - Value to append.
+    pub fn push(&mut self, value: T) {
+        self.inner.push(value);
+    }
+
+    /// Removes the last element of the list and returns it, or `None` if the
+    /// list is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the list's state.
+    pub fn pop(&mut self) -> Option<T> {
+        self.inner.pop()
+    }
+
+    /// Removes the element at `index` and returns it, or `None` if `index` is
+    /// out of bounds.
+    ///
+    /// The removed element is replaced by the last element of the list, so
+    /// this doesn't preserve ordering, but runs in constant time.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the list's state.
+    /// * `index` - Index of the element to remove.
+    pub fn swap_remove(&mut self, index: usize) -> Option<T> {
+        let removed = self.get(index)?;
+        let last = self.inner.pop()?;
+        if index < self.len() {
+            self.set(index, last);
+        }
+        Some(removed)
+    }
+
+    /// Returns the index of the first element equal to `value`, or `None` if
+    /// there is no such element.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the list's state.
+    /// * `value` - Value to look for.
+    #[must_use]
+    pub fn position(&self, value: &T) -> Option<usize> {
+        (0..self.len()).find(|&i| self.get(i).as_ref() == Some(value))
+    }
+
+    /// Returns true if the list contains `value`, and false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the list's state.
+    /// * `value` - Value to look for.
+    #[must_use]
+    pub fn contains(&self, value: &T) -> bool {
+        self.position(value).is_some()
+    }
+
+    /// Copies all the elements of the list to memory.
+    ///
+    /// NOTE: This reads the whole list, which may exceed the gas limit for
+    /// long lists. Prefer [`Self::page`] in public functions.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the list's state.
+    #[must_use]
+    pub fn to_vec(&self) -> Vec<T> {
+        self.page(0, self.len())
+    }
+
+    /// Copies at most `limit` elements of the list to memory, starting from
+    /// `offset`.
+    ///
+    /// Returns fewer elements when the end of the list is reached, and none
+    /// if `offset` is out of bounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the list's state.
+    /// * `offset` - Index of the first element to copy.
+    /// * `limit` - Maximum number of elements to copy.
+    #[must_use]
+    pub fn page(&self, offset: usize, limit: usize) -> Vec<T> {
+        let end = offset.saturating_add(limit).min(self.len());
+        (offset..end).filter_map(|i| self.get(i)).collect()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::vec;
+
+    use alloy_primitives::{uint, U256};
+    use stylus_sdk::storage::StorageU256;
+
+    use super::StorageList;
+
+    type List = StorageList<StorageU256>;
+
+    fn filled(list: &mut List, len: u64) {
+        for i in 0..len {
+            list.push(U256::from(i));
+        }
+    }
+
+    #[motsu::test]
+    fn push_get_set_pop(list: List) {
+        assert!(list.is_empty());
+        list.push(uint!(7_U256));
+        list.push(uint!(8_U256));
+
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.get(1), Some(uint!(8_U256)));
+        assert_eq!(list.get(2), None);
+
+        assert!(list.set(0, uint!(9_U256)));
+        assert!(!list.set(2, uint!(9_U256)));
+        assert_eq!(list.get(0), Some(uint!(9_U256)));
+
+        assert_eq!(list.pop(), Some(uint!(8_U256)));
+        assert_eq!(list.pop(), Some(uint!(9_U256)));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[motsu::test]
+    fn swap_remove(list: List) {
+        filled(list, 4);
+
+        assert_eq!(list.swap_remove(1), Some(uint!(1_U256)));
+        assert_eq!(
+            list.to_vec(),
+            vec![uint!(0_U256), uint!(3_U256), uint!(2_U256)]
+        );
+
+        assert_eq!(list.swap_remove(2), Some(uint!(2_U256)));
+        assert_eq!(list.to_vec(), vec![uint!(0_U256), uint!(3_U256)]);
+
+        assert_eq!(list.swap_remove(5), None);
+        assert_eq!(list.to_vec(), vec![uint!(0_U256), uint!(3_U256)]);
+    }
+
+    #[motsu::test]
+    fn contains(list: List) {
+        filled(list, 3);
+
+        assert!(list.contains(&uint!(2_U256)));
+        assert!(!list.contains(&uint!(3_U256)));
+        assert_eq!(list.position(&uint!(1_U256)), Some(1));
+        assert_eq!(list.position(&uint!(3_U256)), None);
+    }
+
+    #[motsu::test]
+    fn page(list: List) {
+        filled(list, 5);
+
+        assert_eq!(list.page(1, 2), vec![uint!(1_U256), uint!(2_U256)]);
+        assert_eq!(list.page(3, 10), vec![uint!(3_U256), uint!(4_U256)]);
+        assert!(list.page(5, 1).is_empty());
+        assert!(list.page(usize::MAX, usize::MAX).is_empty());
+        assert_eq!(list.to_vec().len(), 5);
+    }
+}
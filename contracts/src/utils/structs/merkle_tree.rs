@@ -0,0 +1,219 @@
+//! Storage-backed append-only Merkle tree of fixed depth.
+//!
+//! Largely inspired by the `MerkleTree` library of `OpenZeppelin`'s Solidity
+//! contracts: a [`MerkleTree`] only stores one node per level, plus the
+//! hashes of empty subtrees, so that pushing a leaf costs `depth` hashes.
+//! Nodes are hashed with the commutative `keccak256`, so leaves can be
+//! proven against the root with the proofs verified by
+//! `openzeppelin_crypto::merkle::Verifier`.
+//!
+//! It is the foundation of contracts like deposit queues or privacy pools,
+//! which need to commit to a growing set of leaves.
+//!
+//! NOTE: Calling [`MerkleTree::setup`] again resets the tree, which should
+//! generally be avoided once leaves have been pushed.
+use alloy_primitives::{B256, U256};
+use alloy_sol_types::sol;
+use openzeppelin_stylus_proc::SolidityError;
+use stylus_sdk::{call::MethodError, crypto::keccak, stylus_proc::sol_storage};
+
+sol! {
+    /// Indicates an error related to a push to a tree of `depth` levels
+    /// that already contains `2^depth` leaves.
+    ///
+    /// * `depth` - Depth of the tree.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error MerkleTreeFull(uint8 depth);
+}
+
+/// A Merkle Tree error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// The tree can't contain more leaves.
+    Full(MerkleTreeFull),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> alloc::vec::Vec<u8> {
+        self.into()
+    }
+}
+
+sol_storage! {
+    /// State of an append-only Merkle tree.
+    pub struct MerkleTree {
+        /// Index of the next leaf to push.
+        uint256 _next_leaf_index;
+        /// Rightmost left node of each level.
+        bytes32[] _sides;
+        /// Hash of an empty subtree of each level.
+        bytes32[] _zeros;
+        /// Current root of the tree.
+        bytes32 _root;
+    }
+}
+
+impl MerkleTree {
+    /// Initializes an empty tree of `depth` levels, whose empty leaves are
+    /// `zero`.
+    ///
+    /// Returns the root of the empty tree.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the tree's state.
+    /// * `depth` - Number of levels of the tree, excluding the root.
+    /// * `zero` - Value of the empty leaves.
+    pub fn setup(&mut self, depth: u8, zero: B256) -> B256 {
+        self._sides.truncate(0);
+        self._zeros.truncate(0);
+
+        let mut current = zero;
+        for _ in 0..depth {
+            self._sides.push(B256::ZERO);
+            self._zeros.push(current);
+            current = commutative_keccak256(current, current);
+        }
+
+        self._next_leaf_index.set(U256::ZERO);
+        self._root.set(current);
+        current
+    }
+
+    /// Returns the current root of the tree.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the tree's state.
+    #[must_use]
+    pub fn root(&self) -> B256 {
+        self._root.get()
+    }
+
+    /// Returns the number of levels of the tree, excluding the root.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the tree's state.
+    #[must_use]
+    pub fn depth(&self) -> u8 {
+        // The depth is set from a `u8` in `Self::setup`.
+        u8::try_from(self._zeros.len()).unwrap_or(u8::MAX)
+    }
+
+    /// Returns the number of leaves pushed to the tree.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the tree's state.
+    #[must_use]
+    pub fn leaf_count(&self) -> U256 {
+        self._next_leaf_index.get()
+    }
+
+    /// Pushes `leaf` to the tree.
+    ///
+    /// Returns the index of the leaf and the new root of the tree.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the tree's state.
+    /// * `leaf` - Leaf to push.
+    ///
+    /// # Errors
+    ///
+    /// If the tree already contains `2^depth` leaves, then the error
+    /// [`Error::Full`] is returned.
+    pub fn push(&mut self, leaf: B256) -> Result<(U256, B256), Error> {
+        let depth = self.depth();
+        let index = self._next_leaf_index.get();
+        if index >= U256::from(1) << depth {
+            return Err(Error::Full(MerkleTreeFull { depth }));
+        }
+
+        let mut current_index = index;
+        let mut current = leaf;
+        for level in 0..usize::from(depth) {
+            let (left, right) = if current_index.bit(0) {
+                let side = self._sides.get(level).unwrap_or_default();
+                (side, current)
+            } else {
+                if let Some(mut side) = self._sides.setter(level) {
+                    side.set(current);
+                }
+                (current, self._zeros.get(level).unwrap_or_default())
+            };
+            current = commutative_keccak256(left, right);
+            current_index >>= 1;
+        }
+
+        self._next_leaf_index.set(index + U256::from(1));
+        self._root.set(current);
+        Ok((index, current))
+    }
+}
+
+/// Hashes the sorted pair `(a, b)` with `keccak256`.
+fn commutative_keccak256(a: B256, b: B256) -> B256 {
+    let (a, b) = if a < b { (a, b) } else { (b, a) };
+    keccak([a.as_slice(), b.as_slice()].concat())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{uint, B256, U256};
+
+    use super::{commutative_keccak256 as hash, Error, MerkleTree};
+
+    const ZERO: B256 = B256::ZERO;
+
+    #[motsu::test]
+    fn setup_computes_empty_root(tree: MerkleTree) {
+        let zero_1 = hash(ZERO, ZERO);
+        let root = tree.setup(2, ZERO);
+
+        assert_eq!(root, hash(zero_1, zero_1));
+        assert_eq!(tree.root(), root);
+        assert_eq!(tree.depth(), 2);
+        assert_eq!(tree.leaf_count(), U256::ZERO);
+    }
+
+    #[motsu::test]
+    fn pushes_leaves(tree: MerkleTree) {
+        let leaves = [B256::repeat_byte(1), B256::repeat_byte(2)];
+        tree.setup(2, ZERO);
+
+        let (index, root) = tree.push(leaves[0]).expect("should push leaf");
+        assert_eq!(index, U256::ZERO);
+        assert_eq!(root, hash(hash(leaves[0], ZERO), hash(ZERO, ZERO)));
+
+        let (index, root) = tree.push(leaves[1]).expect("should push leaf");
+        assert_eq!(index, uint!(1_U256));
+        assert_eq!(root, hash(hash(leaves[0], leaves[1]), hash(ZERO, ZERO)));
+        assert_eq!(tree.root(), root);
+        assert_eq!(tree.leaf_count(), uint!(2_U256));
+    }
+
+    #[motsu::test]
+    fn errors_when_full(tree: MerkleTree) {
+        tree.setup(1, ZERO);
+        tree.push(B256::repeat_byte(1)).expect("should push leaf");
+        tree.push(B256::repeat_byte(2)).expect("should push leaf");
+
+        let err = tree.push(B256::repeat_byte(3)).expect_err("should be full");
+        assert!(matches!(err, Error::Full(_)));
+    }
+
+    #[motsu::test]
+    fn setup_resets_tree(tree: MerkleTree) {
+        tree.setup(1, ZERO);
+        tree.push(B256::repeat_byte(1)).expect("should push leaf");
+        let root = tree.setup(3, B256::repeat_byte(9));
+
+        assert_eq!(tree.leaf_count(), U256::ZERO);
+        assert_eq!(tree.depth(), 3);
+        assert_eq!(tree.root(), root);
+    }
+}
@@ -1,3 +1,6 @@
 //! Solidity storage types used by other contracts.
 pub mod bitmap;
 pub mod checkpoints;
+pub mod iterable_map;
+pub mod list;
+pub mod merkle_tree;
@@ -0,0 +1,455 @@
+//! Token Rescuer mixin.
+//!
+//! Mixin which allows a contract to rescue assets sent to it by mistake:
+//! ERC-20 tokens, ERC-721 tokens and Ether.
+//!
+//! It provides the functions [`TokenRescuer::rescue_erc20`],
+//! [`TokenRescuer::rescue_erc721`] and [`TokenRescuer::rescue_eth`], which
+//! can be exposed by your contract. Each of them first calls
+//! [`TokenRescuer::_check_rescuer`], which the contract implements with its
+//! own access control, e.g. an [`crate::access::ownable::Ownable::only_owner`]
+//! or an [`crate::access::control::AccessControl::only_role`] check.
+//!
+//! Assets managed by the contract itself, like the tokens held by a vesting
+//! wallet or a payment splitter, must never be rescuable. Contracts exclude
+//! them by overriding [`TokenRescuer::is_protected_asset`].
+//!
+//! ```rust,ignore
+//! impl TokenRescuer for Splitter {
+//!     fn _check_rescuer(&self) -> Result<(), Error> {
+//!         self.ownable.only_owner().map_err(|_| {
+//!             TokenRescuerUnauthorizedAccount { account: msg::sender() }.into()
+//!         })
+//!     }
+//!
+//!     fn is_protected_asset(&self, asset: Address) -> bool {
+//!         asset == self.token.get()
+//!     }
+//! }
+//!
+//! #[public]
+//! impl Splitter {
+//!     fn rescue_erc20(
+//!         &mut self,
+//!         token: Address,
+//!         to: Address,
+//!         amount: U256,
+//!     ) -> Result<(), Vec<u8>> {
+//!         TokenRescuer::rescue_erc20(self, token, to, amount)?;
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use alloc::vec::Vec;
+
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::sol;
+use openzeppelin_stylus_proc::SolidityError;
+use stylus_sdk::{
+    call::{self, Call, MethodError},
+    contract, evm,
+    storage::TopLevelStorage,
+};
+
+use crate::{
+    interfaces::erc721::IERC721,
+    token::erc20::utils::safe_erc20::{call_optional_return, IErc20},
+};
+
+/// Asset identifier used for Ether in [`TokenRescuer::is_protected_asset`]
+/// and in the [`AssetRescued`] event.
+pub const ETH_ASSET: Address = Address::ZERO;
+
+sol! {
+    /// Emitted when `amount` (or the token `amount` for ERC-721) of `asset`
+    /// is rescued to `to`.
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    event AssetRescued(address indexed asset, address indexed to, uint256 amount);
+}
+
+sol! {
+    /// Indicates an error related to an `account` that is not allowed to
+    /// rescue assets.
+    ///
+    /// * `account` - Account that requested the rescue.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error TokenRescuerUnauthorizedAccount(address account);
+
+    /// Indicates an error related to an `asset` managed by the contract,
+    /// that cannot be rescued.
+    ///
+    /// * `asset` - Address of the protected asset.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error TokenRescuerProtectedAsset(address asset);
+
+    /// Indicates an error related to the recipient `to` of a rescue.
+    ///
+    /// * `to` - Address of the recipient.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error TokenRescuerInvalidRecipient(address to);
+
+    /// Indicates an error related to a failed transfer of `asset`.
+    ///
+    /// * `asset` - Address of the asset.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    error TokenRescuerFailedTransfer(address asset);
+}
+
+/// A Token Rescuer error.
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    /// The account is not allowed to rescue assets.
+    UnauthorizedAccount(TokenRescuerUnauthorizedAccount),
+    /// The asset is managed by the contract and cannot be rescued.
+    ProtectedAsset(TokenRescuerProtectedAsset),
+    /// The recipient of the rescue is invalid.
+    InvalidRecipient(TokenRescuerInvalidRecipient),
+    /// The transfer of the asset failed.
+    FailedTransfer(TokenRescuerFailedTransfer),
+}
+
+impl MethodError for Error {
+    fn encode(self) -> Vec<u8> {
+        self.into()
+    }
+}
+
+/// Rescue mixin, implemented by contracts that should be able to recover
+/// assets sent to them by mistake.
+///
+/// Every rescue is gated by [`Self::_check_rescuer`], which the contract
+/// must implement.
+pub trait TokenRescuer: TopLevelStorage + Sized {
+    /// Checks that the caller is allowed to rescue assets, e.g. that it is
+    /// the owner of the contract.
+    ///
+    /// Called before every rescue.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    ///
+    /// # Errors
+    ///
+    /// Should return [`Error::UnauthorizedAccount`] if the caller is not
+    /// allowed to rescue assets.
+    fn _check_rescuer(&self) -> Result<(), Error>;
+
+    /// Returns true if `asset` is managed by the contract and must not be
+    /// rescued, and false otherwise. Ether is identified by [`ETH_ASSET`].
+    ///
+    /// No asset is protected by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `&self` - Read access to the contract's state.
+    /// * `asset` - Address of the asset.
+    fn is_protected_asset(&self, asset: Address) -> bool {
+        let _ = asset;
+        false
+    }
+
+    /// Transfers `amount` of the ERC-20 `token` held by the contract to `to`.
+    ///
+    /// Like [`SafeErc20::safe_transfer`], accepts tokens that return no
+    /// value, e.g. USDT.
+    ///
+    /// [`SafeErc20::safe_transfer`]: crate::token::erc20::utils::safe_erc20::SafeErc20::safe_transfer
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `token` - Address of the ERC-20 token.
+    /// * `to` - Address of the recipient.
+    /// * `amount` - Amount of tokens to rescue.
+    ///
+    /// # Errors
+    ///
+    /// If the caller is not allowed to rescue assets, then the error of
+    /// [`Self::_check_rescuer`] is returned.
+    /// If `token` is protected, then the error [`Error::ProtectedAsset`] is
+    /// returned.
+    /// If `to` is `Address::ZERO`, then the error [`Error::InvalidRecipient`]
+    /// is returned.
+    /// If `token` has no code, or the token transfer reverts or returns
+    /// false, then the error [`Error::FailedTransfer`] is returned.
+    ///
+    /// # Events
+    ///
+    /// Emits an [`AssetRescued`] event.
+    fn rescue_erc20(
+        &mut self,
+        token: Address,
+        to: Address,
+        amount: U256,
+    ) -> Result<(), Error> {
+        check_rescue(self, token, to)?;
+
+        call_optional_return(
+            token,
+            &IErc20::transferCall { to, value: amount },
+        )
+        .map_err(|_| TokenRescuerFailedTransfer { asset: token })?;
+
+        evm::log(AssetRescued { asset: token, to, amount });
+        Ok(())
+    }
+
+    /// Transfers the ERC-721 `token_id` token of `token` held by the contract
+    /// to `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `token` - Address of the ERC-721 token.
+    /// * `to` - Address of the recipient.
+    /// * `token_id` - Token id to rescue.
+    ///
+    /// # Errors
+    ///
+    /// If the caller is not allowed to rescue assets, then the error of
+    /// [`Self::_check_rescuer`] is returned.
+    /// If `token` is protected, then the error [`Error::ProtectedAsset`] is
+    /// returned.
+    /// If `to` is `Address::ZERO`, then the error [`Error::InvalidRecipient`]
+    /// is returned.
+    /// If the token transfer reverts, then the error
+    /// [`Error::FailedTransfer`] is returned.
+    ///
+    /// # Events
+    ///
+    /// Emits an [`AssetRescued`] event.
+    fn rescue_erc721(
+        &mut self,
+        token: Address,
+        to: Address,
+        token_id: U256,
+    ) -> Result<(), Error> {
+        check_rescue(self, token, to)?;
+
+        let erc721 = IERC721::new(token);
+        erc721
+            .transfer_from(
+                Call::new_in(self),
+                contract::address(),
+                to,
+                token_id,
+            )
+            .map_err(|_| TokenRescuerFailedTransfer { asset: token })?;
+
+        evm::log(AssetRescued { asset: token, to, amount: token_id });
+        Ok(())
+    }
+
+    /// Transfers `amount` of Ether held by the contract to `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `&mut self` - Write access to the contract's state.
+    /// * `to` - Address of the recipient.
+    /// * `amount` - Amount of Ether to rescue, in wei.
+    ///
+    /// # Errors
+    ///
+    /// If the caller is not allowed to rescue assets, then the error of
+    /// [`Self::_check_rescuer`] is returned.
+    /// If Ether is protected, then the error [`Error::ProtectedAsset`] is
+    /// returned.
+    /// If `to` is `Address::ZERO`, then the error [`Error::InvalidRecipient`]
+    /// is returned.
+    /// If the transfer reverts, then the error [`Error::FailedTransfer`] is
+    /// returned.
+    ///
+    /// # Events
+    ///
+    /// Emits an [`AssetRescued`] event.
+    fn rescue_eth(&mut self, to: Address, amount: U256) -> Result<(), Error> {
+        check_rescue(self, ETH_ASSET, to)?;
+
+        call::transfer_eth(to, amount)
+            .map_err(|_| TokenRescuerFailedTransfer { asset: ETH_ASSET })?;
+
+        evm::log(AssetRescued { asset: ETH_ASSET, to, amount });
+        Ok(())
+    }
+}
+
+/// Checks that the caller can rescue `asset` to `to`.
+///
+/// # Arguments
+///
+/// * `rescuer` - Read access to the rescuing contract's state.
+/// * `asset` - Address of the asset.
+/// * `to` - Address of the recipient.
+///
+/// # Errors
+///
+/// If [`TokenRescuer::_check_rescuer`] fails, then its error is returned.
+/// If `asset` is protected, then the error [`Error::ProtectedAsset`] is
+/// returned.
+/// If `to` is `Address::ZERO`, then the error [`Error::InvalidRecipient`] is
+/// returned.
+fn check_rescue(
+    rescuer: &impl TokenRescuer,
+    asset: Address,
+    to: Address,
+) -> Result<(), Error> {
+    rescuer._check_rescuer()?;
+    if rescuer.is_protected_asset(asset) {
+        return Err(TokenRescuerProtectedAsset { asset }.into());
+    }
+    if to.is_zero() {
+        return Err(TokenRescuerInvalidRecipient { to }.into());
+    }
+    Ok(())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloy_primitives::{address, uint, Address};
+    use alloy_sol_types::{SolCall, SolValue};
+    use motsu::prelude::mock_call;
+    use stylus_sdk::{msg, prelude::sol_storage, storage::TopLevelStorage};
+
+    use super::{
+        Error, TokenRescuer, TokenRescuerFailedTransfer,
+        TokenRescuerProtectedAsset, TokenRescuerUnauthorizedAccount, ETH_ASSET,
+    };
+    use crate::token::erc20::utils::safe_erc20::IErc20;
+
+    const ALICE: Address = address!("A11CEacF9aa32246d767FCCD72e02d6bCbcC375d");
+    const MANAGED: Address =
+        address!("000000000000000000000000000000000000dead");
+    const TOKEN: Address = address!("000000000000000000000000000000000000beef");
+
+    sol_storage! {
+        struct Rescuer {
+            address owner;
+        }
+    }
+
+    unsafe impl TopLevelStorage for Rescuer {}
+
+    impl TokenRescuer for Rescuer {
+        fn _check_rescuer(&self) -> Result<(), Error> {
+            let account = msg::sender();
+            if account != self.owner.get() {
+                return Err(TokenRescuerUnauthorizedAccount { account }.into());
+            }
+            Ok(())
+        }
+
+        fn is_protected_asset(&self, asset: Address) -> bool {
+            asset == MANAGED
+        }
+    }
+
+    #[motsu::test]
+    fn rejects_unauthorized_account(contract: Rescuer) {
+        let err = contract
+            .rescue_erc20(TOKEN, ALICE, uint!(1_U256))
+            .expect_err("should not rescue for non-owner");
+        assert!(matches!(
+            err,
+            Error::UnauthorizedAccount(TokenRescuerUnauthorizedAccount {
+                account
+            }) if account == msg::sender()
+        ));
+
+        let err = contract
+            .rescue_erc721(TOKEN, ALICE, uint!(1_U256))
+            .expect_err("should not rescue for non-owner");
+        assert!(matches!(err, Error::UnauthorizedAccount(_)));
+
+        let err = contract
+            .rescue_eth(ALICE, uint!(1_U256))
+            .expect_err("should not rescue for non-owner");
+        assert!(matches!(err, Error::UnauthorizedAccount(_)));
+    }
+
+    #[motsu::test]
+    fn rejects_protected_asset(contract: Rescuer) {
+        contract.owner.set(msg::sender());
+        let err = contract
+            .rescue_erc20(MANAGED, ALICE, uint!(1_U256))
+            .expect_err("should not rescue protected asset");
+        assert!(matches!(
+            err,
+            Error::ProtectedAsset(TokenRescuerProtectedAsset { asset })
+                if asset == MANAGED
+        ));
+
+        let err = contract
+            .rescue_erc721(MANAGED, ALICE, uint!(1_U256))
+            .expect_err("should not rescue protected asset");
+        assert!(matches!(err, Error::ProtectedAsset(_)));
+    }
+
+    #[motsu::test]
+    fn rejects_invalid_recipient(contract: Rescuer) {
+        contract.owner.set(msg::sender());
+        let err = contract
+            .rescue_erc20(TOKEN, Address::ZERO, uint!(1_U256))
+            .expect_err("should not rescue to zero address");
+        assert!(matches!(err, Error::InvalidRecipient(_)));
+
+        let err = contract
+            .rescue_eth(Address::ZERO, uint!(1_U256))
+            .expect_err("should not rescue to zero address");
+        assert!(matches!(err, Error::InvalidRecipient(_)));
+    }
+
+    #[motsu::test]
+    fn errors_when_erc20_transfer_fails(contract: Rescuer) {
+        contract.owner.set(msg::sender());
+
+        // `TOKEN` has no code until its calls are mocked.
+        let err = contract
+            .rescue_erc20(TOKEN, ALICE, uint!(1_U256))
+            .expect_err("should fail to transfer");
+        assert!(matches!(
+            err,
+            Error::FailedTransfer(TokenRescuerFailedTransfer { asset })
+                if asset == TOKEN
+        ));
+    }
+
+    #[motsu::test]
+    fn rescues_erc20_without_return_value(contract: Rescuer) {
+        contract.owner.set(msg::sender());
+        let selector = IErc20::transferCall::SELECTOR;
+
+        mock_call(TOKEN, selector, vec![]);
+        contract
+            .rescue_erc20(TOKEN, ALICE, uint!(1_U256))
+            .expect("should accept no return data");
+
+        mock_call(TOKEN, selector, true.abi_encode());
+        contract
+            .rescue_erc20(TOKEN, ALICE, uint!(1_U256))
+            .expect("should accept `true`");
+
+        mock_call(TOKEN, selector, false.abi_encode());
+        let err = contract
+            .rescue_erc20(TOKEN, ALICE, uint!(1_U256))
+            .expect_err("should reject `false`");
+        assert!(matches!(err, Error::FailedTransfer(_)));
+    }
+
+    #[motsu::test]
+    fn rescues_eth(contract: Rescuer) {
+        contract.owner.set(msg::sender());
+        assert!(!contract.is_protected_asset(ETH_ASSET));
+        contract.rescue_eth(ALICE, uint!(1_U256)).expect("should rescue eth");
+    }
+}
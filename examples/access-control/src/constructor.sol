@@ -13,6 +13,8 @@ contract AccessControlExample {
 
     mapping(bytes32 role => RoleData) private _roles;
 
+    bool private _paused;
+
     bytes32 public constant DEFAULT_ADMIN_ROLE = 0x00;
 
     event RoleGranted(
@@ -1,4 +1,4 @@
-#![cfg_attr(not(test), no_std, no_main)]
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
 extern crate alloc;
 
 use alloc::vec::Vec;
@@ -7,7 +7,9 @@ use alloy_primitives::{Address, B256, U256};
 use openzeppelin_stylus::{
     access::control::AccessControl,
     token::erc20::{Erc20, IErc20},
+    utils::Pausable,
 };
+use openzeppelin_stylus_proc::{only_role, selectors, when_not_paused};
 use stylus_sdk::prelude::{entrypoint, public, sol_storage};
 
 sol_storage! {
@@ -17,6 +19,7 @@ sol_storage! {
         Erc20 erc20;
         #[borrow]
         AccessControl access;
+        Pausable pausable;
     }
 }
 
@@ -26,29 +29,67 @@ pub const TRANSFER_ROLE: [u8; 32] = [
     166, 190, 60, 19, 139, 159, 191, 0, 55, 82, 164, 200, 188, 232, 111, 108,
 ];
 
+// `keccak256("MINTER_ROLE")`
+pub const MINTER_ROLE: [u8; 32] = [
+    159, 45, 240, 254, 210, 199, 118, 72, 222, 88, 96, 164, 204, 80, 140, 208,
+    129, 140, 133, 184, 184, 161, 171, 76, 238, 239, 141, 152, 28, 137, 86,
+    166,
+];
+
+// `keccak256("PAUSER_ROLE")`
+pub const PAUSER_ROLE: [u8; 32] = [
+    101, 215, 162, 142, 50, 101, 179, 122, 100, 116, 146, 159, 51, 101, 33,
+    179, 50, 193, 104, 27, 147, 63, 108, 185, 243, 55, 102, 115, 68, 13, 134,
+    42,
+];
+
+#[selectors]
 #[public]
 #[inherit(Erc20, AccessControl)]
 impl AccessControlExample {
     pub const TRANSFER_ROLE: [u8; 32] = TRANSFER_ROLE;
 
+    #[only_role(AccessControl::DEFAULT_ADMIN_ROLE)]
     pub fn make_admin(&mut self, account: Address) -> Result<(), Vec<u8>> {
-        self.access.only_role(AccessControl::DEFAULT_ADMIN_ROLE.into())?;
         self.access
             .grant_role(AccessControlExample::TRANSFER_ROLE.into(), account)?;
         Ok(())
     }
 
+    #[only_role("TRANSFER_ROLE")]
     pub fn transfer_from(
         &mut self,
         from: Address,
         to: Address,
         value: U256,
     ) -> Result<bool, Vec<u8>> {
-        self.access.only_role(AccessControlExample::TRANSFER_ROLE.into())?;
         let transfer_result = self.erc20.transfer_from(from, to, value)?;
         Ok(transfer_result)
     }
 
+    #[only_role(MINTER_ROLE)]
+    #[when_not_paused]
+    pub fn mint(&mut self, to: Address, value: U256) -> Result<(), Vec<u8>> {
+        self.erc20._mint(to, value)?;
+        Ok(())
+    }
+
+    pub fn paused(&self) -> bool {
+        self.pausable.when_paused().is_ok()
+    }
+
+    #[only_role(PAUSER_ROLE)]
+    pub fn pause(&mut self) -> Result<(), Vec<u8>> {
+        self.pausable.pause()?;
+        Ok(())
+    }
+
+    #[only_role(PAUSER_ROLE)]
+    pub fn unpause(&mut self) -> Result<(), Vec<u8>> {
+        self.pausable.unpause()?;
+        Ok(())
+    }
+
     // WARNING: This should not be part of the public API, it's here for testing
     // purposes only.
     pub fn set_role_admin(&mut self, role: B256, new_admin_role: B256) {
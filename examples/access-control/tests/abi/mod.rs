@@ -12,8 +12,15 @@ sol!(
 
         function setRoleAdmin(bytes32 role, bytes32 adminRole) public virtual;
 
+        function balanceOf(address account) external view returns (uint256 balance);
+        function mint(address to, uint256 value) external;
+        function paused() external view returns (bool paused);
+        function pause() external;
+        function unpause() external;
+
         error AccessControlUnauthorizedAccount(address account, bytes32 neededRole);
         error AccessControlBadConfirmation();
+        error EnforcedPause();
 
         #[derive(Debug, PartialEq)]
         event RoleAdminChanged(bytes32 indexed role, bytes32 indexed previousAdminRole, bytes32 indexed newAdminRole);
@@ -21,5 +28,11 @@ sol!(
         event RoleGranted(bytes32 indexed role, address indexed account, address indexed sender);
         #[derive(Debug, PartialEq)]
         event RoleRevoked(bytes32 indexed role, address indexed account, address indexed sender);
+        #[derive(Debug, PartialEq)]
+        event Transfer(address indexed from, address indexed to, uint256 value);
+        #[derive(Debug, PartialEq)]
+        event Paused(address account);
+        #[derive(Debug, PartialEq)]
+        event Unpaused(address account);
     }
 );
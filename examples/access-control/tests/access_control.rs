@@ -2,9 +2,11 @@
 
 use abi::AccessControl::{
     self, AccessControlBadConfirmation, AccessControlUnauthorizedAccount,
-    RoleAdminChanged, RoleGranted, RoleRevoked,
+    EnforcedPause, Paused, RoleAdminChanged, RoleGranted, RoleRevoked,
+    Transfer, Unpaused,
 };
-use alloy::hex;
+use alloy::{hex, primitives::Address};
+use alloy_primitives::uint;
 use e2e::{receipt, send, watch, Account, EventExt, ReceiptExt, Revert};
 use eyre::Result;
 
@@ -13,6 +15,8 @@ mod abi;
 const DEFAULT_ADMIN_ROLE: [u8; 32] =
     openzeppelin_stylus::access::control::AccessControl::DEFAULT_ADMIN_ROLE;
 const ROLE: [u8; 32] = access_control_example::TRANSFER_ROLE;
+const MINTER_ROLE: [u8; 32] = access_control_example::MINTER_ROLE;
+const PAUSER_ROLE: [u8; 32] = access_control_example::PAUSER_ROLE;
 const NEW_ADMIN_ROLE: [u8; 32] =
     hex!("879ce0d4bfd332649ca3552efe772a38d64a315eb70ab69689fd309c735946b5");
 
@@ -417,3 +421,127 @@ async fn error_when_previous_admin_revokes_roles(
 
     Ok(())
 }
+
+// ============================================================================
+// Integration Tests: Roles of the Token
+// ============================================================================
+
+#[e2e::test]
+async fn minter_can_mint(alice: Account, bob: Account) -> Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.address()?;
+    let contract = AccessControl::new(contract_addr, &alice.wallet);
+    let contract_bob = AccessControl::new(contract_addr, &bob.wallet);
+
+    let alice_addr = alice.address();
+    let bob_addr = bob.address();
+    let value = uint!(10_U256);
+
+    let _ = watch!(contract.grantRole(MINTER_ROLE.into(), bob_addr))?;
+
+    let receipt = receipt!(contract_bob.mint(alice_addr, value))?;
+    assert!(receipt.emits(Transfer {
+        from: Address::ZERO,
+        to: alice_addr,
+        value
+    }));
+
+    let AccessControl::balanceOfReturn { balance } =
+        contract.balanceOf(alice_addr).call().await?;
+    assert_eq!(balance, value);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn error_when_non_minter_mints(alice: Account) -> Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.address()?;
+    let contract = AccessControl::new(contract_addr, &alice.wallet);
+
+    let alice_addr = alice.address();
+
+    let err = send!(contract.mint(alice_addr, uint!(1_U256)))
+        .expect_err("should not mint without the minter role");
+    assert!(err.reverted_with(AccessControlUnauthorizedAccount {
+        account: alice_addr,
+        neededRole: MINTER_ROLE.into()
+    }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn error_when_revoked_minter_mints(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.address()?;
+    let contract = AccessControl::new(contract_addr, &alice.wallet);
+    let contract_bob = AccessControl::new(contract_addr, &bob.wallet);
+
+    let bob_addr = bob.address();
+
+    let _ = watch!(contract.grantRole(MINTER_ROLE.into(), bob_addr))?;
+    let _ = watch!(contract.revokeRole(MINTER_ROLE.into(), bob_addr))?;
+
+    let err = send!(contract_bob.mint(bob_addr, uint!(1_U256)))
+        .expect_err("should not mint after the minter role is revoked");
+    assert!(err.reverted_with(AccessControlUnauthorizedAccount {
+        account: bob_addr,
+        neededRole: MINTER_ROLE.into()
+    }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn pauser_can_pause_and_unpause(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.address()?;
+    let contract = AccessControl::new(contract_addr, &alice.wallet);
+    let contract_bob = AccessControl::new(contract_addr, &bob.wallet);
+
+    let alice_addr = alice.address();
+    let bob_addr = bob.address();
+
+    let _ = watch!(contract.grantRole(PAUSER_ROLE.into(), bob_addr))?;
+    let _ = watch!(contract.grantRole(MINTER_ROLE.into(), alice_addr))?;
+
+    let receipt = receipt!(contract_bob.pause())?;
+    assert!(receipt.emits(Paused { account: bob_addr }));
+    let AccessControl::pausedReturn { paused } =
+        contract.paused().call().await?;
+    assert!(paused);
+
+    let err = send!(contract.mint(alice_addr, uint!(1_U256)))
+        .expect_err("should not mint while paused");
+    assert!(err.reverted_with(EnforcedPause {}));
+
+    let receipt = receipt!(contract_bob.unpause())?;
+    assert!(receipt.emits(Unpaused { account: bob_addr }));
+    let AccessControl::pausedReturn { paused } =
+        contract.paused().call().await?;
+    assert!(!paused);
+
+    let _ = watch!(contract.mint(alice_addr, uint!(1_U256)))?;
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn error_when_non_pauser_pauses(alice: Account) -> Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.address()?;
+    let contract = AccessControl::new(contract_addr, &alice.wallet);
+
+    let alice_addr = alice.address();
+
+    let err = send!(contract.pause())
+        .expect_err("should not pause without the pauser role");
+    assert!(err.reverted_with(AccessControlUnauthorizedAccount {
+        account: alice_addr,
+        neededRole: PAUSER_ROLE.into()
+    }));
+
+    Ok(())
+}
@@ -1,10 +1,11 @@
-#![cfg_attr(not(test), no_main, no_std)]
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main, no_std)]
 extern crate alloc;
 
 use alloc::vec::Vec;
 
 use alloy_primitives::{Address, U256};
 use openzeppelin_stylus::token::erc20::{extensions::Erc20Metadata, Erc20};
+use openzeppelin_stylus_proc::selectors;
 use stylus_sdk::prelude::{entrypoint, public, sol_storage};
 
 sol_storage! {
@@ -17,6 +18,7 @@ sol_storage! {
     }
 }
 
+#[selectors]
 #[public]
 #[inherit(Erc20, Erc20Metadata)]
 impl Erc20Example {
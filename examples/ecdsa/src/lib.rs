@@ -1,4 +1,4 @@
-#![cfg_attr(not(test), no_std, no_main)]
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
 extern crate alloc;
 
 use alloc::vec::Vec;
@@ -15,7 +15,7 @@ sol_storage! {
 #[public]
 impl ECDSAExample {
     pub fn recover(
-        &mut self,
+        &self,
         hash: B256,
         v: u8,
         r: B256,
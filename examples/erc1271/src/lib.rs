@@ -0,0 +1,65 @@
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use alloy_primitives::{Address, FixedBytes, B256};
+use openzeppelin_stylus::{
+    access::ownable::Ownable,
+    utils::cryptography::erc1271::{Erc1271, SessionKeys},
+};
+use openzeppelin_stylus_proc::{only_owner, selectors};
+use stylus_sdk::{
+    abi::Bytes,
+    prelude::{entrypoint, public, sol_storage},
+};
+
+sol_storage! {
+    #[entrypoint]
+    struct Erc1271Example {
+        #[borrow]
+        Ownable ownable;
+        SessionKeys session_keys;
+    }
+}
+
+impl Erc1271 for Erc1271Example {
+    fn _is_authorized_signer(&self, signer: Address) -> bool {
+        signer == self.ownable.owner()
+            || self.session_keys.is_valid_session_key(signer)
+    }
+}
+
+#[selectors]
+#[public]
+#[inherit(Ownable)]
+impl Erc1271Example {
+    #[selector(name = "isValidSignature")]
+    pub fn is_valid_signature(
+        &self,
+        hash: B256,
+        signature: Bytes,
+    ) -> FixedBytes<4> {
+        Erc1271::is_valid_signature(self, hash, &signature)
+    }
+
+    pub fn session_key_valid_until(&self, key: Address) -> u64 {
+        self.session_keys.session_key_valid_until(key)
+    }
+
+    #[only_owner]
+    pub fn add_session_key(
+        &mut self,
+        key: Address,
+        valid_until: u64,
+    ) -> Result<(), Vec<u8>> {
+        self.session_keys.add_session_key(key, valid_until)?;
+        Ok(())
+    }
+
+    #[only_owner]
+    pub fn revoke_session_key(&mut self, key: Address) -> Result<(), Vec<u8>> {
+        self.session_keys.revoke_session_key(key);
+        Ok(())
+    }
+}
@@ -0,0 +1,20 @@
+#![allow(dead_code)]
+use alloy::sol;
+
+sol!(
+    #[sol(rpc)]
+    contract Erc1271 {
+        function isValidSignature(bytes32 hash, bytes calldata signature) external view returns (bytes4 magicValue);
+        function sessionKeyValidUntil(address key) external view returns (uint64 validUntil);
+        function addSessionKey(address key, uint64 validUntil) external;
+        function revokeSessionKey(address key) external;
+
+        error OwnableUnauthorizedAccount(address account);
+        error SessionKeyInvalid(address key, uint64 validUntil);
+
+        #[derive(Debug, PartialEq)]
+        event SessionKeyAdded(address indexed key, uint64 validUntil);
+        #[derive(Debug, PartialEq)]
+        event SessionKeyRevoked(address indexed key);
+    }
+);
@@ -0,0 +1,139 @@
+#![cfg(feature = "e2e")]
+
+use abi::{
+    Erc1271,
+    Erc1271::{SessionKeyAdded, SessionKeyRevoked},
+};
+use alloy::{
+    primitives::{b256, fixed_bytes, Address, FixedBytes, B256},
+    sol,
+};
+use e2e::{receipt, send, Account, EventExt, ReceiptExt, Revert};
+use eyre::Result;
+
+use crate::Erc1271Example::constructorCall;
+
+mod abi;
+
+sol!("src/constructor.sol");
+
+const HASH: B256 =
+    b256!("a1de988600a42c4b4ab089b619297c17d53cffae5d5120d82d8a92d0bb3b78f2");
+const MAGIC_VALUE: FixedBytes<4> = fixed_bytes!("1626ba7e");
+const INVALID_VALUE: FixedBytes<4> = fixed_bytes!("ffffffff");
+
+fn ctr(owner: Address) -> constructorCall {
+    constructorCall { initialOwner: owner }
+}
+
+async fn is_valid_signature(
+    contract_addr: Address,
+    signer: &Account,
+) -> Result<FixedBytes<4>> {
+    let contract = Erc1271::new(contract_addr, &signer.wallet);
+    let signature = signer.sign_hash(&HASH).await;
+    let Erc1271::isValidSignatureReturn { magicValue } = contract
+        .isValidSignature(HASH, signature.as_bytes().into())
+        .call()
+        .await?;
+    Ok(magicValue)
+}
+
+// ============================================================================
+// Integration Tests: ERC-1271
+// ============================================================================
+
+#[e2e::test]
+async fn validates_owner_signatures(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(alice.address()))
+        .deploy()
+        .await?
+        .address()?;
+
+    assert_eq!(is_valid_signature(contract_addr, &alice).await?, MAGIC_VALUE);
+    assert_eq!(is_valid_signature(contract_addr, &bob).await?, INVALID_VALUE);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn validates_session_key_signatures(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(alice.address()))
+        .deploy()
+        .await?
+        .address()?;
+    let contract = Erc1271::new(contract_addr, &alice.wallet);
+    let bob_addr = bob.address();
+
+    let receipt = receipt!(contract.addSessionKey(bob_addr, u64::MAX))?;
+    assert!(
+        receipt.emits(SessionKeyAdded { key: bob_addr, validUntil: u64::MAX })
+    );
+    let Erc1271::sessionKeyValidUntilReturn { validUntil } =
+        contract.sessionKeyValidUntil(bob_addr).call().await?;
+    assert_eq!(validUntil, u64::MAX);
+    assert_eq!(is_valid_signature(contract_addr, &bob).await?, MAGIC_VALUE);
+
+    let receipt = receipt!(contract.revokeSessionKey(bob_addr))?;
+    assert!(receipt.emits(SessionKeyRevoked { key: bob_addr }));
+    assert_eq!(is_valid_signature(contract_addr, &bob).await?, INVALID_VALUE);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn rejects_expired_session_keys(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(alice.address()))
+        .deploy()
+        .await?
+        .address()?;
+    let contract = Erc1271::new(contract_addr, &alice.wallet);
+    let bob_addr = bob.address();
+
+    let err = send!(contract.addSessionKey(bob_addr, 1))
+        .expect_err("should reject expired session key");
+    assert!(err.reverted_with(Erc1271::SessionKeyInvalid {
+        key: bob_addr,
+        validUntil: 1,
+    }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn prevents_non_owners_from_adding_session_keys(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(alice.address()))
+        .deploy()
+        .await?
+        .address()?;
+    let contract = Erc1271::new(contract_addr, &bob.wallet);
+    let bob_addr = bob.address();
+
+    let err = send!(contract.addSessionKey(bob_addr, u64::MAX))
+        .expect_err("should not add session key when not owner");
+    assert!(err.reverted_with(Erc1271::OwnableUnauthorizedAccount {
+        account: bob_addr,
+    }));
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.21;
+
+contract Erc1967ProxyExample {
+    // bytes32(uint256(keccak256("eip1967.proxy.implementation")) - 1)
+    bytes32 internal constant IMPLEMENTATION_SLOT =
+        0x360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc;
+
+    error ERC1967InvalidImplementation(address implementation);
+    event Upgraded(address indexed implementation);
+
+    constructor(address implementation, bytes memory data) {
+        if (implementation.code.length == 0) {
+            revert ERC1967InvalidImplementation(implementation);
+        }
+        assembly {
+            sstore(IMPLEMENTATION_SLOT, implementation)
+        }
+        emit Upgraded(implementation);
+
+        if (data.length > 0) {
+            (bool success, bytes memory returndata) =
+                implementation.delegatecall(data);
+            if (!success) {
+                assembly {
+                    revert(add(returndata, 0x20), mload(returndata))
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,18 @@
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use openzeppelin_stylus::proxy::erc1967;
+use stylus_sdk::{call::RawCall, prelude::entrypoint, ArbResult};
+
+/// Delegates every call to the implementation stored in the ERC-1967
+/// implementation slot, and returns or reverts with its output.
+///
+/// The proxy has no functions of its own, so upgrades are only possible
+/// through the implementation, e.g. an `UUPSUpgradeable` contract.
+#[entrypoint]
+fn proxy(input: Vec<u8>) -> ArbResult {
+    let implementation = erc1967::get_implementation();
+    RawCall::new_delegate().call(implementation, &input)
+}
@@ -0,0 +1,16 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.21;
+
+contract Erc20BridgedExample {
+    mapping(address account => uint256) private _balances;
+    mapping(address account => mapping(address spender => uint256))
+        private _allowances;
+    uint256 private _totalSupply;
+    address private _l2Gateway;
+    address private _l1Address;
+
+    constructor(address l2Gateway_, address l1Address_) {
+        _l2Gateway = l2Gateway_;
+        _l1Address = l1Address_;
+    }
+}
@@ -0,0 +1,33 @@
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+extern crate alloc;
+
+use core::borrow::{Borrow, BorrowMut};
+
+use openzeppelin_stylus::token::erc20::{extensions::Erc20Bridged, Erc20};
+use openzeppelin_stylus_proc::selectors;
+use stylus_sdk::prelude::{entrypoint, public, sol_storage};
+
+sol_storage! {
+    #[entrypoint]
+    struct Erc20BridgedExample {
+        #[borrow]
+        Erc20Bridged bridged;
+    }
+}
+
+impl Borrow<Erc20> for Erc20BridgedExample {
+    fn borrow(&self) -> &Erc20 {
+        &self.bridged.erc20
+    }
+}
+
+impl BorrowMut<Erc20> for Erc20BridgedExample {
+    fn borrow_mut(&mut self) -> &mut Erc20 {
+        &mut self.bridged.erc20
+    }
+}
+
+#[selectors]
+#[public]
+#[inherit(Erc20Bridged, Erc20)]
+impl Erc20BridgedExample {}
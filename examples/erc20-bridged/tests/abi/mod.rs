@@ -0,0 +1,21 @@
+#![allow(dead_code)]
+use alloy::sol;
+
+sol!(
+    #[sol(rpc)]
+    contract Erc20Bridged {
+        function totalSupply() external view returns (uint256 totalSupply);
+        function balanceOf(address account) external view returns (uint256 balance);
+        function transfer(address recipient, uint256 amount) external returns (bool);
+        function l1Address() external view returns (address l1Address);
+        function l2Gateway() external view returns (address l2Gateway);
+        function bridgeMint(address account, uint256 amount) external;
+        function bridgeBurn(address account, uint256 amount) external;
+
+        error ERC20InsufficientBalance(address sender, uint256 balance, uint256 needed);
+        error ERC20BridgedUnauthorizedGateway(address account);
+
+        #[derive(Debug, PartialEq)]
+        event Transfer(address indexed from, address indexed to, uint256 value);
+    }
+);
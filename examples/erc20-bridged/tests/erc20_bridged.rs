@@ -0,0 +1,117 @@
+#![cfg(feature = "e2e")]
+
+use abi::Erc20Bridged;
+use alloy::{
+    primitives::{address, uint, Address, U256},
+    sol,
+};
+use e2e::{receipt, send, Account, EventExt, ReceiptExt, Revert};
+use eyre::Result;
+
+use crate::Erc20BridgedExample::constructorCall;
+
+mod abi;
+
+sol!("src/constructor.sol");
+
+const L1_TOKEN: Address = address!("B0B0cB49ec2e96DF5F5fFB081acaE66A2cBBc2e2");
+
+fn ctr(gateway: Address) -> constructorCall {
+    constructorCall { l2Gateway_: gateway, l1Address_: L1_TOKEN }
+}
+
+// ============================================================================
+// Integration Tests: ERC-20 Bridged Extension
+// ============================================================================
+
+#[e2e::test]
+async fn constructs(alice: Account) -> Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(alice.address()))
+        .deploy()
+        .await?
+        .address()?;
+    let contract = Erc20Bridged::new(contract_addr, &alice.wallet);
+
+    let Erc20Bridged::l1AddressReturn { l1Address } =
+        contract.l1Address().call().await?;
+    let Erc20Bridged::l2GatewayReturn { l2Gateway } =
+        contract.l2Gateway().call().await?;
+
+    assert_eq!(l1Address, L1_TOKEN);
+    assert_eq!(l2Gateway, alice.address());
+    Ok(())
+}
+
+#[e2e::test]
+async fn mints_and_burns_from_gateway(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(alice.address()))
+        .deploy()
+        .await?
+        .address()?;
+    let contract = Erc20Bridged::new(contract_addr, &alice.wallet);
+    let bob_addr = bob.address();
+
+    let amount = uint!(10_U256);
+    let receipt = receipt!(contract.bridgeMint(bob_addr, amount))?;
+    assert!(receipt.emits(Erc20Bridged::Transfer {
+        from: Address::ZERO,
+        to: bob_addr,
+        value: amount,
+    }));
+
+    let burnt = uint!(4_U256);
+    let receipt = receipt!(contract.bridgeBurn(bob_addr, burnt))?;
+    assert!(receipt.emits(Erc20Bridged::Transfer {
+        from: bob_addr,
+        to: Address::ZERO,
+        value: burnt,
+    }));
+
+    let Erc20Bridged::balanceOfReturn { balance } =
+        contract.balanceOf(bob_addr).call().await?;
+    let Erc20Bridged::totalSupplyReturn { totalSupply: total_supply } =
+        contract.totalSupply().call().await?;
+    assert_eq!(balance, amount - burnt);
+    assert_eq!(total_supply, amount - burnt);
+
+    let err = send!(contract.bridgeBurn(bob_addr, amount))
+        .expect_err("should not burn more than the balance");
+    assert!(err.reverted_with(Erc20Bridged::ERC20InsufficientBalance {
+        sender: bob_addr,
+        balance: amount - burnt,
+        needed: amount,
+    }));
+    Ok(())
+}
+
+#[e2e::test]
+async fn rejects_other_accounts(alice: Account, bob: Account) -> Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(ctr(alice.address()))
+        .deploy()
+        .await?
+        .address()?;
+    let contract = Erc20Bridged::new(contract_addr, &bob.wallet);
+    let bob_addr = bob.address();
+
+    let err = send!(contract.bridgeMint(bob_addr, uint!(1_U256)))
+        .expect_err("should reject non-gateway");
+    assert!(err.reverted_with(Erc20Bridged::ERC20BridgedUnauthorizedGateway {
+        account: bob_addr
+    }));
+
+    let err = send!(contract.bridgeBurn(bob_addr, U256::ZERO))
+        .expect_err("should reject non-gateway");
+    assert!(err.reverted_with(Erc20Bridged::ERC20BridgedUnauthorizedGateway {
+        account: bob_addr
+    }));
+    Ok(())
+}
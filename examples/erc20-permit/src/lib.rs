@@ -1,4 +1,4 @@
-#![cfg_attr(not(test), no_main, no_std)]
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main, no_std)]
 extern crate alloc;
 
 use alloc::vec::Vec;
@@ -7,6 +7,7 @@ use alloy_primitives::{Address, U256};
 use openzeppelin_stylus::{
     token::erc20::extensions::Erc20Permit, utils::cryptography::eip712::IEip712,
 };
+use openzeppelin_stylus_proc::selectors;
 use stylus_sdk::prelude::{entrypoint, public, sol_storage};
 
 sol_storage! {
@@ -24,6 +25,7 @@ impl IEip712 for Eip712 {
     const VERSION: &'static str = "1";
 }
 
+#[selectors]
 #[public]
 #[inherit(Erc20Permit<Eip712>)]
 impl Erc20PermitExample {
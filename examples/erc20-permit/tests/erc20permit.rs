@@ -3,8 +3,9 @@
 use abi::Erc20Permit;
 use alloy::{
     primitives::{b256, keccak256, Address, B256, U256},
+    providers::Provider,
     sol,
-    sol_types::SolType,
+    sol_types::{eip712_domain, SolStruct, SolType},
 };
 use alloy_primitives::uint;
 use e2e::{receipt, send, watch, Account, EventExt, ReceiptExt, Revert};
@@ -27,6 +28,16 @@ type PermitStructHashTuple = sol! {
     tuple(bytes32, address, address, uint256, uint256, uint256)
 };
 
+sol! {
+    struct Permit {
+        address owner;
+        address spender;
+        uint256 value;
+        uint256 nonce;
+        uint256 deadline;
+    }
+}
+
 macro_rules! domain_separator {
     ($contract:expr) => {{
         let Erc20Permit::DOMAIN_SEPARATORReturn { domainSeparator } = $contract
@@ -203,6 +214,81 @@ async fn permit_works(alice: Account, bob: Account) -> Result<()> {
     Ok(())
 }
 
+#[e2e::test]
+async fn permits_with_typed_data_signature(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let contract_addr = alice.as_deployer().deploy().await?.address()?;
+    let contract_alice = Erc20Permit::new(contract_addr, &alice.wallet);
+    let contract_bob = Erc20Permit::new(contract_addr, &bob.wallet);
+    let alice_addr = alice.address();
+    let bob_addr = bob.address();
+
+    let balance = uint!(10_U256);
+    let _ = watch!(contract_alice.mint(alice_addr, balance))?;
+
+    let domain = eip712_domain! {
+        name: "ERC-20 Permit Example",
+        version: "1",
+        chain_id: alice.wallet.get_chain_id().await?,
+        verifying_contract: contract_addr,
+    };
+    assert_eq!(domain.separator(), domain_separator!(contract_alice));
+
+    let permit = Permit {
+        owner: alice_addr,
+        spender: bob_addr,
+        value: balance,
+        nonce: U256::ZERO,
+        deadline: FAIR_DEADLINE,
+    };
+    let signature = alice.sign_typed_data(&domain, &permit).await;
+
+    let _ = watch!(contract_bob.permit(
+        alice_addr,
+        bob_addr,
+        balance,
+        FAIR_DEADLINE,
+        signature.v().y_parity_byte_non_eip155().unwrap(),
+        signature.r().into(),
+        signature.s().into()
+    ))?;
+
+    let receipt =
+        receipt!(contract_bob.transferFrom(alice_addr, bob_addr, balance))?;
+    assert!(receipt.emits(Erc20Permit::Transfer {
+        from: alice_addr,
+        to: bob_addr,
+        value: balance
+    }));
+
+    let Erc20Permit::balanceOfReturn { balance: bob_balance } =
+        contract_alice.balanceOf(bob_addr).call().await?;
+    assert_eq!(balance, bob_balance);
+
+    let err = send!(contract_bob.permit(
+        alice_addr,
+        bob_addr,
+        balance,
+        FAIR_DEADLINE,
+        signature.v().y_parity_byte_non_eip155().unwrap(),
+        signature.r().into(),
+        signature.s().into()
+    ))
+    .expect_err("should return `ERC2612InvalidSigner`");
+    let replayed = Permit { nonce: uint!(1_U256), ..permit };
+    let recovered = signature
+        .recover_address_from_prehash(&replayed.eip712_signing_hash(&domain))
+        .expect("should recover");
+    assert!(err.reverted_with(Erc20Permit::ERC2612InvalidSigner {
+        signer: recovered,
+        owner: alice_addr
+    }));
+
+    Ok(())
+}
+
 #[e2e::test]
 async fn permit_rejects_reused_signature(
     alice: Account,
@@ -1,4 +1,4 @@
-#![cfg_attr(not(test), no_main, no_std)]
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main, no_std)]
 extern crate alloc;
 
 use alloc::vec::Vec;
@@ -11,6 +11,7 @@ use openzeppelin_stylus::{
     },
     utils::{introspection::erc165::IErc165, Pausable},
 };
+use openzeppelin_stylus_proc::{selectors, when_not_paused};
 use stylus_sdk::prelude::{entrypoint, public, sol_storage};
 
 const DECIMALS: u8 = 10;
@@ -29,6 +30,7 @@ sol_storage! {
     }
 }
 
+#[selectors]
 #[public]
 #[inherit(Erc20, Erc20Metadata, Capped, Pausable)]
 impl Erc20Example {
@@ -40,17 +42,17 @@ impl Erc20Example {
         DECIMALS
     }
 
+    #[when_not_paused]
     pub fn burn(&mut self, value: U256) -> Result<(), Vec<u8>> {
-        self.pausable.when_not_paused()?;
         self.erc20.burn(value).map_err(|e| e.into())
     }
 
+    #[when_not_paused]
     pub fn burn_from(
         &mut self,
         account: Address,
         value: U256,
     ) -> Result<(), Vec<u8>> {
-        self.pausable.when_not_paused()?;
         self.erc20.burn_from(account, value).map_err(|e| e.into())
     }
 
@@ -59,12 +61,12 @@ impl Erc20Example {
     // Make sure to handle `Capped` properly. You should not call
     // [`Erc20::_update`] to mint tokens -- it will the break `Capped`
     // mechanism.
+    #[when_not_paused]
     pub fn mint(
         &mut self,
         account: Address,
         value: U256,
     ) -> Result<(), Vec<u8>> {
-        self.pausable.when_not_paused()?;
         let max_supply = self.capped.cap();
 
         // Overflow check required.
@@ -87,22 +89,22 @@ impl Erc20Example {
         Ok(())
     }
 
+    #[when_not_paused]
     pub fn transfer(
         &mut self,
         to: Address,
         value: U256,
     ) -> Result<bool, Vec<u8>> {
-        self.pausable.when_not_paused()?;
         self.erc20.transfer(to, value).map_err(|e| e.into())
     }
 
+    #[when_not_paused]
     pub fn transfer_from(
         &mut self,
         from: Address,
         to: Address,
         value: U256,
     ) -> Result<bool, Vec<u8>> {
-        self.pausable.when_not_paused()?;
         self.erc20.transfer_from(from, to, value).map_err(|e| e.into())
     }
 
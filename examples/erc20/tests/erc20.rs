@@ -6,8 +6,8 @@ use alloy::{
     sol,
 };
 use e2e::{
-    receipt, send, watch, Account, EventExt, Panic, PanicCode, ReceiptExt,
-    Revert,
+    receipt, send, watch, Account, Differential, EventExt, Panic, PanicCode,
+    ReceiptExt, Revert,
 };
 use eyre::Result;
 
@@ -1387,3 +1387,83 @@ async fn support_interface(alice: Account) -> Result<()> {
 
     Ok(())
 }
+
+// ============================================================================
+// Differential Tests: ERC-20 Token against OpenZeppelin Contracts
+// ============================================================================
+
+#[e2e::test]
+async fn matches_reference_implementation(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let stylus = alice
+        .as_deployer()
+        .with_default_constructor::<constructorCall>()
+        .deploy()
+        .await?
+        .address()?;
+    let solidity = e2e::deploy_solidity(
+        &alice,
+        e2e::reference("Erc20Reference")?,
+        constructorCall::default(),
+    )
+    .await?;
+    let diff = Differential::new(stylus, solidity);
+
+    let alice_addr = alice.address();
+    let bob_addr = bob.address();
+    let one = uint!(1_U256);
+    let ten = uint!(10_U256);
+
+    diff.call(&alice, &Erc20::nameCall {}).await?;
+    diff.call(&alice, &Erc20::decimalsCall {}).await?;
+    diff.call(&alice, &Erc20::capCall {}).await?;
+
+    diff.send(&alice, &Erc20::mintCall { account: alice_addr, amount: ten })
+        .await?;
+    diff.send(&alice, &Erc20::mintCall { account: Address::ZERO, amount: one })
+        .await?;
+    diff.send(&alice, &Erc20::mintCall { account: alice_addr, amount: CAP })
+        .await?;
+    diff.send(
+        &alice,
+        &Erc20::transferCall { recipient: bob_addr, amount: one },
+    )
+    .await?;
+    diff.send(
+        &bob,
+        &Erc20::transferCall { recipient: alice_addr, amount: ten },
+    )
+    .await?;
+    diff.send(&alice, &Erc20::approveCall { spender: bob_addr, amount: one })
+        .await?;
+    let transfer_from = Erc20::transferFromCall {
+        sender: alice_addr,
+        recipient: bob_addr,
+        amount: one,
+    };
+    diff.send(&bob, &transfer_from).await?;
+    diff.send(&bob, &transfer_from).await?;
+    diff.send(&alice, &Erc20::burnCall { amount: one }).await?;
+    diff.send(&alice, &Erc20::pauseCall {}).await?;
+    diff.send(
+        &alice,
+        &Erc20::transferCall { recipient: bob_addr, amount: one },
+    )
+    .await?;
+    diff.send(&alice, &Erc20::pauseCall {}).await?;
+    diff.send(&alice, &Erc20::unpauseCall {}).await?;
+
+    diff.call(&alice, &Erc20::totalSupplyCall {}).await?;
+    diff.call(&alice, &Erc20::balanceOfCall { account: alice_addr }).await?;
+    diff.call(&alice, &Erc20::balanceOfCall { account: bob_addr }).await?;
+    diff.call(
+        &alice,
+        &Erc20::allowanceCall { owner: alice_addr, spender: bob_addr },
+    )
+    .await?;
+    diff.call(&alice, &Erc20::pausedCall {}).await?;
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.21;
+
+import {ERC20} from "@openzeppelin/contracts/token/ERC20/ERC20.sol";
+import {ERC20Burnable} from "@openzeppelin/contracts/token/ERC20/extensions/ERC20Burnable.sol";
+import {ERC20Capped} from "@openzeppelin/contracts/token/ERC20/extensions/ERC20Capped.sol";
+import {Pausable} from "@openzeppelin/contracts/utils/Pausable.sol";
+
+/// Reference implementation of the `erc20` example with OpenZeppelin
+/// Contracts, for its differential tests.
+contract Erc20Reference is ERC20Capped, ERC20Burnable, Pausable {
+    constructor(string memory name_, string memory symbol_, uint256 cap_)
+        ERC20(name_, symbol_)
+        ERC20Capped(cap_)
+    {}
+
+    function decimals() public pure override returns (uint8) {
+        return 10;
+    }
+
+    function mint(address account, uint256 value) external whenNotPaused {
+        _mint(account, value);
+    }
+
+    function burn(uint256 value) public override whenNotPaused {
+        super.burn(value);
+    }
+
+    function burnFrom(address account, uint256 value)
+        public
+        override
+        whenNotPaused
+    {
+        super.burnFrom(account, value);
+    }
+
+    function transfer(address to, uint256 value)
+        public
+        override
+        whenNotPaused
+        returns (bool)
+    {
+        return super.transfer(to, value);
+    }
+
+    function transferFrom(address from, address to, uint256 value)
+        public
+        override
+        whenNotPaused
+        returns (bool)
+    {
+        return super.transferFrom(from, to, value);
+    }
+
+    function pause() external {
+        _pause();
+    }
+
+    function unpause() external {
+        _unpause();
+    }
+
+    function _update(address from, address to, uint256 value)
+        internal
+        override(ERC20, ERC20Capped)
+    {
+        super._update(from, to, value);
+    }
+}
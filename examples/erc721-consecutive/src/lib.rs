@@ -1,10 +1,11 @@
-#![cfg_attr(not(test), no_main, no_std)]
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main, no_std)]
 extern crate alloc;
 
 use alloy_primitives::{Address, U256};
 use openzeppelin_stylus::token::erc721::extensions::consecutive::{
     Erc721Consecutive, Error,
 };
+use openzeppelin_stylus_proc::selectors;
 use stylus_sdk::prelude::*;
 
 sol_storage! {
@@ -15,6 +16,7 @@ sol_storage! {
     }
 }
 
+#[selectors]
 #[public]
 #[inherit(Erc721Consecutive)]
 impl Erc721ConsecutiveExample {
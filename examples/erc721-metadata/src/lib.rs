@@ -1,4 +1,4 @@
-#![cfg_attr(not(test), no_main, no_std)]
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main, no_std)]
 extern crate alloc;
 
 use alloc::{
@@ -14,6 +14,7 @@ use openzeppelin_stylus::token::erc721::{
     },
     Erc721, IErc721,
 };
+use openzeppelin_stylus_proc::selectors;
 use stylus_sdk::prelude::{entrypoint, public, sol_storage};
 
 sol_storage! {
@@ -28,6 +29,7 @@ sol_storage! {
     }
 }
 
+#[selectors]
 #[public]
 #[inherit(Erc721, Metadata, UriStorage)]
 impl Erc721MetadataExample {
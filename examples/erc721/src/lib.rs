@@ -1,4 +1,4 @@
-#![cfg_attr(not(test), no_main, no_std)]
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main, no_std)]
 extern crate alloc;
 
 use alloc::vec::Vec;
@@ -11,6 +11,7 @@ use openzeppelin_stylus::{
     },
     utils::{introspection::erc165::IErc165, Pausable},
 };
+use openzeppelin_stylus_proc::selectors;
 use stylus_sdk::{
     abi::Bytes,
     prelude::{entrypoint, public, sol_storage},
@@ -28,6 +29,7 @@ sol_storage! {
     }
 }
 
+#[selectors]
 #[public]
 #[inherit(Erc721, Enumerable, Pausable)]
 impl Erc721Example {
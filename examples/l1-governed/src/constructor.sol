@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.21;
+
+contract L1GovernedExample {
+    address private _governor;
+    uint256 private _value;
+
+    constructor(address governor) {
+        _governor = governor;
+    }
+}
@@ -0,0 +1,54 @@
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+extern crate alloc;
+
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::sol;
+use openzeppelin_stylus::arbitrum::messaging;
+use openzeppelin_stylus_proc::SolidityError;
+use stylus_sdk::{
+    msg,
+    prelude::{entrypoint, public, sol_storage},
+};
+
+sol! {
+    /// The caller is not the alias of the governor on the parent chain.
+    #[derive(Debug)]
+    #[allow(missing_docs)]
+    error L1GovernedUnauthorizedSender(address sender);
+}
+
+#[derive(SolidityError, Debug)]
+pub enum Error {
+    UnauthorizedSender(L1GovernedUnauthorizedSender),
+}
+
+sol_storage! {
+    #[entrypoint]
+    struct L1GovernedExample {
+        /// Governor of the contract on the parent chain.
+        address governor;
+        uint256 value;
+    }
+}
+
+#[public]
+impl L1GovernedExample {
+    pub fn governor(&self) -> Address {
+        self.governor.get()
+    }
+
+    pub fn value(&self) -> U256 {
+        self.value.get()
+    }
+
+    /// Sets the value, through a retryable ticket sent by the governor.
+    pub fn set_value(&mut self, value: U256) -> Result<(), Error> {
+        if !messaging::is_l1_sender(self.governor.get()) {
+            return Err(
+                L1GovernedUnauthorizedSender { sender: msg::sender() }.into()
+            );
+        }
+        self.value.set(value);
+        Ok(())
+    }
+}
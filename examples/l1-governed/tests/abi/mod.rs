@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+use alloy::sol;
+
+sol!(
+    #[sol(rpc)]
+    contract L1Governed {
+        function governor() external view returns (address governor);
+        function value() external view returns (uint256 value);
+        function setValue(uint256 value) external;
+
+        error L1GovernedUnauthorizedSender(address sender);
+    }
+);
@@ -0,0 +1,98 @@
+#![cfg(feature = "e2e")]
+
+use abi::L1Governed;
+use alloy::{
+    primitives::{address, aliases::U160, uint, Address},
+    sol,
+};
+use e2e::{receipt, send, Account, ReceiptExt, Revert};
+use eyre::Result;
+
+use crate::L1GovernedExample::constructorCall;
+
+mod abi;
+
+sol!("src/constructor.sol");
+
+const L1_TO_L2_ALIAS_OFFSET: Address =
+    address!("1111000000000000000000000000000000001111");
+
+/// Returns the address on the parent chain whose alias is `account`, so that
+/// `account` can send the messages of the governor in tests.
+fn l1_address_aliased_to(account: &Account) -> Address {
+    let to_u160 = |address: Address| U160::from_be_bytes(address.into_array());
+    let l1_address =
+        to_u160(account.address()).wrapping_sub(to_u160(L1_TO_L2_ALIAS_OFFSET));
+    Address::from(l1_address.to_be_bytes())
+}
+
+// ============================================================================
+// Integration Tests: L1 Governed
+// ============================================================================
+
+#[e2e::test]
+async fn accepts_messages_from_governor(alice: Account) -> Result<()> {
+    let governor = l1_address_aliased_to(&alice);
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(constructorCall { governor })
+        .deploy()
+        .await?
+        .address()?;
+    let contract = L1Governed::new(contract_addr, &alice.wallet);
+
+    let L1Governed::governorReturn { governor: actual } =
+        contract.governor().call().await?;
+    assert_eq!(actual, governor);
+
+    let value = uint!(42_U256);
+    receipt!(contract.setValue(value))?;
+
+    let L1Governed::valueReturn { value: actual } =
+        contract.value().call().await?;
+    assert_eq!(actual, value);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn rejects_calls_from_governor_address(alice: Account) -> Result<()> {
+    // Without aliasing, a call from the governor's address doesn't come from
+    // the parent chain.
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(constructorCall { governor: alice.address() })
+        .deploy()
+        .await?
+        .address()?;
+    let contract = L1Governed::new(contract_addr, &alice.wallet);
+
+    let err = send!(contract.setValue(uint!(42_U256)))
+        .expect_err("should reject unaliased governor");
+    assert!(err.reverted_with(L1Governed::L1GovernedUnauthorizedSender {
+        sender: alice.address()
+    }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn rejects_other_senders(alice: Account, bob: Account) -> Result<()> {
+    let contract_addr = alice
+        .as_deployer()
+        .with_constructor(constructorCall {
+            governor: l1_address_aliased_to(&alice),
+        })
+        .deploy()
+        .await?
+        .address()?;
+    let contract = L1Governed::new(contract_addr, &bob.wallet);
+
+    let err = send!(contract.setValue(uint!(42_U256)))
+        .expect_err("should reject other senders");
+    assert!(err.reverted_with(L1Governed::L1GovernedUnauthorizedSender {
+        sender: bob.address()
+    }));
+
+    Ok(())
+}
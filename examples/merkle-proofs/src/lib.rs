@@ -1,4 +1,4 @@
-#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![cfg_attr(not(any(feature = "std", feature = "export-abi")), no_std, no_main)]
 extern crate alloc;
 
 use alloc::vec::Vec;
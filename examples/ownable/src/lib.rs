@@ -1,4 +1,4 @@
-#![cfg_attr(not(test), no_std, no_main)]
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
 extern crate alloc;
 
 use alloc::vec::Vec;
@@ -8,6 +8,7 @@ use openzeppelin_stylus::{
     access::ownable::Ownable,
     token::erc20::{Erc20, IErc20},
 };
+use openzeppelin_stylus_proc::{only_owner, selectors};
 use stylus_sdk::prelude::{entrypoint, public, sol_storage};
 
 sol_storage! {
@@ -20,15 +21,16 @@ sol_storage! {
     }
 }
 
+#[selectors]
 #[public]
 #[inherit(Erc20, Ownable)]
 impl OwnableExample {
+    #[only_owner]
     pub fn transfer(
         &mut self,
         to: Address,
         value: U256,
     ) -> Result<(), Vec<u8>> {
-        self.ownable.only_owner()?;
         self.erc20.transfer(to, value)?;
         Ok(())
     }
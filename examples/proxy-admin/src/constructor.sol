@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.21;
+
+contract ProxyAdminExample {
+    address private _owner;
+
+    error OwnableInvalidOwner(address owner);
+    event OwnershipTransferred(
+        address indexed previousOwner,
+        address indexed newOwner
+    );
+
+    constructor(address initialOwner) {
+        if (initialOwner == address(0)) {
+            revert OwnableInvalidOwner(address(0));
+        }
+        _owner = initialOwner;
+        emit OwnershipTransferred(address(0), initialOwner);
+    }
+}
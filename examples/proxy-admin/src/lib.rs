@@ -0,0 +1,35 @@
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+extern crate alloc;
+
+use core::borrow::{Borrow, BorrowMut};
+
+use openzeppelin_stylus::{
+    access::ownable::Ownable, proxy::proxy_admin::ProxyAdmin,
+};
+use openzeppelin_stylus_proc::selectors;
+use stylus_sdk::prelude::{entrypoint, public, sol_storage};
+
+sol_storage! {
+    #[entrypoint]
+    struct ProxyAdminExample {
+        #[borrow]
+        ProxyAdmin admin;
+    }
+}
+
+impl Borrow<Ownable> for ProxyAdminExample {
+    fn borrow(&self) -> &Ownable {
+        &self.admin.ownable
+    }
+}
+
+impl BorrowMut<Ownable> for ProxyAdminExample {
+    fn borrow_mut(&mut self) -> &mut Ownable {
+        &mut self.admin.ownable
+    }
+}
+
+#[selectors]
+#[public]
+#[inherit(ProxyAdmin, Ownable)]
+impl ProxyAdminExample {}
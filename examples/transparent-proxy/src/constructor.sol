@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.21;
+
+contract TransparentProxyExample {
+    // bytes32(uint256(keccak256("eip1967.proxy.implementation")) - 1)
+    bytes32 internal constant IMPLEMENTATION_SLOT =
+        0x360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc;
+    // bytes32(uint256(keccak256("eip1967.proxy.admin")) - 1)
+    bytes32 internal constant ADMIN_SLOT =
+        0xb53127684a568b3173ae13b9f8a6016e243e63b6e8ee1178d6a717850b5d6103;
+
+    error ERC1967InvalidImplementation(address implementation);
+    error ERC1967InvalidAdmin(address admin);
+    event Upgraded(address indexed implementation);
+    event AdminChanged(address previousAdmin, address newAdmin);
+
+    constructor(address implementation, address admin, bytes memory data) {
+        if (implementation.code.length == 0) {
+            revert ERC1967InvalidImplementation(implementation);
+        }
+        if (admin == address(0)) {
+            revert ERC1967InvalidAdmin(address(0));
+        }
+        assembly {
+            sstore(IMPLEMENTATION_SLOT, implementation)
+            sstore(ADMIN_SLOT, admin)
+        }
+        emit Upgraded(implementation);
+        emit AdminChanged(address(0), admin);
+
+        if (data.length > 0) {
+            (bool success, bytes memory returndata) =
+                implementation.delegatecall(data);
+            if (!success) {
+                assembly {
+                    revert(add(returndata, 0x20), mload(returndata))
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,16 @@
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use alloy_primitives::U256;
+use openzeppelin_stylus::proxy::transparent_upgradeable_proxy::TransparentUpgradeableProxy;
+use stylus_sdk::{prelude::entrypoint, storage::StorageType, ArbResult};
+
+#[entrypoint]
+fn proxy(input: Vec<u8>) -> ArbResult {
+    // SAFETY: The proxy has no storage of its own outside of the ERC-1967
+    // slots, so there is no other instance to alias.
+    let mut proxy = unsafe { TransparentUpgradeableProxy::new(U256::ZERO, 0) };
+    proxy.route(&input)
+}
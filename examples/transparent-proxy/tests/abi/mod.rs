@@ -0,0 +1,24 @@
+#![allow(dead_code)]
+use alloy::sol;
+
+sol!(
+    #[sol(rpc)]
+    contract ProxyAdmin {
+        function owner() public view virtual returns (address owner);
+        function UPGRADE_INTERFACE_VERSION() external view returns (string version);
+        function upgradeAndCall(address proxy, address implementation, bytes calldata data) external payable;
+
+        error OwnableUnauthorizedAccount(address account);
+    }
+
+    #[sol(rpc)]
+    contract Counter {
+        function initialize(address owner) external;
+        function number() external view returns (uint256 number);
+        function increment() external;
+        function implementation() external view returns (address implementation);
+
+        #[derive(Debug, PartialEq)]
+        event Upgraded(address indexed implementation);
+    }
+);
@@ -0,0 +1,149 @@
+#![cfg(feature = "e2e")]
+
+use abi::{Counter, Counter::Upgraded, ProxyAdmin};
+use alloy::{
+    primitives::{Address, Bytes, U256},
+    sol,
+    sol_types::SolCall,
+};
+use e2e::{receipt, send, Account, EventExt, ReceiptExt, Revert};
+use eyre::Result;
+
+mod abi;
+
+sol!("src/constructor.sol");
+sol!("../proxy-admin/src/constructor.sol");
+sol!("../uups/src/constructor.sol");
+
+async fn deploy_implementation(account: &Account) -> Result<Address> {
+    account
+        .as_deployer()
+        .with_example("uups")?
+        .with_constructor(UUPSExample::constructorCall {})
+        .deploy()
+        .await?
+        .address()
+}
+
+/// Deploys an implementation behind a proxy, administered by a proxy admin
+/// owned by `account`, and returns the addresses of the proxy, of the admin
+/// and of the implementation.
+async fn deploy_proxy(
+    account: &Account,
+) -> Result<(Address, Address, Address)> {
+    let admin = account
+        .as_deployer()
+        .with_example("proxy-admin")?
+        .with_constructor(ProxyAdminExample::constructorCall {
+            initialOwner: account.address(),
+        })
+        .deploy()
+        .await?
+        .address()?;
+    let implementation = deploy_implementation(account).await?;
+    let data =
+        Counter::initializeCall { owner: account.address() }.abi_encode();
+    let proxy = account
+        .as_deployer()
+        .with_constructor(TransparentProxyExample::constructorCall {
+            implementation,
+            admin,
+            data: data.into(),
+        })
+        .deploy()
+        .await?
+        .address()?;
+    Ok((proxy, admin, implementation))
+}
+
+// ============================================================================
+// Integration Tests: Transparent Upgradeable Proxy
+// ============================================================================
+
+#[e2e::test]
+async fn delegates_calls(alice: Account) -> Result<()> {
+    let (proxy, admin, implementation) = deploy_proxy(&alice).await?;
+    let contract = Counter::new(proxy, &alice.wallet);
+
+    let Counter::implementationReturn { implementation: current } =
+        contract.implementation().call().await?;
+    assert_eq!(current, implementation);
+
+    let _ = receipt!(contract.increment())?;
+    let Counter::numberReturn { number } = contract.number().call().await?;
+    assert_eq!(number, U256::from(1));
+
+    let admin = ProxyAdmin::new(admin, &alice.wallet);
+    let ProxyAdmin::ownerReturn { owner } = admin.owner().call().await?;
+    assert_eq!(owner, alice.address());
+    let ProxyAdmin::UPGRADE_INTERFACE_VERSIONReturn { version } =
+        admin.UPGRADE_INTERFACE_VERSION().call().await?;
+    assert_eq!(version, "5.0.0");
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn upgrades_through_admin(alice: Account) -> Result<()> {
+    let (proxy, admin, _) = deploy_proxy(&alice).await?;
+    let contract = Counter::new(proxy, &alice.wallet);
+    let admin = ProxyAdmin::new(admin, &alice.wallet);
+    let _ = receipt!(contract.increment())?;
+
+    let new_implementation = deploy_implementation(&alice).await?;
+    let receipt = receipt!(admin.upgradeAndCall(
+        proxy,
+        new_implementation,
+        Bytes::new()
+    ))?;
+    assert!(receipt.emits(Upgraded { implementation: new_implementation }));
+
+    let Counter::implementationReturn { implementation } =
+        contract.implementation().call().await?;
+    assert_eq!(implementation, new_implementation);
+    let Counter::numberReturn { number } = contract.number().call().await?;
+    assert_eq!(number, U256::from(1));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn upgrades_and_calls_through_admin(alice: Account) -> Result<()> {
+    let (proxy, admin, _) = deploy_proxy(&alice).await?;
+    let contract = Counter::new(proxy, &alice.wallet);
+    let admin = ProxyAdmin::new(admin, &alice.wallet);
+
+    let new_implementation = deploy_implementation(&alice).await?;
+    let data = Counter::incrementCall {}.abi_encode();
+    let _ =
+        receipt!(admin.upgradeAndCall(proxy, new_implementation, data.into()))?;
+
+    let Counter::numberReturn { number } = contract.number().call().await?;
+    assert_eq!(number, U256::from(1));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn prevents_non_owners_from_upgrading(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let (proxy, admin, implementation) = deploy_proxy(&alice).await?;
+    let contract = Counter::new(proxy, &alice.wallet);
+    let admin = ProxyAdmin::new(admin, &bob.wallet);
+
+    let new_implementation = deploy_implementation(&alice).await?;
+    let err =
+        send!(admin.upgradeAndCall(proxy, new_implementation, Bytes::new()))
+            .expect_err("should not upgrade when not owner");
+    assert!(err.reverted_with(ProxyAdmin::OwnableUnauthorizedAccount {
+        account: bob.address(),
+    }));
+
+    let Counter::implementationReturn { implementation: current } =
+        contract.implementation().call().await?;
+    assert_eq!(current, implementation);
+
+    Ok(())
+}
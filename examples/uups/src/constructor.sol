@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.21;
+
+contract UUPSExample {
+    struct Ownable {
+        address _owner;
+    }
+
+    struct Initializable {
+        uint64 _initialized;
+    }
+
+    Ownable private _ownable;
+    Initializable private _initializable;
+    uint256 private _number;
+
+    event Initialized(uint64 version);
+
+    // Disables the initializers of the implementation, which is only meant
+    // to be used through a proxy.
+    constructor() {
+        _initializable._initialized = type(uint64).max;
+        emit Initialized(type(uint64).max);
+    }
+}
@@ -0,0 +1,71 @@
+#![cfg_attr(not(any(test, feature = "export-abi")), no_std, no_main)]
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use alloy_primitives::{Address, B256, U256};
+use openzeppelin_stylus::{
+    access::ownable::Ownable,
+    proxy::{erc1967, uups_upgradeable::UUPSUpgradeable},
+    utils::initializable::Initializable,
+};
+use openzeppelin_stylus_proc::selectors;
+use stylus_sdk::{
+    abi::Bytes,
+    prelude::{entrypoint, public, sol_storage},
+};
+
+sol_storage! {
+    #[entrypoint]
+    struct UUPSExample {
+        #[borrow]
+        Ownable ownable;
+        Initializable initializable;
+        uint256 number;
+    }
+}
+
+impl UUPSUpgradeable for UUPSExample {
+    fn _authorize_upgrade(&mut self, _: Address) -> Result<(), Vec<u8>> {
+        self.ownable.only_owner()?;
+        Ok(())
+    }
+}
+
+#[selectors]
+#[public]
+#[inherit(Ownable)]
+impl UUPSExample {
+    pub fn initialize(&mut self, owner: Address) -> Result<(), Vec<u8>> {
+        self.initializable.initializer()?;
+        self.ownable._transfer_ownership(owner);
+        Ok(())
+    }
+
+    pub fn number(&self) -> U256 {
+        self.number.get()
+    }
+
+    pub fn increment(&mut self) {
+        let number = self.number.get();
+        self.number.set(number + U256::from(1));
+    }
+
+    pub fn implementation(&self) -> Address {
+        erc1967::get_implementation()
+    }
+
+    #[selector(name = "proxiableUUID")]
+    pub fn proxiable_uuid(&self) -> Result<B256, Vec<u8>> {
+        Ok(UUPSUpgradeable::proxiable_uuid(self)?)
+    }
+
+    #[selector(name = "upgradeToAndCall")]
+    pub fn upgrade_to_and_call(
+        &mut self,
+        new_implementation: Address,
+        data: Bytes,
+    ) -> Result<(), Vec<u8>> {
+        UUPSUpgradeable::upgrade_to_and_call(self, new_implementation, &data)
+    }
+}
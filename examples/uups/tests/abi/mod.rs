@@ -0,0 +1,23 @@
+#![allow(dead_code)]
+use alloy::sol;
+
+sol!(
+    #[sol(rpc)]
+    contract UUPS {
+        function owner() public view virtual returns (address owner);
+        function initialize(address owner) external;
+        function number() external view returns (uint256 number);
+        function increment() external;
+        function implementation() external view returns (address implementation);
+        function proxiableUUID() external view returns (bytes32 uuid);
+        function upgradeToAndCall(address newImplementation, bytes calldata data) external payable;
+
+        error OwnableUnauthorizedAccount(address account);
+        error InvalidInitialization();
+        error UUPSUnauthorizedCallContext();
+        error ERC1967InvalidImplementation(address implementation);
+
+        #[derive(Debug, PartialEq)]
+        event Upgraded(address indexed implementation);
+    }
+);
@@ -0,0 +1,176 @@
+#![cfg(feature = "e2e")]
+
+use abi::{UUPS, UUPS::Upgraded};
+use alloy::{
+    primitives::{b256, Address, Bytes, U256},
+    sol,
+    sol_types::SolCall,
+};
+use e2e::{receipt, send, Account, EventExt, ReceiptExt, Revert};
+use eyre::Result;
+
+mod abi;
+
+sol!("src/constructor.sol");
+sol!("../erc1967-proxy/src/constructor.sol");
+
+async fn deploy_implementation(account: &Account) -> Result<Address> {
+    account
+        .as_deployer()
+        .with_constructor(UUPSExample::constructorCall {})
+        .deploy()
+        .await?
+        .address()
+}
+
+/// Deploys an implementation behind a proxy initialized with `account` as
+/// owner, and returns the addresses of the proxy and of the implementation.
+async fn deploy_proxy(account: &Account) -> Result<(Address, Address)> {
+    let implementation = deploy_implementation(account).await?;
+    let data = UUPS::initializeCall { owner: account.address() }.abi_encode();
+    let proxy = account
+        .as_deployer()
+        .with_example("erc1967-proxy")?
+        .with_constructor(Erc1967ProxyExample::constructorCall {
+            implementation,
+            data: data.into(),
+        })
+        .deploy()
+        .await?
+        .address()?;
+    Ok((proxy, implementation))
+}
+
+// ============================================================================
+// Integration Tests: UUPS Upgradeable
+// ============================================================================
+
+#[e2e::test]
+async fn initializes_through_proxy(alice: Account) -> Result<()> {
+    let (proxy, implementation) = deploy_proxy(&alice).await?;
+    let contract = UUPS::new(proxy, &alice.wallet);
+
+    let UUPS::ownerReturn { owner } = contract.owner().call().await?;
+    assert_eq!(owner, alice.address());
+    let UUPS::implementationReturn { implementation: current } =
+        contract.implementation().call().await?;
+    assert_eq!(current, implementation);
+
+    let _ = receipt!(contract.increment())?;
+    let UUPS::numberReturn { number } = contract.number().call().await?;
+    assert_eq!(number, U256::from(1));
+
+    let err = send!(contract.initialize(alice.address()))
+        .expect_err("should not initialize twice");
+    assert!(err.reverted_with(UUPS::InvalidInitialization {}));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn upgrades_preserving_state(alice: Account) -> Result<()> {
+    let (proxy, _) = deploy_proxy(&alice).await?;
+    let contract = UUPS::new(proxy, &alice.wallet);
+    let _ = receipt!(contract.increment())?;
+
+    let new_implementation = deploy_implementation(&alice).await?;
+    let receipt =
+        receipt!(contract.upgradeToAndCall(new_implementation, Bytes::new()))?;
+    assert!(receipt.emits(Upgraded { implementation: new_implementation }));
+
+    let UUPS::implementationReturn { implementation } =
+        contract.implementation().call().await?;
+    assert_eq!(implementation, new_implementation);
+
+    let UUPS::ownerReturn { owner } = contract.owner().call().await?;
+    assert_eq!(owner, alice.address());
+    let _ = receipt!(contract.increment())?;
+    let UUPS::numberReturn { number } = contract.number().call().await?;
+    assert_eq!(number, U256::from(2));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn upgrades_and_calls(alice: Account) -> Result<()> {
+    let (proxy, _) = deploy_proxy(&alice).await?;
+    let contract = UUPS::new(proxy, &alice.wallet);
+
+    let new_implementation = deploy_implementation(&alice).await?;
+    let data = UUPS::incrementCall {}.abi_encode();
+    let _ =
+        receipt!(contract.upgradeToAndCall(new_implementation, data.into()))?;
+
+    let UUPS::numberReturn { number } = contract.number().call().await?;
+    assert_eq!(number, U256::from(1));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn prevents_non_owners_from_upgrading(
+    alice: Account,
+    bob: Account,
+) -> Result<()> {
+    let (proxy, implementation) = deploy_proxy(&alice).await?;
+    let contract = UUPS::new(proxy, &bob.wallet);
+
+    let new_implementation = deploy_implementation(&alice).await?;
+    let err =
+        send!(contract.upgradeToAndCall(new_implementation, Bytes::new()))
+            .expect_err("should not upgrade when not owner");
+    assert!(err.reverted_with(UUPS::OwnableUnauthorizedAccount {
+        account: bob.address(),
+    }));
+
+    let UUPS::implementationReturn { implementation: current } =
+        contract.implementation().call().await?;
+    assert_eq!(current, implementation);
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn rejects_non_proxiable_implementations(alice: Account) -> Result<()> {
+    let (proxy, _) = deploy_proxy(&alice).await?;
+    let contract = UUPS::new(proxy, &alice.wallet);
+
+    let eoa = alice.address();
+    let err = send!(contract.upgradeToAndCall(eoa, Bytes::new()))
+        .expect_err("should not upgrade to an account without code");
+    assert!(err.reverted_with(UUPS::ERC1967InvalidImplementation {
+        implementation: eoa,
+    }));
+
+    Ok(())
+}
+
+#[e2e::test]
+async fn guards_implementation_against_direct_calls(
+    alice: Account,
+) -> Result<()> {
+    let (proxy, implementation) = deploy_proxy(&alice).await?;
+
+    let direct = UUPS::new(implementation, &alice.wallet);
+    let UUPS::proxiableUUIDReturn { uuid } =
+        direct.proxiableUUID().call().await?;
+    assert_eq!(
+        uuid,
+        b256!(
+            "360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc"
+        )
+    );
+    let err = send!(direct.upgradeToAndCall(proxy, Bytes::new()))
+        .expect_err("should not upgrade outside of a proxy");
+    assert!(err.reverted_with(UUPS::UUPSUnauthorizedCallContext {}));
+    let err = send!(direct.initialize(alice.address()))
+        .expect_err("should have disabled initializers");
+    assert!(err.reverted_with(UUPS::InvalidInitialization {}));
+
+    let contract = UUPS::new(proxy, &alice.wallet);
+    let err = send!(contract.proxiableUUID())
+        .expect_err("should not be proxiable through a proxy");
+    assert!(err.reverted_with(UUPS::UUPSUnauthorizedCallContext {}));
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+//! A [BLAKE2b] [`Hasher`] producing 256-bit digests, which can replace the
+//! default keccak hasher in this library's [merkle proofs][crate].
+//!
+//! [BLAKE2b]: https://www.rfc-editor.org/rfc/rfc7693
+use blake2::{digest::consts::U32, Blake2b, Digest};
+
+use crate::hash::{BuildHasher, Hasher};
+
+/// A [`Hasher`] builder that instantiates a [`Blake2b256`] hasher.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, core::fmt::Debug, Default)]
+pub struct Blake2bBuilder;
+
+impl BuildHasher for Blake2bBuilder {
+    type Hasher = Blake2b256;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Blake2b256(Blake2b::new())
+    }
+}
+
+/// A [`Hasher`] computing `BLAKE2b` digests of 32 bytes, i.e. `BLAKE2b-256`.
+pub struct Blake2b256(Blake2b<U32>);
+
+impl Hasher for Blake2b256 {
+    type Output = [u8; 32];
+
+    fn update(&mut self, input: impl AsRef<[u8]>) {
+        self.0.update(input.as_ref());
+    }
+
+    fn finalize(self) -> Self::Output {
+        self.0.finalize().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::Blake2bBuilder;
+    use crate::{
+        hash::{commutative_hash_pair, BuildHasher, Hasher},
+        merkle::Verifier,
+    };
+
+    #[test]
+    fn hashes_empty_input() {
+        let hasher = Blake2bBuilder.build_hasher();
+        assert_eq!(
+            hasher.finalize(),
+            hex!("0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8")
+        );
+    }
+
+    #[test]
+    fn hashes_input() {
+        let mut hasher = Blake2bBuilder.build_hasher();
+        hasher.update(b"ab");
+        hasher.update(b"c");
+        assert_eq!(
+            hasher.finalize(),
+            hex!("bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319")
+        );
+    }
+
+    #[test]
+    fn verifies_merkle_proof() {
+        let a = [1u8; 32];
+        let b = [2u8; 32];
+        let root = commutative_hash_pair(a, b, Blake2bBuilder.build_hasher());
+
+        assert!(Verifier::verify_with_builder(&[b], root, a, &Blake2bBuilder));
+        assert!(!Verifier::verify_with_builder(&[a], root, a, &Blake2bBuilder));
+    }
+}
@@ -0,0 +1,75 @@
+//! A [BLAKE3] [`Hasher`], which can replace the default keccak hasher in this
+//! library's [merkle proofs][crate].
+//!
+//! [BLAKE3]: https://github.com/BLAKE3-team/BLAKE3-specs
+use crate::hash::{BuildHasher, Hasher};
+
+/// A [`Hasher`] builder that instantiates a [`Blake3`] hasher.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, core::fmt::Debug, Default)]
+pub struct Blake3Builder;
+
+impl BuildHasher for Blake3Builder {
+    type Hasher = Blake3;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        Blake3(::blake3::Hasher::new())
+    }
+}
+
+/// A [`Hasher`] computing BLAKE3 digests of 32 bytes.
+pub struct Blake3(::blake3::Hasher);
+
+impl Hasher for Blake3 {
+    type Output = [u8; 32];
+
+    fn update(&mut self, input: impl AsRef<[u8]>) {
+        self.0.update(input.as_ref());
+    }
+
+    fn finalize(self) -> Self::Output {
+        self.0.finalize().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::Blake3Builder;
+    use crate::{
+        hash::{commutative_hash_pair, BuildHasher, Hasher},
+        merkle::Verifier,
+    };
+
+    #[test]
+    fn hashes_empty_input() {
+        let hasher = Blake3Builder.build_hasher();
+        assert_eq!(
+            hasher.finalize(),
+            hex!("af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262")
+        );
+    }
+
+    #[test]
+    fn hashes_input() {
+        let mut hasher = Blake3Builder.build_hasher();
+        hasher.update(b"ab");
+        hasher.update(b"c");
+        assert_eq!(
+            hasher.finalize(),
+            hex!("6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85")
+        );
+    }
+
+    #[test]
+    fn verifies_merkle_proof() {
+        let a = [1u8; 32];
+        let b = [2u8; 32];
+        let root = commutative_hash_pair(a, b, Blake3Builder.build_hasher());
+
+        assert!(Verifier::verify_with_builder(&[b], root, a, &Blake3Builder));
+        assert!(!Verifier::verify_with_builder(&[a], root, a, &Blake3Builder));
+    }
+}
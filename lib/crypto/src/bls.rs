@@ -0,0 +1,463 @@
+//! BLS signatures over the BLS12-381 curve.
+//!
+//! [BLS signatures] can be aggregated: the signatures of many signers, e.g.
+//! the members of a validator set or of a bridge committee, are combined into
+//! a single signature, verified against the aggregate of their public keys.
+//!
+//! This module follows the "minimal-pubkey-size" variant used by Ethereum's
+//! consensus layer: public keys are points of G1, compressed to 48 bytes,
+//! and signatures are points of G2, compressed to 96 bytes. Messages are
+//! hashed to G2 with the [`DST`] of the proof-of-possession scheme.
+//!
+//! WARNING: Aggregating public keys is only safe when every signer has proven
+//! the possession of its secret key, e.g. when registering its public key,
+//! as otherwise the aggregate is vulnerable to rogue key attacks.
+//!
+//! [`eip2537`] encodes points for the BLS12-381 precompiles of EIP-2537, so
+//! that contracts can verify signatures with them where they are available.
+//!
+//! [BLS signatures]: https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-bls-signature-05
+use alloc::vec::Vec;
+
+use bls12_381::{
+    hash_to_curve::{ExpandMsgXmd, HashToCurve},
+    multi_miller_loop, G1Affine, G1Projective, G2Affine, G2Prepared,
+    G2Projective, Gt,
+};
+
+/// Domain separation tag used to hash messages to G2.
+pub const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// A compressed public key, i.e. a point of G1.
+pub type PublicKey = [u8; 48];
+
+/// A compressed signature, i.e. a point of G2.
+pub type Signature = [u8; 96];
+
+/// An error that occurred while decoding or aggregating BLS keys and
+/// signatures.
+#[derive(core::fmt::Debug, PartialEq, Eq)]
+pub enum BlsError {
+    /// A public key is not a valid point of G1, or is the identity.
+    InvalidPublicKey,
+    /// A signature is not a valid point of G2.
+    InvalidSignature,
+    /// Nothing was provided to aggregate.
+    EmptyAggregate,
+    /// The number of public keys does not match the number of messages.
+    LengthMismatch,
+}
+
+impl core::fmt::Display for BlsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let msg = match self {
+            BlsError::InvalidPublicKey => "invalid public key",
+            BlsError::InvalidSignature => "invalid signature",
+            BlsError::EmptyAggregate => "nothing to aggregate",
+            BlsError::LengthMismatch => {
+                "number of public keys and messages differ"
+            }
+        };
+
+        write!(f, "{msg}")
+    }
+}
+
+impl core::error::Error for BlsError {}
+
+/// Hashes `message` to a point of G2, with [`DST`].
+///
+/// # Arguments
+///
+/// * `message` - The message to hash.
+#[must_use]
+pub fn hash_to_g2(message: &[u8]) -> G2Affine {
+    let point = <G2Projective as HashToCurve<
+        ExpandMsgXmd<sha2::Sha256>,
+    >>::hash_to_curve([message], DST);
+    G2Affine::from(point)
+}
+
+/// Verifies that `signature` is a signature of `message` by the owner of
+/// `public_key`.
+///
+/// # Arguments
+///
+/// * `public_key` - Compressed public key of the signer.
+/// * `message` - The signed message.
+/// * `signature` - Compressed signature.
+///
+/// # Errors
+///
+/// If `public_key` can't be decoded, then the error
+/// [`BlsError::InvalidPublicKey`] is returned.
+/// If `signature` can't be decoded, then the error
+/// [`BlsError::InvalidSignature`] is returned.
+pub fn verify(
+    public_key: &PublicKey,
+    message: &[u8],
+    signature: &Signature,
+) -> Result<bool, BlsError> {
+    let public_key = decode_public_key(public_key)?;
+    let signature = decode_signature(signature)?;
+    Ok(pairing_check(&[(public_key, hash_to_g2(message))], signature))
+}
+
+/// Verifies that `signature` is the aggregate of the signatures of `message`
+/// by the owners of `public_keys`.
+///
+/// # Arguments
+///
+/// * `public_keys` - Compressed public keys of the signers.
+/// * `message` - The message signed by every signer.
+/// * `signature` - Compressed aggregate signature.
+///
+/// # Errors
+///
+/// If `public_keys` is empty, then the error [`BlsError::EmptyAggregate`] is
+/// returned.
+/// If a public key can't be decoded, then the error
+/// [`BlsError::InvalidPublicKey`] is returned.
+/// If `signature` can't be decoded, then the error
+/// [`BlsError::InvalidSignature`] is returned.
+pub fn fast_aggregate_verify(
+    public_keys: &[PublicKey],
+    message: &[u8],
+    signature: &Signature,
+) -> Result<bool, BlsError> {
+    let public_key = aggregate_public_keys(public_keys)?;
+    verify(&public_key, message, signature)
+}
+
+/// Verifies that `signature` is the aggregate of the signatures of each of
+/// `messages` by the owner of the public key at the same index of
+/// `public_keys`.
+///
+/// # Arguments
+///
+/// * `public_keys` - Compressed public keys of the signers.
+/// * `messages` - The message signed by each signer.
+/// * `signature` - Compressed aggregate signature.
+///
+/// # Errors
+///
+/// If `public_keys` is empty, then the error [`BlsError::EmptyAggregate`] is
+/// returned.
+/// If `public_keys` and `messages` have different lengths, then the error
+/// [`BlsError::LengthMismatch`] is returned.
+/// If a public key can't be decoded, then the error
+/// [`BlsError::InvalidPublicKey`] is returned.
+/// If `signature` can't be decoded, then the error
+/// [`BlsError::InvalidSignature`] is returned.
+pub fn aggregate_verify(
+    public_keys: &[PublicKey],
+    messages: &[&[u8]],
+    signature: &Signature,
+) -> Result<bool, BlsError> {
+    if public_keys.is_empty() {
+        return Err(BlsError::EmptyAggregate);
+    }
+    if public_keys.len() != messages.len() {
+        return Err(BlsError::LengthMismatch);
+    }
+
+    let terms = public_keys
+        .iter()
+        .zip(messages)
+        .map(|(public_key, message)| {
+            Ok((decode_public_key(public_key)?, hash_to_g2(message)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let signature = decode_signature(signature)?;
+    Ok(pairing_check(&terms, signature))
+}
+
+/// Aggregates `public_keys` into a single public key.
+///
+/// # Arguments
+///
+/// * `public_keys` - Compressed public keys to aggregate.
+///
+/// # Errors
+///
+/// If `public_keys` is empty, then the error [`BlsError::EmptyAggregate`] is
+/// returned.
+/// If a public key can't be decoded, then the error
+/// [`BlsError::InvalidPublicKey`] is returned.
+pub fn aggregate_public_keys(
+    public_keys: &[PublicKey],
+) -> Result<PublicKey, BlsError> {
+    if public_keys.is_empty() {
+        return Err(BlsError::EmptyAggregate);
+    }
+
+    let mut aggregate = G1Projective::identity();
+    for public_key in public_keys {
+        aggregate += decode_public_key(public_key)?;
+    }
+    Ok(G1Affine::from(aggregate).to_compressed())
+}
+
+/// Aggregates `signatures` into a single signature.
+///
+/// # Arguments
+///
+/// * `signatures` - Compressed signatures to aggregate.
+///
+/// # Errors
+///
+/// If `signatures` is empty, then the error [`BlsError::EmptyAggregate`] is
+/// returned.
+/// If a signature can't be decoded, then the error
+/// [`BlsError::InvalidSignature`] is returned.
+pub fn aggregate_signatures(
+    signatures: &[Signature],
+) -> Result<Signature, BlsError> {
+    if signatures.is_empty() {
+        return Err(BlsError::EmptyAggregate);
+    }
+
+    let mut aggregate = G2Projective::identity();
+    for signature in signatures {
+        aggregate += decode_signature(signature)?;
+    }
+    Ok(G2Affine::from(aggregate).to_compressed())
+}
+
+/// Decodes a public key, checking that it belongs to G1 and is not the
+/// identity.
+fn decode_public_key(public_key: &PublicKey) -> Result<G1Affine, BlsError> {
+    let point: Option<G1Affine> = G1Affine::from_compressed(public_key).into();
+    point
+        .filter(|point| !bool::from(point.is_identity()))
+        .ok_or(BlsError::InvalidPublicKey)
+}
+
+/// Decodes a signature, checking that it belongs to G2.
+fn decode_signature(signature: &Signature) -> Result<G2Affine, BlsError> {
+    Option::from(G2Affine::from_compressed(signature))
+        .ok_or(BlsError::InvalidSignature)
+}
+
+/// Checks that the product of the pairings of `terms` equals the pairing of
+/// the generator of G1 and `signature`.
+fn pairing_check(terms: &[(G1Affine, G2Affine)], signature: G2Affine) -> bool {
+    let generator = -G1Affine::generator();
+    let mut prepared: Vec<(&G1Affine, G2Prepared)> = terms
+        .iter()
+        .map(|(public_key, message)| (public_key, G2Prepared::from(*message)))
+        .collect();
+    prepared.push((&generator, G2Prepared::from(signature)));
+
+    let terms: Vec<_> = prepared.iter().map(|(p, q)| (*p, q)).collect();
+    multi_miller_loop(&terms).final_exponentiation() == Gt::identity()
+}
+
+/// Encoding of points for the BLS12-381 precompiles of [EIP-2537].
+///
+/// Coordinates are big-endian and padded to 64 bytes. Coordinates of G2 are
+/// encoded as `c0 || c1`. The identity is encoded as zeroes.
+///
+/// [EIP-2537]: https://eips.ethereum.org/EIPS/eip-2537
+pub mod eip2537 {
+    use bls12_381::{G1Affine, G2Affine};
+
+    use super::{decode_public_key, decode_signature, BlsError};
+
+    /// Size of an encoded coordinate.
+    const FP_SIZE: usize = 64;
+    /// Padding of each coordinate.
+    const PADDING: usize = 16;
+
+    /// Encodes a point of G1 into 128 bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - The point to encode.
+    #[must_use]
+    pub fn encode_g1(point: &G1Affine) -> [u8; 128] {
+        let mut encoded = [0u8; 128];
+        if bool::from(point.is_identity()) {
+            return encoded;
+        }
+
+        let uncompressed = point.to_uncompressed();
+        for (i, fp) in uncompressed.chunks(48).enumerate() {
+            let start = i * FP_SIZE + PADDING;
+            encoded[start..start + 48].copy_from_slice(fp);
+        }
+        encoded
+    }
+
+    /// Encodes a point of G2 into 256 bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - The point to encode.
+    #[must_use]
+    pub fn encode_g2(point: &G2Affine) -> [u8; 256] {
+        let mut encoded = [0u8; 256];
+        if bool::from(point.is_identity()) {
+            return encoded;
+        }
+
+        // Uncompressed points are encoded as `x.c1 || x.c0 || y.c1 || y.c0`.
+        let uncompressed = point.to_uncompressed();
+        for (i, fp) in uncompressed.chunks(48).enumerate() {
+            let start = (i ^ 1) * FP_SIZE + PADDING;
+            encoded[start..start + 48].copy_from_slice(fp);
+        }
+        encoded
+    }
+
+    /// Decodes a compressed public key and encodes it for the precompiles.
+    ///
+    /// # Arguments
+    ///
+    /// * `public_key` - Compressed public key.
+    ///
+    /// # Errors
+    ///
+    /// If `public_key` can't be decoded, then the error
+    /// [`BlsError::InvalidPublicKey`] is returned.
+    pub fn encode_public_key(
+        public_key: &super::PublicKey,
+    ) -> Result<[u8; 128], BlsError> {
+        decode_public_key(public_key).map(|point| encode_g1(&point))
+    }
+
+    /// Decodes a compressed signature and encodes it for the precompiles.
+    ///
+    /// # Arguments
+    ///
+    /// * `signature` - Compressed signature.
+    ///
+    /// # Errors
+    ///
+    /// If `signature` can't be decoded, then the error
+    /// [`BlsError::InvalidSignature`] is returned.
+    pub fn encode_signature(
+        signature: &super::Signature,
+    ) -> Result<[u8; 256], BlsError> {
+        decode_signature(signature).map(|point| encode_g2(&point))
+    }
+
+    /// Hashes `message` to G2 and encodes it for the precompiles.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The message to hash.
+    #[must_use]
+    pub fn encode_message(message: &[u8]) -> [u8; 256] {
+        encode_g2(&super::hash_to_g2(message))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use bls12_381::{G1Affine, G2Affine, Scalar};
+    use hex_literal::hex;
+
+    use super::*;
+
+    /// Returns the public key of the secret key `secret`.
+    fn public_key(secret: u64) -> PublicKey {
+        G1Affine::from(G1Affine::generator() * Scalar::from(secret))
+            .to_compressed()
+    }
+
+    /// Signs `message` with the secret key `secret`.
+    fn sign(secret: u64, message: &[u8]) -> Signature {
+        G2Affine::from(hash_to_g2(message) * Scalar::from(secret))
+            .to_compressed()
+    }
+
+    #[test]
+    fn verifies_signatures() {
+        let signature = sign(7, b"message");
+
+        assert!(verify(&public_key(7), b"message", &signature).unwrap());
+        assert!(!verify(&public_key(8), b"message", &signature).unwrap());
+        assert!(!verify(&public_key(7), b"other", &signature).unwrap());
+    }
+
+    #[test]
+    fn verifies_aggregate_signatures_of_one_message() {
+        let public_keys = [public_key(1), public_key(2), public_key(3)];
+        let signatures =
+            [sign(1, b"block"), sign(2, b"block"), sign(3, b"block")];
+        let signature = aggregate_signatures(&signatures).unwrap();
+
+        assert!(
+            fast_aggregate_verify(&public_keys, b"block", &signature).unwrap()
+        );
+        assert!(!fast_aggregate_verify(
+            &public_keys[1..],
+            b"block",
+            &signature
+        )
+        .unwrap());
+        assert_eq!(aggregate_public_keys(&public_keys).unwrap(), public_key(6));
+    }
+
+    #[test]
+    fn verifies_aggregate_signatures_of_many_messages() {
+        let public_keys = [public_key(4), public_key(5)];
+        let messages: [&[u8]; 2] = [b"first", b"second"];
+        let signature =
+            aggregate_signatures(&[sign(4, b"first"), sign(5, b"second")])
+                .unwrap();
+
+        assert!(aggregate_verify(&public_keys, &messages, &signature).unwrap());
+        assert!(!aggregate_verify(
+            &public_keys,
+            &[b"second", b"first"],
+            &signature
+        )
+        .unwrap());
+        assert_eq!(
+            aggregate_verify(&public_keys, &messages[..1], &signature),
+            Err(BlsError::LengthMismatch)
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_points() {
+        let signature = sign(1, b"message");
+        let identity = G1Affine::identity().to_compressed();
+
+        assert_eq!(
+            verify(&identity, b"message", &signature),
+            Err(BlsError::InvalidPublicKey)
+        );
+        assert_eq!(
+            verify(&[0xff; 48], b"message", &signature),
+            Err(BlsError::InvalidPublicKey)
+        );
+        assert_eq!(
+            verify(&public_key(1), b"message", &[0xff; 96]),
+            Err(BlsError::InvalidSignature)
+        );
+        assert_eq!(aggregate_public_keys(&[]), Err(BlsError::EmptyAggregate));
+        assert_eq!(aggregate_signatures(&[]), Err(BlsError::EmptyAggregate));
+    }
+
+    #[test]
+    fn encodes_points_for_precompiles() {
+        let generator = eip2537::encode_g1(&G1Affine::generator());
+        assert_eq!(
+            generator[..64],
+            hex!("0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb")
+        );
+        assert_eq!(generator[..16], [0; 16]);
+        assert_eq!(generator[64..80], [0; 16]);
+        assert_eq!(eip2537::encode_g1(&G1Affine::identity()), [0; 128]);
+
+        let generator = eip2537::encode_g2(&G2Affine::generator());
+        assert_eq!(
+            generator[..64],
+            hex!("00000000000000000000000000000000024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8")
+        );
+        assert_eq!(eip2537::encode_g2(&G2Affine::identity()), [0; 256]);
+    }
+}
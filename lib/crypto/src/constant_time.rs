@@ -0,0 +1,62 @@
+//! Comparisons whose running time doesn't depend on the compared values.
+//!
+//! A regular `==` on byte slices returns as soon as it finds a differing
+//! byte, so the time it takes reveals the length of the common prefix of the
+//! operands. When one of them is secret, e.g. the preimage of a commitment,
+//! this lets an attacker guess it byte by byte by timing the comparisons.
+//!
+//! ```rust
+//! use openzeppelin_crypto::constant_time;
+//!
+//! let commitment = [1u8; 32];
+//! assert!(constant_time::eq(commitment, [1u8; 32]));
+//! assert!(!constant_time::eq(commitment, [2u8; 32]));
+//! ```
+use core::hint::black_box;
+
+/// Returns true if `a` and `b` are equal, and false otherwise, in a time
+/// that only depends on their lengths.
+///
+/// NOTE: The lengths of `a` and `b` are not considered secret, so slices of
+/// different lengths return early.
+///
+/// # Arguments
+///
+/// * `a` - First value to compare, e.g. a `[u8; 32]` or a `B256`.
+/// * `b` - Second value to compare.
+#[must_use]
+pub fn eq(a: impl AsRef<[u8]>, b: impl AsRef<[u8]>) -> bool {
+    let (a, b) = (a.as_ref(), b.as_ref());
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let diff = a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y));
+    // Prevents the compiler from short-circuiting the fold on a non-zero
+    // accumulator.
+    black_box(diff) == 0
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::eq;
+
+    #[test]
+    fn compares_values() {
+        assert!(eq([], []));
+        assert!(eq([7u8; 32], [7u8; 32]));
+        assert!(eq(b"commitment", "commitment"));
+
+        let mut other = [7u8; 32];
+        other[31] = 8;
+        assert!(!eq([7u8; 32], other));
+        other[0] = 8;
+        assert!(!eq([7u8; 32], other));
+    }
+
+    #[test]
+    fn rejects_different_lengths() {
+        assert!(!eq([1u8; 32], [1u8; 31]));
+        assert!(!eq([], [0u8]));
+    }
+}
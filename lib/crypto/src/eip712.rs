@@ -0,0 +1,299 @@
+//! Off-chain computation of [EIP-712] hashes.
+//!
+//! These helpers mirror `openzeppelin_stylus::utils::cryptography::eip712`,
+//! so that tests and off-chain tooling can compute the exact digests that
+//! contracts expect to be signed, e.g. for ERC-2612 permits:
+//!
+//! ```rust
+//! use openzeppelin_crypto::eip712::{Domain, StructEncoder};
+//!
+//! let domain = Domain {
+//!     name: "Token",
+//!     version: "1",
+//!     chain_id: 42161,
+//!     verifying_contract: [0xde; 20],
+//! };
+//! let struct_hash = StructEncoder::new(
+//!     "Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)",
+//! )
+//! .address(&[0x01; 20])
+//! .address(&[0x02; 20])
+//! .uint(1000)
+//! .uint(0)
+//! .uint(u128::from(u64::MAX))
+//! .finalize();
+//! let digest = domain.hash_typed_data(&struct_hash);
+//! # assert_ne!(digest, struct_hash);
+//! ```
+//!
+//! [EIP-712]: https://eips.ethereum.org/EIPS/eip-712
+use crate::{hash::Hasher, keccak::Keccak256};
+
+type Bytes32 = [u8; 32];
+
+/// keccak256("EIP712Domain(string name,string version,uint256 chainId,address
+/// verifyingContract)")
+pub const TYPE_HASH: Bytes32 = [
+    0x8b, 0x73, 0xc3, 0xc6, 0x9b, 0xb8, 0xfe, 0x3d, 0x51, 0x2e, 0xcc, 0x4c,
+    0xf7, 0x59, 0xcc, 0x79, 0x23, 0x9f, 0x7b, 0x17, 0x9b, 0x0f, 0xfa, 0xca,
+    0xa9, 0xa7, 0x5d, 0x52, 0x2b, 0x39, 0x40, 0x0f,
+];
+
+/// Prefix for ERC-191 version with `0x01`.
+pub const TYPED_DATA_PREFIX: [u8; 2] = [0x19, 0x01];
+
+/// Fields of an EIP-712 domain, as used by the contracts of this library.
+#[derive(Clone, Copy, core::fmt::Debug, PartialEq, Eq)]
+pub struct Domain<'a> {
+    /// Name of the signing domain, e.g. the name of a token.
+    pub name: &'a str,
+    /// Version of the signing domain.
+    pub version: &'a str,
+    /// Chain id of the contract.
+    pub chain_id: u64,
+    /// Address of the contract.
+    pub verifying_contract: [u8; 20],
+}
+
+impl Domain<'_> {
+    /// Returns the domain separator of this domain.
+    #[must_use]
+    pub fn separator(&self) -> Bytes32 {
+        let mut hasher = Keccak256::new();
+        hasher.update(TYPE_HASH);
+        hasher.update(keccak256(self.name.as_bytes()));
+        hasher.update(keccak256(self.version.as_bytes()));
+        hasher.update(encode_uint(u128::from(self.chain_id)));
+        hasher.update(encode_address(&self.verifying_contract));
+        hasher.finalize()
+    }
+
+    /// Returns the hash of the fully encoded EIP-712 message with
+    /// `struct_hash` for this domain, i.e. the digest to sign.
+    ///
+    /// # Arguments
+    ///
+    /// * `struct_hash` - [Hashed struct] of the message.
+    ///
+    /// [Hashed struct]: https://eips.ethereum.org/EIPS/eip-712#definition-of-hashstruct
+    #[must_use]
+    pub fn hash_typed_data(&self, struct_hash: &Bytes32) -> Bytes32 {
+        to_typed_data_hash(&self.separator(), struct_hash)
+    }
+}
+
+/// Returns the keccak256 digest of an EIP-712 typed data (ERC-191 version
+/// `0x01`), from a `domain_separator` and a `struct_hash`.
+///
+/// # Arguments
+///
+/// * `domain_separator` - Separator of the signing domain.
+/// * `struct_hash` - Hashed struct of the message.
+#[must_use]
+pub fn to_typed_data_hash(
+    domain_separator: &Bytes32,
+    struct_hash: &Bytes32,
+) -> Bytes32 {
+    let mut hasher = Keccak256::new();
+    hasher.update(TYPED_DATA_PREFIX);
+    hasher.update(domain_separator);
+    hasher.update(struct_hash);
+    hasher.finalize()
+}
+
+/// Computes the [hashed struct] of a message, by encoding its members one
+/// after the other.
+///
+/// Members must be encoded in the order of the type, and referenced structs
+/// are encoded with [`Self::word`] from their own hashed struct.
+///
+/// [hashed struct]: https://eips.ethereum.org/EIPS/eip-712#definition-of-hashstruct
+#[derive(Clone)]
+pub struct StructEncoder(Keccak256);
+
+impl StructEncoder {
+    /// Starts encoding a struct of type `encoded_type`, e.g.
+    /// `"Mail(Person from,Person to,string contents)Person(string name,address
+    /// wallet)"`.
+    ///
+    /// # Arguments
+    ///
+    /// * `encoded_type` - Encoding of the type, including referenced types.
+    #[must_use]
+    pub fn new(encoded_type: &str) -> Self {
+        let mut hasher = Keccak256::new();
+        hasher.update(keccak256(encoded_type.as_bytes()));
+        Self(hasher)
+    }
+
+    /// Encodes a member already encoded as 32 bytes, e.g. a `bytes32`, a
+    /// big-endian `uint256` or the hashed struct of a referenced struct.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The encoder.
+    /// * `value` - Encoded member.
+    #[must_use]
+    pub fn word(mut self, value: &Bytes32) -> Self {
+        self.0.update(value);
+        self
+    }
+
+    /// Encodes an `address` member.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The encoder.
+    /// * `value` - Address.
+    #[must_use]
+    pub fn address(self, value: &[u8; 20]) -> Self {
+        self.word(&encode_address(value))
+    }
+
+    /// Encodes an unsigned integer member which fits in a `u128`.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The encoder.
+    /// * `value` - Integer.
+    #[must_use]
+    pub fn uint(self, value: u128) -> Self {
+        self.word(&encode_uint(value))
+    }
+
+    /// Encodes a `bool` member.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The encoder.
+    /// * `value` - Boolean.
+    #[must_use]
+    pub fn bool(self, value: bool) -> Self {
+        self.uint(u128::from(value))
+    }
+
+    /// Encodes a dynamic `bytes` member, as its hash.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The encoder.
+    /// * `value` - Bytes.
+    #[must_use]
+    pub fn bytes(self, value: &[u8]) -> Self {
+        self.word(&keccak256(value))
+    }
+
+    /// Encodes a `string` member, as its hash.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The encoder.
+    /// * `value` - String.
+    #[must_use]
+    pub fn string(self, value: &str) -> Self {
+        self.bytes(value.as_bytes())
+    }
+
+    /// Returns the hashed struct.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The encoder.
+    #[must_use]
+    pub fn finalize(self) -> Bytes32 {
+        self.0.finalize()
+    }
+}
+
+/// Hashes `input` with `keccak256`.
+fn keccak256(input: &[u8]) -> Bytes32 {
+    let mut hasher = Keccak256::new();
+    hasher.update(input);
+    hasher.finalize()
+}
+
+/// Left-pads an address to 32 bytes.
+fn encode_address(value: &[u8; 20]) -> Bytes32 {
+    let mut word = [0u8; 32];
+    word[12..].copy_from_slice(value);
+    word
+}
+
+/// Encodes an integer as a big-endian `uint256`.
+fn encode_uint(value: u128) -> Bytes32 {
+    let mut word = [0u8; 32];
+    word[16..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    //! NOTE: The test vectors are taken from the example of the EIP-712
+    //! specification.
+    use hex_literal::hex;
+
+    use super::{keccak256, Domain, StructEncoder, TYPE_HASH};
+
+    const DOMAIN: Domain = Domain {
+        name: "Ether Mail",
+        version: "1",
+        chain_id: 1,
+        verifying_contract: hex!("CcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"),
+    };
+    const MAIL: &str = "Mail(Person from,Person to,string contents)Person(string name,address wallet)";
+    const PERSON: &str = "Person(string name,address wallet)";
+
+    fn person(name: &str, wallet: [u8; 20]) -> [u8; 32] {
+        StructEncoder::new(PERSON).string(name).address(&wallet).finalize()
+    }
+
+    #[test]
+    fn computes_type_hash() {
+        assert_eq!(
+            keccak256(b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"),
+            TYPE_HASH
+        );
+    }
+
+    #[test]
+    fn computes_mail_digest() {
+        assert_eq!(
+            DOMAIN.separator(),
+            hex!("f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f")
+        );
+
+        let from =
+            person("Cow", hex!("CD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"));
+        let to =
+            person("Bob", hex!("bBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"));
+        let mail = StructEncoder::new(MAIL)
+            .word(&from)
+            .word(&to)
+            .string("Hello, Bob!")
+            .finalize();
+        assert_eq!(
+            mail,
+            hex!("c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e")
+        );
+
+        assert_eq!(
+            DOMAIN.hash_typed_data(&mail),
+            hex!("be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2")
+        );
+    }
+
+    #[test]
+    fn encodes_scalar_members() {
+        let encoded = StructEncoder::new("Flag(bool set,uint256 count)")
+            .bool(true)
+            .uint(1)
+            .finalize();
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        let expected = StructEncoder::new("Flag(bool set,uint256 count)")
+            .word(&one)
+            .word(&one)
+            .finalize();
+        assert_eq!(encoded, expected);
+    }
+}
@@ -1,5 +1,21 @@
 //! An interface to the default hashing algorithm used in this library's [merkle
 //! proofs][crate].
+//!
+//! [`Keccak256`] absorbs its input as a stream, so large inputs, e.g. calldata,
+//! can be hashed chunk by chunk without being copied to an intermediate buffer:
+//!
+//! ```rust
+//! use openzeppelin_crypto::{hash::Hasher, keccak::Keccak256};
+//!
+//! let mut hasher = Keccak256::new();
+//! for chunk in b"hello world".chunks(4) {
+//!     hasher.update(chunk);
+//! }
+//! let hash = hasher.finalize_reset();
+//!
+//! hasher.update(b"hello world");
+//! assert_eq!(hash, hasher.finalize());
+//! ```
 use tiny_keccak::{Hasher as TinyHasher, Keccak};
 
 use crate::hash::{BuildHasher, Hash, Hasher};
@@ -9,6 +25,7 @@ use crate::hash::{BuildHasher, Hash, Hasher};
 ///
 /// It instantiates a [`Keccak256`] hasher.
 #[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, core::fmt::Debug, Default)]
 pub struct KeccakBuilder;
 
 impl BuildHasher for KeccakBuilder {
@@ -16,7 +33,7 @@ impl BuildHasher for KeccakBuilder {
 
     #[inline]
     fn build_hasher(&self) -> Self::Hasher {
-        Keccak256(Keccak::v256())
+        Keccak256::new()
     }
 }
 
@@ -25,8 +42,35 @@ impl BuildHasher for KeccakBuilder {
 /// The underlying implementation is guaranteed to match that of the
 /// `keccak256` algorithm, commonly used in Ethereum.
 #[allow(clippy::module_name_repetitions)]
+#[derive(Clone)]
 pub struct Keccak256(Keccak);
 
+impl Keccak256 {
+    /// Creates a new hasher, with an empty state.
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Keccak::v256())
+    }
+
+    /// Writes the hash of the absorbed input to `output`, without allocating
+    /// a new buffer.
+    pub fn finalize_into(self, output: &mut [u8; 32]) {
+        self.0.finalize(output);
+    }
+
+    /// Returns the hash of the absorbed input, and resets the hasher to an
+    /// empty state, so that it can be reused for another input.
+    pub fn finalize_reset(&mut self) -> [u8; 32] {
+        core::mem::take(self).finalize()
+    }
+}
+
+impl Default for Keccak256 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Hasher for Keccak256 {
     type Output = [u8; 32];
 
@@ -36,7 +80,7 @@ impl Hasher for Keccak256 {
 
     fn finalize(self) -> Self::Output {
         let mut buffer = [0u8; 32];
-        self.0.finalize(&mut buffer);
+        self.finalize_into(&mut buffer);
         buffer
     }
 }
@@ -47,3 +91,47 @@ impl Hash for [u8; 32] {
         state.update(self);
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use hex_literal::hex;
+
+    use super::{Keccak256, KeccakBuilder};
+    use crate::hash::{BuildHasher, Hasher};
+
+    const EMPTY: [u8; 32] = hex!(
+        "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+    );
+
+    #[test]
+    fn hashes_chunks_as_whole_input() {
+        let input: Vec<u8> = (0..=255).cycle().take(1000).collect();
+        let mut whole = KeccakBuilder.build_hasher();
+        whole.update(&input);
+
+        let mut chunked = Keccak256::new();
+        for chunk in input.chunks(7) {
+            chunked.update(chunk);
+        }
+
+        assert_eq!(chunked.finalize(), whole.finalize());
+    }
+
+    #[test]
+    fn finalize_reset_empties_state() {
+        let mut hasher = Keccak256::new();
+        hasher.update([1, 2, 3]);
+        let hash = hasher.finalize_reset();
+
+        hasher.update([1, 2, 3]);
+        assert_eq!(hasher.finalize_reset(), hash);
+        assert_eq!(hasher.finalize_reset(), EMPTY);
+    }
+
+    #[test]
+    fn finalizes_into_buffer() {
+        let mut output = [0xff; 32];
+        Keccak256::new().finalize_into(&mut output);
+        assert_eq!(output, EMPTY);
+    }
+}
@@ -14,15 +14,99 @@ Common cryptographic procedures for a blockchain environment.
 - A `verify_multi_proof` function which can prove multiple values are part of a
   [Merkle tree].
 
+[`merkle/tree.rs`](./src/merkle/tree.rs) and
+[`merkle/incremental.rs`](./src/merkle/incremental.rs) provide a `StandardMerkleTree` which
+builds trees, and their proofs, identical to the ones built by
+`@openzeppelin/merkle-tree`, and an append-only `IncrementalMerkleTree` of
+fixed depth.
+Trees can also be built over any `BuildHasher`, and with a custom
+`LeafEncoding` of their values, with the generic `MerkleTree`.
+
+[`merkle/sparse.rs`](./src/merkle/sparse.rs) provides a `SparseMerkleTree`
+keyed by 256-bit keys, whose proofs can show that a key is either part of the
+tree, or absent from it.
+
 [Merkle tree]: https://en.wikipedia.org/wiki/Merkle_tree
 
+## Hashers
+
+Merkle proofs use `keccak256` by default, but can be verified with any
+`BuildHasher`, e.g. with the following hashers, available behind feature flags:
+
+- `Blake2bBuilder`, enabled by the `blake2` feature, computes `BLAKE2b-256`
+  digests.
+- `Blake3Builder`, enabled by the `blake3` feature, computes `BLAKE3` digests.
+
+## Poseidon
+
+[`poseidon`](./src/poseidon/mod.rs), enabled by the `poseidon` feature, provides
+the zk-friendly Poseidon hash over the BN254 scalar field, compatible with
+circomlib.
+
+## BLS Signatures
+
+[`bls`](./src/bls.rs), enabled by the `bls` feature, verifies and aggregates
+BLS signatures over the BLS12-381 curve, as used by Ethereum's consensus
+layer, and encodes them for the precompiles of EIP-2537.
+
+## Schnorr Signatures
+
+[`schnorr`](./src/schnorr.rs), enabled by the `schnorr` feature, verifies
+BIP-340 Schnorr signatures over the secp256k1 curve, e.g. aggregated
+signatures of `MuSig2` or FROST committees.
+
+## Verifiable Random Functions
+
+[`vrf`](./src/vrf.rs), enabled by the `vrf` feature, verifies
+`ECVRF-SECP256K1-SHA256-TAI` proofs and returns their output, so that
+contracts can consume randomness they can check instead of trusting an
+oracle's word.
+
+## EIP-712 Hashing
+
+[`eip712`](./src/eip712.rs), enabled by the `std` feature, computes EIP-712
+domain separators, hashed structs and digests off-chain, identical to the ones
+computed by the contracts of `openzeppelin-stylus`, e.g. to sign permits in
+tests.
+
+## Constant-Time Comparisons
+
+[`constant_time`](./src/constant_time.rs) provides an `eq` function which
+compares byte strings, e.g. hashes or commitments, in a time that doesn't
+depend on their contents. The proofs of this crate are checked with it.
+
 */
 
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 extern crate alloc;
 
+pub mod constant_time;
+#[cfg(feature = "std")]
+pub mod eip712;
 pub mod hash;
 pub mod merkle;
 
 pub mod keccak;
 pub use keccak::KeccakBuilder;
+
+#[cfg(feature = "blake2")]
+pub mod blake2b;
+#[cfg(feature = "blake2")]
+pub use blake2b::Blake2bBuilder;
+
+#[cfg(feature = "blake3")]
+pub mod blake3;
+#[cfg(feature = "blake3")]
+pub use blake3::Blake3Builder;
+
+#[cfg(feature = "poseidon")]
+pub mod poseidon;
+
+#[cfg(feature = "bls")]
+pub mod bls;
+
+#[cfg(feature = "schnorr")]
+pub mod schnorr;
+
+#[cfg(feature = "vrf")]
+pub mod vrf;
@@ -13,13 +13,78 @@
 use alloc::vec::Vec;
 use core::marker::PhantomData;
 
+pub mod incremental;
+pub mod sparse;
+pub mod tree;
+
 use crate::{
+    constant_time,
     hash::{commutative_hash_pair, BuildHasher, Hasher},
     KeccakBuilder,
 };
 
 type Bytes32 = [u8; 32];
 
+/// Encoding of the values of a Merkle tree into its leaves.
+///
+/// Trees built by `OpenZeppelin`'s libraries use [`DoubleHashLeaf`], but
+/// other ecosystems commonly hash their values only once, with
+/// [`SingleHashLeaf`], or define their own encoding.
+pub trait LeafEncoding {
+    /// Computes the leaf of `value` with the hashing algorithm defined by
+    /// `builder`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Encoded value, e.g. its ABI encoding.
+    /// * `builder` - A [`BuildHasher`] that represents a hashing algorithm.
+    fn leaf_hash<B>(value: &[u8], builder: &B) -> Bytes32
+    where
+        B: BuildHasher,
+        B::Hasher: Hasher<Output = Bytes32>;
+}
+
+/// Leaves computed as the hash of the hash of their value, i.e.
+/// `keccak256(bytes.concat(keccak256(abi.encode(...))))` with the default
+/// hasher.
+///
+/// Hashing values twice prevents second preimage attacks, since a leaf can't
+/// be reinterpreted as the concatenation of two internal nodes.
+#[derive(Clone, Copy, core::fmt::Debug, Default)]
+pub struct DoubleHashLeaf;
+
+impl LeafEncoding for DoubleHashLeaf {
+    fn leaf_hash<B>(value: &[u8], builder: &B) -> Bytes32
+    where
+        B: BuildHasher,
+        B::Hasher: Hasher<Output = Bytes32>,
+    {
+        let hash = SingleHashLeaf::leaf_hash(value, builder);
+        let mut hasher = builder.build_hasher();
+        hasher.update(hash);
+        hasher.finalize()
+    }
+}
+
+/// Leaves computed as the hash of their value.
+///
+/// WARNING: Values of 64 bytes can be reinterpreted as a pair of internal
+/// nodes with this encoding. See the warning of the [module](self).
+#[derive(Clone, Copy, core::fmt::Debug, Default)]
+pub struct SingleHashLeaf;
+
+impl LeafEncoding for SingleHashLeaf {
+    fn leaf_hash<B>(value: &[u8], builder: &B) -> Bytes32
+    where
+        B: BuildHasher,
+        B::Hasher: Hasher<Output = Bytes32>,
+    {
+        let mut hasher = builder.build_hasher();
+        hasher.update(value);
+        hasher.finalize()
+    }
+}
+
 /// Verify merkle proofs.
 pub struct Verifier<B = KeccakBuilder>(PhantomData<B>)
 where
@@ -186,7 +251,45 @@ where
             leaf = commutative_hash_pair(leaf, hash, builder.build_hasher());
         }
 
-        leaf == root
+        constant_time::eq(leaf, root)
+    }
+
+    /// Verify that `value` is part of a Merkle tree defined by `root` by using
+    /// `proof`, after encoding it into a leaf with `L`, and a custom hashing
+    /// algorithm defined by `builder`.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - A slice of hashes that constitute the merkle proof.
+    /// * `root` - The root of the merkle tree, in bytes.
+    /// * `value` - The encoded value to prove.
+    /// * `builder` - A [`BuildHasher`] that represents a hashing algorithm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use openzeppelin_crypto::{
+    ///     merkle::{tree::MerkleTree, SingleHashLeaf, Verifier},
+    ///     KeccakBuilder,
+    /// };
+    ///
+    /// let values = [b"a", b"b", b"c"];
+    /// let tree = MerkleTree::<KeccakBuilder, SingleHashLeaf>::of(&values).unwrap();
+    /// let proof = tree.proof(1).unwrap();
+    ///
+    /// let verification = Verifier::verify_value_with_builder::<SingleHashLeaf>(
+    ///     &proof, tree.root(), b"b", &KeccakBuilder,
+    /// );
+    /// assert!(verification);
+    /// ```
+    pub fn verify_value_with_builder<L: LeafEncoding>(
+        proof: &[Bytes32],
+        root: Bytes32,
+        value: &[u8],
+        builder: &B,
+    ) -> bool {
+        let leaf = L::leaf_hash(value, builder);
+        Self::verify_with_builder(proof, root, leaf, builder)
     }
 
     /// Verify multiple `leaves` can be simultaneously proven to be a part of
@@ -271,7 +374,7 @@ where
             // given the previous check. We use `unwrap_or_else` to avoid
             // eagerly evaluating `proof[0]`, which may panic.
             let rebuilt_root = *leaves.first().unwrap_or_else(|| &proof[0]);
-            return Ok(root == rebuilt_root);
+            return Ok(constant_time::eq(root, rebuilt_root));
         }
 
         // `hashes` represents a queue of hashes, our "main queue".
@@ -310,14 +413,11 @@ where
 
         // We know that `total_hashes > 0`.
         let rebuilt_root = hashes[total_hashes + leaves.len() - 1];
-        Ok(root == rebuilt_root)
+        Ok(constant_time::eq(root, rebuilt_root))
     }
 }
 
 /// An error that occurred while verifying a multi-proof.
-///
-/// TODO: Once <https://github.com/rust-lang/rust/issues/103765> is resolved,
-/// we should derive `core::error::Error`.
 #[derive(core::fmt::Debug)]
 pub enum MultiProofError {
     /// The proof length does not match the flags.
@@ -351,6 +451,8 @@ impl core::fmt::Display for MultiProofError {
     }
 }
 
+impl core::error::Error for MultiProofError {}
+
 #[cfg(all(test, feature = "std"))]
 mod tests {
     //! NOTE: The values used as input for these tests were all generated using
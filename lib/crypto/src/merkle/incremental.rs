@@ -0,0 +1,242 @@
+//! Append-only Merkle tree of fixed depth.
+//!
+//! An [`IncrementalMerkleTree`] only keeps one node per level, the
+//! rightmost left sibling or "side", plus the hashes of empty subtrees, so
+//! that pushing a leaf and computing the new root costs `DEPTH` hashes and
+//! no storage of the whole tree. Roots are compatible with the ones of
+//! `OpenZeppelin`'s Solidity `MerkleTree` library, which makes it suited for
+//! deposit queues and privacy pools, whose members later prove their leaf
+//! with [`super::Verifier`].
+use crate::{
+    hash::{commutative_hash_pair, BuildHasher, Hasher},
+    KeccakBuilder,
+};
+
+type Bytes32 = [u8; 32];
+
+/// An error that occurred while pushing a leaf to an
+/// [`IncrementalMerkleTree`].
+#[derive(core::fmt::Debug, PartialEq, Eq)]
+pub enum IncrementalTreeError {
+    /// The tree already contains `2^DEPTH` leaves.
+    Full,
+}
+
+impl core::fmt::Display for IncrementalTreeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            IncrementalTreeError::Full => write!(f, "merkle tree is full"),
+        }
+    }
+}
+
+impl core::error::Error for IncrementalTreeError {}
+
+/// An append-only Merkle tree with `DEPTH` levels, whose empty leaves are
+/// equal to a `zero` value.
+///
+/// Pairs of nodes are hashed sorted, with the hasher built by `B`.
+pub struct IncrementalMerkleTree<const DEPTH: usize, B = KeccakBuilder>
+where
+    B: BuildHasher,
+{
+    /// Rightmost left node of each level.
+    sides: [Bytes32; DEPTH],
+    /// Hash of an empty subtree of each level.
+    zeros: [Bytes32; DEPTH],
+    /// Current root of the tree.
+    root: Bytes32,
+    /// Index of the next leaf to push.
+    next_leaf_index: usize,
+    /// Builder of the hasher of the nodes.
+    builder: B,
+}
+
+impl<const DEPTH: usize> IncrementalMerkleTree<DEPTH, KeccakBuilder> {
+    /// Creates an empty tree whose leaves are `zero`, hashed with
+    /// `keccak256`.
+    ///
+    /// # Arguments
+    ///
+    /// * `zero` - Value of the empty leaves.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openzeppelin_crypto::merkle::incremental::IncrementalMerkleTree;
+    ///
+    /// let mut tree = IncrementalMerkleTree::<3>::new([0u8; 32]);
+    /// let (index, root) = tree.push([1u8; 32]).unwrap();
+    /// assert_eq!(index, 0);
+    /// assert_eq!(tree.root(), root);
+    /// ```
+    #[must_use]
+    pub fn new(zero: Bytes32) -> Self {
+        Self::with_builder(zero, KeccakBuilder)
+    }
+}
+
+impl<const DEPTH: usize, B> IncrementalMerkleTree<DEPTH, B>
+where
+    B: BuildHasher,
+    B::Hasher: Hasher<Output = Bytes32>,
+{
+    /// Creates an empty tree whose leaves are `zero`, hashed with the hasher
+    /// built by `builder`.
+    ///
+    /// # Arguments
+    ///
+    /// * `zero` - Value of the empty leaves.
+    /// * `builder` - A [`BuildHasher`] that represents a hashing algorithm.
+    pub fn with_builder(zero: Bytes32, builder: B) -> Self {
+        let mut zeros = [[0u8; 32]; DEPTH];
+        let mut current = zero;
+        for level in &mut zeros {
+            *level = current;
+            current =
+                commutative_hash_pair(current, current, builder.build_hasher());
+        }
+
+        Self {
+            sides: [[0u8; 32]; DEPTH],
+            zeros,
+            root: current,
+            next_leaf_index: 0,
+            builder,
+        }
+    }
+
+    /// Returns the current root of the tree.
+    #[must_use]
+    pub fn root(&self) -> Bytes32 {
+        self.root
+    }
+
+    /// Returns the number of leaves pushed to the tree.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.next_leaf_index
+    }
+
+    /// Returns true if no leaf was pushed to the tree, and false otherwise.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.next_leaf_index == 0
+    }
+
+    /// Returns the hash of an empty subtree at `level`, where level 0 is the
+    /// one of the leaves, or `None` if the tree has no such level.
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - Level of the subtree.
+    #[must_use]
+    pub fn zero(&self, level: usize) -> Option<Bytes32> {
+        self.zeros.get(level).copied()
+    }
+
+    /// Pushes `leaf` to the tree.
+    ///
+    /// Returns the index of the leaf and the new root of the tree.
+    ///
+    /// # Arguments
+    ///
+    /// * `leaf` - Leaf to push.
+    ///
+    /// # Errors
+    ///
+    /// If the tree already contains `2^DEPTH` leaves, then the error
+    /// [`IncrementalTreeError::Full`] is returned.
+    pub fn push(
+        &mut self,
+        leaf: Bytes32,
+    ) -> Result<(usize, Bytes32), IncrementalTreeError> {
+        let index = self.next_leaf_index;
+        let capacity = u32::try_from(DEPTH)
+            .ok()
+            .and_then(|depth| 1usize.checked_shl(depth));
+        if capacity.is_some_and(|capacity| index >= capacity) {
+            return Err(IncrementalTreeError::Full);
+        }
+
+        let mut current_index = index;
+        let mut current = leaf;
+        for level in 0..DEPTH {
+            let (left, right) = if current_index.is_multiple_of(2) {
+                self.sides[level] = current;
+                (current, self.zeros[level])
+            } else {
+                (self.sides[level], current)
+            };
+            current =
+                commutative_hash_pair(left, right, self.builder.build_hasher());
+            current_index >>= 1;
+        }
+
+        self.next_leaf_index += 1;
+        self.root = current;
+        Ok((index, current))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{IncrementalMerkleTree, IncrementalTreeError};
+    use crate::{
+        hash::{commutative_hash_pair, BuildHasher},
+        merkle::Verifier,
+        KeccakBuilder,
+    };
+
+    fn hash(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        commutative_hash_pair(a, b, KeccakBuilder.build_hasher())
+    }
+
+    #[test]
+    fn computes_empty_root() {
+        let zero = [0u8; 32];
+        let tree = IncrementalMerkleTree::<2>::new(zero);
+
+        let zero_1 = hash(zero, zero);
+        assert!(tree.is_empty());
+        assert_eq!(tree.zero(0), Some(zero));
+        assert_eq!(tree.zero(1), Some(zero_1));
+        assert_eq!(tree.zero(2), None);
+        assert_eq!(tree.root(), hash(zero_1, zero_1));
+    }
+
+    #[test]
+    fn pushes_leaves() {
+        let zero = [0u8; 32];
+        let leaves = [[1u8; 32], [2u8; 32], [3u8; 32]];
+        let mut tree = IncrementalMerkleTree::<2>::new(zero);
+
+        let (index, root) = tree.push(leaves[0]).unwrap();
+        assert_eq!(index, 0);
+        assert_eq!(root, hash(hash(leaves[0], zero), hash(zero, zero)));
+
+        tree.push(leaves[1]).unwrap();
+        let (index, root) = tree.push(leaves[2]).unwrap();
+        let left = hash(leaves[0], leaves[1]);
+        let right = hash(leaves[2], zero);
+        assert_eq!(index, 2);
+        assert_eq!(tree.len(), 3);
+        assert_eq!(root, hash(left, right));
+        assert_eq!(tree.root(), root);
+
+        // Leaves can be proven against the root.
+        assert!(Verifier::verify(&[zero, left], root, leaves[2]));
+        assert!(Verifier::verify(&[leaves[0], right], root, leaves[1]));
+    }
+
+    #[test]
+    fn errors_when_full() {
+        let mut tree = IncrementalMerkleTree::<1>::new([0u8; 32]);
+        tree.push([1u8; 32]).unwrap();
+        let (_, root) = tree.push([2u8; 32]).unwrap();
+
+        assert_eq!(tree.push([3u8; 32]), Err(IncrementalTreeError::Full));
+        assert_eq!(tree.root(), root);
+        assert_eq!(tree.len(), 2);
+    }
+}
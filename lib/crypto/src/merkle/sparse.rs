@@ -0,0 +1,486 @@
+//! Sparse Merkle tree supporting inclusion and exclusion proofs.
+//!
+//! A [`SparseMerkleTree`] commits to a mapping from 32-byte keys to 32-byte
+//! values. Each key has a fixed position among the `2^256` leaves of the tree,
+//! given by its bits from the most significant one, so whether a key is set
+//! can be proven either way: a registry can prove that an account is
+//! allowlisted, but also that it isn't.
+//!
+//! - The leaf of a set key is `hash(key ‖ value)`.
+//! - Empty leaves, and nodes whose children are both empty, are zero.
+//! - Other nodes are `hash(left ‖ right)`. Pairs are not sorted.
+//!
+//! Proofs are compressed: only the non-empty siblings are included, and a
+//! bitmap tells which of the 256 siblings they are. They are checked with
+//! [`SparseVerifier`], e.g. by a contract.
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::marker::PhantomData;
+
+use crate::{
+    constant_time,
+    hash::{BuildHasher, Hasher},
+    KeccakBuilder,
+};
+
+type Bytes32 = [u8; 32];
+
+/// Number of levels of the tree below its root.
+pub const DEPTH: usize = 256;
+
+/// Value of empty leaves and subtrees.
+const EMPTY: Bytes32 = [0u8; 32];
+
+/// A compressed proof of the leaf of a key in a [`SparseMerkleTree`].
+#[derive(core::fmt::Debug, Clone, PartialEq, Eq)]
+pub struct SparseProof {
+    /// Bit `i` (from the least significant bit of the last byte) is set if
+    /// the sibling at height `i` is not empty. Height 0 is the one of the
+    /// leaves.
+    pub bitmap: Bytes32,
+    /// Non-empty siblings, from the leaves up to the root.
+    pub siblings: Vec<Bytes32>,
+}
+
+/// Returns whether the bit `index` of `bytes` is set, counting from the least
+/// significant bit of the last byte.
+fn bit(bytes: &Bytes32, index: usize) -> bool {
+    bytes[31 - index / 8] >> (index % 8) & 1 == 1
+}
+
+/// Sets the bit `index` of `bytes`, counting from the least significant bit
+/// of the last byte.
+fn set_bit(bytes: &mut Bytes32, index: usize) {
+    bytes[31 - index / 8] |= 1 << (index % 8);
+}
+
+/// Hashes the nodes `left` and `right`, shortcutting empty subtrees.
+fn hash_node<B: BuildHasher>(
+    left: Bytes32,
+    right: Bytes32,
+    builder: &B,
+) -> Bytes32
+where
+    B::Hasher: Hasher<Output = Bytes32>,
+{
+    if left == EMPTY && right == EMPTY {
+        return EMPTY;
+    }
+    let mut hasher = builder.build_hasher();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize()
+}
+
+/// Computes the leaf of `key` set to `value`.
+fn hash_leaf<B: BuildHasher>(
+    key: Bytes32,
+    value: Bytes32,
+    builder: &B,
+) -> Bytes32
+where
+    B::Hasher: Hasher<Output = Bytes32>,
+{
+    let mut hasher = builder.build_hasher();
+    hasher.update(key);
+    hasher.update(value);
+    hasher.finalize()
+}
+
+/// Verify sparse merkle proofs.
+pub struct SparseVerifier<B = KeccakBuilder>(PhantomData<B>)
+where
+    B: BuildHasher;
+
+impl SparseVerifier<KeccakBuilder> {
+    /// Verify that `key` is set to `value` in the tree defined by `root`,
+    /// using the default `keccak256` hashing algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - The proof of the leaf of `key`.
+    /// * `root` - The root of the tree.
+    /// * `key` - The key to prove.
+    /// * `value` - The value of `key`.
+    #[must_use]
+    pub fn verify_inclusion(
+        proof: &SparseProof,
+        root: Bytes32,
+        key: Bytes32,
+        value: Bytes32,
+    ) -> bool {
+        SparseVerifier::verify_inclusion_with_builder(
+            proof,
+            root,
+            key,
+            value,
+            &KeccakBuilder,
+        )
+    }
+
+    /// Verify that `key` is not set in the tree defined by `root`, using the
+    /// default `keccak256` hashing algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - The proof of the leaf of `key`.
+    /// * `root` - The root of the tree.
+    /// * `key` - The key to prove.
+    #[must_use]
+    pub fn verify_exclusion(
+        proof: &SparseProof,
+        root: Bytes32,
+        key: Bytes32,
+    ) -> bool {
+        SparseVerifier::verify_exclusion_with_builder(
+            proof,
+            root,
+            key,
+            &KeccakBuilder,
+        )
+    }
+}
+
+impl<B> SparseVerifier<B>
+where
+    B: BuildHasher,
+    B::Hasher: Hasher<Output = Bytes32>,
+{
+    /// Verify that `key` is set to `value` in the tree defined by `root`,
+    /// using a custom hashing algorithm defined by `builder`.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - The proof of the leaf of `key`.
+    /// * `root` - The root of the tree.
+    /// * `key` - The key to prove.
+    /// * `value` - The value of `key`.
+    /// * `builder` - A [`BuildHasher`] that represents a hashing algorithm.
+    pub fn verify_inclusion_with_builder(
+        proof: &SparseProof,
+        root: Bytes32,
+        key: Bytes32,
+        value: Bytes32,
+        builder: &B,
+    ) -> bool {
+        let leaf = hash_leaf(key, value, builder);
+        Self::compute_root(proof, key, leaf, builder)
+            .is_some_and(|computed| constant_time::eq(computed, root))
+    }
+
+    /// Verify that `key` is not set in the tree defined by `root`, using a
+    /// custom hashing algorithm defined by `builder`.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - The proof of the leaf of `key`.
+    /// * `root` - The root of the tree.
+    /// * `key` - The key to prove.
+    /// * `builder` - A [`BuildHasher`] that represents a hashing algorithm.
+    pub fn verify_exclusion_with_builder(
+        proof: &SparseProof,
+        root: Bytes32,
+        key: Bytes32,
+        builder: &B,
+    ) -> bool {
+        Self::compute_root(proof, key, EMPTY, builder)
+            .is_some_and(|computed| constant_time::eq(computed, root))
+    }
+
+    /// Rebuilds the root from `leaf` at the position of `key`, or returns
+    /// `None` if the proof is malformed.
+    fn compute_root(
+        proof: &SparseProof,
+        key: Bytes32,
+        leaf: Bytes32,
+        builder: &B,
+    ) -> Option<Bytes32> {
+        let mut siblings = proof.siblings.iter();
+        let mut node = leaf;
+        for height in 0..DEPTH {
+            let sibling = if bit(&proof.bitmap, height) {
+                *siblings.next()?
+            } else {
+                EMPTY
+            };
+            // The bit of the key at `height` tells whether the node is the
+            // right child of its parent.
+            node = if bit(&key, height) {
+                hash_node(sibling, node, builder)
+            } else {
+                hash_node(node, sibling, builder)
+            };
+        }
+
+        // All the siblings should be consumed.
+        siblings.next().is_none().then_some(node)
+    }
+}
+
+/// A sparse Merkle tree from 32-byte keys to 32-byte values.
+///
+/// # Examples
+///
+/// ```rust
+/// use openzeppelin_crypto::merkle::sparse::{SparseMerkleTree, SparseVerifier};
+///
+/// let mut tree = SparseMerkleTree::new();
+/// tree.insert([1u8; 32], [2u8; 32]);
+///
+/// let root = tree.root();
+/// let proof = tree.proof([1u8; 32]);
+/// assert!(SparseVerifier::verify_inclusion(&proof, root, [1u8; 32], [2u8; 32]));
+///
+/// let proof = tree.proof([3u8; 32]);
+/// assert!(SparseVerifier::verify_exclusion(&proof, root, [3u8; 32]));
+/// ```
+pub struct SparseMerkleTree<B = KeccakBuilder>
+where
+    B: BuildHasher,
+{
+    /// Values of the set keys, sorted by key.
+    values: BTreeMap<Bytes32, Bytes32>,
+    /// Builder of the hasher of the nodes.
+    builder: B,
+}
+
+impl SparseMerkleTree<KeccakBuilder> {
+    /// Creates an empty tree hashed with `keccak256`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_builder(KeccakBuilder)
+    }
+}
+
+impl Default for SparseMerkleTree<KeccakBuilder> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B> SparseMerkleTree<B>
+where
+    B: BuildHasher,
+    B::Hasher: Hasher<Output = Bytes32>,
+{
+    /// Creates an empty tree hashed with the hasher built by `builder`.
+    ///
+    /// # Arguments
+    ///
+    /// * `builder` - A [`BuildHasher`] that represents a hashing algorithm.
+    pub fn with_builder(builder: B) -> Self {
+        Self { values: BTreeMap::new(), builder }
+    }
+
+    /// Sets `key` to `value`.
+    ///
+    /// Returns the previous value of `key`, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to set.
+    /// * `value` - The value of `key`.
+    pub fn insert(&mut self, key: Bytes32, value: Bytes32) -> Option<Bytes32> {
+        self.values.insert(key, value)
+    }
+
+    /// Unsets `key`.
+    ///
+    /// Returns the previous value of `key`, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to unset.
+    pub fn remove(&mut self, key: &Bytes32) -> Option<Bytes32> {
+        self.values.remove(key)
+    }
+
+    /// Returns the value of `key`, if it is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to look for.
+    #[must_use]
+    pub fn get(&self, key: &Bytes32) -> Option<Bytes32> {
+        self.values.get(key).copied()
+    }
+
+    /// Returns the number of set keys.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if no key is set, and false otherwise.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the root of the tree.
+    #[must_use]
+    pub fn root(&self) -> Bytes32 {
+        let leaves = self.leaves();
+        self.subtree(DEPTH, &leaves)
+    }
+
+    /// Returns the proof of the leaf of `key`, which proves its value if it
+    /// is set, or that it is not set otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to prove.
+    #[must_use]
+    pub fn proof(&self, key: Bytes32) -> SparseProof {
+        let leaves = self.leaves();
+        let mut bitmap = EMPTY;
+        let mut siblings = Vec::new();
+
+        // Walk down from the root, keeping the leaves of the subtree
+        // containing `key`.
+        let mut subtree = &leaves[..];
+        for height in (0..DEPTH).rev() {
+            let split = subtree.partition_point(|(k, _)| !bit(k, height));
+            let (left, right) = subtree.split_at(split);
+            let (next, sibling) =
+                if bit(&key, height) { (right, left) } else { (left, right) };
+
+            let sibling = self.subtree(height, sibling);
+            if sibling != EMPTY {
+                set_bit(&mut bitmap, height);
+                siblings.push(sibling);
+            }
+            subtree = next;
+        }
+
+        siblings.reverse();
+        SparseProof { bitmap, siblings }
+    }
+
+    /// Returns the sorted leaves of the set keys.
+    fn leaves(&self) -> Vec<(Bytes32, Bytes32)> {
+        self.values
+            .iter()
+            .map(|(&key, &value)| (key, hash_leaf(key, value, &self.builder)))
+            .collect()
+    }
+
+    /// Computes the root of the subtree of `height` levels containing the
+    /// sorted `leaves`, which share the path above the subtree.
+    fn subtree(&self, height: usize, leaves: &[(Bytes32, Bytes32)]) -> Bytes32 {
+        match leaves {
+            [] => EMPTY,
+            [(_, leaf)] if height == 0 => *leaf,
+            _ => {
+                let height = height - 1;
+                let split = leaves.partition_point(|(k, _)| !bit(k, height));
+                let (left, right) = leaves.split_at(split);
+                hash_node(
+                    self.subtree(height, left),
+                    self.subtree(height, right),
+                    &self.builder,
+                )
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{
+        hash_leaf, hash_node, SparseMerkleTree, SparseProof, SparseVerifier,
+        EMPTY,
+    };
+    use crate::KeccakBuilder;
+
+    const A: [u8; 32] = [0x0a; 32];
+    const B: [u8; 32] = [0xb0; 32];
+    const C: [u8; 32] = [0xc0; 32];
+
+    #[test]
+    fn empty_tree_has_zero_root() {
+        let tree = SparseMerkleTree::new();
+        assert!(tree.is_empty());
+        assert_eq!(tree.root(), EMPTY);
+
+        let proof = tree.proof(A);
+        assert!(proof.siblings.is_empty());
+        assert!(SparseVerifier::verify_exclusion(&proof, EMPTY, A));
+    }
+
+    #[test]
+    fn computes_root_of_single_leaf() {
+        let mut tree = SparseMerkleTree::new();
+        tree.insert(A, B);
+
+        // Up to the first level, every sibling is empty and every bit of
+        // `A`'s path which is set puts the node on the right.
+        let mut node = hash_leaf(A, B, &KeccakBuilder);
+        for height in 0..256 {
+            node = if super::bit(&A, height) {
+                hash_node(EMPTY, node, &KeccakBuilder)
+            } else {
+                hash_node(node, EMPTY, &KeccakBuilder)
+            };
+        }
+        assert_eq!(tree.root(), node);
+    }
+
+    #[test]
+    fn verifies_inclusion_and_exclusion() {
+        let mut tree = SparseMerkleTree::new();
+        tree.insert(A, [1u8; 32]);
+        tree.insert(B, [2u8; 32]);
+        tree.insert(C, [3u8; 32]);
+        let root = tree.root();
+
+        for (key, value) in [(A, [1u8; 32]), (B, [2u8; 32]), (C, [3u8; 32])] {
+            let proof = tree.proof(key);
+            assert!(SparseVerifier::verify_inclusion(&proof, root, key, value));
+            assert!(!SparseVerifier::verify_inclusion(
+                &proof, root, key, [9u8; 32]
+            ));
+            assert!(!SparseVerifier::verify_exclusion(&proof, root, key));
+        }
+
+        let missing = [0xc1; 32];
+        let proof = tree.proof(missing);
+        assert!(SparseVerifier::verify_exclusion(&proof, root, missing));
+        assert!(!SparseVerifier::verify_inclusion(
+            &proof, root, missing, [3u8; 32]
+        ));
+    }
+
+    #[test]
+    fn updates_root_on_changes() {
+        let mut tree = SparseMerkleTree::new();
+        tree.insert(A, [1u8; 32]);
+        let root_a = tree.root();
+
+        tree.insert(B, [2u8; 32]);
+        assert_ne!(tree.root(), root_a);
+        assert_eq!(tree.insert(B, [3u8; 32]), Some([2u8; 32]));
+
+        assert_eq!(tree.remove(&B), Some([3u8; 32]));
+        assert_eq!(tree.root(), root_a);
+        assert_eq!(tree.get(&A), Some([1u8; 32]));
+        assert_eq!(tree.len(), 1);
+    }
+
+    #[test]
+    fn rejects_malformed_proofs() {
+        let mut tree = SparseMerkleTree::new();
+        tree.insert(A, [1u8; 32]);
+        tree.insert(B, [2u8; 32]);
+        let root = tree.root();
+        let proof = tree.proof(A);
+
+        let mut extra = proof.clone();
+        extra.siblings.push([7u8; 32]);
+        assert!(!SparseVerifier::verify_inclusion(&extra, root, A, [1u8; 32]));
+
+        let missing = SparseProof { bitmap: proof.bitmap, siblings: vec![] };
+        assert!(!SparseVerifier::verify_inclusion(
+            &missing, root, A, [1u8; 32]
+        ));
+    }
+}
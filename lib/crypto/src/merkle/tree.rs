@@ -0,0 +1,623 @@
+//! Construction of Merkle trees compatible with `OpenZeppelin`'s
+//! [merkle tree library](https://github.com/OpenZeppelin/merkle-tree).
+//!
+//! [`StandardMerkleTree`] produces the same roots and proofs as the
+//! library's `StandardMerkleTree`, so trees can be generated in Rust, e.g. in
+//! tests, and their proofs verified on-chain with [`super::Verifier`]:
+//!
+//! - Leaves are the double `keccak256` hash of the ABI-encoded values.
+//! - Leaves are sorted by hash before building the tree.
+//! - Pairs of siblings are sorted before being hashed.
+//!
+//! Trees over other hashing algorithms or leaf formats are built with
+//! [`MerkleTree`], which is generic over a [`BuildHasher`] and a
+//! [`LeafEncoding`].
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use super::{DoubleHashLeaf, LeafEncoding};
+use crate::{
+    hash::{commutative_hash_pair, BuildHasher, Hasher},
+    KeccakBuilder,
+};
+
+type Bytes32 = [u8; 32];
+
+/// Computes the leaf of a value, given its ABI encoding, i.e.
+/// `keccak256(bytes.concat(keccak256(abi.encode(...))))`.
+///
+/// # Arguments
+///
+/// * `encoded` - ABI encoding of the value.
+#[must_use]
+pub fn standard_leaf_hash(encoded: &[u8]) -> Bytes32 {
+    DoubleHashLeaf::leaf_hash(encoded, &KeccakBuilder)
+}
+
+/// A multi-proof of several leaves of a [`MerkleTree`], to be
+/// verified with [`super::Verifier::verify_multi_proof`].
+#[derive(core::fmt::Debug, Clone, PartialEq, Eq)]
+pub struct MultiProof {
+    /// Leaves being proven, in the order expected by the verifier.
+    pub leaves: Vec<Bytes32>,
+    /// Sibling hashes needed to rebuild the root.
+    pub proof: Vec<Bytes32>,
+    /// Whether each step of the verification consumes a computed hash (true)
+    /// or a hash of `proof` (false).
+    pub proof_flags: Vec<bool>,
+}
+
+/// An error that occurred while building a tree or one of its proofs.
+#[derive(core::fmt::Debug, PartialEq, Eq)]
+pub enum TreeError {
+    /// A tree can't be built without leaves.
+    EmptyTree,
+    /// The index of the value does not exist in the tree.
+    IndexOutOfBounds(usize),
+    /// The same value was provided more than once in a multi-proof.
+    DuplicatedIndex(usize),
+}
+
+impl core::fmt::Display for TreeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TreeError::EmptyTree => write!(f, "tree has no leaves"),
+            TreeError::IndexOutOfBounds(index) => {
+                write!(f, "index {index} is out of bounds")
+            }
+            TreeError::DuplicatedIndex(index) => {
+                write!(f, "index {index} is duplicated")
+            }
+        }
+    }
+}
+
+impl core::error::Error for TreeError {}
+
+/// A Merkle tree built like `OpenZeppelin`'s `StandardMerkleTree`, hashing
+/// its nodes with `B` and its values with the leaf encoding `L`.
+///
+/// Values are referred to by their index in the slice the tree was built
+/// from.
+#[derive(core::fmt::Debug, Clone)]
+pub struct MerkleTree<B = KeccakBuilder, L = DoubleHashLeaf>
+where
+    B: BuildHasher,
+{
+    /// Nodes of the complete binary tree, with the root at index 0 and the
+    /// children of node `i` at `2i + 1` and `2i + 2`.
+    tree: Vec<Bytes32>,
+    /// Position in `tree` of the leaf of each value.
+    positions: Vec<usize>,
+    /// Hashing algorithm of the tree.
+    builder: B,
+    _encoding: PhantomData<L>,
+}
+
+/// A Merkle tree identical to the ones built by `OpenZeppelin`'s
+/// `StandardMerkleTree`.
+pub type StandardMerkleTree = MerkleTree<KeccakBuilder, DoubleHashLeaf>;
+
+impl<B, L> MerkleTree<B, L>
+where
+    B: BuildHasher + Default,
+    B::Hasher: Hasher<Output = Bytes32>,
+    L: LeafEncoding,
+{
+    /// Builds a tree from the encodings of its values, e.g. their ABI
+    /// encodings, hashing them with `L`.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - Encodings of the values.
+    ///
+    /// # Errors
+    ///
+    /// If `values` is empty, then the error [`TreeError::EmptyTree`] is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openzeppelin_crypto::merkle::{tree::StandardMerkleTree, Verifier};
+    ///
+    /// let values = [[1u8; 32], [2u8; 32], [3u8; 32]];
+    /// let tree = StandardMerkleTree::of(&values).unwrap();
+    ///
+    /// let leaf = tree.leaf_hash(1).unwrap();
+    /// let proof = tree.proof(1).unwrap();
+    /// assert!(Verifier::verify(&proof, tree.root(), leaf));
+    /// ```
+    pub fn of<V: AsRef<[u8]>>(values: &[V]) -> Result<Self, TreeError> {
+        Self::of_with_builder(values, B::default())
+    }
+
+    /// Builds a tree from already hashed leaves.
+    ///
+    /// # Arguments
+    ///
+    /// * `leaves` - Leaves of the tree.
+    ///
+    /// # Errors
+    ///
+    /// If `leaves` is empty, then the error [`TreeError::EmptyTree`] is
+    /// returned.
+    pub fn from_leaf_hashes(leaves: &[Bytes32]) -> Result<Self, TreeError> {
+        Self::from_leaf_hashes_with_builder(leaves, B::default())
+    }
+}
+
+impl<B, L> MerkleTree<B, L>
+where
+    B: BuildHasher,
+    B::Hasher: Hasher<Output = Bytes32>,
+    L: LeafEncoding,
+{
+    /// Builds a tree from the encodings of its values, hashing them with `L`
+    /// and the hashing algorithm defined by `builder`.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - Encodings of the values.
+    /// * `builder` - A [`BuildHasher`] that represents a hashing algorithm.
+    ///
+    /// # Errors
+    ///
+    /// If `values` is empty, then the error [`TreeError::EmptyTree`] is
+    /// returned.
+    pub fn of_with_builder<V: AsRef<[u8]>>(
+        values: &[V],
+        builder: B,
+    ) -> Result<Self, TreeError> {
+        let leaves = values
+            .iter()
+            .map(|value| L::leaf_hash(value.as_ref(), &builder))
+            .collect::<Vec<_>>();
+        Self::from_leaf_hashes_with_builder(&leaves, builder)
+    }
+
+    /// Builds a tree from already hashed leaves, hashing its nodes with the
+    /// hashing algorithm defined by `builder`.
+    ///
+    /// # Arguments
+    ///
+    /// * `leaves` - Leaves of the tree.
+    /// * `builder` - A [`BuildHasher`] that represents a hashing algorithm.
+    ///
+    /// # Errors
+    ///
+    /// If `leaves` is empty, then the error [`TreeError::EmptyTree`] is
+    /// returned.
+    pub fn from_leaf_hashes_with_builder(
+        leaves: &[Bytes32],
+        builder: B,
+    ) -> Result<Self, TreeError> {
+        if leaves.is_empty() {
+            return Err(TreeError::EmptyTree);
+        }
+
+        // Leaves are sorted by hash, which is stable for duplicated values.
+        let mut order: Vec<usize> = (0..leaves.len()).collect();
+        order.sort_by(|&a, &b| leaves[a].cmp(&leaves[b]));
+
+        let size = 2 * leaves.len() - 1;
+        let mut tree = alloc::vec![[0u8; 32]; size];
+        let mut positions = alloc::vec![0; leaves.len()];
+        for (rank, &index) in order.iter().enumerate() {
+            let position = size - 1 - rank;
+            tree[position] = leaves[index];
+            positions[index] = position;
+        }
+        for i in (0..size - leaves.len()).rev() {
+            tree[i] = commutative_hash_pair(
+                tree[2 * i + 1],
+                tree[2 * i + 2],
+                builder.build_hasher(),
+            );
+        }
+
+        Ok(Self { tree, positions, builder, _encoding: PhantomData })
+    }
+
+    /// Computes the leaf of `value` with the leaf encoding and hashing
+    /// algorithm of the tree, whether it is part of the tree or not.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Encoding of the value.
+    #[must_use]
+    pub fn hash_value(&self, value: &[u8]) -> Bytes32 {
+        L::leaf_hash(value, &self.builder)
+    }
+}
+
+impl<B: BuildHasher, L> MerkleTree<B, L> {
+    /// Returns the root of the tree.
+    #[must_use]
+    pub fn root(&self) -> Bytes32 {
+        self.tree[0]
+    }
+
+    /// Returns the number of leaves of the tree.
+    #[must_use]
+    pub fn leaf_count(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Returns the leaf of the value at `index`, or `None` if it doesn't
+    /// exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of the value.
+    #[must_use]
+    pub fn leaf_hash(&self, index: usize) -> Option<Bytes32> {
+        self.positions.get(index).map(|&position| self.tree[position])
+    }
+
+    /// Returns the proof of the value at `index`, or `None` if it doesn't
+    /// exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of the value.
+    #[must_use]
+    pub fn proof(&self, index: usize) -> Option<Vec<Bytes32>> {
+        let mut position = *self.positions.get(index)?;
+        let mut proof = Vec::new();
+        while position > 0 {
+            proof.push(self.tree[sibling(position)]);
+            position = parent(position);
+        }
+        Some(proof)
+    }
+
+    /// Returns a multi-proof of the values at `indices`.
+    ///
+    /// The `leaves`, `proof` and `proof_flags` of the returned [`MultiProof`]
+    /// are in the order expected by [`super::Verifier::verify_multi_proof`],
+    /// regardless of the order of `indices`.
+    ///
+    /// # Arguments
+    ///
+    /// * `indices` - Indices of the values.
+    ///
+    /// # Errors
+    ///
+    /// If an index doesn't exist, then the error
+    /// [`TreeError::IndexOutOfBounds`] is returned.
+    /// If an index is provided more than once, then the error
+    /// [`TreeError::DuplicatedIndex`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use openzeppelin_crypto::merkle::{
+    ///     tree::{MultiProof, StandardMerkleTree},
+    ///     Verifier,
+    /// };
+    ///
+    /// let values = [[1u8; 32], [2u8; 32], [3u8; 32], [4u8; 32]];
+    /// let tree = StandardMerkleTree::of(&values).unwrap();
+    ///
+    /// let MultiProof { leaves, proof, proof_flags } =
+    ///     tree.multi_proof(&[0, 2, 3]).unwrap();
+    /// let verification =
+    ///     Verifier::verify_multi_proof(&proof, &proof_flags, tree.root(), &leaves);
+    /// assert!(verification.unwrap());
+    /// ```
+    pub fn multi_proof(
+        &self,
+        indices: &[usize],
+    ) -> Result<MultiProof, TreeError> {
+        let mut positions = indices
+            .iter()
+            .map(|&index| {
+                self.positions
+                    .get(index)
+                    .copied()
+                    .ok_or(TreeError::IndexOutOfBounds(index))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        // Leaves are proven from the deepest, rightmost one.
+        positions.sort_unstable_by(|a, b| b.cmp(a));
+        if let Some(pair) = positions.windows(2).find(|pair| pair[0] == pair[1])
+        {
+            let index = self.positions.iter().position(|&p| p == pair[0]);
+            return Err(TreeError::DuplicatedIndex(index.unwrap_or_default()));
+        }
+
+        let leaves = positions.iter().map(|&p| self.tree[p]).collect();
+        let mut proof = Vec::new();
+        let mut proof_flags = Vec::new();
+
+        let mut queue = alloc::collections::VecDeque::from(positions);
+        while let Some(&position) = queue.front() {
+            if position == 0 {
+                break;
+            }
+            queue.pop_front();
+            let sibling = sibling(position);
+            if queue.front() == Some(&sibling) {
+                proof_flags.push(true);
+                queue.pop_front();
+            } else {
+                proof_flags.push(false);
+                proof.push(self.tree[sibling]);
+            }
+            queue.push_back(parent(position));
+        }
+        if indices.is_empty() {
+            proof.push(self.root());
+        }
+
+        Ok(MultiProof { leaves, proof, proof_flags })
+    }
+}
+
+/// Returns the position of the sibling of the node at `position`.
+fn sibling(position: usize) -> usize {
+    if position.is_multiple_of(2) {
+        position - 1
+    } else {
+        position + 1
+    }
+}
+
+/// Returns the position of the parent of the node at `position`.
+fn parent(position: usize) -> usize {
+    (position - 1) / 2
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    //! NOTE: The expected values for these tests were all generated using
+    //! <https://github.com/OpenZeppelin/merkle-tree>.
+    use alloc::{vec, vec::Vec};
+
+    use hex_literal::hex;
+
+    use super::{
+        standard_leaf_hash, MerkleTree, MultiProof, StandardMerkleTree,
+        TreeError,
+    };
+    use crate::{
+        hash::{BuildHasher, Hasher},
+        merkle::{SingleHashLeaf, Verifier},
+        KeccakBuilder,
+    };
+
+    /// ABI-encodes each character of `chars` as a `string`, like
+    /// `toElements(chars)` with the `['string']` encoding.
+    fn to_elements(chars: &str) -> Vec<Vec<u8>> {
+        chars
+            .chars()
+            .map(|c| {
+                let mut encoded = vec![0u8; 96];
+                encoded[31] = 0x20;
+                encoded[63] = 1;
+                encoded[64] = u8::try_from(c).expect("should be ascii");
+                encoded
+            })
+            .collect()
+    }
+
+    #[test]
+    fn builds_same_tree_as_js() {
+        // ```js
+        // const merkleTree = StandardMerkleTree.of(toElements('abc'), ['string']);
+        //
+        // const root = merkleTree.root;
+        // const leaf = merkleTree.leafHash(['a']);
+        // const proof = merkleTree.getProof(['a']);
+        // ```
+        let tree = StandardMerkleTree::of(&to_elements("abc")).unwrap();
+
+        assert_eq!(tree.leaf_count(), 3);
+        assert_eq!(
+            tree.root(),
+            hex!("f2129b5a697531ef818f644564a6552b35c549722385bc52aa7fe46c0b5f46b1")
+        );
+        assert_eq!(
+            tree.leaf_hash(0),
+            Some(hex!("9c15a6a0eaeed500fd9eed4cbeab71f797cefcc67bfd46683e4d2e6ff7f06d1c"))
+        );
+        assert_eq!(
+            tree.proof(0),
+            Some(vec![
+                hex!("19ba6c6333e0e9a15bf67523e0676e2f23eb8e574092552d5e888c64a4bb3681"),
+                hex!("9cf5a63718145ba968a01c1d557020181c5b252f665cf7386d370eddb176517b"),
+            ])
+        );
+        assert_eq!(tree.proof(3), None);
+    }
+
+    #[test]
+    fn proves_every_leaf() {
+        // ```js
+        // const merkleTree = StandardMerkleTree.of(
+        //   toElements('ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/='),
+        //   ['string'],
+        // );
+        //
+        // const root  = merkleTree.root;
+        // const proof = merkleTree.getProof(['A']);
+        // ```
+        let values = to_elements(
+            "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/=",
+        );
+        let tree = StandardMerkleTree::of(&values).unwrap();
+        let root = tree.root();
+
+        assert_eq!(
+            root,
+            hex!("b89eb120147840e813a77109b44063488a346b4ca15686185cf314320560d3f3")
+        );
+        assert_eq!(
+            tree.proof(0).unwrap(),
+            vec![
+                hex!("7051e21dd45e25ed8c605a53da6f77de151dcbf47b0e3ced3c5d8b61f4a13dbc"),
+                hex!("1629d3b5b09b30449d258e35bbd09dd5e8a3abb91425ef810dc27eef995f7490"),
+                hex!("633d21baee4bbe5ed5c51ac0c68f7946b8f28d2937f0ca7ef5e1ea9dbda52e7a"),
+                hex!("8a65d3006581737a3bab46d9e4775dbc1821b1ea813d350a13fcd4f15a8942ec"),
+                hex!("d6c3f3e36cd23ba32443f6a687ecea44ebfe2b8759a62cccf7759ec1fb563c76"),
+                hex!("276141cd72b9b81c67f7182ff8a550b76eb96de9248a3ec027ac048c79649115"),
+            ]
+        );
+
+        for (index, value) in values.iter().enumerate() {
+            let leaf = standard_leaf_hash(value);
+            assert_eq!(tree.leaf_hash(index), Some(leaf));
+            let proof = tree.proof(index).unwrap();
+            assert!(Verifier::verify(&proof, root, leaf));
+        }
+    }
+
+    #[test]
+    fn builds_same_multi_proof_as_js() {
+        // ```js
+        // const merkleTree = StandardMerkleTree.of(toElements('abcdef'), ['string']);
+        //
+        // const root = merkleTree.root;
+        // const { proof, proofFlags, leaves } = merkleTree.getMultiProof(toElements('bdf'));
+        // const hashes = leaves.map(e => merkleTree.leafHash(e));
+        // ```
+        let tree = StandardMerkleTree::of(&to_elements("abcdef")).unwrap();
+        let multi_proof = tree.multi_proof(&[1, 3, 5]).unwrap();
+
+        assert_eq!(
+            tree.root(),
+            hex!("6deb52b5da8fd108f79fab00341f38d2587896634c646ee52e49f845680a70c8")
+        );
+        assert_eq!(
+            multi_proof,
+            MultiProof {
+                leaves: vec![
+                    hex!("19ba6c6333e0e9a15bf67523e0676e2f23eb8e574092552d5e888c64a4bb3681"),
+                    hex!("c62a8cfa41edc0ef6f6ae27a2985b7d39c7fea770787d7e104696c6e81f64848"),
+                    hex!("eba909cf4bb90c6922771d7f126ad0fd11dfde93f3937a196274e1ac20fd2f5b"),
+                ],
+                proof: vec![
+                    hex!("9a4f64e953595df82d1b4f570d34c4f4f0cfaf729a61e9d60e83e579e1aa283e"),
+                    hex!("8076923e76cf01a7c048400a2304c9a9c23bbbdac3a98ea3946340fdafbba34f"),
+                ],
+                proof_flags: vec![false, true, false, true],
+            }
+        );
+    }
+
+    #[test]
+    fn verifies_multi_proofs() {
+        let tree = StandardMerkleTree::of(&to_elements("abcdefg")).unwrap();
+
+        for indices in [vec![], vec![0], vec![6, 2], vec![0, 1, 2, 3, 4, 5, 6]]
+        {
+            let MultiProof { leaves, proof, proof_flags } =
+                tree.multi_proof(&indices).unwrap();
+            let verification = Verifier::verify_multi_proof(
+                &proof,
+                &proof_flags,
+                tree.root(),
+                &leaves,
+            );
+            assert!(verification.unwrap());
+        }
+    }
+
+    #[test]
+    fn verifies_multi_proofs_of_every_subset() {
+        for size in 1..=8 {
+            let values: Vec<[u8; 32]> = (0..size).map(|i| [i; 32]).collect();
+            let tree = StandardMerkleTree::of(&values).unwrap();
+
+            for subset in 0u32..(1 << size) {
+                let indices: Vec<usize> = (0..usize::from(size))
+                    .filter(|&i| subset & (1 << i) != 0)
+                    .collect();
+                let MultiProof { leaves, proof, proof_flags } =
+                    tree.multi_proof(&indices).unwrap();
+
+                assert_eq!(leaves.len(), indices.len());
+                let verification = Verifier::verify_multi_proof(
+                    &proof,
+                    &proof_flags,
+                    tree.root(),
+                    &leaves,
+                );
+                assert!(verification.unwrap(), "{size} leaves, {indices:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let values: [[u8; 32]; 0] = [];
+        assert_eq!(
+            StandardMerkleTree::of(&values).unwrap_err(),
+            TreeError::EmptyTree
+        );
+
+        let tree = StandardMerkleTree::of(&to_elements("abc")).unwrap();
+        assert_eq!(
+            tree.multi_proof(&[0, 3]).unwrap_err(),
+            TreeError::IndexOutOfBounds(3)
+        );
+        assert_eq!(
+            tree.multi_proof(&[1, 0, 1]).unwrap_err(),
+            TreeError::DuplicatedIndex(1)
+        );
+    }
+
+    #[test]
+    fn builds_single_leaf_tree() {
+        let tree = StandardMerkleTree::of(&to_elements("a")).unwrap();
+        let leaf = tree.leaf_hash(0).unwrap();
+
+        assert_eq!(tree.root(), leaf);
+        assert_eq!(tree.proof(0), Some(vec![]));
+    }
+
+    #[test]
+    fn builds_trees_with_custom_leaf_encoding() {
+        let values = to_elements("abcde");
+        let tree =
+            MerkleTree::<KeccakBuilder, SingleHashLeaf>::of(&values).unwrap();
+        let root = tree.root();
+
+        assert_ne!(root, StandardMerkleTree::of(&values).unwrap().root());
+        for (index, value) in values.iter().enumerate() {
+            let mut hasher = KeccakBuilder.build_hasher();
+            hasher.update(value);
+            let leaf = hasher.finalize();
+            assert_eq!(tree.hash_value(value), leaf);
+            assert_eq!(tree.leaf_hash(index), Some(leaf));
+
+            let proof = tree.proof(index).unwrap();
+            assert!(Verifier::verify_value_with_builder::<SingleHashLeaf>(
+                &proof,
+                root,
+                value,
+                &KeccakBuilder,
+            ));
+        }
+    }
+
+    #[cfg(feature = "blake3")]
+    #[test]
+    fn builds_trees_with_custom_hasher() {
+        use crate::Blake3Builder;
+
+        let values = to_elements("abcde");
+        let tree = MerkleTree::<Blake3Builder>::of(&values).unwrap();
+
+        for (index, value) in values.iter().enumerate() {
+            let proof = tree.proof(index).unwrap();
+            assert!(Verifier::verify_with_builder(
+                &proof,
+                tree.root(),
+                tree.hash_value(value),
+                &Blake3Builder,
+            ));
+        }
+    }
+}
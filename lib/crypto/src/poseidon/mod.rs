@@ -0,0 +1,263 @@
+//! Poseidon hash function over the BN254 scalar field.
+//!
+//! [Poseidon] is a hash function designed for zero-knowledge proof systems:
+//! commitments computed with it are cheap to open inside a circuit, e.g. in
+//! zk-airdrop or zk-membership designs.
+//!
+//! This implementation is compatible with [circomlib] and `circomlibjs`, so
+//! `hash(&[a, b])` equals `poseidon([a, b])` computed off-chain or in a
+//! circuit. Hashes of one and two field elements are supported.
+//!
+//! ```rust
+//! use openzeppelin_crypto::poseidon::{self, Fr};
+//!
+//! let commitment = poseidon::hash(&[Fr::from(1u64), Fr::from(2u64)]).unwrap();
+//! ```
+//!
+//! [Poseidon]: https://eprint.iacr.org/2019/458.pdf
+//! [circomlib]: https://github.com/iden3/circomlib/blob/master/circuits/poseidon.circom
+use ark_ff::{BigInteger, Field, PrimeField};
+
+mod constants;
+
+pub use ark_bn254::Fr;
+
+/// Maximum number of field elements that can be hashed at once.
+pub const MAX_INPUTS: usize = 2;
+
+/// Number of full rounds of the permutation, half of them applied before the
+/// partial rounds and half after.
+const FULL_ROUNDS: usize = 8;
+
+/// An error that occurred while computing a Poseidon hash.
+#[derive(core::fmt::Debug, PartialEq, Eq)]
+pub enum PoseidonError {
+    /// The number of inputs is zero or greater than [`MAX_INPUTS`].
+    InvalidInputsLength(usize),
+    /// An input is not a canonical encoding of a field element, i.e. it is
+    /// greater than or equal to the field's modulus.
+    NonCanonicalInput,
+}
+
+impl core::fmt::Display for PoseidonError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PoseidonError::InvalidInputsLength(len) => write!(
+                f,
+                "invalid number of inputs: {len}, expected 1 to {MAX_INPUTS}"
+            ),
+            PoseidonError::NonCanonicalInput => {
+                write!(f, "input is not a canonical field element")
+            }
+        }
+    }
+}
+
+impl core::error::Error for PoseidonError {}
+
+/// Computes the Poseidon hash of `inputs`.
+///
+/// # Arguments
+///
+/// * `inputs` - The field elements to hash.
+///
+/// # Errors
+///
+/// If `inputs` is empty or longer than [`MAX_INPUTS`], then the error
+/// [`PoseidonError::InvalidInputsLength`] is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use openzeppelin_crypto::poseidon::{self, Fr};
+///
+/// let hash = poseidon::hash(&[Fr::from(1u64)]).unwrap();
+/// assert_ne!(hash, poseidon::hash(&[Fr::from(2u64)]).unwrap());
+/// ```
+pub fn hash(inputs: &[Fr]) -> Result<Fr, PoseidonError> {
+    match *inputs {
+        [a] => {
+            let mut state = [Fr::ZERO, a];
+            permute(
+                &mut state,
+                &constants::ROUND_CONSTANTS_2,
+                &constants::MDS_2,
+            );
+            Ok(state[0])
+        }
+        [a, b] => {
+            let mut state = [Fr::ZERO, a, b];
+            permute(
+                &mut state,
+                &constants::ROUND_CONSTANTS_3,
+                &constants::MDS_3,
+            );
+            Ok(state[0])
+        }
+        _ => Err(PoseidonError::InvalidInputsLength(inputs.len())),
+    }
+}
+
+/// Computes the Poseidon hash of `inputs`, encoded as big-endian 32-byte
+/// words, as they are usually passed to contracts.
+///
+/// # Arguments
+///
+/// * `inputs` - The big-endian encodings of the field elements to hash.
+///
+/// # Errors
+///
+/// If `inputs` is empty or longer than [`MAX_INPUTS`], then the error
+/// [`PoseidonError::InvalidInputsLength`] is returned.
+/// If an input is greater than or equal to the field's modulus, then the
+/// error [`PoseidonError::NonCanonicalInput`] is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use openzeppelin_crypto::poseidon;
+/// use hex_literal::hex;
+///
+/// let one = hex!("0000000000000000000000000000000000000000000000000000000000000001");
+/// let two = hex!("0000000000000000000000000000000000000000000000000000000000000002");
+///
+/// let hash = poseidon::hash_bytes(&[one, two]).unwrap();
+/// assert_eq!(
+///     hash,
+///     hex!("115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a")
+/// );
+/// ```
+pub fn hash_bytes(inputs: &[[u8; 32]]) -> Result<[u8; 32], PoseidonError> {
+    let mut elements = [Fr::ZERO; MAX_INPUTS];
+    if inputs.is_empty() || inputs.len() > MAX_INPUTS {
+        return Err(PoseidonError::InvalidInputsLength(inputs.len()));
+    }
+    for (element, input) in elements.iter_mut().zip(inputs) {
+        *element = from_be_bytes(input)?;
+    }
+
+    let hash = hash(&elements[..inputs.len()])?;
+    Ok(to_be_bytes(hash))
+}
+
+/// Applies the Poseidon permutation of width `T` to `state`.
+fn permute<const T: usize>(
+    state: &mut [Fr; T],
+    round_constants: &[Fr],
+    mds: &[[Fr; T]; T],
+) {
+    let rounds = round_constants.len() / T;
+    let partial_rounds = rounds - FULL_ROUNDS;
+    let first_partial = FULL_ROUNDS / 2;
+
+    for (round, constants) in round_constants.chunks_exact(T).enumerate() {
+        for (element, constant) in state.iter_mut().zip(constants) {
+            *element += constant;
+        }
+
+        let is_partial =
+            (first_partial..first_partial + partial_rounds).contains(&round);
+        if is_partial {
+            state[0] = sbox(state[0]);
+        } else {
+            for element in state.iter_mut() {
+                *element = sbox(*element);
+            }
+        }
+
+        let mut mixed = [Fr::ZERO; T];
+        for (result, row) in mixed.iter_mut().zip(mds) {
+            for (coefficient, element) in row.iter().zip(state.iter()) {
+                *result += *coefficient * element;
+            }
+        }
+        *state = mixed;
+    }
+}
+
+/// The S-box of the permutation, `x^5`.
+fn sbox(x: Fr) -> Fr {
+    let x2 = x.square();
+    x2.square() * x
+}
+
+/// Decodes a canonical big-endian encoding of a field element.
+fn from_be_bytes(bytes: &[u8; 32]) -> Result<Fr, PoseidonError> {
+    let element = Fr::from_be_bytes_mod_order(bytes);
+    if to_be_bytes(element) != *bytes {
+        return Err(PoseidonError::NonCanonicalInput);
+    }
+    Ok(element)
+}
+
+/// Encodes a field element as big-endian bytes.
+fn to_be_bytes(element: Fr) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    bytes.copy_from_slice(&element.into_bigint().to_bytes_be());
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::{Field, MontFp};
+    use hex_literal::hex;
+
+    use super::{hash, hash_bytes, Fr, PoseidonError};
+
+    // Values computed with `circomlibjs`.
+    #[test]
+    fn hashes_like_circomlib() {
+        let one = Fr::from(1u64);
+        let two = Fr::from(2u64);
+
+        let expected: Fr = MontFp!("18586133768512220936620570745912940619677854269274689475585506675881198879027");
+        assert_eq!(hash(&[one]), Ok(expected));
+
+        let expected: Fr = MontFp!("7853200120776062878684798364095072458815029376092732009249414926327459813530");
+        assert_eq!(hash(&[one, two]), Ok(expected));
+    }
+
+    #[test]
+    fn hashes_bytes() {
+        let one = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000001"
+        );
+        let two = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000002"
+        );
+
+        assert_eq!(
+            hash_bytes(&[one]),
+            Ok(hex!("29176100eaa962bdc1fe6c654d6a3c130e96a4d1168b33848b897dc502820133"))
+        );
+        assert_eq!(
+            hash_bytes(&[one, two]),
+            Ok(hex!("115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a"))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_inputs_length() {
+        assert_eq!(hash(&[]), Err(PoseidonError::InvalidInputsLength(0)));
+        assert_eq!(
+            hash(&[Fr::ZERO; 3]),
+            Err(PoseidonError::InvalidInputsLength(3))
+        );
+        assert_eq!(
+            hash_bytes(&[[0u8; 32]; 3]),
+            Err(PoseidonError::InvalidInputsLength(3))
+        );
+    }
+
+    #[test]
+    fn rejects_non_canonical_input() {
+        // The BN254 scalar field's modulus.
+        let modulus = hex!(
+            "30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001"
+        );
+        assert_eq!(
+            hash_bytes(&[modulus]),
+            Err(PoseidonError::NonCanonicalInput)
+        );
+    }
+}
@@ -0,0 +1,148 @@
+//! Verification of [BIP-340] Schnorr signatures over the secp256k1 curve.
+//!
+//! BIP-340 signatures are the ones used by Bitcoin's Taproot. Since Schnorr
+//! signatures are linear, a committee can produce a single signature for an
+//! aggregate public key, e.g. with `MuSig2` or FROST, which is verified like
+//! the signature of a single signer.
+//!
+//! Public keys are the 32-byte x-only encoding of BIP-340, and signatures
+//! are the 64-byte encoding `r || s`.
+//!
+//! [BIP-340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+use k256::schnorr::{Signature as SchnorrSignature, VerifyingKey};
+
+/// An x-only public key.
+pub type PublicKey = [u8; 32];
+
+/// A signature, encoded as `r || s`.
+pub type Signature = [u8; 64];
+
+/// An error that occurred while decoding a Schnorr public key or signature.
+#[derive(core::fmt::Debug, PartialEq, Eq)]
+pub enum SchnorrError {
+    /// The public key is not the x coordinate of a point of the curve.
+    InvalidPublicKey,
+    /// The `r` value of the signature is not a field element, or its `s`
+    /// value is not lower than the order of the curve.
+    InvalidSignature,
+}
+
+impl core::fmt::Display for SchnorrError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let msg = match self {
+            SchnorrError::InvalidPublicKey => "invalid public key",
+            SchnorrError::InvalidSignature => "invalid signature",
+        };
+
+        write!(f, "{msg}")
+    }
+}
+
+impl core::error::Error for SchnorrError {}
+
+/// Verifies that `signature` is a BIP-340 signature of `message` by the owner
+/// of `public_key`.
+///
+/// The message is not hashed before being verified, as specified by BIP-340.
+/// It is usually a 32-byte hash.
+///
+/// # Arguments
+///
+/// * `public_key` - X-only public key of the signer.
+/// * `message` - The signed message.
+/// * `signature` - The signature.
+///
+/// # Errors
+///
+/// If `public_key` is not a valid x-only public key, then the error
+/// [`SchnorrError::InvalidPublicKey`] is returned.
+/// If `signature` is malformed, then the error
+/// [`SchnorrError::InvalidSignature`] is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use hex_literal::hex;
+/// use openzeppelin_crypto::schnorr;
+///
+/// let public_key = hex!("F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9");
+/// let signature = hex!("E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA821525F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0");
+///
+/// let verification = schnorr::verify(&public_key, &[0; 32], &signature);
+/// assert!(verification.unwrap());
+/// ```
+pub fn verify(
+    public_key: &PublicKey,
+    message: &[u8],
+    signature: &Signature,
+) -> Result<bool, SchnorrError> {
+    let public_key = VerifyingKey::from_bytes(public_key)
+        .map_err(|_| SchnorrError::InvalidPublicKey)?;
+    let signature = SchnorrSignature::try_from(signature.as_slice())
+        .map_err(|_| SchnorrError::InvalidSignature)?;
+    Ok(public_key.verify_raw(message, &signature).is_ok())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    //! NOTE: The test vectors are taken from
+    //! <https://github.com/bitcoin/bips/blob/master/bip-0340/test-vectors.csv>.
+    use hex_literal::hex;
+
+    use super::{verify, SchnorrError};
+
+    const PUBLIC_KEY: [u8; 32] = hex!(
+        "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659"
+    );
+    const MESSAGE: [u8; 32] = hex!(
+        "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89"
+    );
+    const SIGNATURE: [u8; 64] = hex!("6896BD60EEAE296DB48A229FF71DFE071BDE413E6D43F917DC8DCF8C78DE33418906D11AC976ABCCB20B091292BFF4EA897EFCB639EA871CFA95F6DE339E4B0A");
+
+    #[test]
+    fn verifies_valid_signatures() {
+        assert!(verify(&PUBLIC_KEY, &MESSAGE, &SIGNATURE).unwrap());
+
+        let public_key = hex!(
+            "DD308AFEC5777E13121FA72B9CC1B7CC0139715309B086C960E18FD969774EB8"
+        );
+        let message = hex!(
+            "7E2D58D8B3BCDF1ABADEC7829054F90DDA9805AAB56C77333024B9D0A508B75C"
+        );
+        let signature = hex!("5831AAEED7B44BB74E5EAB94BA9D4294C49BCF2A60728D8B4C200F50DD313C1BAB745879A5AD954A72C45A91C3A51D3C7ADEA98D82F8481E0E1E03674A6F3FB7");
+        assert!(verify(&public_key, &message, &signature).unwrap());
+    }
+
+    #[test]
+    fn rejects_invalid_signatures() {
+        let mut message = MESSAGE;
+        message[0] ^= 1;
+        assert!(!verify(&PUBLIC_KEY, &message, &SIGNATURE).unwrap());
+
+        let mut signature = SIGNATURE;
+        signature[63] ^= 1;
+        assert!(!verify(&PUBLIC_KEY, &MESSAGE, &signature).unwrap());
+    }
+
+    #[test]
+    fn rejects_malformed_inputs() {
+        // Not the x coordinate of a point of the curve.
+        let public_key = hex!(
+            "EEFDEA4CDB677750A420FEE807EACF21EB9898AE79B9768766E4FAA04A2D4A34"
+        );
+        assert_eq!(
+            verify(&public_key, &MESSAGE, &SIGNATURE),
+            Err(SchnorrError::InvalidPublicKey)
+        );
+
+        // `s` is equal to the order of the curve.
+        let mut signature = SIGNATURE;
+        signature[32..].copy_from_slice(&hex!(
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141"
+        ));
+        assert_eq!(
+            verify(&PUBLIC_KEY, &MESSAGE, &signature),
+            Err(SchnorrError::InvalidSignature)
+        );
+    }
+}
@@ -0,0 +1,273 @@
+//! Verification of elliptic curve verifiable random function (ECVRF) proofs.
+//!
+//! A VRF lets the owner of a secret key compute a pseudo-random output from
+//! an input, together with a proof that the output was correctly computed,
+//! which anyone can verify with the matching public key. Unlike a random
+//! value reported by an oracle, a verified output can't be chosen by the
+//! prover, e.g. to pick the winner of a raffle.
+//!
+//! This module implements the `ECVRF-SECP256K1-SHA256-TAI` suite of
+//! [draft-irtf-cfrg-vrf-05], with suite string `0xFE`, as implemented by the
+//! [`vrf`] crate:
+//!
+//! - Public keys and points are compressed SEC1 points of secp256k1.
+//! - Proofs are the 81-byte encoding `gamma || c || s`.
+//! - Inputs are hashed to the curve with the try-and-increment method.
+//!
+//! [draft-irtf-cfrg-vrf-05]: https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-vrf-05
+//! [`vrf`]: https://github.com/witnet/vrf-rs
+use k256::{
+    elliptic_curve::{
+        ops::MulByGenerator,
+        sec1::{FromEncodedPoint, ToEncodedPoint},
+        PrimeField,
+    },
+    AffinePoint, EncodedPoint, FieldBytes, ProjectivePoint, Scalar,
+};
+use sha2::{Digest, Sha256};
+
+use crate::constant_time;
+
+/// Suite string of `ECVRF-SECP256K1-SHA256-TAI`.
+pub const SUITE: u8 = 0xFE;
+
+/// A compressed public key.
+pub type PublicKey = [u8; 33];
+
+/// A proof, encoded as `gamma || c || s`.
+pub type Proof = [u8; 81];
+
+/// An output of the VRF.
+pub type Output = [u8; 32];
+
+/// Size of the `c` value of a proof.
+const C_SIZE: usize = 16;
+
+/// An error that occurred while verifying a VRF proof.
+#[derive(core::fmt::Debug, PartialEq, Eq)]
+pub enum VrfError {
+    /// The public key is not a valid compressed point.
+    InvalidPublicKey,
+    /// The proof can't be decoded.
+    MalformedProof,
+    /// The input could not be hashed to the curve.
+    HashToCurve,
+    /// The proof does not match the public key and the input.
+    InvalidProof,
+}
+
+impl core::fmt::Display for VrfError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let msg = match self {
+            VrfError::InvalidPublicKey => "invalid public key",
+            VrfError::MalformedProof => "malformed proof",
+            VrfError::HashToCurve => "input could not be hashed to the curve",
+            VrfError::InvalidProof => "invalid proof",
+        };
+
+        write!(f, "{msg}")
+    }
+}
+
+impl core::error::Error for VrfError {}
+
+/// Verifies that `proof` was computed from `alpha` by the owner of
+/// `public_key`, and returns the output of the VRF.
+///
+/// # Arguments
+///
+/// * `public_key` - Compressed public key of the prover.
+/// * `proof` - The proof.
+/// * `alpha` - The input of the VRF.
+///
+/// # Errors
+///
+/// If `public_key` can't be decoded, then the error
+/// [`VrfError::InvalidPublicKey`] is returned.
+/// If `proof` can't be decoded, then the error [`VrfError::MalformedProof`]
+/// is returned.
+/// If `alpha` can't be hashed to the curve, which is extremely unlikely, then
+/// the error [`VrfError::HashToCurve`] is returned.
+/// If `proof` is not valid, then the error [`VrfError::InvalidProof`] is
+/// returned.
+pub fn verify(
+    public_key: &PublicKey,
+    proof: &Proof,
+    alpha: &[u8],
+) -> Result<Output, VrfError> {
+    let (gamma, c, s) = decode_proof(proof)?;
+    let key = decode_point(public_key).ok_or(VrfError::InvalidPublicKey)?;
+    let base = hash_to_curve(public_key, alpha)?;
+
+    let c_scalar = scalar_from_c(&c);
+    let u = ProjectivePoint::mul_by_generator(&s) - key * c_scalar;
+    let v = base * s - gamma * c_scalar;
+
+    let derived = hash_points(&[base, gamma, u, v]);
+    if !constant_time::eq(derived, c) {
+        return Err(VrfError::InvalidProof);
+    }
+    Ok(gamma_to_hash(&gamma))
+}
+
+/// Returns the output of the VRF encoded in `proof`, without verifying it.
+///
+/// # Arguments
+///
+/// * `proof` - The proof.
+///
+/// # Errors
+///
+/// If `proof` can't be decoded, then the error [`VrfError::MalformedProof`]
+/// is returned.
+pub fn proof_to_hash(proof: &Proof) -> Result<Output, VrfError> {
+    let (gamma, _, _) = decode_proof(proof)?;
+    Ok(gamma_to_hash(&gamma))
+}
+
+/// Decodes `gamma`, `c` and `s` from `proof`.
+fn decode_proof(
+    proof: &Proof,
+) -> Result<(ProjectivePoint, [u8; C_SIZE], Scalar), VrfError> {
+    let (gamma, rest) = proof.split_at(33);
+    let (c, s) = rest.split_at(C_SIZE);
+
+    let gamma = decode_point(gamma).ok_or(VrfError::MalformedProof)?;
+    let c: [u8; C_SIZE] = c.try_into().map_err(|_| VrfError::MalformedProof)?;
+    let s: Option<Scalar> =
+        Scalar::from_repr(*FieldBytes::from_slice(s)).into();
+    let s = s.ok_or(VrfError::MalformedProof)?;
+    Ok((gamma, c, s))
+}
+
+/// Decodes a compressed SEC1 point.
+fn decode_point(bytes: &[u8]) -> Option<ProjectivePoint> {
+    let encoded = EncodedPoint::from_bytes(bytes).ok()?;
+    if !encoded.is_compressed() {
+        return None;
+    }
+    let point: Option<AffinePoint> =
+        AffinePoint::from_encoded_point(&encoded).into();
+    point.map(ProjectivePoint::from)
+}
+
+/// Encodes `point` as a compressed SEC1 point.
+fn encode_point(point: &ProjectivePoint) -> EncodedPoint {
+    point.to_affine().to_encoded_point(true)
+}
+
+/// Hashes `alpha` to the curve with the try-and-increment method, i.e.
+/// returns the first valid point `0x02 || H(suite || 0x01 || Y || alpha ||
+/// ctr)`.
+fn hash_to_curve(
+    public_key: &PublicKey,
+    alpha: &[u8],
+) -> Result<ProjectivePoint, VrfError> {
+    (0..=u8::MAX)
+        .find_map(|ctr| {
+            let hash = Sha256::new()
+                .chain_update([SUITE, 0x01])
+                .chain_update(public_key)
+                .chain_update(alpha)
+                .chain_update([ctr])
+                .finalize();
+            let mut candidate = [0x02; 33];
+            candidate[1..].copy_from_slice(&hash);
+            decode_point(&candidate)
+        })
+        .ok_or(VrfError::HashToCurve)
+}
+
+/// Hashes `points` into the `c` value of a proof.
+fn hash_points(points: &[ProjectivePoint]) -> [u8; C_SIZE] {
+    let mut hasher = Sha256::new().chain_update([SUITE, 0x02]);
+    for point in points {
+        hasher.update(encode_point(point).as_bytes());
+    }
+    let hash = hasher.finalize();
+
+    let mut c = [0u8; C_SIZE];
+    c.copy_from_slice(&hash[..C_SIZE]);
+    c
+}
+
+/// Converts the `c` value of a proof into a scalar.
+fn scalar_from_c(c: &[u8; C_SIZE]) -> Scalar {
+    let mut bytes = FieldBytes::default();
+    bytes[32 - C_SIZE..].copy_from_slice(c);
+    // A 128-bit value is always lower than the order of the curve.
+    Scalar::from_repr(bytes).unwrap()
+}
+
+/// Computes the output of the VRF from `gamma`.
+fn gamma_to_hash(gamma: &ProjectivePoint) -> Output {
+    Sha256::new()
+        .chain_update([SUITE, 0x03])
+        .chain_update(encode_point(gamma).as_bytes())
+        .finalize()
+        .into()
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    //! NOTE: The test vectors are taken from the tests of
+    //! <https://github.com/witnet/vrf-rs>.
+    use hex_literal::hex;
+
+    use super::{proof_to_hash, verify, VrfError};
+
+    const PUBLIC_KEY: [u8; 33] = hex!(
+        "032c8c31fc9f990c6b55e3865a184a4ce50e09481f2eaeb3e60ec1cea13a6ae645"
+    );
+    const PROOF: [u8; 81] = hex!("031f4dbca087a1972d04a07a779b7df1caa99e0f5db2aa21f3aecc4f9e10e85d0814faa89697b482daa377fb6b4a8b0191a65d34a6d90a8a2461e5db9205d4cf0bb4b2c31b5ef6997a585a9f1a72517b6f");
+    const OUTPUT: [u8; 32] = hex!(
+        "612065e309e937ef46c2ef04d5886b9c6efd2991ac484ec64a9b014366fc5d81"
+    );
+
+    #[test]
+    fn verifies_valid_proofs() {
+        assert_eq!(verify(&PUBLIC_KEY, &PROOF, b"sample"), Ok(OUTPUT));
+        assert_eq!(proof_to_hash(&PROOF), Ok(OUTPUT));
+
+        // Proofs computed with another nonce are valid too.
+        let proof = hex!("031f4dbca087a1972d04a07a779b7df1caa99e0f5db2aa21f3aecc4f9e10e85d08748c9fbe6b95d17359707bfb8e8ab0c93ba0c515333adcb8b64f372c535e115ccf66ebf5abe6fadb01b5efb37c0a0ec9");
+        assert_eq!(verify(&PUBLIC_KEY, &proof, b"sample"), Ok(OUTPUT));
+    }
+
+    #[test]
+    fn rejects_invalid_proofs() {
+        assert_eq!(
+            verify(&PUBLIC_KEY, &PROOF, b"notsample"),
+            Err(VrfError::InvalidProof)
+        );
+
+        let mut proof = PROOF;
+        proof[40] ^= 1;
+        assert_eq!(
+            verify(&PUBLIC_KEY, &proof, b"sample"),
+            Err(VrfError::InvalidProof)
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_inputs() {
+        assert_eq!(
+            verify(&PUBLIC_KEY, &[0; 81], b"sample"),
+            Err(VrfError::MalformedProof)
+        );
+
+        let mut proof = PROOF;
+        proof[49..].copy_from_slice(&[0xff; 32]);
+        assert_eq!(
+            verify(&PUBLIC_KEY, &proof, b"sample"),
+            Err(VrfError::MalformedProof)
+        );
+
+        let mut public_key = PUBLIC_KEY;
+        public_key[0] = 0x04;
+        assert_eq!(
+            verify(&public_key, &PROOF, b"sample"),
+            Err(VrfError::InvalidPublicKey)
+        );
+    }
+}
@@ -14,6 +14,17 @@ mod test;
 ///     let charlie = Account::new().await?;
 ///     // ...
 /// }
+///
+/// #[e2e::test]
+/// async fn bar(signers: [Account; 5]) -> eyre::Result<()> {
+///     // ...
+/// }
+///
+/// // Only runs against a fork of a live chain.
+/// #[e2e::test(fork)]
+/// async fn baz(alice: Account) -> eyre::Result<()> {
+///     // ...
+/// }
 /// ```
 #[proc_macro_attribute]
 pub fn test(attr: TokenStream, input: TokenStream) -> TokenStream {
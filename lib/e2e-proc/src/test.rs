@@ -13,10 +13,25 @@ macro_rules! error {
     }};
 }
 
-/// Defines an end-to-end test that injects test accounts through parameters.
+/// Defines an end-to-end test that injects test accounts through parameters,
+/// either one account, e.g. `alice: Account`, or an array of accounts, e.g.
+/// `accounts: [Account; 3]`.
+///
+/// With the `fork` argument, the test is skipped unless it runs against a
+/// fork, see `e2e::is_fork`.
 ///
 /// For more information see [`crate::test`].
-pub(crate) fn test(_attr: &TokenStream, input: TokenStream) -> TokenStream {
+pub(crate) fn test(attr: &TokenStream, input: TokenStream) -> TokenStream {
+    let attr = proc_macro2::TokenStream::from(attr.clone());
+    let fork_only = if attr.is_empty() {
+        false
+    } else {
+        match syn::parse2::<syn::Ident>(attr.clone()) {
+            Ok(ident) if ident == "fork" => true,
+            _ => error!(attr, "unexpected argument, expected `fork`"),
+        }
+    };
+
     let item_fn = parse_macro_input!(input as syn::ItemFn);
     let attrs = &item_fn.attrs;
     let sig = &item_fn.sig;
@@ -25,12 +40,36 @@ pub(crate) fn test(_attr: &TokenStream, input: TokenStream) -> TokenStream {
     let fn_stmts = &item_fn.block.stmts;
     let fn_args = &sig.inputs;
 
+    let skip_without_fork = fork_only.then(|| {
+        quote! {
+            if !e2e::is_fork() {
+                eprintln!("skipping {}: requires a fork", stringify!(#fn_name));
+                return Ok(());
+            }
+        }
+    });
+
     let account_declarations = fn_args.into_iter().map(|arg| {
         let FnArg::Typed(arg) = arg else {
             error!(arg, "unexpected receiver argument in test signature");
         };
         let account_arg_binding = &arg.pat;
         let account_ty = &arg.ty;
+        if let syn::Type::Array(array) = account_ty.as_ref() {
+            let account_ty = &array.elem;
+            let len = &array.len;
+            return quote! {
+                let #account_arg_binding: [#account_ty; #len] = {
+                    let mut accounts = ::std::vec::Vec::with_capacity(#len);
+                    for _ in 0..#len {
+                        accounts.push(<#account_ty>::new().await?);
+                    }
+                    accounts
+                        .try_into()
+                        .expect("should create the test accounts")
+                };
+            };
+        }
         quote! {
             let #account_arg_binding = #account_ty::new().await?;
         }
@@ -39,6 +78,12 @@ pub(crate) fn test(_attr: &TokenStream, input: TokenStream) -> TokenStream {
         #( #attrs )*
         #[tokio::test]
         async fn #fn_name() #fn_return_type {
+            #skip_without_fork
+            let _gas_report = e2e::GasReport::new(concat!(
+                module_path!(),
+                "::",
+                stringify!(#fn_name)
+            ));
             #( #account_declarations )*
             #( #fn_stmts )*
         }
@@ -1,8 +1,9 @@
 use alloy::{
     network::EthereumWallet,
     primitives::{Address, B256},
-    providers::{Provider, ProviderBuilder},
+    providers::Provider,
     signers::{local::PrivateKeySigner, Signature, Signer},
+    sol_types::{Eip712Domain, SolStruct},
 };
 use eyre::Result;
 use once_cell::sync::Lazy;
@@ -10,7 +11,8 @@ use tokio::sync::{Mutex, MutexGuard};
 
 use crate::{
     deploy::Deployer,
-    system::{fund_account, Wallet, RPC_URL_ENV_VAR_NAME},
+    fork, node,
+    system::{self, fund_account, Wallet},
 };
 
 const DEFAULT_FUNDING_ETH: u32 = 100;
@@ -72,6 +74,44 @@ impl Account {
         self.signer.sign_message(message).await.expect("should sign a message")
     }
 
+    /// Sign the EIP-712 typed data `value` of the `domain`, e.g. the
+    /// `Permit` of an ERC-20 token, as the contracts' `eip712` utilities
+    /// expect it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// sol! {
+    ///     struct Permit {
+    ///         address owner;
+    ///         address spender;
+    ///         uint256 value;
+    ///         uint256 nonce;
+    ///         uint256 deadline;
+    ///     }
+    /// }
+    ///
+    /// let domain = eip712_domain! {
+    ///     name: "ERC-20 Permit Example",
+    ///     version: "1",
+    ///     chain_id: chain_id,
+    ///     verifying_contract: contract_addr,
+    /// };
+    /// let signature = alice.sign_typed_data(&domain, &permit).await;
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// May fail when the method is not implemented for `Signer`. Should not
+    /// happen.
+    pub async fn sign_typed_data<T: SolStruct>(
+        &self,
+        domain: &Eip712Domain,
+        value: &T,
+    ) -> Signature {
+        self.sign_hash(&value.eip712_signing_hash(domain)).await
+    }
+
     /// Create a configurable smart contract deployer on behalf of this account.
     pub fn as_deployer(&self) -> Deployer {
         Deployer::new(self.url().to_string(), self.pk())
@@ -94,25 +134,30 @@ impl AccountFactory {
         SYNC_ACCOUNT_FACTORY.lock().await
     }
 
-    /// Create new account and fund it via nitro test node access.
+    /// Create new account and fund it via nitro test node access, or by
+    /// setting its balance when running against a fork.
     ///
     /// # Errors
     ///
-    /// May fail if unable to find the path to the node or if funding the newly
-    /// created account fails.
+    /// May fail if unable to find the path to the node, if the node isn't
+    /// running and can't be started, or if funding the newly created account
+    /// fails.
     async fn create() -> eyre::Result<Account> {
         let _lock = AccountFactory::lock().await;
+        node::ensure_running().await?;
 
         let signer = PrivateKeySigner::random();
         let addr = signer.address();
-        fund_account(addr, DEFAULT_FUNDING_ETH)?;
+        if fork::is_fork() {
+            fork::fund_account(addr, DEFAULT_FUNDING_ETH).await?;
+        } else {
+            fund_account(addr, DEFAULT_FUNDING_ETH)?;
+        }
 
-        let rpc_url = std::env::var(RPC_URL_ENV_VAR_NAME)
-            .expect("failed to load RPC_URL var from env")
+        let rpc_url = system::rpc_url()
             .parse()
             .expect("failed to parse RPC_URL string into a URL");
-        let wallet = ProviderBuilder::new()
-            .with_recommended_fillers()
+        let wallet = system::builder()
             .wallet(EthereumWallet::from(signer.clone()))
             .on_http(rpc_url);
 
@@ -0,0 +1,74 @@
+use std::{collections::HashMap, path::Path, sync::Mutex};
+
+use alloy::{
+    network::ReceiptResponse,
+    primitives::{keccak256, B256},
+    providers::{Provider, ProviderBuilder},
+    rpc::types::TransactionReceipt,
+};
+use once_cell::sync::Lazy;
+
+/// Receipts of the cached deployments, keyed by [`key`].
+static DEPLOYMENTS: Lazy<Mutex<HashMap<B256, TransactionReceipt>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Returns the key of the deployment of the wasm binary at `wasm` with the
+/// hex-encoded constructor arguments `ctr_args` to the `rpc_url` endpoint.
+///
+/// # Errors
+///
+/// May error if unable to read the wasm binary.
+pub(crate) fn key(
+    wasm: &Path,
+    ctr_args: Option<&str>,
+    rpc_url: &str,
+) -> eyre::Result<B256> {
+    let mut preimage = std::fs::read(wasm)?;
+    preimage.extend_from_slice(ctr_args.unwrap_or_default().as_bytes());
+    preimage.extend_from_slice(rpc_url.as_bytes());
+    Ok(keccak256(preimage))
+}
+
+/// Returns the receipt of the cached deployment with `key`, if its contract
+/// is still deployed at `rpc_url`.
+///
+/// A contract without code means that the chain has been reset, e.g. by
+/// [`crate::revert_to`], so the deployment is evicted from the cache.
+///
+/// # Errors
+///
+/// May error if unable to read the code of the contract.
+pub(crate) async fn get(
+    key: B256,
+    rpc_url: &str,
+) -> eyre::Result<Option<TransactionReceipt>> {
+    let Some(receipt) = lock().get(&key).cloned() else {
+        return Ok(None);
+    };
+    let Some(address) = receipt.contract_address() else {
+        return Ok(None);
+    };
+
+    let code = ProviderBuilder::new()
+        .on_http(rpc_url.parse()?)
+        .get_code_at(address)
+        .await?;
+    if code.is_empty() {
+        lock().remove(&key);
+        return Ok(None);
+    }
+    Ok(Some(receipt))
+}
+
+/// Caches the `receipt` of the deployment with `key`.
+pub(crate) fn insert(key: B256, receipt: TransactionReceipt) {
+    lock().insert(key, receipt);
+}
+
+/// Locks the cache.
+///
+/// Deployments are not awaited while the lock is held, so a poisoned lock
+/// still holds a consistent cache.
+fn lock() -> std::sync::MutexGuard<'static, HashMap<B256, TransactionReceipt>> {
+    DEPLOYMENTS.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+}
@@ -1,20 +1,74 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use alloy::{rpc::types::TransactionReceipt, sol_types::SolConstructor};
+use alloy::{
+    network::{EthereumWallet, TransactionBuilder},
+    primitives::{Address, Bytes},
+    providers::Provider,
+    rpc::types::{TransactionReceipt, TransactionRequest},
+    signers::local::PrivateKeySigner,
+    sol_types::{SolCall, SolConstructor},
+};
+use eyre::bail;
 use koba::config::Deploy;
 
-use crate::project::Crate;
+use crate::{
+    cache, nonce,
+    project::{self, Crate},
+    system, Account, ReceiptExt,
+};
+
+/// Deploys the example `name` of the workspace, i.e. the crate in
+/// `examples/{name}`, on behalf of `account` and with `constructor`, and
+/// returns its address.
+///
+/// Shorthand of [`Deployer::with_example`] for tests deploying the contracts
+/// of other examples, e.g. a token:
+///
+/// ```rust,ignore
+/// sol!("../erc20/src/constructor.sol");
+///
+/// let ctr = Erc20Example::constructorCall { name_, symbol_, cap_ };
+/// let token = e2e::deploy_example("erc20", ctr, &alice).await?;
+/// ```
+///
+/// # Errors
+///
+/// May error if the workspace has no example `name`, or if the deployment
+/// fails, see [`Deployer::deploy`].
+pub async fn deploy_example<C: SolConstructor + Send>(
+    name: &str,
+    constructor: C,
+    account: &Account,
+) -> eyre::Result<Address> {
+    account
+        .as_deployer()
+        .with_example(name)?
+        .with_constructor(constructor)
+        .deploy()
+        .await?
+        .address()
+}
 
 /// A basic smart contract deployer.
 pub struct Deployer {
     rpc_url: String,
     private_key: String,
     ctr_args: Option<String>,
+    initializer: Option<Bytes>,
+    manifest_dir: Option<PathBuf>,
+    cached: bool,
 }
 
 impl Deployer {
     pub fn new(rpc_url: String, private_key: String) -> Self {
-        Self { rpc_url, private_key, ctr_args: None }
+        Self {
+            rpc_url,
+            private_key,
+            ctr_args: None,
+            initializer: None,
+            manifest_dir: None,
+            cached: false,
+        }
     }
 
     /// Add solidity constructor to the deployer.
@@ -33,8 +87,63 @@ impl Deployer {
         self.with_constructor(C::default())
     }
 
+    /// Initialize the contract by calling `initializer` right after its
+    /// activation, instead of running a Solidity constructor.
+    ///
+    /// The `src/constructor.sol` of the crate, if any, is ignored, so contracts
+    /// initialized by a Rust entrypoint need no Solidity constructor:
+    ///
+    /// ```rust,ignore
+    /// let contract_addr = alice
+    ///     .as_deployer()
+    ///     .with_initializer(Ownable::initializeCall { owner: alice_addr })
+    ///     .deploy()
+    ///     .await?
+    ///     .address()?;
+    /// ```
+    ///
+    /// NOTE: Anyone could call the initializer of the contract between its
+    /// deployment and its initialization, which is fine in tests only.
+    pub fn with_initializer<C: SolCall>(mut self, initializer: C) -> Deployer {
+        self.initializer = Some(initializer.abi_encode().into());
+        self
+    }
+
+    /// Deploy the contract of the crate whose manifest lives in
+    /// `manifest_dir` instead of the current crate, e.g. a proxy.
+    ///
+    /// The crate must be a member of the same workspace, so that its wasm
+    /// binary is built to the same `target` directory.
+    pub fn with_crate(mut self, manifest_dir: impl AsRef<Path>) -> Deployer {
+        self.manifest_dir = Some(manifest_dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Deploy the contract of the example `name` of the workspace, i.e. the
+    /// crate in `examples/{name}`, wherever the tests run from.
+    ///
+    /// # Errors
+    ///
+    /// May error if the current crate is not in a workspace, or if the
+    /// workspace has no example `name`.
+    pub fn with_example(self, name: &str) -> eyre::Result<Deployer> {
+        Ok(self.with_crate(project::example_dir(name)?))
+    }
+
+    /// Reuse the contract deployed by a previous test of the same binary, if
+    /// it was deployed from the same wasm binary and with the same
+    /// constructor, and if the chain hasn't been reset since.
+    ///
+    /// NOTE: Tests sharing a contract also share its state, so only cache
+    /// contracts whose state the tests don't depend on, or that they don't
+    /// modify, e.g. tokens a test only holds or reads.
+    pub fn with_cache(mut self) -> Deployer {
+        self.cached = true;
+        self
+    }
+
     /// Deploy and activate the contract implemented as `#[entrypoint]` in the
-    /// current crate.
+    /// current crate, or in the crate set with [`Deployer::with_crate`].
     /// Consumes currently configured deployer.
     ///
     /// # Errors
@@ -42,19 +151,44 @@ impl Deployer {
     /// May error if:
     ///
     /// - Unable to collect information about the crate required for deployment.
+    /// - Unable to check whether a cached contract is still deployed.
     /// - [`koba::deploy`] errors.
+    /// - The initializer set with [`Deployer::with_initializer`] reverts.
     pub async fn deploy(self) -> eyre::Result<TransactionReceipt> {
-        let pkg = Crate::new()?;
+        let pkg = match self.manifest_dir {
+            Some(manifest_dir) => Crate::at(manifest_dir)?,
+            None => Crate::new()?,
+        };
         let wasm_path = pkg.wasm;
         let sol_path = pkg.manifest_dir.join("src/constructor.sol");
-        let sol =
-            if Path::new(&sol_path).exists() { Some(sol_path) } else { None };
+        let sol = if self.initializer.is_none() && Path::new(&sol_path).exists()
+        {
+            Some(sol_path)
+        } else {
+            None
+        };
+        let ctr_args =
+            if self.initializer.is_none() { self.ctr_args } else { None };
+
+        let key = if self.cached {
+            let args = match &self.initializer {
+                Some(initializer) => Some(alloy::hex::encode(initializer)),
+                None => ctr_args.clone(),
+            };
+            let key = cache::key(&wasm_path, args.as_deref(), &self.rpc_url)?;
+            if let Some(receipt) = cache::get(key, &self.rpc_url).await? {
+                return Ok(receipt);
+            }
+            Some(key)
+        } else {
+            None
+        };
 
         let config = Deploy {
             generate_config: koba::config::Generate {
                 wasm: wasm_path.clone(),
                 sol,
-                args: self.ctr_args,
+                args: ctr_args,
                 legacy: false,
             },
             auth: koba::config::PrivateKey {
@@ -67,6 +201,46 @@ impl Deployer {
             deploy_only: false,
             quiet: false,
         };
-        koba::deploy(&config).await
+        // koba sends the deployment itself, so the nonce of the deployer must
+        // be read from the chain again by its wallet.
+        let deployer: PrivateKeySigner =
+            config.auth.private_key.as_deref().unwrap_or_default().parse()?;
+        let receipt =
+            nonce::bypass(deployer.address(), koba::deploy(&config)).await?;
+
+        if let Some(initializer) = self.initializer {
+            initialize(&config.endpoint, deployer, &receipt, initializer)
+                .await?;
+        }
+
+        if let Some(key) = key {
+            cache::insert(key, receipt.clone());
+        }
+        Ok(receipt)
+    }
+}
+
+/// Calls the initializer of the contract deployed with `receipt` on behalf of
+/// `deployer`, with the `initializer` calldata.
+///
+/// # Errors
+///
+/// May error if the initializer can't be sent, or if it reverts.
+async fn initialize(
+    rpc_url: &str,
+    deployer: PrivateKeySigner,
+    receipt: &TransactionReceipt,
+    initializer: Bytes,
+) -> eyre::Result<()> {
+    let wallet = system::builder()
+        .wallet(EthereumWallet::from(deployer))
+        .on_http(rpc_url.parse()?);
+    let tx = TransactionRequest::default()
+        .with_to(receipt.address()?)
+        .with_input(initializer);
+    let receipt = wallet.send_transaction(tx).await?.get_receipt().await?;
+    if !receipt.status() {
+        bail!("contract initializer reverted in {}", receipt.transaction_hash);
     }
+    Ok(())
 }
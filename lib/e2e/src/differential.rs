@@ -0,0 +1,237 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use alloy::{
+    network::TransactionBuilder,
+    primitives::{Address, Bytes, B256},
+    providers::Provider,
+    rpc::types::{TransactionReceipt, TransactionRequest},
+    sol_types::{SolCall, SolConstructor},
+};
+use eyre::{bail, ensure, Context};
+
+use crate::{environment::get_workspace_root, error, Account, ReceiptExt};
+
+/// Returns the path to the bytecode of the reference Solidity contract
+/// `name`, built by `scripts/build-reference.sh` from the `.sol` files of the
+/// `tests/reference` directories of the examples.
+///
+/// # Errors
+///
+/// May error if unable to locate the root of the workspace, or if the
+/// contract wasn't built.
+pub fn reference(name: &str) -> eyre::Result<PathBuf> {
+    let bin = get_workspace_root()?
+        .join("target")
+        .join("reference")
+        .join(format!("{name}.bin"));
+    if !bin.exists() {
+        bail!(
+            "reference contract {name} not found at {}, build it with `scripts/build-reference.sh`",
+            bin.display()
+        );
+    }
+    Ok(bin)
+}
+
+/// Deploys the Solidity contract whose bytecode is the hex file `bin`, as
+/// output by `solc --bin`, e.g. a [`reference`] contract, on behalf of
+/// `account` and with `constructor`, and returns its address.
+///
+/// # Errors
+///
+/// May error if the bytecode can't be read, or if the deployment fails.
+pub async fn deploy_solidity<C: SolConstructor>(
+    account: &Account,
+    bin: impl AsRef<Path>,
+    constructor: C,
+) -> eyre::Result<Address> {
+    let bin = bin.as_ref();
+    let bytecode: Bytes = fs::read_to_string(bin)
+        .wrap_err_with(|| format!("should read bytecode {}", bin.display()))?
+        .trim()
+        .parse()?;
+    let code = [bytecode.as_ref(), &constructor.abi_encode()].concat();
+
+    let tx = TransactionRequest::default().with_deploy_code(code);
+    let receipt =
+        account.wallet.send_transaction(tx).await?.get_receipt().await?;
+    if !receipt.status() {
+        bail!("deployment of {} reverted", bin.display());
+    }
+    receipt.address()
+}
+
+/// Selector of the `Panic(uint256)` revert data of Solidity.
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// Outcome of a call, as observed by [`Differential`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    /// The call returned `data`.
+    Returned(Bytes),
+    /// The call reverted with `data`, empty for Stylus panics.
+    Reverted(Bytes),
+}
+
+impl Outcome {
+    /// Returns the selector of the revert data, if the call reverted with
+    /// any.
+    #[must_use]
+    pub fn revert_selector(&self) -> Option<&[u8]> {
+        match self {
+            Outcome::Returned(_) => None,
+            Outcome::Reverted(data) => data.get(..4),
+        }
+    }
+}
+
+/// Differential tester of a Stylus contract against its reference Solidity
+/// implementation, i.e. the contract it ports.
+///
+/// Both contracts are deployed from the same state, then each call is sent to
+/// both of them, and the tester errors at the first difference of return
+/// values, revert selectors or emitted events:
+///
+/// ```rust,ignore
+/// let diff = Differential::new(stylus_addr, solidity_addr);
+/// diff.send(&alice, &Erc20::mintCall { account, amount }).await?;
+/// diff.send(&alice, &Erc20::transferCall { recipient, amount }).await?;
+/// diff.call(&alice, &Erc20::balanceOfCall { account }).await?;
+/// ```
+///
+/// Revert data is compared by selector only, and a Solidity `Panic(uint256)`
+/// matches a Stylus revert without data, since Stylus contracts panic without
+/// a reason. Events are compared without the address of their emitter.
+#[derive(Clone, Copy, Debug)]
+pub struct Differential {
+    /// Address of the Stylus contract.
+    pub stylus: Address,
+    /// Address of the reference Solidity contract.
+    pub solidity: Address,
+}
+
+impl Differential {
+    /// Creates a differential tester of the Stylus contract `stylus` against
+    /// the Solidity contract `solidity`.
+    #[must_use]
+    pub fn new(stylus: Address, solidity: Address) -> Self {
+        Self { stylus, solidity }
+    }
+
+    /// Calls both contracts with `call` on behalf of `account`, without
+    /// sending a transaction, e.g. to compare their state through getters,
+    /// and returns the outcome of the Stylus contract.
+    ///
+    /// # Errors
+    ///
+    /// May error if a call fails for another reason than a revert, or if the
+    /// outcomes of the contracts differ.
+    pub async fn call<C: SolCall>(
+        &self,
+        account: &Account,
+        call: &C,
+    ) -> eyre::Result<Outcome> {
+        let input: Bytes = call.abi_encode().into();
+        let stylus = outcome(account, self.stylus, &input).await?;
+        let solidity = outcome(account, self.solidity, &input).await?;
+        let same = match (&stylus, &solidity) {
+            (Outcome::Returned(stylus), Outcome::Returned(solidity)) => {
+                stylus == solidity
+            }
+            (Outcome::Reverted(_), Outcome::Reverted(_)) => {
+                match (stylus.revert_selector(), solidity.revert_selector()) {
+                    (None, Some(selector)) => selector == PANIC_SELECTOR,
+                    (stylus, solidity) => stylus == solidity,
+                }
+            }
+            _ => false,
+        };
+        ensure!(
+            same,
+            "`{}` diverged: Stylus {stylus:?}, Solidity {solidity:?}",
+            C::SIGNATURE
+        );
+        Ok(stylus)
+    }
+
+    /// Sends `call` to both contracts on behalf of `account`, if it doesn't
+    /// revert, and returns the outcome of the Stylus contract.
+    ///
+    /// The outcomes are compared like with [`Self::call`], along with the
+    /// events emitted by the transactions.
+    ///
+    /// # Errors
+    ///
+    /// May error if a transaction fails for another reason than a revert, or
+    /// if the outcomes or the events of the contracts differ.
+    pub async fn send<C: SolCall>(
+        &self,
+        account: &Account,
+        call: &C,
+    ) -> eyre::Result<Outcome> {
+        let outcome = self.call(account, call).await?;
+        if let Outcome::Reverted(_) = outcome {
+            return Ok(outcome);
+        }
+
+        let input: Bytes = call.abi_encode().into();
+        let stylus = send(account, self.stylus, &input).await?;
+        let solidity = send(account, self.solidity, &input).await?;
+        ensure!(
+            logs(&stylus) == logs(&solidity),
+            "`{}` emitted different events: Stylus {:?}, Solidity {:?}",
+            C::SIGNATURE,
+            logs(&stylus),
+            logs(&solidity)
+        );
+        Ok(outcome)
+    }
+}
+
+/// Calls `to` with `input` on behalf of `account`, without sending a
+/// transaction.
+async fn outcome(
+    account: &Account,
+    to: Address,
+    input: &Bytes,
+) -> eyre::Result<Outcome> {
+    let tx = TransactionRequest::default()
+        .with_from(account.address())
+        .with_to(to)
+        .with_input(input.clone());
+    match account.wallet.call(&tx).await {
+        Ok(data) => Ok(Outcome::Returned(data)),
+        Err(err) if err.as_error_resp().is_some() => {
+            Ok(Outcome::Reverted(error::revert_data(&err).unwrap_or_default()))
+        }
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Sends a transaction calling `to` with `input` on behalf of `account`, and
+/// returns its receipt.
+async fn send(
+    account: &Account,
+    to: Address,
+    input: &Bytes,
+) -> eyre::Result<TransactionReceipt> {
+    let tx =
+        TransactionRequest::default().with_to(to).with_input(input.clone());
+    let receipt =
+        account.wallet.send_transaction(tx).await?.get_receipt().await?;
+    ensure!(receipt.status(), "transaction to {to} reverted");
+    Ok(receipt)
+}
+
+/// Returns the topics and data of the logs of `receipt`, in order.
+fn logs(receipt: &TransactionReceipt) -> Vec<(Vec<B256>, Bytes)> {
+    receipt
+        .inner
+        .logs()
+        .iter()
+        .map(|log| (log.topics().to_vec(), log.data().data.clone()))
+        .collect()
+}
@@ -1,4 +1,5 @@
 use alloy::{
+    primitives::Bytes,
     sol_types::SolError,
     transports::{RpcError, TransportErrorKind},
 };
@@ -60,6 +61,36 @@ pub trait Revert<E> {
     fn reverted_with(&self, expected: E) -> bool;
 }
 
+/// An error carrying the data of a revert, to inspect its fields rather
+/// than only comparing it with [`Revert::reverted_with`].
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let err = send!(contract.transfer(bob_addr, value))
+///     .expect_err("should not transfer more than the balance");
+/// let ERC20InsufficientBalance { balance, needed, .. } =
+///     err.decoded_revert().expect("should revert with the balance");
+/// assert_eq!(needed, value);
+/// ```
+pub trait RevertData {
+    /// Returns the abi-encoded data of the revert, or `None` if `Self` is not
+    /// a revert with data.
+    fn revert_data(&self) -> Option<Bytes>;
+
+    /// Decodes the data of the revert as the error `E`, or returns `None` if
+    /// `Self` is not a revert with an `E` error.
+    fn decoded_revert<E: SolError>(&self) -> Option<E> {
+        E::abi_decode(&self.revert_data()?, true).ok()
+    }
+}
+
+/// Extracts the data of a revert from the error response of the node.
+pub(crate) fn revert_data(err: &RpcError<TransportErrorKind>) -> Option<Bytes> {
+    let data = err.as_error_resp()?.data.as_ref()?;
+    serde_json::from_str(data.get()).ok()
+}
+
 impl Panic for alloy::contract::Error {
     fn panicked_with(&self, _code: PanicCode) -> bool {
         let Self::TransportError(e) = self else {
@@ -80,37 +111,31 @@ impl Panic for alloy::contract::Error {
     }
 }
 
-impl<E: SolError> Revert<E> for alloy::contract::Error {
-    fn reverted_with(&self, expected: E) -> bool {
+impl RevertData for alloy::contract::Error {
+    fn revert_data(&self) -> Option<Bytes> {
         let Self::TransportError(e) = self else {
-            return false;
+            return None;
         };
+        revert_data(e)
+    }
+}
 
-        let raw_value = e
-            .as_error_resp()
-            .and_then(|payload| payload.data.clone())
-            .expect("should extract the error");
-        let actual = &raw_value.get().trim_matches('"')[2..];
-        let expected = alloy::hex::encode(expected.abi_encode());
-        expected == actual
+impl RevertData for eyre::Report {
+    fn revert_data(&self) -> Option<Bytes> {
+        self.chain()
+            .find_map(|err| err.downcast_ref::<RpcError<TransportErrorKind>>())
+            .and_then(revert_data)
+    }
+}
+
+impl<E: SolError> Revert<E> for alloy::contract::Error {
+    fn reverted_with(&self, expected: E) -> bool {
+        self.revert_data().is_some_and(|data| data == expected.abi_encode())
     }
 }
 
 impl<E: SolError> Revert<E> for eyre::Report {
     fn reverted_with(&self, expected: E) -> bool {
-        let Some(received) = self
-            .chain()
-            .find_map(|err| err.downcast_ref::<RpcError<TransportErrorKind>>())
-        else {
-            return false;
-        };
-        let RpcError::ErrorResp(received) = received else {
-            return false;
-        };
-        let Some(received) = &received.data else {
-            return false;
-        };
-        let expected = alloy::hex::encode(expected.abi_encode());
-        received.to_string().contains(&expected)
+        self.revert_data().is_some_and(|data| data == expected.abi_encode())
     }
 }
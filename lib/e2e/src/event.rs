@@ -4,6 +4,48 @@ use alloy::{rpc::types::eth::TransactionReceipt, sol_types::SolEvent};
 pub trait EventExt<E> {
     /// Asserts the contract emitted the `expected` event.
     fn emits(&self, expected: E) -> bool;
+
+    /// Asserts the contract emitted exactly the `expected` events of type
+    /// `E`, in this order, and no other event of type `E`.
+    fn emits_exactly(&self, expected: &[E]) -> bool;
+
+    /// Asserts the contract emitted the `expected` event exactly `count`
+    /// times.
+    fn emits_count(&self, expected: E, count: usize) -> bool;
+
+    /// Returns all the events of type `E` emitted, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let transfers: Vec<Erc20::Transfer> = receipt.events();
+    /// assert_eq!(transfers.len(), 2);
+    /// ```
+    fn events(&self) -> Vec<E>;
+
+    /// Returns the index, among the logs of the receipt, of the first
+    /// `expected` event at index `from` or after it. See
+    /// [`crate::emits_in_order`].
+    fn position_from(&self, expected: &E, from: usize) -> Option<usize>;
+}
+
+/// Returns the events of type `E` among the logs of `receipt`, with their
+/// index.
+///
+/// Logs are matched on their first topic, since decoding alone doesn't tell
+/// apart events with the same parameters.
+fn decoded<E: SolEvent>(
+    receipt: &TransactionReceipt,
+) -> impl Iterator<Item = (usize, E)> + '_ {
+    receipt
+        .inner
+        .logs()
+        .iter()
+        .enumerate()
+        .filter(|(_, log)| log.topics().first() == Some(&E::SIGNATURE_HASH))
+        .filter_map(|(index, log)| {
+            log.log_decode().ok().map(|log| (index, log.inner.data))
+        })
 }
 
 impl<E> EventExt<E> for TransactionReceipt
@@ -13,11 +55,25 @@ where
 {
     fn emits(&self, expected: E) -> bool {
         // Extract all events that are the expected type.
-        self.inner
-            .logs()
-            .iter()
-            .filter_map(|log| log.log_decode().ok())
-            .map(|log| log.inner.data)
-            .any(|event| expected == event)
+        decoded(self).any(|(_, event)| expected == event)
+    }
+
+    fn emits_exactly(&self, expected: &[E]) -> bool {
+        let events: Vec<E> = self.events();
+        events == expected
+    }
+
+    fn emits_count(&self, expected: E, count: usize) -> bool {
+        decoded(self).filter(|(_, event)| expected == *event).count() == count
+    }
+
+    fn events(&self) -> Vec<E> {
+        decoded(self).map(|(_, event)| event).collect()
+    }
+
+    fn position_from(&self, expected: &E, from: usize) -> Option<usize> {
+        decoded(self)
+            .find(|(index, event)| *index >= from && expected == event)
+            .map(|(index, _)| index)
     }
 }
@@ -0,0 +1,71 @@
+use alloy::{
+    primitives::{Address, U256},
+    providers::Provider as _,
+};
+use eyre::Context;
+
+use crate::system::{provider, Provider};
+
+/// Name of the environment variable with the endpoint of the forked chain.
+pub(crate) const FORK_URL_ENV_VAR_NAME: &str = "FORK_URL";
+
+/// Returns true if the tests run against a fork of a live chain, i.e. if the
+/// `FORK_URL` environment variable is set, and false if they run against the
+/// nitro test node.
+///
+/// The fork itself must be served at `RPC_URL`, e.g. by
+/// `scripts/fork-node.sh`.
+#[must_use]
+pub fn is_fork() -> bool {
+    std::env::var_os(FORK_URL_ENV_VAR_NAME).is_some()
+}
+
+/// Sets the balance of `address` to `amount` eth in the fork.
+///
+/// # Errors
+///
+/// May fail if the node rejects `anvil_setBalance`.
+pub(crate) async fn fund_account(
+    address: Address,
+    amount: u32,
+) -> eyre::Result<()> {
+    let wei = U256::from(amount) * U256::from(10).pow(U256::from(18));
+    let () = provider()
+        .raw_request("anvil_setBalance".into(), (address, wei))
+        .await
+        .wrap_err(format!(
+            "account's wallet wasn't funded - address is {address}"
+        ))?;
+    Ok(())
+}
+
+/// Impersonates `address` in the fork, e.g. a whale of a live token, and
+/// returns a provider sending its transactions.
+///
+/// The provider has no wallet: set the sender of the calls to `address`,
+/// e.g. `contract.transfer(to, value).from(address)`.
+///
+/// # Errors
+///
+/// May fail if the node rejects `anvil_impersonateAccount`.
+pub async fn impersonate(address: Address) -> eyre::Result<Provider> {
+    let provider = provider();
+    let () = provider
+        .raw_request("anvil_impersonateAccount".into(), (address,))
+        .await
+        .wrap_err(format!("should impersonate {address}"))?;
+    Ok(provider)
+}
+
+/// Stops impersonating `address` in the fork, see [`impersonate`].
+///
+/// # Errors
+///
+/// May fail if the node rejects `anvil_stopImpersonatingAccount`.
+pub async fn stop_impersonating(address: Address) -> eyre::Result<()> {
+    let () = provider()
+        .raw_request("anvil_stopImpersonatingAccount".into(), (address,))
+        .await
+        .wrap_err(format!("should stop impersonating {address}"))?;
+    Ok(())
+}
@@ -0,0 +1,199 @@
+use std::{cell::RefCell, path::Path, sync::Mutex};
+
+use alloy::{
+    network::Network,
+    primitives::TxHash,
+    providers::PendingTransactionBuilder,
+    rpc::types::{AnyTransactionReceipt, TransactionReceipt},
+    transports::{Transport, TransportResult},
+};
+use eyre::{bail, Context, ContextCompat};
+use serde_json::{Map, Value};
+
+/// File of the gas baselines of the tests of a crate, in its manifest
+/// directory.
+const BASELINE_FILE: &str = "gas-baseline.json";
+
+/// When set, the gas baselines are overwritten with the gas used by the tests
+/// instead of being checked.
+const UPDATE_ENV_VAR_NAME: &str = "GAS_BASELINE_UPDATE";
+
+/// Percentage of gas the tests can use above their baseline.
+const TOLERANCE_ENV_VAR_NAME: &str = "GAS_TOLERANCE";
+
+/// Default of [`TOLERANCE_ENV_VAR_NAME`].
+const DEFAULT_TOLERANCE: u128 = 1;
+
+/// Guards the [`BASELINE_FILE`] against the tests running concurrently.
+static BASELINE_LOCK: Mutex<()> = Mutex::new(());
+
+thread_local! {
+    /// Gas used by the calls of the current test, with their expression.
+    ///
+    /// `#[e2e::test]`s run on a current-thread runtime, so each test has its
+    /// own record.
+    static GAS_USED: RefCell<Vec<(String, u128)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Transaction receipt of any network, see [`record`].
+#[doc(hidden)]
+pub trait GasReceipt {
+    /// Returns the hash of the transaction.
+    fn transaction_hash(&self) -> TxHash;
+
+    /// Returns the gas used by the transaction.
+    fn gas_used(&self) -> u128;
+}
+
+impl<T> GasReceipt for TransactionReceipt<T> {
+    fn transaction_hash(&self) -> TxHash {
+        self.transaction_hash
+    }
+
+    fn gas_used(&self) -> u128 {
+        self.gas_used
+    }
+}
+
+impl GasReceipt for AnyTransactionReceipt {
+    fn transaction_hash(&self) -> TxHash {
+        self.inner.transaction_hash()
+    }
+
+    fn gas_used(&self) -> u128 {
+        self.inner.gas_used()
+    }
+}
+
+/// Records the gas used by the transaction of `receipt`, sent by the `call`
+/// expression, when the `gas-report` feature is enabled.
+#[doc(hidden)]
+pub fn record(call: &str, receipt: &impl GasReceipt) {
+    if cfg!(feature = "gas-report") {
+        GAS_USED.with_borrow_mut(|calls| {
+            calls.push((call.to_owned(), receipt.gas_used()));
+        });
+    }
+}
+
+/// Waits for the transaction sent by the `call` expression to be confirmed,
+/// fetching its receipt to record its gas when the `gas-report` feature is
+/// enabled. See [`crate::watch`].
+#[doc(hidden)]
+pub async fn watch<T, N>(
+    call: &str,
+    pending: PendingTransactionBuilder<'_, T, N>,
+) -> TransportResult<TxHash>
+where
+    T: Transport + Clone,
+    N: Network,
+    N::ReceiptResponse: GasReceipt,
+{
+    if cfg!(feature = "gas-report") {
+        let receipt = pending.get_receipt().await?;
+        record(call, &receipt);
+        Ok(receipt.transaction_hash())
+    } else {
+        pending.watch().await
+    }
+}
+
+/// Summary of the gas used by the calls of a test, printed when it is
+/// dropped at the end of the test.
+///
+/// `#[e2e::test]` sets it up for every test. The calls made with
+/// [`crate::receipt`] and [`crate::watch`] are only recorded when the
+/// `gas-report` feature is enabled, and the summary is printed along with
+/// the output of the test, e.g. with `cargo test -- --nocapture`.
+///
+/// The test then fails if its total exceeds its baseline in the
+/// `gas-baseline.json` file of the crate by more than `GAS_TOLERANCE` percent,
+/// 1% by default. Set `GAS_BASELINE_UPDATE` to record the baselines instead.
+#[derive(Debug)]
+pub struct GasReport {
+    test: &'static str,
+}
+
+impl GasReport {
+    /// Starts recording the calls of `test`.
+    #[must_use]
+    pub fn new(test: &'static str) -> Self {
+        GAS_USED.with_borrow_mut(Vec::clear);
+        Self { test }
+    }
+}
+
+impl Drop for GasReport {
+    fn drop(&mut self) {
+        let calls = GAS_USED.take();
+        if calls.is_empty() {
+            return;
+        }
+
+        let total: u128 = calls.iter().map(|(_, gas)| gas).sum();
+        println!("gas used by {}:", self.test);
+        for (call, gas) in calls {
+            println!("  {gas:>10}  {call}");
+        }
+        println!("  {total:>10}  total");
+
+        match check_baseline(self.test, total) {
+            Err(err) if !std::thread::panicking() => panic!("{err:#}"),
+            _ => {}
+        }
+    }
+}
+
+/// Checks that `test` used at most `total` gas plus the tolerance read from
+/// [`TOLERANCE_ENV_VAR_NAME`] above its baseline, if it has one, or records
+/// `total` as its baseline if [`UPDATE_ENV_VAR_NAME`] is set.
+///
+/// # Errors
+///
+/// May error if the [`BASELINE_FILE`] can't be read or written, or if the
+/// test used more gas than tolerated.
+fn check_baseline(test: &str, total: u128) -> eyre::Result<()> {
+    let _lock =
+        BASELINE_LOCK.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+    let path = std::env::current_dir()?.join(BASELINE_FILE);
+    let mut baselines = read_baselines(&path)?;
+
+    if std::env::var_os(UPDATE_ENV_VAR_NAME).is_some() {
+        baselines.insert(test.to_owned(), u64::try_from(total)?.into());
+        let json = serde_json::to_string_pretty(&baselines)?;
+        std::fs::write(&path, json + "\n")?;
+        return Ok(());
+    }
+
+    let Some(baseline) = baselines.get(test) else {
+        return Ok(());
+    };
+    let baseline = baseline
+        .as_u64()
+        .map(u128::from)
+        .with_context(|| format!("invalid gas baseline of {test}"))?;
+    let tolerance = match std::env::var(TOLERANCE_ENV_VAR_NAME) {
+        Ok(tolerance) => tolerance.parse().wrap_err_with(|| {
+            format!("failed to parse {TOLERANCE_ENV_VAR_NAME}")
+        })?,
+        Err(_) => DEFAULT_TOLERANCE,
+    };
+    let max = baseline + baseline * tolerance / 100;
+    if total > max {
+        bail!(
+            "{test} used {total} gas, more than its baseline of {baseline} plus {tolerance}%, update it with `{UPDATE_ENV_VAR_NAME}=1` if expected"
+        );
+    }
+    Ok(())
+}
+
+/// Reads the gas baselines from the [`BASELINE_FILE`] at `path`, or none if
+/// it doesn't exist.
+fn read_baselines(path: &Path) -> eyre::Result<Map<String, Value>> {
+    if !path.exists() {
+        return Ok(Map::new());
+    }
+    let json = std::fs::read_to_string(path)?;
+    serde_json::from_str(&json)
+        .wrap_err_with(|| format!("failed to parse {}", path.display()))
+}
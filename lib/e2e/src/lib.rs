@@ -1,19 +1,40 @@
 #![doc = include_str!("../README.md")]
 mod account;
+mod cache;
 mod deploy;
+mod differential;
 mod environment;
 mod error;
 mod event;
+mod fork;
+#[doc(hidden)]
+pub mod gas;
+mod node;
+mod nonce;
 mod project;
 mod receipt;
+mod snapshot;
 mod system;
+mod time;
+mod token;
+mod trace;
 
 pub use account::Account;
+pub use deploy::deploy_example;
+pub use differential::{deploy_solidity, reference, Differential, Outcome};
 pub use e2e_proc::test;
-pub use error::{Panic, PanicCode, Revert};
+pub use error::{Panic, PanicCode, Revert, RevertData};
 pub use event::EventExt;
+pub use fork::{impersonate, is_fork, stop_impersonating};
+pub use gas::GasReport;
+pub use node::{is_node_running, start_node, stop_node};
+pub use nonce::NonceManager;
 pub use receipt::ReceiptExt;
+pub use snapshot::{revert_to, snapshot};
 pub use system::{fund_account, provider, Provider, Wallet};
+pub use time::{increase_time, mine, set_next_block_timestamp};
+pub use token::{deploy_erc20_mock, fund_account_erc20};
+pub use trace::{trace_call, trace_transaction, CallFrame, TraceDecoder};
 
 /// This macro provides a shorthand for broadcasting the transaction to the
 /// network.
@@ -61,7 +82,7 @@ macro_rules! send {
 #[macro_export]
 macro_rules! watch {
     ($e:expr) => {
-        $crate::send!($e)?.watch().await
+        $crate::gas::watch(stringify!($e), $crate::send!($e)?).await
     };
 }
 
@@ -87,6 +108,67 @@ macro_rules! watch {
 #[macro_export]
 macro_rules! receipt {
     ($e:expr) => {
-        $crate::send!($e)?.get_receipt().await
+        $crate::send!($e)?.get_receipt().await.map(|receipt| {
+            $crate::gas::record(stringify!($e), &receipt);
+            receipt
+        })
     };
 }
+
+/// This macro asserts that a transaction receipt contains the given events,
+/// possibly of different types, in this order, although other events may
+/// be emitted in between.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[e2e::test]
+/// async fn foo(alice: Account) -> eyre::Result<()> {
+///     // ...
+///     let receipt = receipt!(contract.transferFrom(from, to, value))?;
+///     assert!(emits_in_order!(
+///         receipt,
+///         Erc20::Approval { owner: from, spender: alice_addr, value: left },
+///         Erc20::Transfer { from, to, value },
+///     ));
+///     // ...
+/// }
+/// ```
+#[macro_export]
+macro_rules! emits_in_order {
+    ($receipt:expr, $($event:expr),+ $(,)?) => {{
+        let receipt = &$receipt;
+        let mut from = 0;
+        let mut ordered = true;
+        $(
+            match $crate::EventExt::position_from(receipt, &$event, from) {
+                Some(index) if ordered => from = index + 1,
+                _ => ordered = false,
+            }
+        )+
+        ordered
+    }};
+}
+
+/// This macro asserts that the transaction of a receipt used at most the
+/// given amount of gas.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[e2e::test]
+/// async fn foo(alice: Account) -> eyre::Result<()> {
+///     // ...
+///     let receipt = receipt!(contract.transfer(bob_addr, value))?;
+///     assert_gas!(receipt, <= 60_000);
+///     // ...
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_gas {
+    ($receipt:expr, <= $max:expr) => {{
+        let gas = $crate::gas::GasReceipt::gas_used(&$receipt);
+        let max: u128 = $max;
+        assert!(gas <= max, "transaction used {gas} gas, more than {max}");
+    }};
+}
@@ -0,0 +1,115 @@
+use std::time::Duration;
+
+use alloy::providers::{Provider, ProviderBuilder};
+use eyre::{bail, Context};
+use tokio::{process::Command, sync::Mutex};
+
+use crate::{environment::get_workspace_root, fork, system};
+
+/// Name of the environment variable that stops the tests from starting the
+/// nitro test node themselves.
+const NO_START_ENV_VAR_NAME: &str = "E2E_NO_START_NODE";
+
+/// Time the nitro test node is given to answer once started, which includes
+/// its initialization on the first run.
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// Interval between the health checks of a starting node.
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Returns true if the node at `RPC_URL` answers requests, and false
+/// otherwise.
+pub async fn is_node_running() -> bool {
+    let Ok(rpc_url) = system::rpc_url().parse() else {
+        return false;
+    };
+    ProviderBuilder::new().on_http(rpc_url).get_chain_id().await.is_ok()
+}
+
+/// Starts the nitro test node of the workspace in the background with
+/// `scripts/nitro-testnode.sh`, setting it up first if it was never set up,
+/// and waits for it to answer at `RPC_URL`.
+///
+/// Does nothing if the node is already running. `#[e2e::test]`s start the
+/// node themselves unless `E2E_NO_START_NODE` is set.
+///
+/// # Errors
+///
+/// May fail if the script can't be run, e.g. because `docker` is not
+/// available, or if the node doesn't answer in time.
+pub async fn start_node() -> eyre::Result<()> {
+    if is_node_running().await {
+        return Ok(());
+    }
+
+    let status = Command::new(script()?)
+        .arg("--detach")
+        .status()
+        .await
+        .wrap_err("should run `scripts/nitro-testnode.sh`")?;
+    if !status.success() {
+        bail!("nitro test node failed to start: {status}");
+    }
+
+    let started = tokio::time::Instant::now();
+    while !is_node_running().await {
+        if started.elapsed() > STARTUP_TIMEOUT {
+            bail!(
+                "nitro test node didn't answer at {} after {}s",
+                system::rpc_url(),
+                STARTUP_TIMEOUT.as_secs()
+            );
+        }
+        tokio::time::sleep(HEALTH_CHECK_INTERVAL).await;
+    }
+    Ok(())
+}
+
+/// Stops the containers of the nitro test node of the workspace.
+///
+/// Its state is kept, so that a later [`start_node`] resumes it.
+///
+/// # Errors
+///
+/// May fail if the script can't be run, or if the containers can't be
+/// stopped.
+pub async fn stop_node() -> eyre::Result<()> {
+    let status = Command::new(script()?)
+        .arg("--quit")
+        .status()
+        .await
+        .wrap_err("should run `scripts/nitro-testnode.sh`")?;
+    if !status.success() {
+        bail!("nitro test node failed to stop: {status}");
+    }
+    Ok(())
+}
+
+/// Starts the nitro test node once per test binary if it isn't running, see
+/// [`start_node`].
+///
+/// Forks and nodes managed separately, i.e. when `E2E_NO_START_NODE` is set,
+/// are left alone.
+///
+/// # Errors
+///
+/// May fail if the node can't be started.
+pub(crate) async fn ensure_running() -> eyre::Result<()> {
+    static CHECKED: Mutex<bool> = Mutex::const_new(false);
+
+    let mut checked = CHECKED.lock().await;
+    if *checked
+        || fork::is_fork()
+        || std::env::var_os(NO_START_ENV_VAR_NAME).is_some()
+    {
+        return Ok(());
+    }
+    start_node().await?;
+    *checked = true;
+    Ok(())
+}
+
+/// Returns the path to the script managing the nitro test node.
+fn script() -> eyre::Result<std::path::PathBuf> {
+    Ok(get_workspace_root()?.join("scripts/nitro-testnode.sh"))
+}
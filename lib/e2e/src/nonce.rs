@@ -0,0 +1,101 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use alloy::{
+    network::{Network, TransactionBuilder},
+    primitives::Address,
+    providers::{
+        fillers::{FillerControlFlow, TxFiller},
+        Provider, SendableTx,
+    },
+    transports::{Transport, TransportResult},
+};
+use once_cell::sync::Lazy;
+
+/// Next nonce of each account, if known, shared by all the wallets of the
+/// test binary.
+type Nonces = HashMap<Address, Arc<tokio::sync::Mutex<Option<u64>>>>;
+
+static NONCES: Lazy<Mutex<Nonces>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Returns the lock of the nonce of `address`.
+fn nonce_of(address: Address) -> Arc<tokio::sync::Mutex<Option<u64>>> {
+    let mut nonces = NONCES.lock().unwrap_or_else(PoisonError::into_inner);
+    Arc::clone(nonces.entry(address).or_default())
+}
+
+/// A nonce filler sharing the nonces of the accounts across all their
+/// wallets, e.g. clones of an [`crate::Account`] used by concurrent tasks.
+///
+/// Unlike the `NonceFiller` of `alloy`, whose clones track nonces
+/// independently, transactions sent concurrently from the same account never
+/// get the same nonce, so tests can run with `--test-threads` greater than
+/// one.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NonceManager;
+
+impl<N: Network> TxFiller<N> for NonceManager {
+    type Fillable = u64;
+
+    fn status(&self, tx: &N::TransactionRequest) -> FillerControlFlow {
+        if tx.nonce().is_some() {
+            return FillerControlFlow::Finished;
+        }
+        if tx.from().is_none() {
+            return FillerControlFlow::missing("NonceManager", vec!["from"]);
+        }
+        FillerControlFlow::Ready
+    }
+
+    fn fill_sync(&self, _tx: &mut SendableTx<N>) {}
+
+    async fn prepare<P, T>(
+        &self,
+        provider: &P,
+        tx: &N::TransactionRequest,
+    ) -> TransportResult<Self::Fillable>
+    where
+        P: Provider<T, N>,
+        T: Transport + Clone,
+    {
+        let from = tx.from().expect("should be checked by `status`");
+        let lock = nonce_of(from);
+        let mut nonce = lock.lock().await;
+        let next = match *nonce {
+            Some(next) => next,
+            None => provider.get_transaction_count(from).await?,
+        };
+        *nonce = Some(next + 1);
+        Ok(next)
+    }
+
+    async fn fill(
+        &self,
+        nonce: Self::Fillable,
+        mut tx: SendableTx<N>,
+    ) -> TransportResult<SendableTx<N>> {
+        if let Some(builder) = tx.as_mut_builder() {
+            builder.set_nonce(nonce);
+        }
+        Ok(tx)
+    }
+}
+
+/// Runs `transactions`, which send transactions from `address` without the
+/// [`NonceManager`], e.g. a deployment, while no other transaction of
+/// `address` is being filled.
+///
+/// The nonce of `address` is read from the chain again afterwards.
+pub(crate) async fn bypass<F: Future>(
+    address: Address,
+    transactions: F,
+) -> F::Output {
+    let lock = nonce_of(address);
+    let mut nonce = lock.lock().await;
+    let output = transactions.await;
+    *nonce = None;
+    output
+}
@@ -9,6 +9,8 @@ use std::{
 use eyre::bail;
 use toml::Table;
 
+use crate::environment::get_workspace_root;
+
 /// Information about the crate subject of an integration test.
 pub(crate) struct Crate {
     /// Path to the directory where the crate's manifest lives.
@@ -28,14 +30,50 @@ impl Crate {
     /// - Could not read the package name from the manifest file.
     /// - Could not read the path to the compiled wasm binary.
     pub(crate) fn new() -> eyre::Result<Self> {
-        let manifest_dir = env::current_dir()?;
+        Self::at(env::current_dir()?)
+    }
+
+    /// Collects information about the crate whose manifest lives in
+    /// `manifest_dir`.
+    ///
+    /// # Errors
+    ///
+    /// May error if:
+    ///
+    /// - Could not read the package name from the manifest file.
+    /// - Could not read the path to the compiled wasm binary.
+    pub(crate) fn at(manifest_dir: PathBuf) -> eyre::Result<Self> {
         let name = read_pkg_name(&manifest_dir)?;
         let wasm = get_wasm(&name)?;
+        if !wasm.exists() {
+            bail!(
+                "wasm binary of {name} not found at {}, build it with `cargo build --release --target wasm32-unknown-unknown -p {name}`",
+                wasm.display()
+            );
+        }
 
         Ok(Self { manifest_dir, wasm })
     }
 }
 
+/// Returns the path to the manifest directory of the example `name` of the
+/// workspace of the current crate, i.e. `examples/{name}`.
+///
+/// # Errors
+///
+/// May error if:
+///
+/// - Unable to locate the root of the workspace.
+/// - The workspace has no example `name`.
+pub(crate) fn example_dir(name: &str) -> eyre::Result<PathBuf> {
+    let examples = get_workspace_root()?.join("examples");
+    let manifest_dir = examples.join(name);
+    if !manifest_dir.join("Cargo.toml").exists() {
+        bail!("example {name} not found in {}", examples.display());
+    }
+    Ok(manifest_dir)
+}
+
 /// Reads and parses the package name from a manifest in `path`.
 ///
 /// # Errors
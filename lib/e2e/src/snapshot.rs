@@ -0,0 +1,53 @@
+use alloy::{
+    primitives::U256,
+    providers::Provider,
+    transports::http::{Client, Http},
+};
+use eyre::{bail, Context};
+
+/// Snapshots the state of the chain, returning the id of the snapshot to
+/// revert to with [`revert_to`].
+///
+/// NOTE: The node must expose the `evm_*` development methods, see
+/// [`crate::increase_time`].
+///
+/// # Errors
+///
+/// May fail if the node rejects `evm_snapshot`.
+pub async fn snapshot<P>(provider: &P) -> eyre::Result<U256>
+where
+    P: Provider<Http<Client>>,
+{
+    provider
+        .raw_request("evm_snapshot".into(), ())
+        .await
+        .wrap_err("should snapshot the state of the chain")
+}
+
+/// Reverts the state of the chain to the snapshot `id`, returned by
+/// [`snapshot`].
+///
+/// A snapshot can only be reverted to once: take a new one after reverting
+/// to reuse the same state in another scenario.
+///
+/// NOTE: Reverting also resets the nonces of the accounts, which their
+/// wallets cache: send the transactions of the next scenario from accounts
+/// that haven't sent any since the snapshot, e.g. new ones.
+///
+/// # Errors
+///
+/// May fail if the node rejects `evm_revert`, or if there is no snapshot
+/// `id`.
+pub async fn revert_to<P>(provider: &P, id: U256) -> eyre::Result<()>
+where
+    P: Provider<Http<Client>>,
+{
+    let reverted: bool = provider
+        .raw_request("evm_revert".into(), (id,))
+        .await
+        .wrap_err("should revert the state of the chain")?;
+    if !reverted {
+        bail!("snapshot {id} doesn't exist");
+    }
+    Ok(())
+}
@@ -3,8 +3,7 @@ use alloy::{
     primitives::Address,
     providers::{
         fillers::{
-            ChainIdFiller, FillProvider, GasFiller, JoinFill, NonceFiller,
-            WalletFiller,
+            ChainIdFiller, FillProvider, GasFiller, JoinFill, WalletFiller,
         },
         Identity, ProviderBuilder, RootProvider,
     },
@@ -12,52 +11,60 @@ use alloy::{
 };
 use eyre::{bail, Context};
 
-use crate::environment::get_node_path;
+use crate::{environment::get_node_path, nonce::NonceManager};
 
-pub(crate) const RPC_URL_ENV_VAR_NAME: &str = "RPC_URL";
+const RPC_URL_ENV_VAR_NAME: &str = "RPC_URL";
+
+/// Endpoint of the nitro test node, used when `RPC_URL` is not set.
+const DEFAULT_RPC_URL: &str = "http://localhost:8547";
+
+/// Fillers of the transactions of the tests.
+type Fillers = JoinFill<
+    JoinFill<JoinFill<Identity, GasFiller>, NonceManager>,
+    ChainIdFiller,
+>;
 
 /// Convenience type alias that represents an Ethereum wallet.
 pub type Wallet = FillProvider<
-    JoinFill<
-        JoinFill<
-            JoinFill<JoinFill<Identity, GasFiller>, NonceFiller>,
-            ChainIdFiller,
-        >,
-        WalletFiller<EthereumWallet>,
-    >,
+    JoinFill<Fillers, WalletFiller<EthereumWallet>>,
     RootProvider<Http<Client>>,
     Http<Client>,
     Ethereum,
 >;
 
 /// Convenience type alias that represents an alloy provider.
-pub type Provider = FillProvider<
-    JoinFill<
-        JoinFill<JoinFill<Identity, GasFiller>, NonceFiller>,
-        ChainIdFiller,
-    >,
-    RootProvider<Http<Client>>,
-    Http<Client>,
-    Ethereum,
->;
+pub type Provider =
+    FillProvider<Fillers, RootProvider<Http<Client>>, Http<Client>, Ethereum>;
 
 /// Load the `name` environment variable.
 fn env(name: &str) -> eyre::Result<String> {
     std::env::var(name).wrap_err(format!("failed to load {name}"))
 }
 
+/// Returns the `RPC_URL` rpc endpoint, or the endpoint of the local nitro
+/// test node if it is not set.
+pub(crate) fn rpc_url() -> String {
+    env(RPC_URL_ENV_VAR_NAME).unwrap_or_else(|_| DEFAULT_RPC_URL.to_owned())
+}
+
 /// Returns an alloy provider connected to the `RPC_URL` rpc endpoint.
 ///
 /// # Panics
 ///
-/// May panic if unable to load the `RPC_URL` environment variable.
+/// May panic if the `RPC_URL` environment variable is not a URL.
 #[must_use]
 pub fn provider() -> Provider {
-    let rpc_url = env(RPC_URL_ENV_VAR_NAME)
-        .expect("failed to load RPC_URL var from env")
-        .parse()
-        .expect("failed to parse RPC_URL string into a URL");
-    ProviderBuilder::new().with_recommended_fillers().on_http(rpc_url)
+    let rpc_url =
+        rpc_url().parse().expect("failed to parse RPC_URL string into a URL");
+    builder().on_http(rpc_url)
+}
+
+/// Returns a provider builder with the fillers of [`Provider`].
+pub(crate) fn builder() -> ProviderBuilder<Identity, Fillers> {
+    ProviderBuilder::new()
+        .filler(GasFiller)
+        .filler(NonceManager)
+        .filler(ChainIdFiller::default())
 }
 
 /// Send `amount` eth to `address` in the nitro-tesnode.
@@ -0,0 +1,73 @@
+use alloy::{
+    providers::Provider,
+    transports::http::{Client, Http},
+};
+use eyre::Context;
+
+/// Advances the time of the next blocks by `seconds`, without waiting for the
+/// wall-clock time to pass.
+///
+/// Works with any provider of the tests, e.g. the wallet of an [`Account`] or
+/// [`crate::provider`].
+///
+/// NOTE: The node must expose the `evm_*` development methods, like `anvil`
+/// or `hardhat` do.
+///
+/// # Errors
+///
+/// May fail if the node rejects `evm_increaseTime`.
+///
+/// [`Account`]: crate::Account
+pub async fn increase_time<P>(provider: &P, seconds: u64) -> eyre::Result<()>
+where
+    P: Provider<Http<Client>>,
+{
+    let _: serde_json::Value = provider
+        .raw_request("evm_increaseTime".into(), (seconds,))
+        .await
+        .wrap_err("should increase the time of the node")?;
+    Ok(())
+}
+
+/// Sets the timestamp of the next block to `timestamp`.
+///
+/// NOTE: The node must expose the `evm_*` development methods, see
+/// [`increase_time`].
+///
+/// # Errors
+///
+/// May fail if the node rejects `evm_setNextBlockTimestamp`, e.g. because
+/// `timestamp` is not after the timestamp of the latest block.
+pub async fn set_next_block_timestamp<P>(
+    provider: &P,
+    timestamp: u64,
+) -> eyre::Result<()>
+where
+    P: Provider<Http<Client>>,
+{
+    let _: serde_json::Value = provider
+        .raw_request("evm_setNextBlockTimestamp".into(), (timestamp,))
+        .await
+        .wrap_err("should set the timestamp of the next block")?;
+    Ok(())
+}
+
+/// Mines a new block, e.g. for the time set by [`increase_time`] or
+/// [`set_next_block_timestamp`] to apply to view calls.
+///
+/// NOTE: The node must expose the `evm_*` development methods, see
+/// [`increase_time`].
+///
+/// # Errors
+///
+/// May fail if the node rejects `evm_mine`.
+pub async fn mine<P>(provider: &P) -> eyre::Result<()>
+where
+    P: Provider<Http<Client>>,
+{
+    let _: serde_json::Value = provider
+        .raw_request("evm_mine".into(), ())
+        .await
+        .wrap_err("should mine a block")?;
+    Ok(())
+}
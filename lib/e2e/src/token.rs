@@ -0,0 +1,71 @@
+use alloy::{
+    primitives::{Address, U256},
+    sol,
+};
+use eyre::ensure;
+
+use crate::{deploy::deploy_example, Account};
+
+sol! {
+    /// Mintable ERC-20 token, e.g. the `erc20` example of the workspace.
+    #[sol(rpc)]
+    contract MintableErc20 {
+        function balanceOf(address account) external view returns (uint256 balance);
+        function mint(address account, uint256 amount) external;
+    }
+
+    /// Constructor of the `erc20` example, see `examples/erc20/src/constructor.sol`.
+    contract Erc20Example {
+        constructor(string memory name, string memory symbol, uint256 cap);
+    }
+}
+
+/// Deploys a mintable ERC-20 mock on behalf of `account`, i.e. the `erc20`
+/// example of the workspace without a cap, and returns its address.
+///
+/// Tests can reuse the mock to fund several accounts with
+/// [`fund_account_erc20`].
+///
+/// # Errors
+///
+/// May error if the deployment fails, see [`deploy_example`].
+pub async fn deploy_erc20_mock(account: &Account) -> eyre::Result<Address> {
+    let ctr = Erc20Example::constructorCall {
+        name: "Mock Token".to_owned(),
+        symbol: "MTK".to_owned(),
+        cap: U256::MAX,
+    };
+    deploy_example("erc20", ctr, account).await
+}
+
+/// Mints `amount` of the mintable ERC-20 `token` to `recipient` on behalf of
+/// `account`, and checks that the balance of `recipient` increased by
+/// `amount`.
+///
+/// `token` must expose an unrestricted `mint(address,uint256)`, e.g. a mock
+/// deployed with [`deploy_erc20_mock`].
+///
+/// # Errors
+///
+/// May error if minting fails, or if the balance of `recipient` didn't
+/// increase by `amount`.
+pub async fn fund_account_erc20(
+    token: Address,
+    recipient: Address,
+    amount: U256,
+    account: &Account,
+) -> eyre::Result<()> {
+    let token = MintableErc20::new(token, &account.wallet);
+    let MintableErc20::balanceOfReturn { balance: initial } =
+        token.balanceOf(recipient).call().await?;
+
+    let _ = crate::watch!(token.mint(recipient, amount))?;
+
+    let MintableErc20::balanceOfReturn { balance } =
+        token.balanceOf(recipient).call().await?;
+    ensure!(
+        balance == initial + amount,
+        "balance of {recipient} should increase by {amount}, but is {balance}"
+    );
+    Ok(())
+}
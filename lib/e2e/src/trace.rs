@@ -0,0 +1,224 @@
+use std::{fmt::Write, str::FromStr};
+
+use alloy::{
+    primitives::{Address, Bytes, TxHash},
+    providers::Provider,
+    rpc::types::TransactionRequest,
+    sol_types::{GenericContractError, SolInterface},
+    transports::http::{Client, Http},
+};
+use eyre::{Context, ContextCompat};
+use serde_json::{json, Value};
+
+/// A call of the trace of a transaction, as returned by the `callTracer` of
+/// the `debug_*` tracing methods of the node.
+#[derive(Clone, Debug)]
+pub struct CallFrame {
+    /// Type of the call, e.g. `CALL`, `STATICCALL` or `DELEGATECALL`.
+    pub kind: String,
+    /// Caller of the call.
+    pub from: Address,
+    /// Target of the call, if any.
+    pub to: Option<Address>,
+    /// Calldata of the call.
+    pub input: Bytes,
+    /// Return or revert data of the call.
+    pub output: Bytes,
+    /// Reason of the failure of the call, if it failed.
+    pub error: Option<String>,
+    /// Calls made by the call, in order.
+    pub calls: Vec<CallFrame>,
+}
+
+impl CallFrame {
+    /// Parses the frame returned by the `callTracer`.
+    ///
+    /// # Errors
+    ///
+    /// May error if a field of the frame is missing or malformed.
+    fn parse(frame: &Value) -> eyre::Result<Self> {
+        let calls = match frame.get("calls") {
+            Some(Value::Array(calls)) => {
+                calls.iter().map(Self::parse).collect::<eyre::Result<_>>()?
+            }
+            _ => Vec::new(),
+        };
+        Ok(Self {
+            kind: field(frame, "type")?
+                .context("call frame should have a type")?,
+            from: field(frame, "from")?
+                .context("call frame should have a caller")?,
+            to: field(frame, "to")?,
+            input: field(frame, "input")?.unwrap_or_default(),
+            output: field(frame, "output")?.unwrap_or_default(),
+            error: field(frame, "error")?,
+            calls,
+        })
+    }
+
+    /// Returns true if the call failed, and false otherwise.
+    #[must_use]
+    pub fn failed(&self) -> bool {
+        self.error.is_some()
+    }
+}
+
+/// Parses the field `name` of the call frame `frame`, if present.
+fn field<T>(frame: &Value, name: &str) -> eyre::Result<Option<T>>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    frame
+        .get(name)
+        .and_then(Value::as_str)
+        .map(str::parse)
+        .transpose()
+        .wrap_err_with(|| format!("malformed `{name}` of call frame"))
+}
+
+/// Traces the calls of the mined transaction `tx_hash`.
+///
+/// NOTE: The node must expose the `debug_*` methods.
+///
+/// # Errors
+///
+/// May fail if the node rejects `debug_traceTransaction`, or returns a
+/// malformed trace.
+pub async fn trace_transaction<P>(
+    provider: &P,
+    tx_hash: TxHash,
+) -> eyre::Result<CallFrame>
+where
+    P: Provider<Http<Client>>,
+{
+    let frame: Value = provider
+        .raw_request(
+            "debug_traceTransaction".into(),
+            (tx_hash, json!({ "tracer": "callTracer" })),
+        )
+        .await
+        .wrap_err("should trace the transaction")?;
+    CallFrame::parse(&frame)
+}
+
+/// Traces the calls of `tx` executed on top of the latest block, without
+/// sending it, e.g. for a call rejected by the gas estimation:
+///
+/// ```rust,ignore
+/// let call = contract.release(token).from(alice_addr);
+/// let trace = e2e::trace_call(&alice.wallet, call.as_ref()).await?;
+/// println!("{}", TraceDecoder::new().with_abi::<Erc20::Erc20Errors>().format(&trace));
+/// ```
+///
+/// NOTE: The node must expose the `debug_*` methods.
+///
+/// # Errors
+///
+/// May fail if the node rejects `debug_traceCall`, or returns a malformed
+/// trace.
+pub async fn trace_call<P>(
+    provider: &P,
+    tx: &TransactionRequest,
+) -> eyre::Result<CallFrame>
+where
+    P: Provider<Http<Client>>,
+{
+    let frame: Value = provider
+        .raw_request(
+            "debug_traceCall".into(),
+            (tx, "latest", json!({ "tracer": "callTracer" })),
+        )
+        .await
+        .wrap_err("should trace the call")?;
+    CallFrame::parse(&frame)
+}
+
+/// Decodes a calldata or a revert data, if it matches the ABI.
+type Decode = fn(&[u8]) -> Option<String>;
+
+/// Pretty-printer of the traces of [`trace_transaction`] and [`trace_call`],
+/// decoding calldata and revert data with the ABIs it knows.
+///
+/// ABIs are the call and error enums generated by `sol!`, e.g.
+/// `Erc20::Erc20Calls` and `Erc20::Erc20Errors`. `Error(string)` and
+/// `Panic(uint256)` revert data are always decoded.
+#[derive(Clone, Debug)]
+pub struct TraceDecoder {
+    abis: Vec<Decode>,
+}
+
+impl Default for TraceDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TraceDecoder {
+    /// Creates a decoder of the `Error(string)` and `Panic(uint256)` revert
+    /// data only.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { abis: Vec::new() }.with_abi::<GenericContractError>()
+    }
+
+    /// Decodes the calldata or revert data matching the ABI `I` as well.
+    #[must_use]
+    pub fn with_abi<I: SolInterface + std::fmt::Debug>(mut self) -> Self {
+        self.abis.push(|data| {
+            I::abi_decode(data, false)
+                .ok()
+                .map(|decoded| format!("{decoded:?}"))
+        });
+        self
+    }
+
+    /// Decodes `data` with the first ABI matching it, or formats its selector
+    /// and length otherwise.
+    #[must_use]
+    pub fn decode(&self, data: &[u8]) -> String {
+        if data.is_empty() {
+            return "0x".to_owned();
+        }
+        self.abis.iter().find_map(|decode| decode(data)).unwrap_or_else(|| {
+            let selector = &data[..data.len().min(4)];
+            format!(
+                "{} ({} bytes)",
+                alloy::hex::encode_prefixed(selector),
+                data.len()
+            )
+        })
+    }
+
+    /// Formats `frame` and its nested calls as a tree, one call per line,
+    /// along with the revert data of the failed calls.
+    #[must_use]
+    pub fn format(&self, frame: &CallFrame) -> String {
+        let mut out = String::new();
+        self.write_frame(&mut out, frame, 0);
+        out
+    }
+
+    fn write_frame(&self, out: &mut String, frame: &CallFrame, depth: usize) {
+        let indent = "  ".repeat(depth);
+        let to = frame.to.map(|to| to.to_string()).unwrap_or_default();
+        // Writing to a `String` can't fail.
+        let _ = writeln!(
+            out,
+            "{indent}{} {} -> {to} {}",
+            frame.kind,
+            frame.from,
+            self.decode(&frame.input)
+        );
+        if let Some(error) = &frame.error {
+            let _ = writeln!(
+                out,
+                "{indent}  ! {error}: {}",
+                self.decode(&frame.output)
+            );
+        }
+        for call in &frame.calls {
+            self.write_frame(out, call, depth + 1);
+        }
+    }
+}
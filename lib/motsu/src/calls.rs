@@ -0,0 +1,200 @@
+//! Calls to other contracts, either mocked or routed to the contracts deployed
+//! by the test.
+use std::{borrow::BorrowMut, collections::HashMap, sync::Mutex};
+
+use once_cell::sync::Lazy;
+use stylus_sdk::{
+    abi::Router, alloy_primitives::Address, prelude::StorageType,
+    storage::TopLevelStorage, ArbResult,
+};
+
+use crate::{context::DefaultStorage, storage::with_address};
+
+/// Response of a mocked call.
+struct Mock {
+    /// Whether the call succeeds or reverts.
+    success: bool,
+    /// Return data of the call, or its revert data if it reverts.
+    data: Vec<u8>,
+}
+
+/// Mocked calls, keyed by their target and selector.
+type Mocks = HashMap<(Address, [u8; 4]), Mock>;
+
+/// Mocked calls of the current test.
+static MOCKS: Lazy<Mutex<Mocks>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Routes a calldata to the methods of a deployed contract.
+type Route = fn(&[u8]) -> ArbResult;
+
+/// Contracts deployed by the current test, keyed by their address.
+static CONTRACTS: Lazy<Mutex<HashMap<Address, Route>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Return data of the last call.
+pub(crate) static RETURN_DATA: Mutex<Vec<u8>> = Mutex::new(Vec::new());
+
+/// Mocks the calls to the function with `selector` of `target`, which then
+/// return `return_data` until the end of the current test.
+///
+/// `target` also reports having code, e.g. for `Address::has_code`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// mock_call(
+///     TOKEN,
+///     IErc20::balanceOfCall::SELECTOR,
+///     U256::from(10).abi_encode(),
+/// );
+/// ```
+///
+/// # Panics
+///
+/// May panic if unable to lock `MOCKS`.
+pub fn mock_call(
+    target: Address,
+    selector: [u8; 4],
+    return_data: impl Into<Vec<u8>>,
+) {
+    let mock = Mock { success: true, data: return_data.into() };
+    MOCKS.lock().unwrap().insert((target, selector), mock);
+}
+
+/// Mocks the calls to the function with `selector` of `target`, which then
+/// revert with `revert_data` until the end of the current test.
+///
+/// See [`mock_call`].
+///
+/// # Panics
+///
+/// May panic if unable to lock `MOCKS`.
+pub fn mock_revert(
+    target: Address,
+    selector: [u8; 4],
+    revert_data: impl Into<Vec<u8>>,
+) {
+    let mock = Mock { success: false, data: revert_data.into() };
+    MOCKS.lock().unwrap().insert((target, selector), mock);
+}
+
+/// Deploys a contract of type `C` at `address`, with its own storage, until
+/// the end of the current test.
+///
+/// Calls to `address` are then routed to the public methods of `C`, e.g. the
+/// `transfer` of a token called by the contract under test, and its storage
+/// can be accessed with [`crate::prelude::with_contract`].
+///
+/// NOTE: `msg::sender()` and `contract::address()` are cached by `stylus_sdk`
+/// for the whole test binary, so they are still [`crate::prelude::MSG_SENDER`]
+/// and [`crate::prelude::CONTRACT_ADDRESS`] in the routed calls.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// deploy::<Token>(TOKEN);
+/// with_contract::<Token, _>(TOKEN, |token| {
+///     token.erc20._mint(msg::sender(), U256::from(10)).unwrap();
+/// });
+/// contract.safe_transfer(TOKEN, ALICE, U256::from(10)).unwrap();
+/// ```
+///
+/// # Panics
+///
+/// May panic if unable to lock `CONTRACTS`.
+pub fn deploy<C>(address: Address)
+where
+    C: StorageType + TopLevelStorage + Router<C> + BorrowMut<C::Storage>,
+{
+    CONTRACTS.lock().unwrap().insert(address, route::<C>);
+}
+
+/// Routes `calldata` to the public methods of `C`, like the entrypoint of a
+/// contract does.
+fn route<C>(calldata: &[u8]) -> ArbResult
+where
+    C: StorageType + TopLevelStorage + Router<C> + BorrowMut<C::Storage>,
+{
+    let Some(selector) = calldata.get(..4) else {
+        return Err(Vec::new());
+    };
+    let selector = u32::from_be_bytes(selector.try_into().unwrap());
+    let mut contract = <C as DefaultStorage>::default();
+    C::route(&mut contract, selector, &calldata[4..])
+        .unwrap_or_else(|| Err(Vec::new()))
+}
+
+/// Calls `target` with `calldata`, returning whether the call succeeded and
+/// storing its return data in [`RETURN_DATA`].
+///
+/// Mocked calls take precedence over the contracts deployed at `target`,
+/// which run with their own storage, unless `delegate` is true. Other calls
+/// succeed without return data.
+///
+/// # Panics
+///
+/// May panic if unable to lock `MOCKS`, `CONTRACTS` or `RETURN_DATA`.
+pub(crate) fn call(target: Address, calldata: &[u8], delegate: bool) -> bool {
+    let (success, data) = mocked(target, calldata)
+        .or_else(|| {
+            // Copy the route, since the contract may call further contracts.
+            let route = *CONTRACTS.lock().unwrap().get(&target)?;
+            let res = if delegate {
+                route(calldata)
+            } else {
+                with_address(target, || route(calldata))
+            };
+            Some(match res {
+                Ok(data) => (true, data),
+                Err(data) => (false, data),
+            })
+        })
+        .unwrap_or((true, Vec::new()));
+    *RETURN_DATA.lock().unwrap() = data;
+    success
+}
+
+/// Returns the success and the return data of the call to `target` with
+/// `calldata`, if mocked.
+///
+/// # Panics
+///
+/// May panic if unable to lock `MOCKS`.
+fn mocked(target: Address, calldata: &[u8]) -> Option<(bool, Vec<u8>)> {
+    let selector = <[u8; 4]>::try_from(calldata.get(..4)?).ok()?;
+    let mocks = MOCKS.lock().unwrap();
+    let mock = mocks.get(&(target, selector))?;
+    Some((mock.success, mock.data.clone()))
+}
+
+/// Returns true if `target` has code, i.e. its calls are mocked or a contract
+/// is deployed at it, and false otherwise.
+///
+/// # Panics
+///
+/// May panic if unable to lock `MOCKS` or `CONTRACTS`.
+pub(crate) fn has_code(target: Address) -> bool {
+    is_deployed(target)
+        || MOCKS.lock().unwrap().keys().any(|(address, _)| *address == target)
+}
+
+/// Returns true if a contract is deployed at `target`, and false otherwise.
+///
+/// # Panics
+///
+/// May panic if unable to lock `CONTRACTS`.
+fn is_deployed(target: Address) -> bool {
+    CONTRACTS.lock().unwrap().contains_key(&target)
+}
+
+/// Clears the mocked calls, the deployed contracts and the return data of the
+/// last call.
+///
+/// # Panics
+///
+/// May panic if unable to lock `MOCKS`, `CONTRACTS` or `RETURN_DATA`.
+pub(crate) fn reset_calls() {
+    MOCKS.lock().unwrap().clear();
+    CONTRACTS.lock().unwrap().clear();
+    RETURN_DATA.lock().unwrap().clear();
+}
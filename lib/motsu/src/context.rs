@@ -1,9 +1,12 @@
 //! Unit-testing context for Stylus contracts.
 use std::sync::{Mutex, MutexGuard};
 
-use stylus_sdk::{alloy_primitives::uint, prelude::StorageType};
+use stylus_sdk::{
+    alloy_primitives::{uint, Address},
+    prelude::StorageType,
+};
 
-use crate::storage::reset_storage;
+use crate::storage::{reset_storage, with_address};
 
 /// A global static mutex.
 ///
@@ -31,6 +34,18 @@ pub fn with_context<C: StorageType>(closure: impl FnOnce(&mut C)) {
     reset_storage();
 }
 
+/// Runs `closure` with the contract of type `C` at `address`, e.g. to set up
+/// or read the state of a contract deployed with [`crate::prelude::deploy`].
+///
+/// The contract under test keeps its own storage, at
+/// [`crate::prelude::CONTRACT_ADDRESS`].
+pub fn with_contract<C: StorageType, R>(
+    address: Address,
+    closure: impl FnOnce(&mut C) -> R,
+) -> R {
+    with_address(address, || closure(&mut C::default()))
+}
+
 /// Initializes fields of contract storage and child contract storages with
 /// default values.
 pub trait DefaultStorage: StorageType {
@@ -0,0 +1,119 @@
+//! Snapshots of the storage layout of contracts.
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    shims::Bytes32,
+    storage::{current_address, STORAGE},
+};
+
+/// Directory of the snapshots, relative to the manifest of the tested crate.
+const LAYOUTS_DIR: &str = "storage-layouts";
+
+/// Name of the environment variable that, when set, overwrites the snapshots
+/// with the layouts of the current run.
+const UPDATE_ENV_VAR_NAME: &str = "STORAGE_LAYOUT_UPDATE";
+
+/// Asserts that the storage of the contract under test matches the snapshot
+/// `name`, i.e. the file `storage-layouts/{name}.txt` of the tested crate,
+/// listing each written slot along with its value.
+///
+/// The test first writes a distinct value to each field of the contract, so
+/// that moving, resizing or reordering a field changes the slots of the
+/// snapshot, which would corrupt the state of upgradeable deployments:
+///
+/// ```rust,ignore
+/// #[motsu::test]
+/// fn keeps_storage_layout(contract: Erc20) {
+///     contract._mint(ALICE, U256::from(1)).unwrap();
+///     contract.approve(ALICE, U256::from(2)).unwrap();
+///     assert_storage_layout("erc20");
+/// }
+/// ```
+///
+/// Run the test with `STORAGE_LAYOUT_UPDATE=1` to record a new snapshot, or
+/// to accept an intended change of layout.
+///
+/// # Panics
+///
+/// * If the storage differs from the snapshot, or there is no snapshot.
+/// * If unable to read or write the snapshot.
+pub fn assert_storage_layout(name: &str) {
+    let path = snapshot_path(name);
+    let layout = format_layout(&words());
+
+    if std::env::var_os(UPDATE_ENV_VAR_NAME).is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, layout).unwrap();
+        return;
+    }
+
+    let Ok(snapshot) = std::fs::read_to_string(&path) else {
+        panic!(
+            "no storage layout snapshot at {}, record it with `{UPDATE_ENV_VAR_NAME}=1`",
+            path.display()
+        );
+    };
+    assert!(
+        snapshot == layout,
+        "storage layout of {name} changed, update {} with `{UPDATE_ENV_VAR_NAME}=1` if expected:\n{}",
+        path.display(),
+        diff(&snapshot, &layout)
+    );
+}
+
+/// Returns the path to the snapshot `name` of the tested crate.
+///
+/// # Panics
+///
+/// If `CARGO_MANIFEST_DIR` is not set, i.e. the test doesn't run with
+/// `cargo test`.
+fn snapshot_path(name: &str) -> PathBuf {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .expect("tests should run with `cargo test`");
+    Path::new(&manifest_dir).join(LAYOUTS_DIR).join(format!("{name}.txt"))
+}
+
+/// Returns the words of storage of the contract under test, sorted by slot.
+///
+/// # Panics
+///
+/// May panic if unable to lock `STORAGE`.
+fn words() -> BTreeMap<Bytes32, Bytes32> {
+    let address = current_address();
+    STORAGE
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|((owner, _), _)| *owner == address)
+        .map(|((_, slot), value)| (*slot, *value))
+        .collect()
+}
+
+/// Formats `words` as one `{slot} {value}` line per word.
+fn format_layout(words: &BTreeMap<Bytes32, Bytes32>) -> String {
+    words.iter().fold(String::new(), |mut out, (slot, value)| {
+        // Writing to a `String` can't fail.
+        let _ = writeln!(
+            out,
+            "{} {}",
+            const_hex::encode_prefixed(slot),
+            const_hex::encode_prefixed(value)
+        );
+        out
+    })
+}
+
+/// Lists the lines removed from `old` and added to `new`.
+fn diff(old: &str, new: &str) -> String {
+    let removed = old.lines().filter(|line| !new.lines().any(|l| l == *line));
+    let added = new.lines().filter(|line| !old.lines().any(|l| l == *line));
+    removed
+        .map(|line| format!("- {line}"))
+        .chain(added.map(|line| format!("+ {line}")))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -42,6 +42,25 @@
 //! }
 //! ```
 //!
+//! The events emitted during a test are returned by
+//! [`prelude::emitted_events`].
+//!
+//! Calls to other contracts succeed without return data, unless mocked by the
+//! test with [`prelude::mock_call`] or [`prelude::mock_revert`], or routed to
+//! the contracts deployed by the test with [`prelude::deploy`], which have
+//! their own storage, accessed with [`prelude::with_contract`].
+//!
+//! ETH balances are zero unless set by the test with
+//! [`prelude::set_balance`], e.g. for `contract::balance()`, and are cleared
+//! at the end of each test. `msg::value()` is always zero, and
+//! `block::timestamp()` and `block::number()` are always
+//! [`prelude::BLOCK_TIMESTAMP`] and [`prelude::BLOCK_NUMBER`], since
+//! `stylus_sdk` caches them for the whole test binary.
+//!
+//! [`prelude::assert_storage_layout`] compares the storage written by a test
+//! with a snapshot committed along with the crate, so that changes to the
+//! storage layout of upgradeable contracts don't go unnoticed.
+//!
 //! Note that currently, test suites using [`motsu::test`][test_attribute] will
 //! run serially because of global access to storage.
 //!
@@ -53,7 +72,10 @@
 //! requests.
 //!
 //! [test_attribute]: crate::test
+mod calls;
 mod context;
+mod layout;
+mod logs;
 pub mod prelude;
 mod shims;
 mod storage;
@@ -0,0 +1,71 @@
+//! Logs emitted by the contracts under test.
+use std::sync::Mutex;
+
+use stylus_sdk::{
+    alloy_primitives::{Bytes, B256},
+    alloy_sol_types::SolEvent,
+};
+
+/// Logs emitted during the current test, in order, as their topics and data.
+pub(crate) static LOGS: Mutex<Vec<(Vec<B256>, Bytes)>> = Mutex::new(Vec::new());
+
+/// Returns the events of type `E` emitted during the current test, in order.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[motsu::test]
+/// fn emits_transfer(contract: Erc20) {
+///     contract._mint(ALICE, U256::from(1)).unwrap();
+///     assert_eq!(
+///         emitted_events::<Transfer>(),
+///         vec![Transfer { from: Address::ZERO, to: ALICE, value: U256::from(1) }]
+///     );
+/// }
+/// ```
+///
+/// # Panics
+///
+/// May panic if unable to lock `LOGS`.
+#[must_use]
+pub fn emitted_events<E: SolEvent>() -> Vec<E> {
+    LOGS.lock()
+        .unwrap()
+        .iter()
+        .filter(|(topics, _)| {
+            E::ANONYMOUS || topics.first() == Some(&E::SIGNATURE_HASH)
+        })
+        .filter_map(|(topics, data)| {
+            E::decode_raw_log(topics.iter().copied(), data, false).ok()
+        })
+        .collect()
+}
+
+/// Returns the topics and data of the logs emitted during the current test,
+/// in order.
+///
+/// # Panics
+///
+/// May panic if unable to lock `LOGS`.
+#[must_use]
+pub fn emitted_logs() -> Vec<(Vec<B256>, Bytes)> {
+    LOGS.lock().unwrap().clone()
+}
+
+/// Records a log with `topics` and `data`.
+///
+/// # Panics
+///
+/// May panic if unable to lock `LOGS`.
+pub(crate) fn record(topics: Vec<B256>, data: Bytes) {
+    LOGS.lock().unwrap().push((topics, data));
+}
+
+/// Clears the logs emitted during the current test.
+///
+/// # Panics
+///
+/// May panic if unable to lock `LOGS`.
+pub(crate) fn reset_logs() {
+    LOGS.lock().unwrap().clear();
+}
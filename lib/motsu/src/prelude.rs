@@ -1,6 +1,9 @@
 //! Common imports for `motsu` tests.
 pub use crate::{
-    context::{acquire_storage, with_context, DefaultStorage},
+    calls::{deploy, mock_call, mock_revert},
+    context::{acquire_storage, with_context, with_contract, DefaultStorage},
+    layout::assert_storage_layout,
+    logs::{emitted_events, emitted_logs},
     shims::*,
     storage::reset_storage,
 };
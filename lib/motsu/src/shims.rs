@@ -68,11 +68,17 @@
 //! }
 //! ```
 #![allow(clippy::missing_safety_doc)]
-use std::slice;
+use std::{collections::HashMap, slice, sync::Mutex};
 
+use once_cell::sync::Lazy;
+use stylus_sdk::alloy_primitives::{Address, B256, U256};
 use tiny_keccak::{Hasher, Keccak};
 
-use crate::storage::{read_bytes32, write_bytes32, STORAGE};
+use crate::{
+    calls::{self, RETURN_DATA},
+    logs,
+    storage::{current_address, read_bytes32, write_bytes32, STORAGE},
+};
 
 pub(crate) const WORD_BYTES: usize = 32;
 pub(crate) type Bytes32 = [u8; WORD_BYTES];
@@ -115,7 +121,7 @@ pub unsafe extern "C" fn storage_load_bytes32(key: *const u8, out: *mut u8) {
     let value = STORAGE
         .lock()
         .unwrap()
-        .get(&key)
+        .get(&(current_address(), key))
         .map(Bytes32::to_owned)
         .unwrap_or_default();
 
@@ -142,7 +148,7 @@ pub unsafe extern "C" fn storage_cache_bytes32(
     value: *const u8,
 ) {
     let (key, value) = unsafe { (read_bytes32(key), read_bytes32(value)) };
-    STORAGE.lock().unwrap().insert(key, value);
+    STORAGE.lock().unwrap().insert((current_address(), key), value);
 }
 
 /// Persists any dirty values in the storage cache to the EVM state trie,
@@ -164,10 +170,22 @@ pub const CONTRACT_ADDRESS: &[u8; 42] =
 /// Arbitrum's CHAID ID.
 pub const CHAIN_ID: u64 = 42161;
 
+/// Dummy block timestamp set for tests: 1st January 2025 00:00:00.
+pub const BLOCK_TIMESTAMP: u64 = 1_735_689_600;
+
+/// Dummy block number set for tests: the Ethereum block of
+/// [`BLOCK_TIMESTAMP`].
+pub const BLOCK_NUMBER: u64 = 21_525_891;
+
 /// Externally Owned Account (EOA) code hash.
 pub const EOA_CODEHASH: &[u8; 66] =
     b"0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470";
 
+/// Dummy code hash of the accounts whose calls are mocked, see
+/// [`calls::mock_call`].
+pub const MOCK_CODEHASH: &[u8; 66] =
+    b"0x1111111111111111111111111111111111111111111111111111111111111111";
+
 /// Gets the address of the account that called the program. For normal
 /// L2-to-L2 transactions the semantics are equivalent to that of the EVM's
 /// [`CALLER`] opcode, including in cases arising from [`DELEGATE_CALL`].
@@ -189,6 +207,20 @@ pub unsafe extern "C" fn msg_sender(sender: *mut u8) {
     std::ptr::copy(addr.as_ptr(), sender, 20);
 }
 
+/// Gets the ETH value in wei sent to the program. The semantics are
+/// equivalent to that of the EVM's [`CALLVALUE`] opcode.
+///
+/// Unit tests never send a value, so it is always zero.
+///
+/// NOTE: The value can't be set per test like balances can: `stylus_sdk`
+/// caches `msg::value()` on its first call for the whole test binary.
+///
+/// [`CALLVALUE`]: https://www.evm.codes/#34
+#[no_mangle]
+pub unsafe extern "C" fn msg_value(value: *mut u8) {
+    std::ptr::write_bytes(value, 0, 32);
+}
+
 /// Gets the address of the current program. The semantics are equivalent to
 /// that of the EVM's [`ADDRESS`] opcode.
 ///
@@ -224,9 +256,62 @@ pub unsafe extern "C" fn chainid() -> u64 {
 /// [`LOG2`]: https://www.evm.codes/#a2
 /// [`LOG3`]: https://www.evm.codes/#a3
 /// [`LOG4`]: https://www.evm.codes/#a4
+///
+/// The logs are recorded until the end of the test, see
+/// [`logs::emitted_events`].
+///
+/// # Panics
+///
+/// May panic if unable to lock `LOGS`.
+#[no_mangle]
+pub unsafe extern "C" fn emit_log(data: *const u8, len: usize, topics: usize) {
+    let bytes = slice::from_raw_parts(data, len);
+    let (topics, data) = bytes.split_at(topics * WORD_BYTES);
+    let topics =
+        topics.chunks_exact(WORD_BYTES).map(B256::from_slice).collect();
+    logs::record(topics, data.to_vec().into());
+}
+
+/// Balances of the accounts, as set by the current test with
+/// [`set_balance`].
+static BALANCES: Lazy<Mutex<HashMap<Address, U256>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Sets the ETH balance in wei of `account` until the end of the current
+/// test, e.g. of [`CONTRACT_ADDRESS`] for `contract::balance()` to return it.
+///
+/// # Panics
+///
+/// May panic if unable to lock `BALANCES`.
+pub fn set_balance(account: Address, balance: U256) {
+    BALANCES.lock().unwrap().insert(account, balance);
+}
+
+/// Clears the balances set with [`set_balance`].
+///
+/// # Panics
+///
+/// May panic if unable to lock `BALANCES`.
+pub(crate) fn reset_balances() {
+    BALANCES.lock().unwrap().clear();
+}
+
+/// Gets the ETH balance in wei of the account at the given address. The
+/// semantics are equivalent to that of the EVM's [`BALANCE`] opcode.
+///
+/// Accounts have no balance unless set with [`set_balance`].
+///
+/// [`BALANCE`]: https://www.evm.codes/#31
+///
+/// # Panics
+///
+/// May panic if unable to lock `BALANCES`.
 #[no_mangle]
-pub unsafe extern "C" fn emit_log(_: *const u8, _: usize, _: usize) {
-    // No-op: we don't check for events in our unit-tests.
+pub unsafe extern "C" fn account_balance(address: *const u8, dest: *mut u8) {
+    let account = read_address(address);
+    let balance =
+        BALANCES.lock().unwrap().get(&account).copied().unwrap_or_default();
+    std::ptr::copy(balance.to_be_bytes::<32>().as_ptr(), dest, 32);
 }
 
 /// Gets the code hash of the account at the given address.
@@ -237,13 +322,23 @@ pub unsafe extern "C" fn emit_log(_: *const u8, _: usize, _: usize) {
 ///
 /// [`EXT_CODEHASH`]: https://www.evm.codes/#3F
 ///
+/// Accounts have no code, unless their calls are mocked with
+/// [`calls::mock_call`] or [`calls::mock_revert`], or a contract is deployed
+/// at them with [`calls::deploy`].
+///
 /// # Panics
 ///
-/// May panic if fails to parse `ACCOUNT_CODEHASH` as a keccack hash.
+/// May panic if fails to parse `EOA_CODEHASH` or `MOCK_CODEHASH` as a keccack
+/// hash.
 #[no_mangle]
-pub unsafe extern "C" fn account_codehash(_address: *const u8, dest: *mut u8) {
+pub unsafe extern "C" fn account_codehash(address: *const u8, dest: *mut u8) {
+    let codehash = if calls::has_code(read_address(address)) {
+        MOCK_CODEHASH
+    } else {
+        EOA_CODEHASH
+    };
     let account_codehash =
-        const_hex::const_decode_to_array::<32>(EOA_CODEHASH).unwrap();
+        const_hex::const_decode_to_array::<32>(codehash).unwrap();
 
     std::ptr::copy(account_codehash.as_ptr(), dest, 32);
 }
@@ -253,12 +348,13 @@ pub unsafe extern "C" fn account_codehash(_address: *const u8, dest: *mut u8) {
 /// equivalent to that of the EVM's [`RETURN_DATA_SIZE`] opcode.
 ///
 /// [`RETURN_DATA_SIZE`]: https://www.evm.codes/#3d
+///
+/// # Panics
+///
+/// May panic if unable to lock `RETURN_DATA`.
 #[no_mangle]
 pub unsafe extern "C" fn return_data_size() -> usize {
-    // TODO: #156
-    // No-op: we do not use this function in our unit-tests,
-    // but the binary does include it.
-    0
+    RETURN_DATA.lock().unwrap().len()
 }
 
 /// Copies the bytes of the last EVM call or deployment return result. Does not
@@ -269,16 +365,22 @@ pub unsafe extern "C" fn return_data_size() -> usize {
 /// Returns the number of bytes written.
 ///
 /// [`RETURN_DATA_COPY`]: https://www.evm.codes/#3e
+///
+/// # Panics
+///
+/// May panic if unable to lock `RETURN_DATA`.
 #[no_mangle]
 pub unsafe extern "C" fn read_return_data(
-    _dest: *mut u8,
-    _offset: usize,
-    _size: usize,
+    dest: *mut u8,
+    offset: usize,
+    size: usize,
 ) -> usize {
-    // TODO: #156
-    // No-op: we do not use this function in our unit-tests,
-    // but the binary does include it.
-    0
+    let data = RETURN_DATA.lock().unwrap();
+    let start = offset.min(data.len());
+    let end = offset.saturating_add(size).min(data.len());
+    let bytes = &data[start..end];
+    std::ptr::copy(bytes.as_ptr(), dest, bytes.len());
+    bytes.len()
 }
 
 /// Calls the contract at the given address with options for passing value and
@@ -294,20 +396,21 @@ pub unsafe extern "C" fn read_return_data(
 /// callvalue stipends and the 63/64 gas rule. This means that supplying the
 /// `u64::MAX` gas can be used to send as much as possible.
 ///
+/// Calls succeed without return data, unless mocked with
+/// [`calls::mock_call`] or [`calls::mock_revert`], or routed to a contract
+/// deployed with [`calls::deploy`].
+///
 /// [`CALL`]: https://www.evm.codes/#f1
 #[no_mangle]
 pub unsafe extern "C" fn call_contract(
-    _contract: *const u8,
-    _calldata: *const u8,
-    _calldata_len: usize,
+    contract: *const u8,
+    calldata: *const u8,
+    calldata_len: usize,
     _value: *const u8,
     _gas: u64,
-    _return_data_len: *mut usize,
+    return_data_len: *mut usize,
 ) -> u8 {
-    // TODO: #156
-    // No-op: we do not use this function in our unit-tests,
-    // but the binary does include it.
-    0
+    call(contract, calldata, calldata_len, return_data_len, false)
 }
 
 /// Static calls the contract at the given address, with the option to limit the
@@ -323,19 +426,18 @@ pub unsafe extern "C" fn call_contract(
 /// including the 63/64 gas rule. This means that supplying `u64::MAX` gas can
 /// be used to send as much as possible.
 ///
+/// Calls are mocked like with [`call_contract`].
+///
 /// [`STATIC_CALL`]: https://www.evm.codes/#FA
 #[no_mangle]
 pub unsafe extern "C" fn static_call_contract(
-    _contract: *const u8,
-    _calldata: *const u8,
-    _calldata_len: usize,
+    contract: *const u8,
+    calldata: *const u8,
+    calldata_len: usize,
     _gas: u64,
-    _return_data_len: *mut usize,
+    return_data_len: *mut usize,
 ) -> u8 {
-    // TODO: #156
-    // No-op: we do not use this function in our unit-tests,
-    // but the binary does include it.
-    0
+    call(contract, calldata, calldata_len, return_data_len, false)
 }
 
 /// Delegate calls the contract at the given address, with the option to limit
@@ -351,28 +453,66 @@ pub unsafe extern "C" fn static_call_contract(
 /// including the 63/64 gas rule. This means that supplying `u64::MAX` gas can
 /// be used to send as much as possible.
 ///
+/// Calls are mocked like with [`call_contract`], but the contracts deployed
+/// at `contract` run with the storage of the caller.
+///
 /// [`DELEGATE_CALL`]: https://www.evm.codes/#F4
 #[no_mangle]
 pub unsafe extern "C" fn delegate_call_contract(
-    _contract: *const u8,
-    _calldata: *const u8,
-    _calldata_len: usize,
+    contract: *const u8,
+    calldata: *const u8,
+    calldata_len: usize,
     _gas: u64,
-    _return_data_len: *mut usize,
+    return_data_len: *mut usize,
 ) -> u8 {
-    // TODO: #156
-    // No-op: we do not use this function in our unit-tests,
-    // but the binary does include it.
-    0
+    call(contract, calldata, calldata_len, return_data_len, true)
+}
+
+/// Performs a call to `contract` with the calldata of `calldata_len` bytes at
+/// `calldata`, writing the length of its return data to `return_data_len`,
+/// and returns its status.
+unsafe fn call(
+    contract: *const u8,
+    calldata: *const u8,
+    calldata_len: usize,
+    return_data_len: *mut usize,
+    delegate: bool,
+) -> u8 {
+    let calldata = slice::from_raw_parts(calldata, calldata_len);
+    let success = calls::call(read_address(contract), calldata, delegate);
+    *return_data_len = return_data_size();
+    u8::from(!success)
+}
+
+/// Reads the address at `address`.
+unsafe fn read_address(address: *const u8) -> Address {
+    let mut account = Address::ZERO;
+    std::ptr::copy(address, account.as_mut_ptr(), 20);
+    account
 }
 
 /// Gets a bounded estimate of the Unix timestamp at which the Sequencer
 /// sequenced the transaction. See [`Block Numbers and Time`] for more
 /// information on how this value is determined.
 ///
+/// It is always [`BLOCK_TIMESTAMP`]: `stylus_sdk` caches `block::timestamp()`
+/// on its first call for the whole test binary, so it can't be changed per
+/// test. Tests of time-dependent logic derive their timestamps from it.
+///
 /// [`Block Numbers and Time`]: https://developer.arbitrum.io/time
 #[no_mangle]
 pub unsafe extern "C" fn block_timestamp() -> u64 {
-    // Epoch timestamp: 1st January 2025 00::00::00
-    1_735_689_600
+    BLOCK_TIMESTAMP
+}
+
+/// Gets a bounded estimate of the L1 block number at which the Sequencer
+/// sequenced the transaction. See [`Block Numbers and Time`] for more
+/// information on how this value is determined.
+///
+/// It is always [`BLOCK_NUMBER`], see [`block_timestamp`].
+///
+/// [`Block Numbers and Time`]: https://developer.arbitrum.io/time
+#[no_mangle]
+pub unsafe extern "C" fn block_number() -> u64 {
+    BLOCK_NUMBER
 }
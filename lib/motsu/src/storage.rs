@@ -2,13 +2,59 @@
 use std::{collections::HashMap, ptr, sync::Mutex};
 
 use once_cell::sync::Lazy;
+use stylus_sdk::alloy_primitives::Address;
 
-use crate::shims::{Bytes32, WORD_BYTES};
+use crate::{
+    calls::reset_calls,
+    logs::reset_logs,
+    shims::{reset_balances, Bytes32, CONTRACT_ADDRESS, WORD_BYTES},
+};
+
+/// Words of storage, keyed by the address of the contract owning them and their
+/// slot.
+type Storage = HashMap<(Address, Bytes32), Bytes32>;
 
 /// Storage mock: A global mutable key-value store.
-pub(crate) static STORAGE: Lazy<Mutex<HashMap<Bytes32, Bytes32>>> =
+pub(crate) static STORAGE: Lazy<Mutex<Storage>> =
     Lazy::new(|| Mutex::new(HashMap::new()));
 
+/// Address of the contract whose storage is currently accessed.
+static CURRENT_ADDRESS: Lazy<Mutex<Address>> =
+    Lazy::new(|| Mutex::new(default_address()));
+
+/// Returns [`CONTRACT_ADDRESS`], the address of the contract under test.
+fn default_address() -> Address {
+    Address::from(
+        const_hex::const_decode_to_array::<20>(CONTRACT_ADDRESS).unwrap(),
+    )
+}
+
+/// Returns the address of the contract whose storage is currently accessed.
+///
+/// # Panics
+///
+/// May panic if unable to lock `CURRENT_ADDRESS`.
+pub(crate) fn current_address() -> Address {
+    *CURRENT_ADDRESS.lock().unwrap()
+}
+
+/// Runs `closure` with the storage of the contract at `address`, restoring
+/// the previous storage afterwards.
+///
+/// # Panics
+///
+/// May panic if unable to lock `CURRENT_ADDRESS`.
+pub(crate) fn with_address<R>(
+    address: Address,
+    closure: impl FnOnce() -> R,
+) -> R {
+    let previous =
+        std::mem::replace(&mut *CURRENT_ADDRESS.lock().unwrap(), address);
+    let res = closure();
+    *CURRENT_ADDRESS.lock().unwrap() = previous;
+    res
+}
+
 /// Read the word at address `key`.
 pub(crate) unsafe fn read_bytes32(key: *const u8) -> Bytes32 {
     let mut res = Bytes32::default();
@@ -21,7 +67,8 @@ pub(crate) unsafe fn write_bytes32(key: *mut u8, val: Bytes32) {
     ptr::copy(val.as_ptr(), key, WORD_BYTES);
 }
 
-/// Clears storage, removing all key-value pairs.
+/// Clears storage, removing all key-value pairs, along with the balances, the
+/// mocked calls, the deployed contracts and the logs of the test.
 ///
 /// # Panics
 ///
@@ -29,4 +76,8 @@ pub(crate) unsafe fn write_bytes32(key: *mut u8, val: Bytes32) {
 #[allow(clippy::module_name_repetitions)]
 pub fn reset_storage() {
     STORAGE.lock().unwrap().clear();
+    *CURRENT_ADDRESS.lock().unwrap() = default_address();
+    reset_balances();
+    reset_calls();
+    reset_logs();
 }
@@ -0,0 +1,25 @@
+#!/bin/bash
+set -e
+
+MYDIR=$(realpath "$(dirname "$0")")
+cd "$MYDIR"
+cd ..
+
+# Builds the reference Solidity contracts of the differential tests, i.e. the
+# `.sol` files in the `tests/reference` directories of the examples, against
+# OpenZeppelin Contracts.
+OZ_VERSION=${OZ_VERSION:-v5.0.2}
+REFERENCE_DIR=target/reference
+OZ_DIR="$REFERENCE_DIR/openzeppelin-contracts-$OZ_VERSION"
+
+if [ ! -d "$OZ_DIR" ]; then
+  git clone --quiet --depth 1 --branch "$OZ_VERSION" \
+    https://github.com/OpenZeppelin/openzeppelin-contracts "$OZ_DIR"
+fi
+
+# shellcheck disable=SC2046
+solc --bin --optimize --overwrite \
+  --output-dir "$REFERENCE_DIR" \
+  --allow-paths "$OZ_DIR" \
+  "@openzeppelin/contracts/=$OZ_DIR/contracts/" \
+  $(find ./examples -path "*/tests/reference/*.sol")
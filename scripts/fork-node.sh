@@ -0,0 +1,19 @@
+#!/bin/bash
+# Serves a fork of the chain at `FORK_URL`, optionally at block `FORK_BLOCK`,
+# at the same endpoint as the nitro test node, for `#[e2e::test(fork)]`s.
+#
+# NOTE: The fork is served by `anvil`, which doesn't execute Stylus programs:
+# only the Solidity contracts of the forked chain can be called.
+set -e
+
+if [ -z "$FORK_URL" ]; then
+  echo "FORK_URL must be set to the endpoint of the chain to fork" >&2
+  exit 1
+fi
+
+ARGS=(--fork-url "$FORK_URL" --port 8547)
+if [ -n "$FORK_BLOCK" ]; then
+  ARGS+=(--fork-block-number "$FORK_BLOCK")
+fi
+
+anvil "${ARGS[@]}"
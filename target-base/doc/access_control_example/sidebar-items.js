@@ -0,0 +1 @@
+window.SIDEBAR_ITEMS = {"constant":["TRANSFER_ROLE"],"fn":["mark_used","user_entrypoint"]};
\ No newline at end of file
@@ -0,0 +1,2 @@
+window.ALL_CRATES = ["access_control_example","basic_example","cryptography_example","e2e_proc","erc20_example","erc20_permit_example","erc721_consecutive_example","erc721_example","erc721_metadata_example","merkle_proofs_example","motsu","motsu_proc","openzeppelin_stylus_proc","ownable_example"];
+//{"start":21,"fragment_lengths":[24,16,23,11,16,23,29,17,26,24,8,13,27,18]}
\ No newline at end of file
@@ -0,0 +1 @@
+window.SIDEBAR_ITEMS = {"attr":["test"]};
\ No newline at end of file
@@ -0,0 +1 @@
+window.SIDEBAR_ITEMS = {"enum":["VerifierError"],"fn":["mark_used","user_entrypoint"],"struct":["MerkleProofInvalidMultiProofLength","MerkleProofInvalidRootChild","MerkleProofInvalidTotalHashes"]};
\ No newline at end of file
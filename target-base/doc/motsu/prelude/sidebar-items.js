@@ -0,0 +1 @@
+window.SIDEBAR_ITEMS = {"constant":["CHAIN_ID","CONTRACT_ADDRESS","EOA_CODEHASH","MSG_SENDER"],"fn":["account_codehash","acquire_storage","block_timestamp","call_contract","chainid","contract_address","delegate_call_contract","emit_log","msg_sender","native_keccak256","read_return_data","reset_storage","return_data_size","static_call_contract","storage_cache_bytes32","storage_flush_cache","storage_load_bytes32","with_context"],"trait":["DefaultStorage"]};
\ No newline at end of file
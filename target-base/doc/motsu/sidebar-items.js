@@ -0,0 +1 @@
+window.SIDEBAR_ITEMS = {"attr":["test"],"mod":["prelude"]};
\ No newline at end of file
@@ -0,0 +1 @@
+window.SIDEBAR_ITEMS = {"attr":["interface_id"]};
\ No newline at end of file
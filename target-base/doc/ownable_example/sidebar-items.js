@@ -0,0 +1 @@
+window.SIDEBAR_ITEMS = {"fn":["mark_used","user_entrypoint"]};
\ No newline at end of file
@@ -0,0 +1 @@
+rn_("IcQAAJUAlwABQ4p+AAAAFYTAAABRKQA8AD0APgA/AGoAOwPNAAAAkmVvdFHDAAAzAE4ATwBQAFEAUcMAADIASgBLAEwATQBnAwD9MIkExQAAAJLFAAAAm9IEAAAAGw==")
\ No newline at end of file
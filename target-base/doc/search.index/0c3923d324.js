@@ -0,0 +1 @@
+rn_("BQLAAAAhiQCdABEkAGxtFQLAAAAWlwAgkACeAGJzkQAFicAAABORABVtACWCwQAAIZAAngBrAIoAIcMAADQAQAD7Amlz9wAA/RVBQMUAAABpxgAAAJLUawAAAB8FEA==")
\ No newline at end of file
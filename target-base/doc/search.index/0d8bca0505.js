@@ -0,0 +1 @@
+rn_("VQHCAABSUgBgAGEAYgBjAG0mACoAKwAsAC0ARcDCCoEAABuXABmRABqVABNqADsAdAB1AHgA8wACYXLUQQAAACgHBw==")
\ No newline at end of file
@@ -0,0 +1 @@
+rn_("IcEAAI8AnwAhxQAAXgBoAEXAwAqBAAAblwAZkQAalQATagA7AHQAdQB4AKcBAP0RAA/NAAAAmcoAAACSwgAAAF8BQwAAAA==")
\ No newline at end of file
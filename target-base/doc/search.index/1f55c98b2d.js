@@ -0,0 +1 @@
+rn_("QcMAADEARABFAEYAIcUAAIkAnQAhxQAAjwCfAAXAxgCiAAAfnwAYnQAangBr/RAJAsMAAAB3xgAAAJbbwgiB4AAAAA0=")
\ No newline at end of file
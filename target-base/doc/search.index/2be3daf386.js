@@ -0,0 +1 @@
+rn_("VQHAAABSUgBgAGEAYgBjAG0mACoAKwAsAC0AQcAAAEEAaQBwAHcAIcUAAIkAnQAhxQAAjwCfAAXAxgCiAAAfnwAYnQAangBr/RAJAsMAAAB3xgAAAJb7AmZ0AUDjCwAAAA4AAAAyAAAASgAAAAcDFQHBAAAVmAB4bwAxwAAAQgBnAJsA+/0EWBE=")
\ No newline at end of file
@@ -0,0 +1 @@
+rn_("NYHCAAAmiACLAHIAlACZABuJxwAAAJghwgAANQBtAPsCZXJFAsAAALNkAEduAHoAewB8AGl0HgAgACEAIgCr/RQABsAAAAAnzQAAAJw=")
\ No newline at end of file
@@ -0,0 +1 @@
+rn_("BQLAAAAVkQATmwBocKHBAAA5APsCZHMFAsUAABR3ABWfAGVsEwACxQAAAHVpc+EoAAAANgAAAAFFAsEAALNkAEduAHoAewB8AGl0HgAgACEAIgCL/UUgAMcAAACVyQAAAHngAAAAQzHIAAByAJQAmQAFwMAAqgAAH58AGo8AKIkAnQAqkACeABsCyQAAAJtkbiHFAACQAJ4ABcDGAKIAAB+fABidABqeABOEAsYAAACWaXMhwwAAjwCfAMMA/VCgIcUAAAB9zgAAAJnCAAAAQcQAAACY228AAAACAgMHFQEDAgICIcAAAIkAnQAxxwAAcgCUAJkA8wD9nEkMAQ0AAAA=")
\ No newline at end of file
@@ -0,0 +1 @@
+rn_("AUWKfgAAADWBwgAAJogAiwByAJQAmQBbA8cAAACYYW95AUDkDAAAABYAAAAzAAAATgAAAGQAAAADAyAhwQAANQBtABUBwAAAFWwAZjgA+wJyeOMA/RRAAcYAAACKin4AAAA=")
\ No newline at end of file
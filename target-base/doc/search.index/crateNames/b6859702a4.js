@@ -0,0 +1 @@
+rd_("Afaccess_control_examplembasic_exampleAdcryptography_examplehe2e_procmerc20_exampleAderc20_permit_exampleAjerc721_consecutive_examplenerc721_exampleAgerc721_metadata_exampleAemerkle_proofs_exampleemotsujmotsu_procAhopenzeppelin_stylus_procoownable_example")
\ No newline at end of file
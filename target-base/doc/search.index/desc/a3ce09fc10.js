@@ -0,0 +1 @@
+rd_("AoReturns the argument unchanged.000BaCalls <code>U::from(self)</code>.000CkDefines an end-to-end Stylus contract test that sets up \xe2\x80\xa6CiDefines a unit test that provides access to Stylus\xe2\x80\x99 \xe2\x80\xa60AoMotsu - Unit Testing for StylusCmGets the chain ID of the current chain. The semantics are \xe2\x80\xa6CmInitializes fields of contract storage and child contract \xe2\x80\xa60BlCommon imports for <code>motsu</code> tests.AfArbitrum\xe2\x80\x99s CHAID ID.AlEnd-to-end Procedural MacrosCnEmits an EVM log with the given number of topics and data, \xe2\x80\xa6AoDummy msg sender set for tests.ChProcedural macro definitions used in <code>motsu</code>.ClGets the address of the account that called the program. \xe2\x80\xa6BiExternally Owned Account (EOA) code hash.CgComputes the interface id as an associated constant \xe2\x80\xa6CnDecorates a closure by running it with exclusive access to \xe2\x80\xa6ClCalls the contract at the given address with options for \xe2\x80\xa6BmClears storage, removing all key-value pairs.<AkAcquires access to storage.CnGets a bounded estimate of the Unix timestamp at which the \xe2\x80\xa6BeDummy contract address set for tests.CkGets the code hash of the account at the given address. \xe2\x80\xa6CnGets the address of the current program. The semantics are \xe2\x80\xa6DeEfficiently computes the <code>keccak256</code> hash of the given \xe2\x80\xa6CnCopies the bytes of the last EVM call or deployment return \xe2\x80\xa6CiReturns the length of the last EVM call or deployment \xe2\x80\xa6CmPersists any dirty values in the storage cache to the EVM \xe2\x80\xa6ClStatic calls the contract at the given address, with the \xe2\x80\xa6ClReads a 32-byte value from permanent storage. Stylus\xe2\x80\x99s \xe2\x80\xa6CjWrites a 32-byte value to the permanent storage cache. \xe2\x80\xa6CnDelegate calls the contract at the given address, with the \xe2\x80\xa6DiProcedural macro definitions used in <code>openzeppelin-stylus</code> \xe2\x80\xa6DjCustom error with signature <code>MerkleProofInvalidRootChild()</code> \xe2\x80\xa6DkCustom error with signature <code>MerkleProofInvalidTotalHashes()</code>\xe2\x80\xa6AoCustom error with signature \xe2\x80\xa6")
\ No newline at end of file
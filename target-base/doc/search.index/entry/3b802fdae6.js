@@ -0,0 +1 @@
+rd_("Ak[150,13,151,151,160,66,0,0]Ak[150,13,151,151,158,66,0,0]Ak[150,13,151,151,159,66,0,0]Ak[150,13,151,151,160,12,0,0]Ak[150,13,151,151,158,12,0,0]Ak[150,13,151,151,159,12,0,0]Dn[150,13,151,151,113,12,0,0,\"impl-From%3CMultiProofError%3E-for-VerifierError\"]Fa[150,13,151,151,113,12,0,0,\"impl-From%3CMerkleProofInvalidMultiProofLength%3E-for-VerifierError\"]El[150,13,151,151,113,12,0,0,\"impl-From%3CMerkleProofInvalidTotalHashes%3E-for-VerifierError\"]Ak[150,13,151,151,113,12,0,0]Ej[150,13,151,151,113,12,0,0,\"impl-From%3CMerkleProofInvalidRootChild%3E-for-VerifierError\"]Ak[150,13,151,151,160,13,0,0]Ak[150,13,151,151,158,13,0,0]Ak[150,13,151,151,159,13,0,0]Ak[150,13,151,151,113,13,0,0]Ad[66,23,67,0,0,0,0,0]Af[35,23,36,104,0,0,0,0]Af[103,23,104,0,0,0,0,0]Ak[150,13,151,151,160,31,0,0]Ak[150,13,151,151,158,31,0,0]Ak[150,13,151,151,159,31,0,0]Ab[35,3,0,0,0,0,0,0]Ak[150,13,151,151,160,39,0,0]Ak[150,13,151,151,158,39,0,0]Ak[150,13,151,151,159,39,0,0]Ak[150,13,151,151,113,39,0,0]Al[150,13,151,151,160,106,0,0]Al[150,13,151,151,158,106,0,0]Al[150,13,151,151,159,106,0,0]Ad[35,7,58,37,0,0,0,0]Ag[35,13,58,54,118,0,0,0]0Ac[35,2,36,0,0,0,0,0]Aj[150,13,151,151,160,5,0,0]Aj[150,13,151,151,158,5,0,0]Aj[150,13,151,151,159,5,0,0]Aj[150,13,151,151,113,5,0,0]Ae[35,18,58,37,0,0,0,0]Ab[66,3,0,0,0,0,0,0]8Ak[150,13,151,151,160,50,0,0]Ak[150,13,151,151,158,50,0,0]Ak[150,13,151,151,159,50,0,0]Ak[150,13,151,151,160,66,0,0]Ak[150,13,151,151,158,66,0,0]Ak[150,13,151,151,159,66,0,0]Ak[150,13,151,151,160,51,0,0]Ak[150,13,151,151,158,51,0,0]Ak[150,13,151,151,159,51,0,0]Ak[150,13,151,151,113,51,0,0]Ak[150,13,151,151,160,52,0,0]Ak[150,13,151,151,158,52,0,0]Ak[150,13,151,151,159,52,0,0]Ak[150,13,151,151,113,52,0,0]Ae[152,7,153,0,0,0,0,0]Ae[113,7,114,0,0,0,0,0]Ae[146,7,147,0,0,0,0,0]Ae[115,7,116,0,0,0,0,0]Ae[147,7,148,0,0,0,0,0]Ae[156,7,157,0,0,0,0,0]Ae[118,7,119,0,0,0,0,0]Ae[154,7,155,0,0,0,0,0]Ae[150,7,151,0,0,0,0,0]Ae[125,7,126,0,0,0,0,0]Ae[35,18,58,37,0,0,0,0]Ak[150,13,151,151,160,83,0,0]Ak[150,13,151,151,158,83,0,0]Ak[150,13,151,151,159,83,0,0]Ak[150,13,151,151,113,83,0,0]Ak[150,13,151,151,160,50,0,0]Ak[150,13,151,151,158,50,0,0]Ak[150,13,151,151,159,50,0,0]Ad[35,7,58,37,0,0,0,0]8Af[155,23,156,0,0,0,0,0]Ad[35,7,58,54,0,0,0,0]Af[152,18,153,0,0,0,0,0]Ae[150,6,151,0,0,0,0,0]Ac[113,3,0,0,0,0,0,0]5Ac[115,3,0,0,0,0,0,0]Ad[35,7,58,60,0,0,0,0]Ae[35,10,58,54,0,0,0,0]Ac[118,3,0,0,0,0,0,0]79Al[150,13,151,151,160,111,0,1]Al[150,13,151,151,158,111,0,1]Al[150,13,151,151,159,111,0,1]Ac[125,3,0,0,0,0,0,0]Ae[152,7,153,0,0,0,0,0]Ae[113,7,114,0,0,0,0,0]Ae[146,7,147,0,0,0,0,0]Ae[115,7,116,0,0,0,0,0]Ae[147,7,148,0,0,0,0,0]Ae[156,7,157,0,0,0,0,0]Ae[118,7,119,0,0,0,0,0]Ae[154,7,155,0,0,0,0,0]Ae[150,7,151,0,0,0,0,0]Ae[125,7,126,0,0,0,0,0]Ae[35,18,58,37,0,0,0,0]Aj[150,15,151,151,113,0,0,0]Ad[35,7,58,37,0,0,0,0]0000110Ac[146,3,0,0,0,0,0,0]Ac[147,3,0,0,0,0,0,0]22Ac[150,3,0,0,0,0,0,0]3Ac[152,3,0,0,0,0,0,0]4Ac[154,3,0,0,0,0,0,0]Ac[155,3,0,0,0,0,0,0]Ac[156,3,0,0,0,0,0,0]Ae[150,5,151,0,0,0,0,0]00")
\ No newline at end of file
@@ -0,0 +1 @@
+rd_("n[\"{{}AD`}\",[]]n[\"{{}ACl}\",[]]n[\"{{}ACn}\",[]]A`[\"{cc{}}\",[\"T\"]]00m[\"{O`Nb}\",[]]n[\"{AD`Nb}\",[]]n[\"{ACnNb}\",[]]3n[\"{AClNb}\",[]]Aa[\"{{}c{}}\",[\"U\"]]000Ag[\"{{{Kl{AD`}}}AD`}\",[]]Ag[\"{{{Kl{ACl}}}ACl}\",[]]Ag[\"{{{Kl{ACn}}}ACn}\",[]]Ai[\"{Kl{{Kl{c}}}{}}\",[\"T\"]]000Ac[\"{{}{{l{h}}}}\",[]]00m[\"{{}Af}\",[]]m[\"{{}Nl}\",[]]0m[\"{KlEd}\",[]]0002Ah[\"{{{Gb{h}}DlDl}Cl}\",[]]Aa[\"{Klc{}}\",[\"T\"]]00Bh[\"{{{Kl{AD`}}}c{}}\",[\"SolError::Token\"]]Bh[\"{{{Kl{ACl}}}c{}}\",[\"SolError::Token\"]]Bh[\"{{{Kl{ACn}}}c{}}\",[\"SolError::Token\"]]An[\"{c{{Eb{e}}}{}{}}\",[\"U\",\"T\"]]000Ai[\"{{}{{Eb{c}}}{}}\",[\"U\"]]000m[\"{{}Cl}\",[]]000000000m[\"{{}Kl}\",[]]Ba[\"{{{Kl{n}}}{{Kl{nc}}}{}}\",[\"T\"]]000Al[\"{{Kl{Kl{nc}}}Cl{}}\",[\"T\"]]00Ae[\"{{{Gb{nh}}}Cl}\",[]]3Bd[\"{eClMf{{E`{{Kl{nc}}}}}}\",[\"C\",\"\"]]m[\"{{}D`}\",[]]Bn[\"{{{Gb{h}}{Gb{h}}Dl{Gb{h}}Af{Gb{nDl}}}h}\",[]]7Ae[\"{{}{{L`{Cl}}}}\",[]]m[\"{{}Af}\",[]]Ag[\"{{Kl{Gb{nh}}}Cl}\",[]]00m[\"{DlDl}\",[]]000000000:Al[\"{{{Gb{h}}{Gb{nh}}}Cl}\",[]]8An[\"{{{Gb{h}}Dl{Gb{nh}}}Cl}\",[]]Ai[\"{{{Gb{nh}}DlDl}Dl}\",[]]m[\"{{}Dl}\",[]]m[\"{AlCl}\",[]]Bg[\"{{{Gb{h}}{Gb{h}}DlAf{Gb{nDl}}}h}\",[]]5Ak[\"{{{Gb{h}}{Gb{h}}}Cl}\",[]]1")
\ No newline at end of file
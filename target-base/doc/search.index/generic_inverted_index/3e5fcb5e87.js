@@ -0,0 +1 @@
+rb_("RWYABBYAAAAXAAAAGAAAABkAAAA7MAAAAQAACgAEAA4AAgAUAAAARAACAE4AAwA7MAAAAQAACgACACoAAwBHAAYAADswAAABAAAGAAEAYAAGAAABbQAAAEFsAAAABEoAAABLAAAATAAAAE0AAAAAAAABbQAAAA==")
\ No newline at end of file
@@ -0,0 +1 @@
+rd_("b()b->b[]bu8cAnycVeccmutcnew00cu64dFromdIntodbooldfrom0000000dinto000dtest00duniteCloneearrayeclone00emotsueshimseusizefBorrowfFnOncefResultfTypeIdfborrow000fencode00gToOwnedgTryFromgTryIntogchainidgcontextgdefault0gpointergprelude0gstoragegtype_id000hCHAIN_IDhSolErrorhe2e_prochemit_loghto_owned00htokenize00htry_from000htry_into000iBorrowMutimark_used000000000ireferencejMSG_SENDERjMutexGuardjborrow_mut000jclone_into00jmotsu_procjmsg_senderkMethodErrorkStorageTypelEOA_CODEHASHlinterface_idlwith_contextmCloneToUninitmTRANSFER_ROLEmVerifierErrormbasic_examplemcall_contractmerc20_examplemreset_storagenDefaultStoragenerc721_exampleoMultiProofErroroacquire_storageoblock_timestampoclone_to_uninit00oownable_exampleouser_entrypoint000000000A`CONTRACT_ADDRESSA`InvalidRootChildA`account_codehashA`contract_addressA`native_keccak256A`read_return_dataA`return_data_sizeAbInvalidProofLengthAbInvalidTotalHashesAcstorage_flush_cacheAdcryptography_exampleAderc20_permit_exampleAdstatic_call_contractAdstorage_load_bytes32Aemerkle_proofs_exampleAestorage_cache_bytes32Afaccess_control_exampleAfdelegate_call_contractAgerc721_metadata_exampleAhopenzeppelin_stylus_procAjerc721_consecutive_exampleAkMerkleProofInvalidRootChildAmMerkleProofInvalidTotalHashesBbMerkleProofInvalidMultiProofLength")
\ No newline at end of file
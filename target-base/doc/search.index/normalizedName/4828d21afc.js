@@ -0,0 +1 @@
+rd_("b()b->b[]bu8canycveccmutcnew00cu64dfromdintodbool222222221111dtest00dunitecloneearray111emotsueshimseusizefborrowffnoncefresultftypeid3333fencode00gtoownedgtryfromgtryintogchainidgcontextgdefault0gpointergprelude0gstorage::::5hsolerrorge2eprocgemitlog;;;htokenize00;;;;::::iborrowmuthmarkused000000000ireferenceimsgsenderjmutexguard4444icloneinto00imotsuproc3kmethoderrorkstoragetypekeoacodehashkinterfaceidkwithcontextmclonetouninitltransferrolemverifiererrorlbasicexamplelcallcontractlerc20examplelresetstoragendefaultstoragemerc721exampleomultiprooferrornacquirestoragenblocktimestamp;;;nownableexamplenuserentrypoint000000000ocontractaddressA`invalidrootchildoaccountcodehash2onativekeccak256nreadreturndatanreturndatasizeAbinvalidprooflengthAbinvalidtotalhashesAastorageflushcacheAccryptographyexampleAberc20permitexampleAbstaticcallcontractAbstorageloadbytes32AcmerkleproofsexampleAcstoragecachebytes32AdaccesscontrolexampleAddelegatecallcontractAeerc721metadataexampleAfopenzeppelinstylusprocAherc721consecutiveexampleAkmerkleproofinvalidrootchildAmmerkleproofinvalidtotalhashesBbmerkleproofinvalidmultiprooflength")
\ No newline at end of file
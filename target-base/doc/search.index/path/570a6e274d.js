@@ -0,0 +1 @@
+rd_("f[1,\"\"]000A`[10,\"core::any\"]Am[5,\"alloc::vec\",\"alloc::vec\"]f[0,\"\"]3Ad[10,\"core::convert\"]044Ab[10,\"core::clone\"]5f[3,\"\"]k[2,\"motsu\"]7Ac[10,\"core::borrow\"]Al[10,\"core::ops\",\"core::ops\"]Ba[6,\"core::result\",\"core::result\"]Ak[5,\"core::any\",\"core::any\"]Ad[10,\"alloc::borrow\"]885<55Bd[10,\"alloy_sol_types::types::error\"]75=Ci[5,\"std::sync::poison::mutex\",\"std::sync::poison::mutex\"]8An[10,\"stylus_sdk::call::error\"]D`[10,\"stylus_sdk::storage::traits\",\"stylus_sdk::storage::traits\"];Cc[6,\"merkle_proofs_example\",\"merkle_proofs_example\"];;Bf[10,\"motsu::prelude\",\"motsu::context\"]<Co[6,\"openzeppelin_crypto::merkle\",\"openzeppelin_crypto::merkle\"]========Cc[5,\"merkle_proofs_example\",\"merkle_proofs_example\"]00")
\ No newline at end of file
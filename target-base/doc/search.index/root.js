@@ -0,0 +1 @@
+rr_('{"normalizedName":{"I":"VQHBAAAfmwBwJQBHAEgASQCOACHAAAA1AG0AAUTacQAAAAIDBxUBAwICAlsDwwAAAF9hZ3QBQ4pTAAAABcDGAKIAAB+fABidABqeABOGAsYAAACWaXMFAsAAABaMALNHAGNubwH+AgQCBsEAAACMxwAAAHkBggPQAAAAnMUAAAB2zQAAAJpjZW0VAcAAABAKADSMACHBAACVAJcACQPQAAAAnMUAAAB2zQAAAJpjZW0FAsAAABZzAByTAGVwMwD8I0DAAAAAjMMAAABC0pUAAAACBYPAAAAafQBBMQBEAEUARgBRwgAAUgBgAGEAYgBjABDJdGhjb250ZXh0AABtAPIAhAJtbgDiBwAAACYAAAAqAAAAIDAFwMMAqgAAH58AGo8AKIkAnQAqkACeABDKaWZpZXJlcnJvcgAAcAAigAICoAAAAAXFAAAAnMcAAACMY3Jla/KAgoFlhsAAABZfABmcAAYAUgBgAGEAYgBjABUBxQAAE44Ac40ABQLFAAAUdwAVnwBlbBMBAsUAAAB1aXPhKAAAADYAAAABocEAADkAEMJ6ZQAAJQAASmVudHJ5cG9pbnSKfgAAAAASgAEB6QAAAFNyZDKAhgLEAAAAkcIAAACbaHBBwgAAbgB6AHsAfAAigAEBoAAAAB10bhKAhAHHAAAAinTGagAE/QmJBYMAAAAKoAAAAAPBAAAAX8gAAAB4wgAAAGc2OG5zAAEjAAAAFQHGAAATjgBzjQAVAcAAABNnAHAjAKHFAAB0APsCdHUFAsEAABacABiMAGVrBQLEAAAUdwAVnwBlbMvBAgrLAAAAlMUAAAB5JQLAAAAWbQAWaQBjb48AnwAFAsAAACOJAJ0AFooAaG8hxgAAkACeAAUCwAAAGZoAJIgAiwBhZFMA/RgYBsUAAACawAAAAHnCAAAAjs0AAACUAY0AAABQwWQAACkAPAA9AD4APwDyAWWEAAABagAAABKAAQHFAAAAm3BsAUSKfgAAAFDDbnRvAAAzAE4ATwBQAFEAUMNyb20AADIASgBLAEwATQDygAIBZmlwNYHBAAAmiACLAHIAlACZABDIc2ZlcnJvbGUAAG8A8oABAW5jMoACiMcAAACYYXlBxQAAbgB6AHsAfAAhyAAAkACeAEXAwwqBAAAblwAZkQAalQATagA7AHQAdQB4AEDEd25lZAAAMQBEAEUARgCwxWVuaXplAABHALYDAAL9QAANywAAAJJrbwDkDAAAABYAAAAzAAAATgAAAGQAAAADAyAFA8AAABSTABRfACA1AG0AYWd0FQHAAAAVbABmOAAggAEBogAAABo3dDPSgAHEAiDKAAAAmXP2bwD9EEBB/YUJCsEAAABDAOsGAAAAGgAAACgAAAA1AAAAUgAAAGAAAABtAAAAcgAAAHoAAAB+AAAAlAAAAJkAAAADAgMQKQAVAsAAABEoABNnAGxwIwAlAcAAAB2YAGOIAIsABQLAAAAXcQAhJQCOAGN6IcYAAF4AaAABSop+AAAAIcMAAF4AaADLxAakywAAAJzpAAAAU8YAAAB0xAAAAJYQw3lwZQAAagAQym9hZGJ5dGVzMzIAAJUAEMlsdXNoY2FjaGUAAJEAEMthY2hlYnl0ZXMzMgAAlwDyBHJhZ2XACoEAATsAAADhdAAAAHgAAAAQEM5pY2NhbGxjb250cmFjdAAAlAASgAEBwAAAAHl0bTIAiQHGAAAAm3kAgxoAAAAQxmxlcnJvcgAAQQAhwQAAkACeABDCbXMAACQAUgCEAsMAAACRY2UA0msAAAAf9NoAA/4EXSAFAAMETcwAAACYDMUAAABvDxDCAAAAmxESaG90ANUkAAAAZAMFDgFDin4AAAAbAs0AAACSb3RRwwAAMwBOAE8AUABRAFHDAAAyAEoASwBMAE0A+wNmaXBVAcAAAFJSAGAAYQBiAGMAbSYAKgArACwALQBBwAAAQQBpAHAAdwBrwgAhwAAAAG8ricAAAAAfFQHEAAATjgBzjQD5Al1fbHUFAsEAABZzAByTAGVw+QIAXDI3RcDBCoEAABuXABmRABqVABNqADsAdAB1AHgAPQDIEgwwAMcAAABvyAAAAJLgAAAAHwFHin4AAAArAsAAAABdY3ShwwAAOQAQw2l6ZQAAjgDyB3VybmRhdGEBAHMAAY0AAAAhwAAAiACLABDCbHQAACgAEMh0c3RvcmFnZQAAdABygJICxAAAAHhzdBDGZXJlbmNlAABdABDLZHJldHVybmRhdGEAAI0A9gOA/SEABsAAJcIAAABwdtQAgv0tdUHgAAAAX8QAAABsyAAAAHDIAAAAkyvj2vOGANc4AAAACR0KAQcHAUHacQAAAAIDBxUBAwICAlHBAAApADwAPQA+AD8AEwD9ACkBywAAAJvRAAAAm8kAAACTAWoAAAAhxQAAjwCfAGGB/RAJAsMAAAB3dADGAAAAljHAAABCAGcAmwD7AmNvoMRsdWRlAAA5APKAgogAA2ludIIAlwAAADgAin4AAAB2AQAC/ZCIBMcAAACSzQAAAJvNAAAAkm9yAAF5AAAABQLBAABDbgB6AHsAfAAYigBpdBUBwQAAE2cAcCMAIcQAAIkAnQAhxwAAkACeAPsDYWNzAUDjCwAAAA4AAAAyAAAASgAAAAcDFQLAAAATQQAVmAByeG8A8wCCAQ0AAACxxQAARwABAoLAAAAAOIp+AAAAFQHAAAAbkgByQwBp/RAJAsMAAAB3hhLGAAAAlrUCwQAAI2sAigATaQBoci4ANQHAAAAZeQBrQgBnAJsABQLAAAAXawAXlQBjZEHBAAAxAEQARQBGABDKYmxlZXhhbXBsZQAAfQDygIGC8AABAQCCbm0A4SYAAAAqAAAAAxDUZW56ZXBwZWxpbnN0eWx1c3Byb2MAAJsAAAAC/W19DRQ6/vYvWV1n3WoM3FdYawmaLqO1atBqPfBPkSil00V9TyuCc2SVWlNL15MH3n/6MhvbQUKgdS9dBpTr4x9VyYstDYvKBQVfMg5fSl66zQ/ncHcA5AwAAAAWAAAAMwAAAE4AAABkAAAAAwMgBcDEAKoAAB+fABqPACiJAJ0AKpAAngAFA8AAAB2cABZvABibAGVmdAXQwASEAAAgNABAAEJuAHoAewB8AFAdAG4AegB7AHwAsUcAIcIAAI8AnwAhwQAAXgBoABUBwgAAE44Ac40A+4MFisAAACAnAF0Asi4AQccAAG4AegB7AHwAscMAAGQAQcAAADEARABFAEYA4gABA6IAAAAHd2RpdADhHgAAACAAAAACEMxpdmVrZWNjYWsyNTYAAIwAEoABAcgAAAB9dGL2K4CD/gBTEMvCAAAAJ3v50GiN4AAAAATQAAAAm/EAAS5sAXkAAAAFAsEAABaTABJDAGVsEMZ4Z3VhcmQAAF8A8gCCAAEGAAAA4VIAAABgAAAAAxDEcnJvcgAAdwASh3RpcHJvb2aCAcQAAACfbPKAAgBsdCDGc2VuZGVyAABeAGgA8gABAGcAASQAAAAUAcN0c3UAABNyb2NnAHAjABDHb2RlcnJvcgAAaQASgAGBygAAAJpoEMdleGFtcGxlAACWAATAxm52YWxpZACiAAAfdWx0aXByb29mbGVuZ3RonwAYb290Y2hpbGSdABpvdGFsaGFzaGVzngDyiGtsZXByb29mAgBpczKAAgHDAAAAeXJ0cwBGcmt1c2VkilMAAAAA8gD9EUAKAmlwAOMLAAAADgAAADIAAABKAAAABwNRwQAAJQBHAEgASQCOAFUDwAAAFpMAF20AEkMAZWhsHQBuAHoAewB8AAUCwAAAFXkAECQAZXMhwQAAiQCdACHKAACQAJ4AIcgAAIkAnQDDAMAAqs4AAACfyQAAAI/jKQAAADQAAAA8AAAAbAAAAABABQLAAAAblAAWcQBjZQUCwAAAIDQAQABAbgB6AHsAfABkdABKb3RhbGhhc2hlcwGQAAAAAZ4AAAAASG9vdGNoaWxkAYkAAAABnQAAABDKcm9vZmxlbmd0aAAAjwByhGFsaWTAAKgBzgAAAJ9tAEDhDAAAABYAAAAD4jMAAABOAAAAZAAAAAMgEMVhY2VpZAAAbADyAXIBAGYAATgAAADyAIoAAIp+AAAAcoACAsgAAACbdHZpc3TugAH+AA+migAKC8UAAABwxwAAAHAOD5/HAAAAeBCgEW4FksAAACSPAJ8AF5EABcDFAKIAAB+fABidABqeACsCxQAAAHBlbgUCwQAAEW8AEHcAbHIjgQLCAAAAXWVypQHCAAAWdQBzNgArAsIAAABsY3UAQm9t4QsAAAAOAAAAB+EyAAAASgAAAAMQxG9uY2UAACcA9gOAAv0RCQLGAAAAlm5yQcYAAG4AegB7AHwATQH9gUBKygAAAJrGAAAAaQDFAAAAdLDBAAAAarWJwAAAEnkAFHgAGgDVAAD+BAQAB8vHAAAAdI8AwQAAACjSkAAAAA4RgQLSxgAAAJZpcyOBAuAAAABCY28rAs0AAACbcHKhwgAAOQCLns8AAACZygAAAJvIAAAAlQUCwAAAYCkAPAA9AD4APwBsALJkAGRuIcMAAGsAigClAcMAABZ1AHM2ABsDxAAAAF3HAAAAkWFlbCHGAAB9AJwAAUDiMQAAAEQAAABTAAAAApAFAsAAABmXABmZAGNsEwADxAAAAIzKAAAAnGFjdQEFAAAABQLBAAARbwBAQQBpAHAAdwBschGHAuDGAAAAlmlzGQJ8wgAAAHBuchDOZXRhZGF0YWV4YW1wbGUAAJoAEMZ4YW1wbGUAAHYAENFvbnNlY3V0aXZlZXhhbXBsZQAAnADygjIxAwBjZW0EAsEwAAAWeGFtcGxlcwAcZXJtaXRleGFtcGxlkwBlcPKAAgAyNzYFAAH9MBUBxAAAAGzIAAAAcMgAAACTYwDTOAAAACYKEMlhY29kZWhhc2gAAGsAscIAAEcAIcIAAF4AaACwwmRlAAAuABKAiILgAAAAXfQBgAH9SAGEAAJaA4rQAAAAm2MQxWl0bG9nAABDABDFZXByb2MAAEIAAAD+AgCcAP3/jTYezUEEbyQ11movTvv6/N5Xbsnj33odoG6fygAAAI3FAAAAl22LtakKFoqj0PccD66xYwXy39Y+zgAAAJlvkk7NBTZ5RpoXxwAAAIxIYeZoVj8FxxutPLQFF0hV2ncJQuIAAAAHaA7NaJYAOjAAAAEAAAAAACAAEAAAAB4AIAAhACIAJQAnAC4ALwAwADkAOgA7AEcASABJAF0AagBvAHEAcwB0AHUAdgB4AH0AjgCRAJIAkwCWAJgAmgCcACHHAACJAJ0ABQLAAAAhiACLABeNAHN0+4ohxAAAiACLABUCwgAAFpoAE44AZXONACUBwAAAEmkAcl4AaAAQ0WVnYXRlY2FsbGNvbnRyYWN0AACZAKQBxGF1bHQAABZ0b3JhZ2V1AHM2APAAAgIAASECZmxocgDhLgAAADkAAAAhtAwAgv0LkAUABMUAAACVBc4AAACfyQAAAI8GRQDpKQAAADEAAAA0AAAAPAAAAEQAAABTAAAAXwAAAGwAAACJAAAAnQAAAAAEKQAANYHAAAAmiACLAHIAlACZACUDwAAAEWwAH5gAFXEAaXN4JwBdAAUCwAAAE4wAGZQAa2wbi88AAACYygAAAIoQ0XlwdG9ncmFwaHlleGFtcGxlAACSALUBwQAAI2sAigBoLgAgxmRkcmVzcwAAiACLAPICY3SBAADTcgAAACIFEoCBiMcAAACYAEJ4dAE1AAAAAW0AAADygAIAZXISgAEBzQAAAJx0czKAAQLJAAAAim5kdUQCw29uZQAAs250b2QAR291bmluaXRuAHoAewB8AGl0HgAgACEAIgAhwgAAiQCdABUBwAAAFpcAYpEAIMRpbmlkAAA0AEAA8oCBhhUBwgAAFpcAYpEAAElsY29udHJhY3QBcgAAANKUAAAABTKAAQLCAAAAjGxjawAA/YFIAQgU2UvyeER16dnFXkdlVrsO8yEx+jyec6WoHUnqo90Zkqee2WWk4sLORLZl0R/IAAAAecoAAAB4dwKLKnbKAAAAnDI3Y2VrcXR1ANQFAAAAPSU0IcUAAJUAlwBUAcNyb3cAAFJ1dFIAYABhAGIAYwBtJgAqACsALAAtACKAAgCRAAAADW9yEMtja3RpbWVzdGFtcAAAeQASgIiCxgAAAH0QynNpY2V4YW1wbGUAAHEA8oADAWFsb3kFAsAAAByUABqMAGN2FQLAAAAWmgATjgBlc40AW4fLAAAAmQUCwAAAF3EAEY4AY3olgsAAACGQAJ4AawCKAPsCaGnxAQGubAF5AAAAMcgAAHIAlACZAAXAwQCqAAAfnwAajwAoiQCdACqQAJ4AIcUAAJAAngD7A2hpbCHDAAA0AEAABf3ACwABAAAZmgAWlQAkiACLABmNAAFEilMAAAAQwmF5AAAfAFKAAQLgAAAAX3JkawCAAQGgAAAABMYAAABveXMVAcEAABaXAGKRABDLdWlyZXN0b3JhZ2UAAHgAEMt1bnRjb2RlaGFzaAAAigAQ0HNzY29udHJvbGV4YW1wbGUAAJgA8oCKALQCgAL9kEAEAAPBAAAAbATFAAAAazJjcQAAwAIk/VqdTldEyO01Y5RC3vwkxF1VZ8gAAAB9YteMiZTFAAAAmmflGIUibRd8tD7CAAAAjBeviRkGQ/Ea7sbIAAAAkiwuK2TREywfqlcbUsvF1uAAAAAfAAGNAAAAEMFdAAACABDBPgAAAQAQwSkAAAAAAIDwAAAAAAAhAAAAAAAIfuL9APAAAAAAAAL/QQAAACCAGQIHRksx9MA0K0WZXx3K6S1NZgEDxQEMBvU0a38rs/hcQ1o8OkEF0ZfsvxgucMO4Sb7jEVZYvJEgGDZamGAvUJtJ31cHYjIYYiS1/SqQVQvA1954m6BLuWL1tVkjSU4gWRYAQ0slAuAAAAAADFIVwesSMT0RnyhgdOwMOHuWsrTgAAAACsAAAACMHcG3Nd8Zkqee2eAAAAAD4AAAAAHgAAAAAh0OZoVIDDkj0yQnNdKBFm62B0ylygAAAHhoDs1olghE7iMXZezBVrQ=","N":"J`","E":"OjAAAAAAAAA=","H":"SCjSGvw="},"crateNames":{"N":"n","E":"OjAAAAAAAAA=","H":"toWXAqQ="},"name":{"N":"J`","E":"OjAAAAAAAAA=","H":"Yf2j0M4="},"path":{"N":"Cf","E":"OzAAAAEAAGkAFgAHAAIADgAOACAAAgAqAAYANAAAADYAAQA6AAAAPAAEAEMADgBTAAkAXgAAAGAABgBoAAAAawACAG8AAAByAAAAdAAAAHgABAB+ABMAlAABAJcAAACZAAAA","H":"VwpuJ00="},"entry":{"N":"Gl","E":"OzAAAAEAACMAEAAAAAYACgADAB0AAgAkAAUAMQACADUAAAA4AAEAOwAAAEEAAABSAAAAXQAAAF8AAABnAAAAaQABAG4AAAB3AAAA","H":"O4Av2uY="},"desc":{"N":"Bm","E":"OzAAAAEAAHIAFwAAAA0AEQACABUAAAAdAAUAJAAPADUAAAA4AAEAOwAEAEEAAABEABkAXwAHAGkAAQBuAAMAcwAAAHYAAQB6AA0AiQAAAI8AAQCSAAEAlgAAAJgAAACaAAAAnAAAAA==","H":"o84J/BA="},"function":{"N":"Fb","E":"OzAAAAEAAD0AGQAAAAYACgADABoABQAjAAYAMQACADUAAAA4AAMAQQABAFIAAABdAAAAXwAAAGcAAABpAAEAbAAAAG4AAABwAAEAcwAAAHUAAgB9AAAAiQAAAI8AAQCSAAEAlgAAAJgAAACaAAUA","H":"WLb7yKk="},"type":{"N":"Ah","E":"OzAAAAEAAIcADwAEAAAABwACAAsAAQAOAA4AHgAAACAABAAmAAAAKgANADkAIwBeAAAAYAAJAGsABABxAAMAdgAAAHgAJAA=","H":"vgNio2Q="},"alias":{"N":"`","E":"OzAAAAEAAJ8AAQAAAJ8A","H":"YhUMUyw="},"generic_inverted_index":{"N":"b","E":"OjAAAAAAAAA=","H":"Pl/LXoc="}}')
\ No newline at end of file
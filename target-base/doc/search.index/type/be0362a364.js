@@ -0,0 +1 @@
+rd_("Go[\"\",\"ADswAAABAAAKAAIAUwAJAHQAAAACeAAAAJEAAAAAAmgAAACLAAAAOjAAAAEAAAAAAAcAEAAAAEMAZABlAGYAegB7AHwAlwACigAAAJUAAAACbQAAAIwAAAA=\"]Ag[\"AAAAAAAAAAFtAAAA\",\"\"]Bg[\"\",\"AAAAAAAAAAAAAAAAAAAAAAABbwAAAA==\"]Ig[\"AAAAAAJoAAAAiwAAAAVDAAAAegAAAHsAAAB8AAAAlwAAAAOKAAAAjQAAAJUAAAABjAAAAAAAApQAAACZAAAAAAFyAAAA\",\"AAADLgAAAC8AAAAwAAAAAAAAAAAAAAKUAAAAmQAAAAABcgAAAA==\"]Ak[\"\",\"AAADLgAAAC8AAAAwAAAA\"]Hg[\"AAAAAAJoAAAAiwAAADswAAABAAAJAAIAYAAGAHoAAgADigAAAI0AAACVAAAAAm0AAACMAAAAAAAClAAAAJkAAAAAAXIAAAA=\",\"AAAAAAAEYAAAAGEAAABiAAAAYwAAAA==\"]Ec[\"AAAAAAAAAAAAAAKUAAAAmQAAAAABcgAAAA==\",\"AAI0AAAAeQAAAAAAAAAAAAAAAAAAAAAAAAFAAAAA\"]Ac[\"AAABkQAAAA==\",\"\"]75Gc[\"AAA7MAAAAQAACQABAH4ACQAAAAFDAAAAAY0AAAABjAAAAAAAApQAAACZAAAAAAFyAAAA\",\"AAGOAAAAOzAAAAEAAAkAAQB+AAkAAAAAAY0AAAA=\"]7Ci[\"\",\"AAAETgAAAE8AAABQAAAAUQAAAARKAAAASwAAAEwAAABNAAAA\",1]Bc[\"\",\"AAAEPAAAAD0AAAA+AAAAPwAAAA==\"]Fe[\"AAAAAAJoAAAAiwAAAAVDAAAAegAAAHsAAAB8AAAAlwAAAAOKAAAAjQAAAJUAAAABjAAAAAAAApQAAACZAAAAAAFyAAAA\",\"\",1]Km[\"AAA7MAAAAQAABgACADwAAwBEAAIAOzAAAAEAAAkAAwAgAAIAKgADAEcAAgAAOzAAAAEAAAkAAgBgAAYAegACAAABbQAAAA==\",\"AAAABCoAAAArAAAALAAAAC0AAAAABGAAAABhAAAAYgAAAGMAAAAAAAAAAAAAAAAAAANeAAAAawAAAIgAAAA=\",1]Ac[\"\",\"AAABeAAAAA==\"]<Bc[\"\",\"AAAEEQAAABIAAAATAAAAFQAAAA==\"]Ag[\"\",\"AAI2AAAANwAAAA==\"]Ac[\"AAABEQAAAA==\",\"\"]Bo[\"AAABFQAAAAIhAAAASAAAAA==\",\"AAEIAAAAAAEhAAAA\"]Bo[\"AAABEwAAAAIiAAAASQAAAA==\",\"AAEJAAAAAAEiAAAA\"]Bo[\"AAABEgAAAAIgAAAARwAAAA==\",\"AAEHAAAAAAEgAAAA\"]")
\ No newline at end of file
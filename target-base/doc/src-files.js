@@ -0,0 +1,2 @@
+createSrcSidebar('[["access_control_example",["",[],["lib.rs"]]],["basic_example",["",[],["lib.rs"]]],["cryptography_example",["",[],["lib.rs"]]],["e2e_proc",["",[],["lib.rs","test.rs"]]],["erc20_example",["",[],["lib.rs"]]],["erc20_permit_example",["",[],["lib.rs"]]],["erc721_consecutive_example",["",[],["lib.rs"]]],["erc721_example",["",[],["lib.rs"]]],["erc721_metadata_example",["",[],["lib.rs"]]],["merkle_proofs_example",["",[],["lib.rs"]]],["motsu",["",[],["context.rs","lib.rs","prelude.rs","shims.rs","storage.rs"]]],["motsu_proc",["",[],["lib.rs","test.rs"]]],["openzeppelin_stylus_proc",["",[],["interface_id.rs","lib.rs"]]],["ownable_example",["",[],["lib.rs"]]]]');
+//{"start":19,"fragment_lengths":[45,37,44,42,37,44,50,38,47,45,79,44,66,39]}
\ No newline at end of file
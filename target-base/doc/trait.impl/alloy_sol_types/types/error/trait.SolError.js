@@ -0,0 +1,9 @@
+(function() {
+    const implementors = Object.fromEntries([["merkle_proofs_example",[["impl SolError for <a class=\"struct\" href=\"merkle_proofs_example/struct.MerkleProofInvalidMultiProofLength.html\" title=\"struct merkle_proofs_example::MerkleProofInvalidMultiProofLength\">MerkleProofInvalidMultiProofLength</a>",0],["impl SolError for <a class=\"struct\" href=\"merkle_proofs_example/struct.MerkleProofInvalidRootChild.html\" title=\"struct merkle_proofs_example::MerkleProofInvalidRootChild\">MerkleProofInvalidRootChild</a>",0],["impl SolError for <a class=\"struct\" href=\"merkle_proofs_example/struct.MerkleProofInvalidTotalHashes.html\" title=\"struct merkle_proofs_example::MerkleProofInvalidTotalHashes\">MerkleProofInvalidTotalHashes</a>",0]]]]);
+    if (window.register_implementors) {
+        window.register_implementors(implementors);
+    } else {
+        window.pending_implementors = implementors;
+    }
+})()
+//{"start":59,"fragment_lengths":[699]}
\ No newline at end of file
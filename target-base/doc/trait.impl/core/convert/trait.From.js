@@ -0,0 +1,9 @@
+(function() {
+    const implementors = Object.fromEntries([["merkle_proofs_example",[["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/nightly/core/convert/trait.From.html\" title=\"trait core::convert::From\">From</a>&lt;<a class=\"enum\" href=\"merkle_proofs_example/enum.VerifierError.html\" title=\"enum merkle_proofs_example::VerifierError\">VerifierError</a>&gt; for <a class=\"struct\" href=\"https://doc.rust-lang.org/nightly/alloc/vec/struct.Vec.html\" title=\"struct alloc::vec::Vec\">Vec</a>&lt;<a class=\"primitive\" href=\"https://doc.rust-lang.org/nightly/std/primitive.u8.html\">u8</a>&gt;",0],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/nightly/core/convert/trait.From.html\" title=\"trait core::convert::From\">From</a>&lt;<a class=\"struct\" href=\"merkle_proofs_example/struct.MerkleProofInvalidMultiProofLength.html\" title=\"struct merkle_proofs_example::MerkleProofInvalidMultiProofLength\">MerkleProofInvalidMultiProofLength</a>&gt; for <a class=\"enum\" href=\"merkle_proofs_example/enum.VerifierError.html\" title=\"enum merkle_proofs_example::VerifierError\">VerifierError</a>",0],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/nightly/core/convert/trait.From.html\" title=\"trait core::convert::From\">From</a>&lt;<a class=\"struct\" href=\"merkle_proofs_example/struct.MerkleProofInvalidRootChild.html\" title=\"struct merkle_proofs_example::MerkleProofInvalidRootChild\">MerkleProofInvalidRootChild</a>&gt; for <a class=\"enum\" href=\"merkle_proofs_example/enum.VerifierError.html\" title=\"enum merkle_proofs_example::VerifierError\">VerifierError</a>",0],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/nightly/core/convert/trait.From.html\" title=\"trait core::convert::From\">From</a>&lt;<a class=\"struct\" href=\"merkle_proofs_example/struct.MerkleProofInvalidTotalHashes.html\" title=\"struct merkle_proofs_example::MerkleProofInvalidTotalHashes\">MerkleProofInvalidTotalHashes</a>&gt; for <a class=\"enum\" href=\"merkle_proofs_example/enum.VerifierError.html\" title=\"enum merkle_proofs_example::VerifierError\">VerifierError</a>",0],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/nightly/core/convert/trait.From.html\" title=\"trait core::convert::From\">From</a>&lt;MultiProofError&gt; for <a class=\"enum\" href=\"merkle_proofs_example/enum.VerifierError.html\" title=\"enum merkle_proofs_example::VerifierError\">VerifierError</a>",0]]]]);
+    if (window.register_implementors) {
+        window.register_implementors(implementors);
+    } else {
+        window.pending_implementors = implementors;
+    }
+})()
+//{"start":59,"fragment_lengths":[2380]}
\ No newline at end of file
@@ -0,0 +1,9 @@
+(function() {
+    const implementors = Object.fromEntries([["merkle_proofs_example",[["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/nightly/core/marker/trait.Send.html\" title=\"trait core::marker::Send\">Send</a> for <a class=\"enum\" href=\"merkle_proofs_example/enum.VerifierError.html\" title=\"enum merkle_proofs_example::VerifierError\">VerifierError</a>",0,1,["merkle_proofs_example::VerifierError"]],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/nightly/core/marker/trait.Send.html\" title=\"trait core::marker::Send\">Send</a> for <a class=\"struct\" href=\"merkle_proofs_example/struct.MerkleProofInvalidMultiProofLength.html\" title=\"struct merkle_proofs_example::MerkleProofInvalidMultiProofLength\">MerkleProofInvalidMultiProofLength</a>",0,1,["merkle_proofs_example::MerkleProofInvalidMultiProofLength"]],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/nightly/core/marker/trait.Send.html\" title=\"trait core::marker::Send\">Send</a> for <a class=\"struct\" href=\"merkle_proofs_example/struct.MerkleProofInvalidRootChild.html\" title=\"struct merkle_proofs_example::MerkleProofInvalidRootChild\">MerkleProofInvalidRootChild</a>",0,1,["merkle_proofs_example::MerkleProofInvalidRootChild"]],["impl <a class=\"trait\" href=\"https://doc.rust-lang.org/nightly/core/marker/trait.Send.html\" title=\"trait core::marker::Send\">Send</a> for <a class=\"struct\" href=\"merkle_proofs_example/struct.MerkleProofInvalidTotalHashes.html\" title=\"struct merkle_proofs_example::MerkleProofInvalidTotalHashes\">MerkleProofInvalidTotalHashes</a>",0,1,["merkle_proofs_example::MerkleProofInvalidTotalHashes"]]]]]);
+    if (window.register_implementors) {
+        window.register_implementors(implementors);
+    } else {
+        window.pending_implementors = implementors;
+    }
+})()
+//{"start":59,"fragment_lengths":[1589]}
\ No newline at end of file
@@ -0,0 +1,9 @@
+(function() {
+    const implementors = Object.fromEntries([["motsu",[]]]);
+    if (window.register_implementors) {
+        window.register_implementors(implementors);
+    } else {
+        window.pending_implementors = implementors;
+    }
+})()
+//{"start":59,"fragment_lengths":[12]}
\ No newline at end of file